@@ -71,11 +71,7 @@ fn spi_query_title(title: &str) -> Option<i64> {
 fn spi_query_by_id(id: i64) -> Option<String> {
     let (returned_id, title) = Spi::connect(|client| {
         let tuptable = client
-            .select(
-                "SELECT id, title FROM spi.spi_example WHERE id = $1",
-                None,
-                Some(vec![(PgBuiltInOids::INT8OID.oid(), id.into_datum())]),
-            )
+            .select("SELECT id, title FROM spi.spi_example WHERE id = $1", None, (id,))
             .first();
 
         Ok(Some(tuptable.get_two::<i64, String>()))
@@ -7,7 +7,7 @@ All rights reserved.
 Use of this source code is governed by the MIT license that can be found in the LICENSE file.
 */
 use pgx::prelude::*;
-use pgx::{IntoDatum, SpiTupleTable};
+use pgx::SpiTupleTable;
 
 pgx::pg_module_magic!();
 
@@ -76,30 +76,23 @@ fn filter_by_breed(
     ),
 > {
     /*
-        This function is a simple example of using SPI to return a set of rows
+        This function is a simple example of using SPI to stream a set of rows
         from a query. This query will return the records for the given breed.
+
+        Rather than collecting every row into a `Vec` first, the rows are fetched
+        from a cursor in batches of 100 as Postgres asks for them.
     */
 
     let query = "SELECT * FROM spi_srf.dog_daycare WHERE dog_breed = $1;";
-    let args = vec![(PgBuiltInOids::TEXTOID.oid(), breed.into_datum())];
-
-    let mut results = Vec::new();
 
     Spi::connect(|client| {
-        let mut tup_table: SpiTupleTable = client.select(query, None, Some(args));
+        let cursor = client.open_cursor(query, (breed,));
 
-        while let Some(row) = tup_table.next() {
-            results.push((
-                row["dog_name"].value(),
-                row["dog_age"].value(),
-                row["dog_breed"].value(),
-            ));
-        }
-
-        Ok(Some(()))
-    });
-
-    TableIterator::new(results.into_iter())
+        Ok(Some(TableIterator::from_cursor(cursor, 100, |row| {
+            (row["dog_name"].value(), row["dog_age"].value(), row["dog_breed"].value())
+        })))
+    })
+    .unwrap()
 }
 
 #[cfg(any(test, feature = "pg_test"))]
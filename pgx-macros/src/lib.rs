@@ -24,9 +24,11 @@ use pgx_sql_entity_graph::{
 };
 
 use crate::rewriter::PgGuardRewriter;
+use crate::spi::impl_from_spi_row;
 
 mod operators;
 mod rewriter;
+mod spi;
 
 /// Declare a function as `#[pg_guard]` to indicate that it is called from a Postgres `extern "C"`
 /// function so that Rust `panic!()`s (and Postgres `elog(ERROR)`s) will be properly handled by `pgx`
@@ -455,6 +457,7 @@ Optionally accepts the following attributes:
 * `parallel_safe`: Corresponds to [`PARALLEL SAFE`](https://www.postgresql.org/docs/current/sql-createfunction.html).
 * `parallel_unsafe`: Corresponds to [`PARALLEL UNSAFE`](https://www.postgresql.org/docs/current/sql-createfunction.html).
 * `parallel_restricted`: Corresponds to [`PARALLEL RESTRICTED`](https://www.postgresql.org/docs/current/sql-createfunction.html).
+* `materialize`: Return all of a `SetOfIterator` or `TableIterator`'s rows at once, in a [`Tuplestore`](pgx::Tuplestore), rather than one row per call.
 * `no_guard`: Do not use `#[pg_guard]` with the function.
 * `sql`: Same arguments as [`#[pgx(sql = ..)]`](macro@pgx).
 * `name`: Specifies target function name. Defaults to Rust function name.
@@ -595,6 +598,56 @@ pub fn pg_extern(attr: TokenStream, item: TokenStream) -> TokenStream {
     }
 }
 
+/**
+Declare a function as `#[pg_procedure]` to create it as a Postgres `PROCEDURE`, which is invoked
+with `CALL` rather than `SELECT`.
+
+It accepts the same arguments and attributes as [`macro@pg_extern`], except those which only apply
+to functions: `immutable`, `stable`, `volatile`, `strict`, `parallel_*`, `cost` and operator
+attributes.  Procedures cannot return a value.
+
+Unlike functions, procedures may commit or roll back the current transaction, using a `SpiClient`
+from `Spi::connect_nonatomic`, given the procedure's own `fcinfo`:
+
+```rust,ignore
+use pgx::*;
+#[pg_procedure]
+fn insert_in_batches(fcinfo: pg_sys::FunctionCallInfo, batches: i32) {
+    // SAFETY: `fcinfo` is the one Postgres gave us
+    unsafe {
+        Spi::connect_nonatomic(fcinfo, |client| {
+            for i in 0..batches {
+                client.update("INSERT INTO batches VALUES ($1)", None, (i,));
+                client.commit();
+            }
+            Ok(Some(()))
+        });
+    }
+}
+```
+
+```sql
+CALL insert_in_batches(10);
+```
+*/
+#[proc_macro_attribute]
+pub fn pg_procedure(attr: TokenStream, item: TokenStream) -> TokenStream {
+    fn wrapped(attr: TokenStream, item: TokenStream) -> Result<TokenStream, syn::Error> {
+        let pg_procedure_item = PgExtern::procedure(attr.into(), item.into())?;
+        Ok(pg_procedure_item.to_token_stream().into())
+    }
+
+    match wrapped(attr, item) {
+        Ok(tokens) => tokens,
+        Err(e) => {
+            let msg = e.to_string();
+            TokenStream::from(quote! {
+              compile_error!(#msg);
+            })
+        }
+    }
+}
+
 /**
 Generate necessary bindings for using the enum with PostgreSQL.
 
@@ -1029,6 +1082,37 @@ pub fn postgres_hash(input: TokenStream) -> TokenStream {
     impl_postgres_hash(ast).unwrap_or_else(syn::Error::into_compile_error).into()
 }
 
+/**
+Generate a `pgx::spi::FromSpiRow` implementation so rows returned by SPI can be converted into this struct.
+
+Each field is read from the column of the same name.  Fields of tuple structs are read by position.
+
+```rust,ignore
+use pgx::prelude::*;
+
+#[derive(FromSpiRow)]
+struct Dog {
+    name: String,
+    #[spi(name = "scritches")]
+    pets: Option<i32>,
+    #[spi(ordinal = 3)]
+    age: i64,
+}
+```
+Fields accept the following attributes:
+
+* `name`: The name of the column to read, when it differs from the field name.
+* `ordinal`: The 1-based position of the column to read.
+
+Fields whose type is an `Option<T>` receive `None` for a SQL `NULL`.  A `NULL` in any other field is
+reported as an error.
+*/
+#[proc_macro_derive(FromSpiRow, attributes(spi))]
+pub fn from_spi_row(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as syn::DeriveInput);
+    impl_from_spi_row(ast).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
 /**
 Declare a `pgx::Aggregate` implentation on a type as able to used by Postgres as an aggregate.
 
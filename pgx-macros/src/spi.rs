@@ -0,0 +1,156 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, GenericArgument, Lit, Meta, NestedMeta, PathArguments, Type};
+
+/// How a struct field finds its column in a `SpiHeapTupleData`
+enum ColumnRef {
+    Name(String),
+    Ordinal(usize),
+}
+
+pub(crate) fn impl_from_spi_row(ast: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &ast.data {
+        Data::Struct(s) => &s.fields,
+        _ => {
+            return Err(syn::Error::new(
+                ast.span(),
+                "#[derive(FromSpiRow)] can only be applied to structs",
+            ))
+        }
+    };
+
+    let mut field_values = Vec::new();
+    for (idx, field) in fields.iter().enumerate() {
+        let default = match &field.ident {
+            Some(ident) => ColumnRef::Name(ident.to_string().trim_start_matches("r#").to_string()),
+            None => ColumnRef::Ordinal(idx + 1),
+        };
+        let column = column_ref(&field.attrs)?.unwrap_or(default);
+
+        let (ty, nullable) = match option_inner(&field.ty) {
+            Some(inner) => (inner, true),
+            None => (&field.ty, false),
+        };
+
+        let (lookup, column_label) = match column {
+            ColumnRef::Name(name) => {
+                (quote! { ::pgx::spi::from_spi_row::by_name::<#ty>(row, #name)? }, name)
+            }
+            ColumnRef::Ordinal(ordinal) => (
+                quote! { ::pgx::spi::from_spi_row::by_ordinal::<#ty>(row, #ordinal)? },
+                format!("#{}", ordinal),
+            ),
+        };
+
+        let value = if nullable {
+            lookup
+        } else {
+            quote! { ::pgx::spi::from_spi_row::not_null(#lookup, #column_label)? }
+        };
+
+        field_values.push(match &field.ident {
+            Some(ident) => quote! { #ident: #value },
+            None => value,
+        });
+    }
+
+    let construct = match fields {
+        Fields::Named(_) => quote! { Self { #(#field_values),* } },
+        Fields::Unnamed(_) => quote! { Self ( #(#field_values),* ) },
+        Fields::Unit => quote! { Self },
+    };
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::pgx::spi::FromSpiRow for #name #ty_generics #where_clause {
+            fn from_spi_row(
+                row: &::pgx::spi::SpiHeapTupleData,
+            ) -> ::core::result::Result<Self, ::pgx::spi::FromSpiRowError> {
+                ::core::result::Result::Ok(#construct)
+            }
+        }
+    })
+}
+
+/// Parse `#[spi(name = "column")]` or `#[spi(ordinal = N)]` from a field's attributes
+fn column_ref(attrs: &[syn::Attribute]) -> syn::Result<Option<ColumnRef>> {
+    let mut column = None;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("spi")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            other => {
+                return Err(syn::Error::new(
+                    other.span(),
+                    "expected `#[spi(name = \"...\")]` or `#[spi(ordinal = N)]`",
+                ))
+            }
+        };
+
+        for nested in list.nested.iter() {
+            let name_value = match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) => nv,
+                other => {
+                    return Err(syn::Error::new(
+                        other.span(),
+                        "expected `name = \"...\"` or `ordinal = N`",
+                    ))
+                }
+            };
+
+            if column.is_some() {
+                return Err(syn::Error::new(
+                    name_value.span(),
+                    "a field can only be mapped to one column",
+                ));
+            }
+
+            column = Some(match (&name_value.lit, name_value.path.get_ident()) {
+                (Lit::Str(s), Some(ident)) if ident == "name" => ColumnRef::Name(s.value()),
+                (Lit::Int(i), Some(ident)) if ident == "ordinal" => {
+                    let ordinal = i.base10_parse::<usize>()?;
+                    if ordinal == 0 {
+                        return Err(syn::Error::new(i.span(), "column ordinals are 1-based"));
+                    }
+                    ColumnRef::Ordinal(ordinal)
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        name_value.span(),
+                        "expected `name = \"...\"` or `ordinal = N`",
+                    ))
+                }
+            });
+        }
+    }
+    Ok(column)
+}
+
+/// If `ty` is spelled `Option<T>`, return `T`
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => &type_path.path,
+        _ => return None,
+    };
+    let last = path.segments.last()?;
+    if last.ident != "Option" {
+        return None;
+    }
+    match &last.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first()? {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
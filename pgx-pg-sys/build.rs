@@ -371,6 +371,17 @@ fn impl_pg_node(
         }
     }
 
+    // the `NodeTag` constants, so nodes with a tag of their own can say what it is
+    let node_tags = items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Const(c) if c.ident.to_string().starts_with("NodeTag_T_") => {
+                Some(c.ident.to_string())
+            }
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
     // the set of types which subclass `Node` according to postgres' object system
     let mut node_set = HashSet::new();
     // fill in any children of the roots with a recursive DFS
@@ -380,6 +391,20 @@ fn impl_pg_node(
         dfs_find_nodes(root, &struct_graph, &mut node_set);
     }
 
+    // every node struct by name and the type aliases bindgen emitted, so that
+    // `impl_serialize_node` can decide how to serialize each field
+    let node_structs = node_set
+        .iter()
+        .map(|node| (node.struct_.ident.to_string(), node.struct_))
+        .collect::<HashMap<_, _>>();
+    let type_aliases = items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Type(t) => Some((t.ident.to_string(), &*t.ty)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
     let nodes: Box<dyn std::iter::Iterator<Item = StructDescriptor>> = if is_for_release {
         // if it's for release we want to sort by struct name to avoid diff churn
         let mut set = node_set.into_iter().collect::<Vec<_>>();
@@ -390,6 +415,9 @@ fn impl_pg_node(
         Box::new(node_set.into_iter())
     };
 
+    // the arms of `serialize_node()`, which dispatches on a node's runtime tag
+    let mut serialize_arms = proc_macro2::TokenStream::new();
+
     // now we can finally iterate the Nodes and emit out Display impl
     for node_struct in nodes {
         let struct_name = &node_struct.struct_.ident;
@@ -400,6 +428,30 @@ fn impl_pg_node(
             impl pg_sys::PgNode for #struct_name {}
         });
 
+        // and the PgTaggedNode trait for those with a NodeTag of their own
+        let node_tag = format!("NodeTag_T_{}", struct_name);
+        if node_tags.contains(&node_tag) {
+            let node_tag = syn::Ident::new(&node_tag, proc_macro2::Span::call_site());
+            pgnode_impls.extend(quote! {
+                impl pg_sys::PgTaggedNode for #struct_name {
+                    const NODE_TAG: pg_sys::NodeTag = pg_sys::#node_tag;
+                }
+            });
+            serialize_arms.extend(quote! {
+                pg_sys::#node_tag => serde::Serialize::serialize(&*node.cast::<#struct_name>(), serializer),
+            });
+        }
+
+        // `List` and (before Postgres 15) `Value` have hand-written impls, as what they hold
+        // depends on their tag rather than being described by their fields
+        if struct_name != "List" && struct_name != "Value" {
+            pgnode_impls.extend(impl_serialize_node(
+                node_struct.struct_,
+                &node_structs,
+                &type_aliases,
+            ));
+        }
+
         // impl Rust's Display trait for all nodes
         pgnode_impls.extend(quote! {
             impl std::fmt::Display for #struct_name {
@@ -410,9 +462,155 @@ fn impl_pg_node(
         });
     }
 
+    // and the function that serializes any `Node` pointer as whatever node it actually is
+    pgnode_impls.extend(quote! {
+        pub(crate) unsafe fn serialize_node<S: serde::Serializer>(
+            node: *const pg_sys::Node,
+            serializer: S,
+        ) -> std::result::Result<S::Ok, S::Error> {
+            match (*node).type_ {
+                #serialize_arms
+                _ => pg_sys::node_serde::serialize_untyped_node(node, serializer),
+            }
+        }
+    });
+
     Ok(pgnode_impls)
 }
 
+/// How a field of a `Node` struct is represented when the node is serialized
+enum NodeField {
+    /// an integer, float or bool that serializes as itself
+    Scalar,
+    /// a `Datum`, serialized as its raw value
+    Datum,
+    /// a `char *`, serialized as a string
+    CString,
+    /// a pointer to some `Node`, serialized as whatever node it points to at runtime
+    NodePtr,
+    /// a `Node` embedded by value, generally the "parent" struct such as `Plan` in `SeqScan`
+    Node,
+}
+
+/// Decide how to serialize a field of type `ty`, looking through type aliases.  Fields we
+/// cannot make sense of generically (unions, arrays, bitfields, function pointers, and
+/// pointers to anything that isn't a `Node` or a string) return `None` and are skipped.
+fn classify_node_field(
+    ty: &syn::Type,
+    node_structs: &HashMap<String, &syn::ItemStruct>,
+    type_aliases: &HashMap<String, &syn::Type>,
+) -> Option<NodeField> {
+    const SCALARS: &[&str] = &[
+        "bool",
+        "i8",
+        "i16",
+        "i32",
+        "i64",
+        "u8",
+        "u16",
+        "u32",
+        "u64",
+        "f32",
+        "f64",
+        "c_char",
+        "c_schar",
+        "c_uchar",
+        "c_short",
+        "c_ushort",
+        "c_int",
+        "c_uint",
+        "c_long",
+        "c_ulong",
+        "c_longlong",
+        "c_ulonglong",
+        "c_float",
+        "c_double",
+    ];
+
+    match ty {
+        syn::Type::Path(p) => {
+            let name = p.path.segments.last()?.ident.to_string();
+            if name == "NodeTag" {
+                // the node's own tag is already represented by its type name
+                None
+            } else if name == "Datum" {
+                Some(NodeField::Datum)
+            } else if SCALARS.contains(&name.as_str()) {
+                Some(NodeField::Scalar)
+            } else if let Some(node) = node_structs.get(&name) {
+                // a node with nothing but its tag, such as `Expr`, has nothing to say
+                (node.fields.len() > 1).then_some(NodeField::Node)
+            } else {
+                classify_node_field(type_aliases.get(&name)?, node_structs, type_aliases)
+            }
+        }
+        syn::Type::Ptr(ptr) => {
+            // resolve the pointee through any aliases, but not through further pointers
+            let mut pointee = &*ptr.elem;
+            loop {
+                let name = match pointee {
+                    syn::Type::Path(p) => p.path.segments.last()?.ident.to_string(),
+                    _ => return None,
+                };
+                if name == "c_char" {
+                    return Some(NodeField::CString);
+                } else if node_structs.contains_key(&name) {
+                    return Some(NodeField::NodePtr);
+                }
+                pointee = type_aliases.get(&name)?;
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Implement `serde::Serialize` for the `Node` struct `node`, as a struct named after the node
+/// type whose fields are the ones `classify_node_field()` knows how to represent.
+fn impl_serialize_node(
+    node: &syn::ItemStruct,
+    node_structs: &HashMap<String, &syn::ItemStruct>,
+    type_aliases: &HashMap<String, &syn::Type>,
+) -> proc_macro2::TokenStream {
+    let struct_name = &node.ident;
+    let node_type = struct_name.to_string();
+
+    let mut fields = Vec::new();
+    for field in node.fields.iter() {
+        let ident = match &field.ident {
+            Some(ident) => ident,
+            None => continue,
+        };
+        // bindgen appends an underscore to names which are Rust keywords
+        let name = ident.to_string();
+        let name = name.strip_suffix('_').unwrap_or(&name).to_string();
+        let value = match classify_node_field(&field.ty, node_structs, type_aliases) {
+            Some(NodeField::Scalar) | Some(NodeField::Node) => quote! { &self.#ident },
+            Some(NodeField::Datum) => quote! { &self.#ident.value() },
+            Some(NodeField::CString) => {
+                quote! { &pg_sys::node_serde::CStringPtr(self.#ident as *const std::os::raw::c_char) }
+            }
+            Some(NodeField::NodePtr) => {
+                quote! { &pg_sys::node_serde::NodePtr(self.#ident as *const pg_sys::Node) }
+            }
+            None => continue,
+        };
+        fields.push(quote! { state.serialize_field(#name, #value)?; });
+    }
+    let len = fields.len() + 1;
+
+    quote! {
+        impl serde::Serialize for #struct_name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                use serde::ser::SerializeStruct;
+                let mut state = serializer.serialize_struct(#node_type, #len)?;
+                state.serialize_field("node", #node_type)?;
+                #(#fields)*
+                state.end()
+            }
+        }
+    }
+}
+
 /// Given a root node, dfs_find_nodes adds all its children nodes to `node_set`.
 fn dfs_find_nodes<'graph>(
     node: &'graph StructDescriptor<'graph>,
@@ -26,6 +26,7 @@ Use of this source code is governed by the MIT license that can be found in the
 #include "utils/builtins.h"
 #include "utils/array.h"
 #include "storage/spin.h"
+#include "portability/instr_time.h"
 
 
 PGDLLEXPORT MemoryContext pgx_GetMemoryContextChunk(void *ptr);
@@ -240,3 +241,13 @@ PGDLLEXPORT bool pgx_SpinLockFree(slock_t *lock);
 bool pgx_SpinLockFree(slock_t *lock) {
     return SpinLockFree(lock);
 }
+
+PGDLLEXPORT void pgx_INSTR_TIME_SET_CURRENT(instr_time *t);
+void pgx_INSTR_TIME_SET_CURRENT(instr_time *t) {
+    INSTR_TIME_SET_CURRENT(*t);
+}
+
+PGDLLEXPORT void pgx_INSTR_TIME_SUBTRACT(instr_time *x, instr_time *y);
+void pgx_INSTR_TIME_SUBTRACT(instr_time *x, instr_time *y) {
+    INSTR_TIME_SUBTRACT(*x, *y);
+}
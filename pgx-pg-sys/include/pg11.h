@@ -28,6 +28,7 @@ Use of this source code is governed by the MIT license that can be found in the
 #include "catalog/index.h"
 #include "catalog/indexing.h"
 #include "catalog/namespace.h"
+#include "catalog/objectaccess.h"
 #include "catalog/objectaddress.h"
 #include "catalog/pg_authid.h"
 #include "catalog/pg_class.h"
@@ -47,11 +48,15 @@ Use of this source code is governed by the MIT license that can be found in the
 #include "commands/proclang.h"
 #include "commands/tablecmds.h"
 #include "commands/trigger.h"
+#include "commands/user.h"
 #include "commands/vacuum.h"
 #include "executor/executor.h"
 #include "executor/spi.h"
 #include "foreign/fdwapi.h"
 #include "foreign/foreign.h"
+#include "libpq/auth.h"
+#include "libpq/crypt.h"
+#include "libpq/libpq-be.h"
 #include "mb/pg_wchar.h"
 
 #define ScanKey struct ScanKeyData *
@@ -71,6 +76,7 @@ Use of this source code is governed by the MIT license that can be found in the
 #include "optimizer/cost.h"
 #include "optimizer/pathnode.h"
 #include "optimizer/paths.h"
+#include "optimizer/plancat.h"
 #include "optimizer/planmain.h"
 #include "optimizer/planner.h"
 #include "optimizer/restrictinfo.h"
@@ -167,6 +167,13 @@ pub trait PgNode: seal::Sealed {
     }
 }
 
+/// A [`PgNode`] type with a `NodeTag` of its own, which a `pg_sys::Node` can be checked against
+/// before it's downcast to the type
+pub trait PgTaggedNode: PgNode {
+    /// The tag of nodes of this type
+    const NODE_TAG: NodeTag;
+}
+
 mod seal {
     pub trait Sealed {}
 }
@@ -508,6 +515,20 @@ mod all_versions {
         pub fn SpinLockFree(lock: *mut pg_sys::slock_t) -> bool;
     }
 
+    #[pgx_macros::pg_guard]
+    extern "C" {
+        /// ```c
+        /// #define INSTR_TIME_SET_CURRENT(t)
+        /// ```
+        #[link_name = "pgx_INSTR_TIME_SET_CURRENT"]
+        pub fn INSTR_TIME_SET_CURRENT(t: *mut pg_sys::instr_time);
+        /// ```c
+        /// #define INSTR_TIME_SUBTRACT(x,y)
+        /// ```
+        #[link_name = "pgx_INSTR_TIME_SUBTRACT"]
+        pub fn INSTR_TIME_SUBTRACT(x: *mut pg_sys::instr_time, y: *mut pg_sys::instr_time);
+    }
+
     #[inline(always)]
     pub unsafe fn MemoryContextSwitchTo(context: crate::MemoryContext) -> crate::MemoryContext {
         let old = crate::CurrentMemoryContext;
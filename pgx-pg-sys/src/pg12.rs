@@ -35916,9 +35916,25 @@ extern "C" {
 extern "C" {
     pub static mut MyStartTimestamp: TimestampTz;
 }
+pub type CAC_state = ::std::os::raw::c_uint;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
 pub struct Port {
+    pub sock: pgsocket,
+    pub noblock: bool,
+    pub proto: ProtocolVersion,
+    pub laddr: SockAddr,
+    pub raddr: SockAddr,
+    pub remote_host: *mut ::std::os::raw::c_char,
+    pub remote_hostname: *mut ::std::os::raw::c_char,
+    pub remote_hostname_resolv: ::std::os::raw::c_int,
+    pub remote_hostname_errcode: ::std::os::raw::c_int,
+    pub remote_port: *mut ::std::os::raw::c_char,
+    pub canAcceptConnections: CAC_state,
+    pub database_name: *mut ::std::os::raw::c_char,
+    pub user_name: *mut ::std::os::raw::c_char,
+    pub cmdline_options: *mut ::std::os::raw::c_char,
+    pub guc_options: *mut List,
+    pub application_name: *mut ::std::os::raw::c_char,
     _unused: [u8; 0],
 }
 extern "C" {
@@ -104026,8 +104042,99 @@ pub struct RowSecurityDesc {
 pub struct SnapBuild {
     pub _address: u8,
 }
+pub type ClientAuthentication_hook_type =
+    ::std::option::Option<unsafe extern "C" fn(arg1: *mut Port, arg2: ::std::os::raw::c_int)>;
+extern "C" {
+    pub static mut ClientAuthentication_hook: ClientAuthentication_hook_type;
+}
+pub const PasswordType_PASSWORD_TYPE_PLAINTEXT: PasswordType = 0;
+pub const PasswordType_PASSWORD_TYPE_MD5: PasswordType = 1;
+pub const PasswordType_PASSWORD_TYPE_SCRAM_SHA_256: PasswordType = 2;
+pub type PasswordType = ::std::os::raw::c_uint;
+pub type check_password_hook_type = ::std::option::Option<
+    unsafe extern "C" fn(
+        username: *const ::std::os::raw::c_char,
+        shadow_pass: *const ::std::os::raw::c_char,
+        password_type: PasswordType,
+        validuntil_time: Datum,
+        validuntil_null: bool,
+    ),
+>;
+extern "C" {
+    pub static mut check_password_hook: check_password_hook_type;
+}
+pub const ObjectAccessType_OAT_POST_CREATE: ObjectAccessType = 0;
+pub const ObjectAccessType_OAT_DROP: ObjectAccessType = 1;
+pub const ObjectAccessType_OAT_POST_ALTER: ObjectAccessType = 2;
+pub const ObjectAccessType_OAT_NAMESPACE_SEARCH: ObjectAccessType = 3;
+pub const ObjectAccessType_OAT_FUNCTION_EXECUTE: ObjectAccessType = 4;
+pub type ObjectAccessType = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ObjectAccessPostCreate {
+    pub is_internal: bool,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ObjectAccessDrop {
+    pub dropflags: ::std::os::raw::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ObjectAccessPostAlter {
+    pub auxiliary_id: Oid,
+    pub is_internal: bool,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ObjectAccessNamespaceSearch {
+    pub ereport_on_violation: bool,
+    pub result: bool,
+}
+pub type object_access_hook_type = ::std::option::Option<
+    unsafe extern "C" fn(
+        access: ObjectAccessType,
+        classId: Oid,
+        objectId: Oid,
+        subId: ::std::os::raw::c_int,
+        arg: *mut ::std::os::raw::c_void,
+    ),
+>;
+extern "C" {
+    pub static mut object_access_hook: object_access_hook_type;
+}
+pub type get_relation_info_hook_type = ::std::option::Option<
+    unsafe extern "C" fn(
+        root: *mut PlannerInfo,
+        relationObjectId: Oid,
+        inhparent: bool,
+        rel: *mut RelOptInfo,
+    ),
+>;
+extern "C" {
+    pub static mut get_relation_info_hook: get_relation_info_hook_type;
+}
 impl pg_sys::seal::Sealed for A_ArrayExpr {}
 impl pg_sys::PgNode for A_ArrayExpr {}
+impl pg_sys::PgTaggedNode for A_ArrayExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_A_ArrayExpr;
+}
+impl serde::Serialize for A_ArrayExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("A_ArrayExpr", 3usize)?;
+        state.serialize_field("node", "A_ArrayExpr")?;
+        state.serialize_field(
+            "elements",
+            &pg_sys::node_serde::NodePtr(self.elements as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for A_ArrayExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104035,6 +104142,22 @@ impl std::fmt::Display for A_ArrayExpr {
 }
 impl pg_sys::seal::Sealed for A_Const {}
 impl pg_sys::PgNode for A_Const {}
+impl pg_sys::PgTaggedNode for A_Const {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_A_Const;
+}
+impl serde::Serialize for A_Const {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("A_Const", 3usize)?;
+        state.serialize_field("node", "A_Const")?;
+        state.serialize_field("val", &self.val)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for A_Const {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104042,6 +104165,34 @@ impl std::fmt::Display for A_Const {
 }
 impl pg_sys::seal::Sealed for A_Expr {}
 impl pg_sys::PgNode for A_Expr {}
+impl pg_sys::PgTaggedNode for A_Expr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_A_Expr;
+}
+impl serde::Serialize for A_Expr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("A_Expr", 6usize)?;
+        state.serialize_field("node", "A_Expr")?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::NodePtr(self.name as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "lexpr",
+            &pg_sys::node_serde::NodePtr(self.lexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rexpr",
+            &pg_sys::node_serde::NodePtr(self.rexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for A_Expr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104049,6 +104200,29 @@ impl std::fmt::Display for A_Expr {
 }
 impl pg_sys::seal::Sealed for A_Indices {}
 impl pg_sys::PgNode for A_Indices {}
+impl pg_sys::PgTaggedNode for A_Indices {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_A_Indices;
+}
+impl serde::Serialize for A_Indices {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("A_Indices", 4usize)?;
+        state.serialize_field("node", "A_Indices")?;
+        state.serialize_field("is_slice", &self.is_slice)?;
+        state.serialize_field(
+            "lidx",
+            &pg_sys::node_serde::NodePtr(self.lidx as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "uidx",
+            &pg_sys::node_serde::NodePtr(self.uidx as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for A_Indices {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104056,6 +104230,28 @@ impl std::fmt::Display for A_Indices {
 }
 impl pg_sys::seal::Sealed for A_Indirection {}
 impl pg_sys::PgNode for A_Indirection {}
+impl pg_sys::PgTaggedNode for A_Indirection {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_A_Indirection;
+}
+impl serde::Serialize for A_Indirection {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("A_Indirection", 3usize)?;
+        state.serialize_field("node", "A_Indirection")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indirection",
+            &pg_sys::node_serde::NodePtr(self.indirection as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for A_Indirection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104063,6 +104259,20 @@ impl std::fmt::Display for A_Indirection {
 }
 impl pg_sys::seal::Sealed for A_Star {}
 impl pg_sys::PgNode for A_Star {}
+impl pg_sys::PgTaggedNode for A_Star {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_A_Star;
+}
+impl serde::Serialize for A_Star {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("A_Star", 1usize)?;
+        state.serialize_field("node", "A_Star")?;
+        state.end()
+    }
+}
 impl std::fmt::Display for A_Star {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104070,6 +104280,28 @@ impl std::fmt::Display for A_Star {
 }
 impl pg_sys::seal::Sealed for AccessPriv {}
 impl pg_sys::PgNode for AccessPriv {}
+impl pg_sys::PgTaggedNode for AccessPriv {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AccessPriv;
+}
+impl serde::Serialize for AccessPriv {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AccessPriv", 3usize)?;
+        state.serialize_field("node", "AccessPriv")?;
+        state.serialize_field(
+            "priv_name",
+            &pg_sys::node_serde::CStringPtr(self.priv_name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "cols",
+            &pg_sys::node_serde::NodePtr(self.cols as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AccessPriv {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104077,6 +104309,33 @@ impl std::fmt::Display for AccessPriv {
 }
 impl pg_sys::seal::Sealed for Agg {}
 impl pg_sys::PgNode for Agg {}
+impl pg_sys::PgTaggedNode for Agg {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Agg;
+}
+impl serde::Serialize for Agg {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Agg", 8usize)?;
+        state.serialize_field("node", "Agg")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field("aggstrategy", &self.aggstrategy)?;
+        state.serialize_field("aggsplit", &self.aggsplit)?;
+        state.serialize_field("numCols", &self.numCols)?;
+        state.serialize_field("numGroups", &self.numGroups)?;
+        state.serialize_field(
+            "groupingSets",
+            &pg_sys::node_serde::NodePtr(self.groupingSets as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "chain",
+            &pg_sys::node_serde::NodePtr(self.chain as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Agg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104084,6 +104343,36 @@ impl std::fmt::Display for Agg {
 }
 impl pg_sys::seal::Sealed for AggPath {}
 impl pg_sys::PgNode for AggPath {}
+impl pg_sys::PgTaggedNode for AggPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AggPath;
+}
+impl serde::Serialize for AggPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AggPath", 8usize)?;
+        state.serialize_field("node", "AggPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field("aggstrategy", &self.aggstrategy)?;
+        state.serialize_field("aggsplit", &self.aggsplit)?;
+        state.serialize_field("numGroups", &self.numGroups)?;
+        state.serialize_field(
+            "groupClause",
+            &pg_sys::node_serde::NodePtr(self.groupClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "qual",
+            &pg_sys::node_serde::NodePtr(self.qual as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AggPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104091,6 +104380,62 @@ impl std::fmt::Display for AggPath {
 }
 impl pg_sys::seal::Sealed for AggState {}
 impl pg_sys::PgNode for AggState {}
+impl pg_sys::PgTaggedNode for AggState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AggState;
+}
+impl serde::Serialize for AggState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AggState", 22usize)?;
+        state.serialize_field("node", "AggState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field(
+            "aggs",
+            &pg_sys::node_serde::NodePtr(self.aggs as *const pg_sys::Node),
+        )?;
+        state.serialize_field("numaggs", &self.numaggs)?;
+        state.serialize_field("numtrans", &self.numtrans)?;
+        state.serialize_field("aggstrategy", &self.aggstrategy)?;
+        state.serialize_field("aggsplit", &self.aggsplit)?;
+        state.serialize_field("numphases", &self.numphases)?;
+        state.serialize_field("current_phase", &self.current_phase)?;
+        state.serialize_field(
+            "hashcontext",
+            &pg_sys::node_serde::NodePtr(self.hashcontext as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "tmpcontext",
+            &pg_sys::node_serde::NodePtr(self.tmpcontext as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "curaggcontext",
+            &pg_sys::node_serde::NodePtr(self.curaggcontext as *const pg_sys::Node),
+        )?;
+        state.serialize_field("input_done", &self.input_done)?;
+        state.serialize_field("agg_done", &self.agg_done)?;
+        state.serialize_field("projected_set", &self.projected_set)?;
+        state.serialize_field("current_set", &self.current_set)?;
+        state.serialize_field(
+            "all_grouped_cols",
+            &pg_sys::node_serde::NodePtr(self.all_grouped_cols as *const pg_sys::Node),
+        )?;
+        state.serialize_field("maxsets", &self.maxsets)?;
+        state.serialize_field(
+            "sort_slot",
+            &pg_sys::node_serde::NodePtr(self.sort_slot as *const pg_sys::Node),
+        )?;
+        state.serialize_field("table_filled", &self.table_filled)?;
+        state.serialize_field("num_hashes", &self.num_hashes)?;
+        state.serialize_field(
+            "combinedproj",
+            &pg_sys::node_serde::NodePtr(self.combinedproj as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AggState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104098,6 +104443,55 @@ impl std::fmt::Display for AggState {
 }
 impl pg_sys::seal::Sealed for Aggref {}
 impl pg_sys::PgNode for Aggref {}
+impl pg_sys::PgTaggedNode for Aggref {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Aggref;
+}
+impl serde::Serialize for Aggref {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Aggref", 18usize)?;
+        state.serialize_field("node", "Aggref")?;
+        state.serialize_field("aggfnoid", &self.aggfnoid)?;
+        state.serialize_field("aggtype", &self.aggtype)?;
+        state.serialize_field("aggcollid", &self.aggcollid)?;
+        state.serialize_field("inputcollid", &self.inputcollid)?;
+        state.serialize_field("aggtranstype", &self.aggtranstype)?;
+        state.serialize_field(
+            "aggargtypes",
+            &pg_sys::node_serde::NodePtr(self.aggargtypes as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "aggdirectargs",
+            &pg_sys::node_serde::NodePtr(self.aggdirectargs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "aggorder",
+            &pg_sys::node_serde::NodePtr(self.aggorder as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "aggdistinct",
+            &pg_sys::node_serde::NodePtr(self.aggdistinct as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "aggfilter",
+            &pg_sys::node_serde::NodePtr(self.aggfilter as *const pg_sys::Node),
+        )?;
+        state.serialize_field("aggstar", &self.aggstar)?;
+        state.serialize_field("aggvariadic", &self.aggvariadic)?;
+        state.serialize_field("aggkind", &self.aggkind)?;
+        state.serialize_field("agglevelsup", &self.agglevelsup)?;
+        state.serialize_field("aggsplit", &self.aggsplit)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Aggref {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104105,6 +104499,25 @@ impl std::fmt::Display for Aggref {
 }
 impl pg_sys::seal::Sealed for AggrefExprState {}
 impl pg_sys::PgNode for AggrefExprState {}
+impl pg_sys::PgTaggedNode for AggrefExprState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AggrefExprState;
+}
+impl serde::Serialize for AggrefExprState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AggrefExprState", 3usize)?;
+        state.serialize_field("node", "AggrefExprState")?;
+        state.serialize_field(
+            "aggref",
+            &pg_sys::node_serde::NodePtr(self.aggref as *const pg_sys::Node),
+        )?;
+        state.serialize_field("aggno", &self.aggno)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AggrefExprState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104112,6 +104525,28 @@ impl std::fmt::Display for AggrefExprState {
 }
 impl pg_sys::seal::Sealed for Alias {}
 impl pg_sys::PgNode for Alias {}
+impl pg_sys::PgTaggedNode for Alias {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Alias;
+}
+impl serde::Serialize for Alias {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Alias", 3usize)?;
+        state.serialize_field("node", "Alias")?;
+        state.serialize_field(
+            "aliasname",
+            &pg_sys::node_serde::CStringPtr(self.aliasname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "colnames",
+            &pg_sys::node_serde::NodePtr(self.colnames as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Alias {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104119,6 +104554,24 @@ impl std::fmt::Display for Alias {
 }
 impl pg_sys::seal::Sealed for AlterCollationStmt {}
 impl pg_sys::PgNode for AlterCollationStmt {}
+impl pg_sys::PgTaggedNode for AlterCollationStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterCollationStmt;
+}
+impl serde::Serialize for AlterCollationStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterCollationStmt", 2usize)?;
+        state.serialize_field("node", "AlterCollationStmt")?;
+        state.serialize_field(
+            "collname",
+            &pg_sys::node_serde::NodePtr(self.collname as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterCollationStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104126,6 +104579,28 @@ impl std::fmt::Display for AlterCollationStmt {
 }
 impl pg_sys::seal::Sealed for AlterDatabaseSetStmt {}
 impl pg_sys::PgNode for AlterDatabaseSetStmt {}
+impl pg_sys::PgTaggedNode for AlterDatabaseSetStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterDatabaseSetStmt;
+}
+impl serde::Serialize for AlterDatabaseSetStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterDatabaseSetStmt", 3usize)?;
+        state.serialize_field("node", "AlterDatabaseSetStmt")?;
+        state.serialize_field(
+            "dbname",
+            &pg_sys::node_serde::CStringPtr(self.dbname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "setstmt",
+            &pg_sys::node_serde::NodePtr(self.setstmt as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterDatabaseSetStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104133,6 +104608,28 @@ impl std::fmt::Display for AlterDatabaseSetStmt {
 }
 impl pg_sys::seal::Sealed for AlterDatabaseStmt {}
 impl pg_sys::PgNode for AlterDatabaseStmt {}
+impl pg_sys::PgTaggedNode for AlterDatabaseStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterDatabaseStmt;
+}
+impl serde::Serialize for AlterDatabaseStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterDatabaseStmt", 3usize)?;
+        state.serialize_field("node", "AlterDatabaseStmt")?;
+        state.serialize_field(
+            "dbname",
+            &pg_sys::node_serde::CStringPtr(self.dbname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterDatabaseStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104140,6 +104637,28 @@ impl std::fmt::Display for AlterDatabaseStmt {
 }
 impl pg_sys::seal::Sealed for AlterDefaultPrivilegesStmt {}
 impl pg_sys::PgNode for AlterDefaultPrivilegesStmt {}
+impl pg_sys::PgTaggedNode for AlterDefaultPrivilegesStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterDefaultPrivilegesStmt;
+}
+impl serde::Serialize for AlterDefaultPrivilegesStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterDefaultPrivilegesStmt", 3usize)?;
+        state.serialize_field("node", "AlterDefaultPrivilegesStmt")?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "action",
+            &pg_sys::node_serde::NodePtr(self.action as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterDefaultPrivilegesStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104147,6 +104666,35 @@ impl std::fmt::Display for AlterDefaultPrivilegesStmt {
 }
 impl pg_sys::seal::Sealed for AlterDomainStmt {}
 impl pg_sys::PgNode for AlterDomainStmt {}
+impl pg_sys::PgTaggedNode for AlterDomainStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterDomainStmt;
+}
+impl serde::Serialize for AlterDomainStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterDomainStmt", 7usize)?;
+        state.serialize_field("node", "AlterDomainStmt")?;
+        state.serialize_field("subtype", &self.subtype)?;
+        state.serialize_field(
+            "typeName",
+            &pg_sys::node_serde::NodePtr(self.typeName as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "def",
+            &pg_sys::node_serde::NodePtr(self.def as *const pg_sys::Node),
+        )?;
+        state.serialize_field("behavior", &self.behavior)?;
+        state.serialize_field("missing_ok", &self.missing_ok)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterDomainStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104154,6 +104702,38 @@ impl std::fmt::Display for AlterDomainStmt {
 }
 impl pg_sys::seal::Sealed for AlterEnumStmt {}
 impl pg_sys::PgNode for AlterEnumStmt {}
+impl pg_sys::PgTaggedNode for AlterEnumStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterEnumStmt;
+}
+impl serde::Serialize for AlterEnumStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterEnumStmt", 7usize)?;
+        state.serialize_field("node", "AlterEnumStmt")?;
+        state.serialize_field(
+            "typeName",
+            &pg_sys::node_serde::NodePtr(self.typeName as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "oldVal",
+            &pg_sys::node_serde::CStringPtr(self.oldVal as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "newVal",
+            &pg_sys::node_serde::CStringPtr(self.newVal as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "newValNeighbor",
+            &pg_sys::node_serde::CStringPtr(self.newValNeighbor as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("newValIsAfter", &self.newValIsAfter)?;
+        state.serialize_field("skipIfNewValExists", &self.skipIfNewValExists)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterEnumStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104161,6 +104741,25 @@ impl std::fmt::Display for AlterEnumStmt {
 }
 impl pg_sys::seal::Sealed for AlterEventTrigStmt {}
 impl pg_sys::PgNode for AlterEventTrigStmt {}
+impl pg_sys::PgTaggedNode for AlterEventTrigStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterEventTrigStmt;
+}
+impl serde::Serialize for AlterEventTrigStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterEventTrigStmt", 3usize)?;
+        state.serialize_field("node", "AlterEventTrigStmt")?;
+        state.serialize_field(
+            "trigname",
+            &pg_sys::node_serde::CStringPtr(self.trigname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("tgenabled", &self.tgenabled)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterEventTrigStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104168,6 +104767,30 @@ impl std::fmt::Display for AlterEventTrigStmt {
 }
 impl pg_sys::seal::Sealed for AlterExtensionContentsStmt {}
 impl pg_sys::PgNode for AlterExtensionContentsStmt {}
+impl pg_sys::PgTaggedNode for AlterExtensionContentsStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterExtensionContentsStmt;
+}
+impl serde::Serialize for AlterExtensionContentsStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterExtensionContentsStmt", 5usize)?;
+        state.serialize_field("node", "AlterExtensionContentsStmt")?;
+        state.serialize_field(
+            "extname",
+            &pg_sys::node_serde::CStringPtr(self.extname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("action", &self.action)?;
+        state.serialize_field("objtype", &self.objtype)?;
+        state.serialize_field(
+            "object",
+            &pg_sys::node_serde::NodePtr(self.object as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterExtensionContentsStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104175,6 +104798,28 @@ impl std::fmt::Display for AlterExtensionContentsStmt {
 }
 impl pg_sys::seal::Sealed for AlterExtensionStmt {}
 impl pg_sys::PgNode for AlterExtensionStmt {}
+impl pg_sys::PgTaggedNode for AlterExtensionStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterExtensionStmt;
+}
+impl serde::Serialize for AlterExtensionStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterExtensionStmt", 3usize)?;
+        state.serialize_field("node", "AlterExtensionStmt")?;
+        state.serialize_field(
+            "extname",
+            &pg_sys::node_serde::CStringPtr(self.extname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterExtensionStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104182,6 +104827,32 @@ impl std::fmt::Display for AlterExtensionStmt {
 }
 impl pg_sys::seal::Sealed for AlterFdwStmt {}
 impl pg_sys::PgNode for AlterFdwStmt {}
+impl pg_sys::PgTaggedNode for AlterFdwStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterFdwStmt;
+}
+impl serde::Serialize for AlterFdwStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterFdwStmt", 4usize)?;
+        state.serialize_field("node", "AlterFdwStmt")?;
+        state.serialize_field(
+            "fdwname",
+            &pg_sys::node_serde::CStringPtr(self.fdwname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "func_options",
+            &pg_sys::node_serde::NodePtr(self.func_options as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterFdwStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104189,6 +104860,33 @@ impl std::fmt::Display for AlterFdwStmt {
 }
 impl pg_sys::seal::Sealed for AlterForeignServerStmt {}
 impl pg_sys::PgNode for AlterForeignServerStmt {}
+impl pg_sys::PgTaggedNode for AlterForeignServerStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterForeignServerStmt;
+}
+impl serde::Serialize for AlterForeignServerStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterForeignServerStmt", 5usize)?;
+        state.serialize_field("node", "AlterForeignServerStmt")?;
+        state.serialize_field(
+            "servername",
+            &pg_sys::node_serde::CStringPtr(self.servername as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "version",
+            &pg_sys::node_serde::CStringPtr(self.version as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field("has_version", &self.has_version)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterForeignServerStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104196,6 +104894,29 @@ impl std::fmt::Display for AlterForeignServerStmt {
 }
 impl pg_sys::seal::Sealed for AlterFunctionStmt {}
 impl pg_sys::PgNode for AlterFunctionStmt {}
+impl pg_sys::PgTaggedNode for AlterFunctionStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterFunctionStmt;
+}
+impl serde::Serialize for AlterFunctionStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterFunctionStmt", 4usize)?;
+        state.serialize_field("node", "AlterFunctionStmt")?;
+        state.serialize_field("objtype", &self.objtype)?;
+        state.serialize_field(
+            "func",
+            &pg_sys::node_serde::NodePtr(self.func as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "actions",
+            &pg_sys::node_serde::NodePtr(self.actions as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterFunctionStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104203,6 +104924,33 @@ impl std::fmt::Display for AlterFunctionStmt {
 }
 impl pg_sys::seal::Sealed for AlterObjectDependsStmt {}
 impl pg_sys::PgNode for AlterObjectDependsStmt {}
+impl pg_sys::PgTaggedNode for AlterObjectDependsStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterObjectDependsStmt;
+}
+impl serde::Serialize for AlterObjectDependsStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterObjectDependsStmt", 5usize)?;
+        state.serialize_field("node", "AlterObjectDependsStmt")?;
+        state.serialize_field("objectType", &self.objectType)?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "object",
+            &pg_sys::node_serde::NodePtr(self.object as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "extname",
+            &pg_sys::node_serde::NodePtr(self.extname as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterObjectDependsStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104210,6 +104958,34 @@ impl std::fmt::Display for AlterObjectDependsStmt {
 }
 impl pg_sys::seal::Sealed for AlterObjectSchemaStmt {}
 impl pg_sys::PgNode for AlterObjectSchemaStmt {}
+impl pg_sys::PgTaggedNode for AlterObjectSchemaStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterObjectSchemaStmt;
+}
+impl serde::Serialize for AlterObjectSchemaStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterObjectSchemaStmt", 6usize)?;
+        state.serialize_field("node", "AlterObjectSchemaStmt")?;
+        state.serialize_field("objectType", &self.objectType)?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "object",
+            &pg_sys::node_serde::NodePtr(self.object as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "newschema",
+            &pg_sys::node_serde::CStringPtr(self.newschema as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("missing_ok", &self.missing_ok)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterObjectSchemaStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104217,6 +104993,33 @@ impl std::fmt::Display for AlterObjectSchemaStmt {
 }
 impl pg_sys::seal::Sealed for AlterOpFamilyStmt {}
 impl pg_sys::PgNode for AlterOpFamilyStmt {}
+impl pg_sys::PgTaggedNode for AlterOpFamilyStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterOpFamilyStmt;
+}
+impl serde::Serialize for AlterOpFamilyStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterOpFamilyStmt", 5usize)?;
+        state.serialize_field("node", "AlterOpFamilyStmt")?;
+        state.serialize_field(
+            "opfamilyname",
+            &pg_sys::node_serde::NodePtr(self.opfamilyname as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "amname",
+            &pg_sys::node_serde::CStringPtr(self.amname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("isDrop", &self.isDrop)?;
+        state.serialize_field(
+            "items",
+            &pg_sys::node_serde::NodePtr(self.items as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterOpFamilyStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104224,6 +105027,28 @@ impl std::fmt::Display for AlterOpFamilyStmt {
 }
 impl pg_sys::seal::Sealed for AlterOperatorStmt {}
 impl pg_sys::PgNode for AlterOperatorStmt {}
+impl pg_sys::PgTaggedNode for AlterOperatorStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterOperatorStmt;
+}
+impl serde::Serialize for AlterOperatorStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterOperatorStmt", 3usize)?;
+        state.serialize_field("node", "AlterOperatorStmt")?;
+        state.serialize_field(
+            "opername",
+            &pg_sys::node_serde::NodePtr(self.opername as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterOperatorStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104231,6 +105056,33 @@ impl std::fmt::Display for AlterOperatorStmt {
 }
 impl pg_sys::seal::Sealed for AlterOwnerStmt {}
 impl pg_sys::PgNode for AlterOwnerStmt {}
+impl pg_sys::PgTaggedNode for AlterOwnerStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterOwnerStmt;
+}
+impl serde::Serialize for AlterOwnerStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterOwnerStmt", 5usize)?;
+        state.serialize_field("node", "AlterOwnerStmt")?;
+        state.serialize_field("objectType", &self.objectType)?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "object",
+            &pg_sys::node_serde::NodePtr(self.object as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "newowner",
+            &pg_sys::node_serde::NodePtr(self.newowner as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterOwnerStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104238,6 +105090,40 @@ impl std::fmt::Display for AlterOwnerStmt {
 }
 impl pg_sys::seal::Sealed for AlterPolicyStmt {}
 impl pg_sys::PgNode for AlterPolicyStmt {}
+impl pg_sys::PgTaggedNode for AlterPolicyStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterPolicyStmt;
+}
+impl serde::Serialize for AlterPolicyStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterPolicyStmt", 6usize)?;
+        state.serialize_field("node", "AlterPolicyStmt")?;
+        state.serialize_field(
+            "policy_name",
+            &pg_sys::node_serde::CStringPtr(self.policy_name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "table",
+            &pg_sys::node_serde::NodePtr(self.table as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "roles",
+            &pg_sys::node_serde::NodePtr(self.roles as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "qual",
+            &pg_sys::node_serde::NodePtr(self.qual as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "with_check",
+            &pg_sys::node_serde::NodePtr(self.with_check as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterPolicyStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104245,6 +105131,34 @@ impl std::fmt::Display for AlterPolicyStmt {
 }
 impl pg_sys::seal::Sealed for AlterPublicationStmt {}
 impl pg_sys::PgNode for AlterPublicationStmt {}
+impl pg_sys::PgTaggedNode for AlterPublicationStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterPublicationStmt;
+}
+impl serde::Serialize for AlterPublicationStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterPublicationStmt", 6usize)?;
+        state.serialize_field("node", "AlterPublicationStmt")?;
+        state.serialize_field(
+            "pubname",
+            &pg_sys::node_serde::CStringPtr(self.pubname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "tables",
+            &pg_sys::node_serde::NodePtr(self.tables as *const pg_sys::Node),
+        )?;
+        state.serialize_field("for_all_tables", &self.for_all_tables)?;
+        state.serialize_field("tableAction", &self.tableAction)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterPublicationStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104252,6 +105166,32 @@ impl std::fmt::Display for AlterPublicationStmt {
 }
 impl pg_sys::seal::Sealed for AlterRoleSetStmt {}
 impl pg_sys::PgNode for AlterRoleSetStmt {}
+impl pg_sys::PgTaggedNode for AlterRoleSetStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterRoleSetStmt;
+}
+impl serde::Serialize for AlterRoleSetStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterRoleSetStmt", 4usize)?;
+        state.serialize_field("node", "AlterRoleSetStmt")?;
+        state.serialize_field(
+            "role",
+            &pg_sys::node_serde::NodePtr(self.role as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "database",
+            &pg_sys::node_serde::CStringPtr(self.database as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "setstmt",
+            &pg_sys::node_serde::NodePtr(self.setstmt as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterRoleSetStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104259,6 +105199,29 @@ impl std::fmt::Display for AlterRoleSetStmt {
 }
 impl pg_sys::seal::Sealed for AlterRoleStmt {}
 impl pg_sys::PgNode for AlterRoleStmt {}
+impl pg_sys::PgTaggedNode for AlterRoleStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterRoleStmt;
+}
+impl serde::Serialize for AlterRoleStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterRoleStmt", 4usize)?;
+        state.serialize_field("node", "AlterRoleStmt")?;
+        state.serialize_field(
+            "role",
+            &pg_sys::node_serde::NodePtr(self.role as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field("action", &self.action)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterRoleStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104266,6 +105229,30 @@ impl std::fmt::Display for AlterRoleStmt {
 }
 impl pg_sys::seal::Sealed for AlterSeqStmt {}
 impl pg_sys::PgNode for AlterSeqStmt {}
+impl pg_sys::PgTaggedNode for AlterSeqStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterSeqStmt;
+}
+impl serde::Serialize for AlterSeqStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterSeqStmt", 5usize)?;
+        state.serialize_field("node", "AlterSeqStmt")?;
+        state.serialize_field(
+            "sequence",
+            &pg_sys::node_serde::NodePtr(self.sequence as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field("for_identity", &self.for_identity)?;
+        state.serialize_field("missing_ok", &self.missing_ok)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterSeqStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104273,6 +105260,37 @@ impl std::fmt::Display for AlterSeqStmt {
 }
 impl pg_sys::seal::Sealed for AlterSubscriptionStmt {}
 impl pg_sys::PgNode for AlterSubscriptionStmt {}
+impl pg_sys::PgTaggedNode for AlterSubscriptionStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterSubscriptionStmt;
+}
+impl serde::Serialize for AlterSubscriptionStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterSubscriptionStmt", 6usize)?;
+        state.serialize_field("node", "AlterSubscriptionStmt")?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field(
+            "subname",
+            &pg_sys::node_serde::CStringPtr(self.subname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "conninfo",
+            &pg_sys::node_serde::CStringPtr(self.conninfo as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "publication",
+            &pg_sys::node_serde::NodePtr(self.publication as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterSubscriptionStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104280,6 +105298,24 @@ impl std::fmt::Display for AlterSubscriptionStmt {
 }
 impl pg_sys::seal::Sealed for AlterSystemStmt {}
 impl pg_sys::PgNode for AlterSystemStmt {}
+impl pg_sys::PgTaggedNode for AlterSystemStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterSystemStmt;
+}
+impl serde::Serialize for AlterSystemStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterSystemStmt", 2usize)?;
+        state.serialize_field("node", "AlterSystemStmt")?;
+        state.serialize_field(
+            "setstmt",
+            &pg_sys::node_serde::NodePtr(self.setstmt as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterSystemStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104287,6 +105323,36 @@ impl std::fmt::Display for AlterSystemStmt {
 }
 impl pg_sys::seal::Sealed for AlterTSConfigurationStmt {}
 impl pg_sys::PgNode for AlterTSConfigurationStmt {}
+impl pg_sys::PgTaggedNode for AlterTSConfigurationStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterTSConfigurationStmt;
+}
+impl serde::Serialize for AlterTSConfigurationStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterTSConfigurationStmt", 8usize)?;
+        state.serialize_field("node", "AlterTSConfigurationStmt")?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field(
+            "cfgname",
+            &pg_sys::node_serde::NodePtr(self.cfgname as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "tokentype",
+            &pg_sys::node_serde::NodePtr(self.tokentype as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "dicts",
+            &pg_sys::node_serde::NodePtr(self.dicts as *const pg_sys::Node),
+        )?;
+        state.serialize_field("override", &self.override_)?;
+        state.serialize_field("replace", &self.replace)?;
+        state.serialize_field("missing_ok", &self.missing_ok)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterTSConfigurationStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104294,6 +105360,28 @@ impl std::fmt::Display for AlterTSConfigurationStmt {
 }
 impl pg_sys::seal::Sealed for AlterTSDictionaryStmt {}
 impl pg_sys::PgNode for AlterTSDictionaryStmt {}
+impl pg_sys::PgTaggedNode for AlterTSDictionaryStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterTSDictionaryStmt;
+}
+impl serde::Serialize for AlterTSDictionaryStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterTSDictionaryStmt", 3usize)?;
+        state.serialize_field("node", "AlterTSDictionaryStmt")?;
+        state.serialize_field(
+            "dictname",
+            &pg_sys::node_serde::NodePtr(self.dictname as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterTSDictionaryStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104301,6 +105389,37 @@ impl std::fmt::Display for AlterTSDictionaryStmt {
 }
 impl pg_sys::seal::Sealed for AlterTableCmd {}
 impl pg_sys::PgNode for AlterTableCmd {}
+impl pg_sys::PgTaggedNode for AlterTableCmd {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterTableCmd;
+}
+impl serde::Serialize for AlterTableCmd {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterTableCmd", 9usize)?;
+        state.serialize_field("node", "AlterTableCmd")?;
+        state.serialize_field("subtype", &self.subtype)?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("num", &self.num)?;
+        state.serialize_field(
+            "newowner",
+            &pg_sys::node_serde::NodePtr(self.newowner as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "def",
+            &pg_sys::node_serde::NodePtr(self.def as *const pg_sys::Node),
+        )?;
+        state.serialize_field("behavior", &self.behavior)?;
+        state.serialize_field("missing_ok", &self.missing_ok)?;
+        state.serialize_field("recurse", &self.recurse)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterTableCmd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104308,6 +105427,36 @@ impl std::fmt::Display for AlterTableCmd {
 }
 impl pg_sys::seal::Sealed for AlterTableMoveAllStmt {}
 impl pg_sys::PgNode for AlterTableMoveAllStmt {}
+impl pg_sys::PgTaggedNode for AlterTableMoveAllStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterTableMoveAllStmt;
+}
+impl serde::Serialize for AlterTableMoveAllStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterTableMoveAllStmt", 6usize)?;
+        state.serialize_field("node", "AlterTableMoveAllStmt")?;
+        state.serialize_field(
+            "orig_tablespacename",
+            &pg_sys::node_serde::CStringPtr(
+                self.orig_tablespacename as *const std::os::raw::c_char,
+            ),
+        )?;
+        state.serialize_field("objtype", &self.objtype)?;
+        state.serialize_field(
+            "roles",
+            &pg_sys::node_serde::NodePtr(self.roles as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "new_tablespacename",
+            &pg_sys::node_serde::CStringPtr(self.new_tablespacename as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("nowait", &self.nowait)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterTableMoveAllStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104315,6 +105464,29 @@ impl std::fmt::Display for AlterTableMoveAllStmt {
 }
 impl pg_sys::seal::Sealed for AlterTableSpaceOptionsStmt {}
 impl pg_sys::PgNode for AlterTableSpaceOptionsStmt {}
+impl pg_sys::PgTaggedNode for AlterTableSpaceOptionsStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterTableSpaceOptionsStmt;
+}
+impl serde::Serialize for AlterTableSpaceOptionsStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterTableSpaceOptionsStmt", 4usize)?;
+        state.serialize_field("node", "AlterTableSpaceOptionsStmt")?;
+        state.serialize_field(
+            "tablespacename",
+            &pg_sys::node_serde::CStringPtr(self.tablespacename as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field("isReset", &self.isReset)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterTableSpaceOptionsStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104322,6 +105494,30 @@ impl std::fmt::Display for AlterTableSpaceOptionsStmt {
 }
 impl pg_sys::seal::Sealed for AlterTableStmt {}
 impl pg_sys::PgNode for AlterTableStmt {}
+impl pg_sys::PgTaggedNode for AlterTableStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterTableStmt;
+}
+impl serde::Serialize for AlterTableStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterTableStmt", 5usize)?;
+        state.serialize_field("node", "AlterTableStmt")?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "cmds",
+            &pg_sys::node_serde::NodePtr(self.cmds as *const pg_sys::Node),
+        )?;
+        state.serialize_field("relkind", &self.relkind)?;
+        state.serialize_field("missing_ok", &self.missing_ok)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterTableStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104329,6 +105525,32 @@ impl std::fmt::Display for AlterTableStmt {
 }
 impl pg_sys::seal::Sealed for AlterUserMappingStmt {}
 impl pg_sys::PgNode for AlterUserMappingStmt {}
+impl pg_sys::PgTaggedNode for AlterUserMappingStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlterUserMappingStmt;
+}
+impl serde::Serialize for AlterUserMappingStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlterUserMappingStmt", 4usize)?;
+        state.serialize_field("node", "AlterUserMappingStmt")?;
+        state.serialize_field(
+            "user",
+            &pg_sys::node_serde::NodePtr(self.user as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "servername",
+            &pg_sys::node_serde::CStringPtr(self.servername as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlterUserMappingStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104336,6 +105558,24 @@ impl std::fmt::Display for AlterUserMappingStmt {
 }
 impl pg_sys::seal::Sealed for AlternativeSubPlan {}
 impl pg_sys::PgNode for AlternativeSubPlan {}
+impl pg_sys::PgTaggedNode for AlternativeSubPlan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlternativeSubPlan;
+}
+impl serde::Serialize for AlternativeSubPlan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlternativeSubPlan", 2usize)?;
+        state.serialize_field("node", "AlternativeSubPlan")?;
+        state.serialize_field(
+            "subplans",
+            &pg_sys::node_serde::NodePtr(self.subplans as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlternativeSubPlan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104343,6 +105583,29 @@ impl std::fmt::Display for AlternativeSubPlan {
 }
 impl pg_sys::seal::Sealed for AlternativeSubPlanState {}
 impl pg_sys::PgNode for AlternativeSubPlanState {}
+impl pg_sys::PgTaggedNode for AlternativeSubPlanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AlternativeSubPlanState;
+}
+impl serde::Serialize for AlternativeSubPlanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AlternativeSubPlanState", 4usize)?;
+        state.serialize_field("node", "AlternativeSubPlanState")?;
+        state.serialize_field(
+            "subplan",
+            &pg_sys::node_serde::NodePtr(self.subplan as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "subplans",
+            &pg_sys::node_serde::NodePtr(self.subplans as *const pg_sys::Node),
+        )?;
+        state.serialize_field("active", &self.active)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AlternativeSubPlanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104350,6 +105613,30 @@ impl std::fmt::Display for AlternativeSubPlanState {
 }
 impl pg_sys::seal::Sealed for Append {}
 impl pg_sys::PgNode for Append {}
+impl pg_sys::PgTaggedNode for Append {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Append;
+}
+impl serde::Serialize for Append {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Append", 5usize)?;
+        state.serialize_field("node", "Append")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field(
+            "appendplans",
+            &pg_sys::node_serde::NodePtr(self.appendplans as *const pg_sys::Node),
+        )?;
+        state.serialize_field("first_partial_plan", &self.first_partial_plan)?;
+        state.serialize_field(
+            "part_prune_info",
+            &pg_sys::node_serde::NodePtr(self.part_prune_info as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Append {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104357,6 +105644,31 @@ impl std::fmt::Display for Append {
 }
 impl pg_sys::seal::Sealed for AppendPath {}
 impl pg_sys::PgNode for AppendPath {}
+impl pg_sys::PgTaggedNode for AppendPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AppendPath;
+}
+impl serde::Serialize for AppendPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AppendPath", 6usize)?;
+        state.serialize_field("node", "AppendPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "partitioned_rels",
+            &pg_sys::node_serde::NodePtr(self.partitioned_rels as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "subpaths",
+            &pg_sys::node_serde::NodePtr(self.subpaths as *const pg_sys::Node),
+        )?;
+        state.serialize_field("first_partial_path", &self.first_partial_path)?;
+        state.serialize_field("limit_tuples", &self.limit_tuples)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AppendPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104364,6 +105676,29 @@ impl std::fmt::Display for AppendPath {
 }
 impl pg_sys::seal::Sealed for AppendRelInfo {}
 impl pg_sys::PgNode for AppendRelInfo {}
+impl pg_sys::PgTaggedNode for AppendRelInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AppendRelInfo;
+}
+impl serde::Serialize for AppendRelInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AppendRelInfo", 7usize)?;
+        state.serialize_field("node", "AppendRelInfo")?;
+        state.serialize_field("parent_relid", &self.parent_relid)?;
+        state.serialize_field("child_relid", &self.child_relid)?;
+        state.serialize_field("parent_reltype", &self.parent_reltype)?;
+        state.serialize_field("child_reltype", &self.child_reltype)?;
+        state.serialize_field(
+            "translated_vars",
+            &pg_sys::node_serde::NodePtr(self.translated_vars as *const pg_sys::Node),
+        )?;
+        state.serialize_field("parent_reloid", &self.parent_reloid)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AppendRelInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104371,6 +105706,24 @@ impl std::fmt::Display for AppendRelInfo {
 }
 impl pg_sys::seal::Sealed for AppendState {}
 impl pg_sys::PgNode for AppendState {}
+impl pg_sys::PgTaggedNode for AppendState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_AppendState;
+}
+impl serde::Serialize for AppendState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("AppendState", 5usize)?;
+        state.serialize_field("node", "AppendState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field("as_nplans", &self.as_nplans)?;
+        state.serialize_field("as_whichplan", &self.as_whichplan)?;
+        state.serialize_field("as_first_partial_plan", &self.as_first_partial_plan)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for AppendState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104378,6 +105731,33 @@ impl std::fmt::Display for AppendState {
 }
 impl pg_sys::seal::Sealed for ArrayCoerceExpr {}
 impl pg_sys::PgNode for ArrayCoerceExpr {}
+impl pg_sys::PgTaggedNode for ArrayCoerceExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ArrayCoerceExpr;
+}
+impl serde::Serialize for ArrayCoerceExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ArrayCoerceExpr", 8usize)?;
+        state.serialize_field("node", "ArrayCoerceExpr")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "elemexpr",
+            &pg_sys::node_serde::NodePtr(self.elemexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field("resulttype", &self.resulttype)?;
+        state.serialize_field("resulttypmod", &self.resulttypmod)?;
+        state.serialize_field("resultcollid", &self.resultcollid)?;
+        state.serialize_field("coerceformat", &self.coerceformat)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ArrayCoerceExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104385,6 +105765,29 @@ impl std::fmt::Display for ArrayCoerceExpr {
 }
 impl pg_sys::seal::Sealed for ArrayExpr {}
 impl pg_sys::PgNode for ArrayExpr {}
+impl pg_sys::PgTaggedNode for ArrayExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ArrayExpr;
+}
+impl serde::Serialize for ArrayExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ArrayExpr", 7usize)?;
+        state.serialize_field("node", "ArrayExpr")?;
+        state.serialize_field("array_typeid", &self.array_typeid)?;
+        state.serialize_field("array_collid", &self.array_collid)?;
+        state.serialize_field("element_typeid", &self.element_typeid)?;
+        state.serialize_field(
+            "elements",
+            &pg_sys::node_serde::NodePtr(self.elements as *const pg_sys::Node),
+        )?;
+        state.serialize_field("multidims", &self.multidims)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ArrayExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104392,6 +105795,24 @@ impl std::fmt::Display for ArrayExpr {
 }
 impl pg_sys::seal::Sealed for BaseBackupCmd {}
 impl pg_sys::PgNode for BaseBackupCmd {}
+impl pg_sys::PgTaggedNode for BaseBackupCmd {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BaseBackupCmd;
+}
+impl serde::Serialize for BaseBackupCmd {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BaseBackupCmd", 2usize)?;
+        state.serialize_field("node", "BaseBackupCmd")?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BaseBackupCmd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104399,6 +105820,25 @@ impl std::fmt::Display for BaseBackupCmd {
 }
 impl pg_sys::seal::Sealed for BitmapAnd {}
 impl pg_sys::PgNode for BitmapAnd {}
+impl pg_sys::PgTaggedNode for BitmapAnd {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BitmapAnd;
+}
+impl serde::Serialize for BitmapAnd {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BitmapAnd", 3usize)?;
+        state.serialize_field("node", "BitmapAnd")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field(
+            "bitmapplans",
+            &pg_sys::node_serde::NodePtr(self.bitmapplans as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BitmapAnd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104406,6 +105846,26 @@ impl std::fmt::Display for BitmapAnd {
 }
 impl pg_sys::seal::Sealed for BitmapAndPath {}
 impl pg_sys::PgNode for BitmapAndPath {}
+impl pg_sys::PgTaggedNode for BitmapAndPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BitmapAndPath;
+}
+impl serde::Serialize for BitmapAndPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BitmapAndPath", 4usize)?;
+        state.serialize_field("node", "BitmapAndPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "bitmapquals",
+            &pg_sys::node_serde::NodePtr(self.bitmapquals as *const pg_sys::Node),
+        )?;
+        state.serialize_field("bitmapselectivity", &self.bitmapselectivity)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BitmapAndPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104413,6 +105873,22 @@ impl std::fmt::Display for BitmapAndPath {
 }
 impl pg_sys::seal::Sealed for BitmapAndState {}
 impl pg_sys::PgNode for BitmapAndState {}
+impl pg_sys::PgTaggedNode for BitmapAndState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BitmapAndState;
+}
+impl serde::Serialize for BitmapAndState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BitmapAndState", 3usize)?;
+        state.serialize_field("node", "BitmapAndState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field("nplans", &self.nplans)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BitmapAndState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104420,6 +105896,25 @@ impl std::fmt::Display for BitmapAndState {
 }
 impl pg_sys::seal::Sealed for BitmapHeapPath {}
 impl pg_sys::PgNode for BitmapHeapPath {}
+impl pg_sys::PgTaggedNode for BitmapHeapPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BitmapHeapPath;
+}
+impl serde::Serialize for BitmapHeapPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BitmapHeapPath", 3usize)?;
+        state.serialize_field("node", "BitmapHeapPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "bitmapqual",
+            &pg_sys::node_serde::NodePtr(self.bitmapqual as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BitmapHeapPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104427,6 +105922,25 @@ impl std::fmt::Display for BitmapHeapPath {
 }
 impl pg_sys::seal::Sealed for BitmapHeapScan {}
 impl pg_sys::PgNode for BitmapHeapScan {}
+impl pg_sys::PgTaggedNode for BitmapHeapScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BitmapHeapScan;
+}
+impl serde::Serialize for BitmapHeapScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BitmapHeapScan", 3usize)?;
+        state.serialize_field("node", "BitmapHeapScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field(
+            "bitmapqualorig",
+            &pg_sys::node_serde::NodePtr(self.bitmapqualorig as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BitmapHeapScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104434,6 +105948,35 @@ impl std::fmt::Display for BitmapHeapScan {
 }
 impl pg_sys::seal::Sealed for BitmapHeapScanState {}
 impl pg_sys::PgNode for BitmapHeapScanState {}
+impl pg_sys::PgTaggedNode for BitmapHeapScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BitmapHeapScanState;
+}
+impl serde::Serialize for BitmapHeapScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BitmapHeapScanState", 13usize)?;
+        state.serialize_field("node", "BitmapHeapScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field(
+            "bitmapqualorig",
+            &pg_sys::node_serde::NodePtr(self.bitmapqualorig as *const pg_sys::Node),
+        )?;
+        state.serialize_field("can_skip_fetch", &self.can_skip_fetch)?;
+        state.serialize_field("return_empty_tuples", &self.return_empty_tuples)?;
+        state.serialize_field("vmbuffer", &self.vmbuffer)?;
+        state.serialize_field("pvmbuffer", &self.pvmbuffer)?;
+        state.serialize_field("exact_pages", &self.exact_pages)?;
+        state.serialize_field("lossy_pages", &self.lossy_pages)?;
+        state.serialize_field("prefetch_pages", &self.prefetch_pages)?;
+        state.serialize_field("prefetch_target", &self.prefetch_target)?;
+        state.serialize_field("prefetch_maximum", &self.prefetch_maximum)?;
+        state.serialize_field("initialized", &self.initialized)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BitmapHeapScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104441,6 +105984,31 @@ impl std::fmt::Display for BitmapHeapScanState {
 }
 impl pg_sys::seal::Sealed for BitmapIndexScan {}
 impl pg_sys::PgNode for BitmapIndexScan {}
+impl pg_sys::PgTaggedNode for BitmapIndexScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BitmapIndexScan;
+}
+impl serde::Serialize for BitmapIndexScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BitmapIndexScan", 6usize)?;
+        state.serialize_field("node", "BitmapIndexScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field("indexid", &self.indexid)?;
+        state.serialize_field("isshared", &self.isshared)?;
+        state.serialize_field(
+            "indexqual",
+            &pg_sys::node_serde::NodePtr(self.indexqual as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexqualorig",
+            &pg_sys::node_serde::NodePtr(self.indexqualorig as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BitmapIndexScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104448,6 +106016,29 @@ impl std::fmt::Display for BitmapIndexScan {
 }
 impl pg_sys::seal::Sealed for BitmapIndexScanState {}
 impl pg_sys::PgNode for BitmapIndexScanState {}
+impl pg_sys::PgTaggedNode for BitmapIndexScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BitmapIndexScanState;
+}
+impl serde::Serialize for BitmapIndexScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BitmapIndexScanState", 7usize)?;
+        state.serialize_field("node", "BitmapIndexScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field("biss_NumScanKeys", &self.biss_NumScanKeys)?;
+        state.serialize_field("biss_NumRuntimeKeys", &self.biss_NumRuntimeKeys)?;
+        state.serialize_field("biss_NumArrayKeys", &self.biss_NumArrayKeys)?;
+        state.serialize_field("biss_RuntimeKeysReady", &self.biss_RuntimeKeysReady)?;
+        state.serialize_field(
+            "biss_RuntimeContext",
+            &pg_sys::node_serde::NodePtr(self.biss_RuntimeContext as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BitmapIndexScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104455,6 +106046,26 @@ impl std::fmt::Display for BitmapIndexScanState {
 }
 impl pg_sys::seal::Sealed for BitmapOr {}
 impl pg_sys::PgNode for BitmapOr {}
+impl pg_sys::PgTaggedNode for BitmapOr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BitmapOr;
+}
+impl serde::Serialize for BitmapOr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BitmapOr", 4usize)?;
+        state.serialize_field("node", "BitmapOr")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field("isshared", &self.isshared)?;
+        state.serialize_field(
+            "bitmapplans",
+            &pg_sys::node_serde::NodePtr(self.bitmapplans as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BitmapOr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104462,6 +106073,26 @@ impl std::fmt::Display for BitmapOr {
 }
 impl pg_sys::seal::Sealed for BitmapOrPath {}
 impl pg_sys::PgNode for BitmapOrPath {}
+impl pg_sys::PgTaggedNode for BitmapOrPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BitmapOrPath;
+}
+impl serde::Serialize for BitmapOrPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BitmapOrPath", 4usize)?;
+        state.serialize_field("node", "BitmapOrPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "bitmapquals",
+            &pg_sys::node_serde::NodePtr(self.bitmapquals as *const pg_sys::Node),
+        )?;
+        state.serialize_field("bitmapselectivity", &self.bitmapselectivity)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BitmapOrPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104469,6 +106100,22 @@ impl std::fmt::Display for BitmapOrPath {
 }
 impl pg_sys::seal::Sealed for BitmapOrState {}
 impl pg_sys::PgNode for BitmapOrState {}
+impl pg_sys::PgTaggedNode for BitmapOrState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BitmapOrState;
+}
+impl serde::Serialize for BitmapOrState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BitmapOrState", 3usize)?;
+        state.serialize_field("node", "BitmapOrState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field("nplans", &self.nplans)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BitmapOrState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104476,6 +106123,26 @@ impl std::fmt::Display for BitmapOrState {
 }
 impl pg_sys::seal::Sealed for BoolExpr {}
 impl pg_sys::PgNode for BoolExpr {}
+impl pg_sys::PgTaggedNode for BoolExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BoolExpr;
+}
+impl serde::Serialize for BoolExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BoolExpr", 4usize)?;
+        state.serialize_field("node", "BoolExpr")?;
+        state.serialize_field("boolop", &self.boolop)?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BoolExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104483,6 +106150,26 @@ impl std::fmt::Display for BoolExpr {
 }
 impl pg_sys::seal::Sealed for BooleanTest {}
 impl pg_sys::PgNode for BooleanTest {}
+impl pg_sys::PgTaggedNode for BooleanTest {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_BooleanTest;
+}
+impl serde::Serialize for BooleanTest {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BooleanTest", 4usize)?;
+        state.serialize_field("node", "BooleanTest")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field("booltesttype", &self.booltesttype)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BooleanTest {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104490,6 +106177,19 @@ impl std::fmt::Display for BooleanTest {
 }
 impl pg_sys::seal::Sealed for BufferHeapTupleTableSlot {}
 impl pg_sys::PgNode for BufferHeapTupleTableSlot {}
+impl serde::Serialize for BufferHeapTupleTableSlot {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("BufferHeapTupleTableSlot", 3usize)?;
+        state.serialize_field("node", "BufferHeapTupleTableSlot")?;
+        state.serialize_field("base", &self.base)?;
+        state.serialize_field("buffer", &self.buffer)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for BufferHeapTupleTableSlot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104497,6 +106197,21 @@ impl std::fmt::Display for BufferHeapTupleTableSlot {
 }
 impl pg_sys::seal::Sealed for CallContext {}
 impl pg_sys::PgNode for CallContext {}
+impl pg_sys::PgTaggedNode for CallContext {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CallContext;
+}
+impl serde::Serialize for CallContext {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CallContext", 2usize)?;
+        state.serialize_field("node", "CallContext")?;
+        state.serialize_field("atomic", &self.atomic)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CallContext {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104504,6 +106219,28 @@ impl std::fmt::Display for CallContext {
 }
 impl pg_sys::seal::Sealed for CallStmt {}
 impl pg_sys::PgNode for CallStmt {}
+impl pg_sys::PgTaggedNode for CallStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CallStmt;
+}
+impl serde::Serialize for CallStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CallStmt", 3usize)?;
+        state.serialize_field("node", "CallStmt")?;
+        state.serialize_field(
+            "funccall",
+            &pg_sys::node_serde::NodePtr(self.funccall as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "funcexpr",
+            &pg_sys::node_serde::NodePtr(self.funcexpr as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CallStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104511,6 +106248,35 @@ impl std::fmt::Display for CallStmt {
 }
 impl pg_sys::seal::Sealed for CaseExpr {}
 impl pg_sys::PgNode for CaseExpr {}
+impl pg_sys::PgTaggedNode for CaseExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CaseExpr;
+}
+impl serde::Serialize for CaseExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CaseExpr", 7usize)?;
+        state.serialize_field("node", "CaseExpr")?;
+        state.serialize_field("casetype", &self.casetype)?;
+        state.serialize_field("casecollid", &self.casecollid)?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "defresult",
+            &pg_sys::node_serde::NodePtr(self.defresult as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CaseExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104518,6 +106284,23 @@ impl std::fmt::Display for CaseExpr {
 }
 impl pg_sys::seal::Sealed for CaseTestExpr {}
 impl pg_sys::PgNode for CaseTestExpr {}
+impl pg_sys::PgTaggedNode for CaseTestExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CaseTestExpr;
+}
+impl serde::Serialize for CaseTestExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CaseTestExpr", 4usize)?;
+        state.serialize_field("node", "CaseTestExpr")?;
+        state.serialize_field("typeId", &self.typeId)?;
+        state.serialize_field("typeMod", &self.typeMod)?;
+        state.serialize_field("collation", &self.collation)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CaseTestExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104525,6 +106308,29 @@ impl std::fmt::Display for CaseTestExpr {
 }
 impl pg_sys::seal::Sealed for CaseWhen {}
 impl pg_sys::PgNode for CaseWhen {}
+impl pg_sys::PgTaggedNode for CaseWhen {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CaseWhen;
+}
+impl serde::Serialize for CaseWhen {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CaseWhen", 4usize)?;
+        state.serialize_field("node", "CaseWhen")?;
+        state.serialize_field(
+            "expr",
+            &pg_sys::node_serde::NodePtr(self.expr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "result",
+            &pg_sys::node_serde::NodePtr(self.result as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CaseWhen {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104532,6 +106338,20 @@ impl std::fmt::Display for CaseWhen {
 }
 impl pg_sys::seal::Sealed for CheckPointStmt {}
 impl pg_sys::PgNode for CheckPointStmt {}
+impl pg_sys::PgTaggedNode for CheckPointStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CheckPointStmt;
+}
+impl serde::Serialize for CheckPointStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CheckPointStmt", 1usize)?;
+        state.serialize_field("node", "CheckPointStmt")?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CheckPointStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104539,6 +106359,24 @@ impl std::fmt::Display for CheckPointStmt {
 }
 impl pg_sys::seal::Sealed for ClosePortalStmt {}
 impl pg_sys::PgNode for ClosePortalStmt {}
+impl pg_sys::PgTaggedNode for ClosePortalStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ClosePortalStmt;
+}
+impl serde::Serialize for ClosePortalStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ClosePortalStmt", 2usize)?;
+        state.serialize_field("node", "ClosePortalStmt")?;
+        state.serialize_field(
+            "portalname",
+            &pg_sys::node_serde::CStringPtr(self.portalname as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ClosePortalStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104546,6 +106384,29 @@ impl std::fmt::Display for ClosePortalStmt {
 }
 impl pg_sys::seal::Sealed for ClusterStmt {}
 impl pg_sys::PgNode for ClusterStmt {}
+impl pg_sys::PgTaggedNode for ClusterStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ClusterStmt;
+}
+impl serde::Serialize for ClusterStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ClusterStmt", 4usize)?;
+        state.serialize_field("node", "ClusterStmt")?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexname",
+            &pg_sys::node_serde::CStringPtr(self.indexname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("options", &self.options)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ClusterStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104553,6 +106414,27 @@ impl std::fmt::Display for ClusterStmt {
 }
 impl pg_sys::seal::Sealed for CoalesceExpr {}
 impl pg_sys::PgNode for CoalesceExpr {}
+impl pg_sys::PgTaggedNode for CoalesceExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CoalesceExpr;
+}
+impl serde::Serialize for CoalesceExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CoalesceExpr", 5usize)?;
+        state.serialize_field("node", "CoalesceExpr")?;
+        state.serialize_field("coalescetype", &self.coalescetype)?;
+        state.serialize_field("coalescecollid", &self.coalescecollid)?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CoalesceExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104560,6 +106442,29 @@ impl std::fmt::Display for CoalesceExpr {
 }
 impl pg_sys::seal::Sealed for CoerceToDomain {}
 impl pg_sys::PgNode for CoerceToDomain {}
+impl pg_sys::PgTaggedNode for CoerceToDomain {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CoerceToDomain;
+}
+impl serde::Serialize for CoerceToDomain {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CoerceToDomain", 7usize)?;
+        state.serialize_field("node", "CoerceToDomain")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field("resulttype", &self.resulttype)?;
+        state.serialize_field("resulttypmod", &self.resulttypmod)?;
+        state.serialize_field("resultcollid", &self.resultcollid)?;
+        state.serialize_field("coercionformat", &self.coercionformat)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CoerceToDomain {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104567,6 +106472,24 @@ impl std::fmt::Display for CoerceToDomain {
 }
 impl pg_sys::seal::Sealed for CoerceToDomainValue {}
 impl pg_sys::PgNode for CoerceToDomainValue {}
+impl pg_sys::PgTaggedNode for CoerceToDomainValue {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CoerceToDomainValue;
+}
+impl serde::Serialize for CoerceToDomainValue {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CoerceToDomainValue", 5usize)?;
+        state.serialize_field("node", "CoerceToDomainValue")?;
+        state.serialize_field("typeId", &self.typeId)?;
+        state.serialize_field("typeMod", &self.typeMod)?;
+        state.serialize_field("collation", &self.collation)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CoerceToDomainValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104574,6 +106497,28 @@ impl std::fmt::Display for CoerceToDomainValue {
 }
 impl pg_sys::seal::Sealed for CoerceViaIO {}
 impl pg_sys::PgNode for CoerceViaIO {}
+impl pg_sys::PgTaggedNode for CoerceViaIO {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CoerceViaIO;
+}
+impl serde::Serialize for CoerceViaIO {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CoerceViaIO", 6usize)?;
+        state.serialize_field("node", "CoerceViaIO")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field("resulttype", &self.resulttype)?;
+        state.serialize_field("resultcollid", &self.resultcollid)?;
+        state.serialize_field("coerceformat", &self.coerceformat)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CoerceViaIO {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104581,6 +106526,29 @@ impl std::fmt::Display for CoerceViaIO {
 }
 impl pg_sys::seal::Sealed for CollateClause {}
 impl pg_sys::PgNode for CollateClause {}
+impl pg_sys::PgTaggedNode for CollateClause {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CollateClause;
+}
+impl serde::Serialize for CollateClause {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CollateClause", 4usize)?;
+        state.serialize_field("node", "CollateClause")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "collname",
+            &pg_sys::node_serde::NodePtr(self.collname as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CollateClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104588,6 +106556,26 @@ impl std::fmt::Display for CollateClause {
 }
 impl pg_sys::seal::Sealed for CollateExpr {}
 impl pg_sys::PgNode for CollateExpr {}
+impl pg_sys::PgTaggedNode for CollateExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CollateExpr;
+}
+impl serde::Serialize for CollateExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CollateExpr", 4usize)?;
+        state.serialize_field("node", "CollateExpr")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field("collOid", &self.collOid)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CollateExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104595,6 +106583,61 @@ impl std::fmt::Display for CollateExpr {
 }
 impl pg_sys::seal::Sealed for ColumnDef {}
 impl pg_sys::PgNode for ColumnDef {}
+impl pg_sys::PgTaggedNode for ColumnDef {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ColumnDef;
+}
+impl serde::Serialize for ColumnDef {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ColumnDef", 18usize)?;
+        state.serialize_field("node", "ColumnDef")?;
+        state.serialize_field(
+            "colname",
+            &pg_sys::node_serde::CStringPtr(self.colname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "typeName",
+            &pg_sys::node_serde::NodePtr(self.typeName as *const pg_sys::Node),
+        )?;
+        state.serialize_field("inhcount", &self.inhcount)?;
+        state.serialize_field("is_local", &self.is_local)?;
+        state.serialize_field("is_not_null", &self.is_not_null)?;
+        state.serialize_field("is_from_type", &self.is_from_type)?;
+        state.serialize_field("storage", &self.storage)?;
+        state.serialize_field(
+            "raw_default",
+            &pg_sys::node_serde::NodePtr(self.raw_default as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "cooked_default",
+            &pg_sys::node_serde::NodePtr(self.cooked_default as *const pg_sys::Node),
+        )?;
+        state.serialize_field("identity", &self.identity)?;
+        state.serialize_field(
+            "identitySequence",
+            &pg_sys::node_serde::NodePtr(self.identitySequence as *const pg_sys::Node),
+        )?;
+        state.serialize_field("generated", &self.generated)?;
+        state.serialize_field(
+            "collClause",
+            &pg_sys::node_serde::NodePtr(self.collClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field("collOid", &self.collOid)?;
+        state.serialize_field(
+            "constraints",
+            &pg_sys::node_serde::NodePtr(self.constraints as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "fdwoptions",
+            &pg_sys::node_serde::NodePtr(self.fdwoptions as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ColumnDef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104602,6 +106645,25 @@ impl std::fmt::Display for ColumnDef {
 }
 impl pg_sys::seal::Sealed for ColumnRef {}
 impl pg_sys::PgNode for ColumnRef {}
+impl pg_sys::PgTaggedNode for ColumnRef {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ColumnRef;
+}
+impl serde::Serialize for ColumnRef {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ColumnRef", 3usize)?;
+        state.serialize_field("node", "ColumnRef")?;
+        state.serialize_field(
+            "fields",
+            &pg_sys::node_serde::NodePtr(self.fields as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ColumnRef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104609,6 +106671,29 @@ impl std::fmt::Display for ColumnRef {
 }
 impl pg_sys::seal::Sealed for CommentStmt {}
 impl pg_sys::PgNode for CommentStmt {}
+impl pg_sys::PgTaggedNode for CommentStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CommentStmt;
+}
+impl serde::Serialize for CommentStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CommentStmt", 4usize)?;
+        state.serialize_field("node", "CommentStmt")?;
+        state.serialize_field("objtype", &self.objtype)?;
+        state.serialize_field(
+            "object",
+            &pg_sys::node_serde::NodePtr(self.object as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "comment",
+            &pg_sys::node_serde::CStringPtr(self.comment as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CommentStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104616,6 +106701,52 @@ impl std::fmt::Display for CommentStmt {
 }
 impl pg_sys::seal::Sealed for CommonTableExpr {}
 impl pg_sys::PgNode for CommonTableExpr {}
+impl pg_sys::PgTaggedNode for CommonTableExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CommonTableExpr;
+}
+impl serde::Serialize for CommonTableExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CommonTableExpr", 12usize)?;
+        state.serialize_field("node", "CommonTableExpr")?;
+        state.serialize_field(
+            "ctename",
+            &pg_sys::node_serde::CStringPtr(self.ctename as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "aliascolnames",
+            &pg_sys::node_serde::NodePtr(self.aliascolnames as *const pg_sys::Node),
+        )?;
+        state.serialize_field("ctematerialized", &self.ctematerialized)?;
+        state.serialize_field(
+            "ctequery",
+            &pg_sys::node_serde::NodePtr(self.ctequery as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.serialize_field("cterecursive", &self.cterecursive)?;
+        state.serialize_field("cterefcount", &self.cterefcount)?;
+        state.serialize_field(
+            "ctecolnames",
+            &pg_sys::node_serde::NodePtr(self.ctecolnames as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ctecoltypes",
+            &pg_sys::node_serde::NodePtr(self.ctecoltypes as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ctecoltypmods",
+            &pg_sys::node_serde::NodePtr(self.ctecoltypmods as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ctecolcollations",
+            &pg_sys::node_serde::NodePtr(self.ctecolcollations as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CommonTableExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104623,6 +106754,28 @@ impl std::fmt::Display for CommonTableExpr {
 }
 impl pg_sys::seal::Sealed for CompositeTypeStmt {}
 impl pg_sys::PgNode for CompositeTypeStmt {}
+impl pg_sys::PgTaggedNode for CompositeTypeStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CompositeTypeStmt;
+}
+impl serde::Serialize for CompositeTypeStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CompositeTypeStmt", 3usize)?;
+        state.serialize_field("node", "CompositeTypeStmt")?;
+        state.serialize_field(
+            "typevar",
+            &pg_sys::node_serde::NodePtr(self.typevar as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "coldeflist",
+            &pg_sys::node_serde::NodePtr(self.coldeflist as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CompositeTypeStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104630,6 +106783,28 @@ impl std::fmt::Display for CompositeTypeStmt {
 }
 impl pg_sys::seal::Sealed for Const {}
 impl pg_sys::PgNode for Const {}
+impl pg_sys::PgTaggedNode for Const {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Const;
+}
+impl serde::Serialize for Const {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Const", 9usize)?;
+        state.serialize_field("node", "Const")?;
+        state.serialize_field("consttype", &self.consttype)?;
+        state.serialize_field("consttypmod", &self.consttypmod)?;
+        state.serialize_field("constcollid", &self.constcollid)?;
+        state.serialize_field("constlen", &self.constlen)?;
+        state.serialize_field("constvalue", &self.constvalue.value())?;
+        state.serialize_field("constisnull", &self.constisnull)?;
+        state.serialize_field("constbyval", &self.constbyval)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Const {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104637,6 +106812,93 @@ impl std::fmt::Display for Const {
 }
 impl pg_sys::seal::Sealed for Constraint {}
 impl pg_sys::PgNode for Constraint {}
+impl pg_sys::PgTaggedNode for Constraint {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Constraint;
+}
+impl serde::Serialize for Constraint {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Constraint", 29usize)?;
+        state.serialize_field("node", "Constraint")?;
+        state.serialize_field("contype", &self.contype)?;
+        state.serialize_field(
+            "conname",
+            &pg_sys::node_serde::CStringPtr(self.conname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("deferrable", &self.deferrable)?;
+        state.serialize_field("initdeferred", &self.initdeferred)?;
+        state.serialize_field("location", &self.location)?;
+        state.serialize_field("is_no_inherit", &self.is_no_inherit)?;
+        state.serialize_field(
+            "raw_expr",
+            &pg_sys::node_serde::NodePtr(self.raw_expr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "cooked_expr",
+            &pg_sys::node_serde::CStringPtr(self.cooked_expr as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("generated_when", &self.generated_when)?;
+        state.serialize_field(
+            "keys",
+            &pg_sys::node_serde::NodePtr(self.keys as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "including",
+            &pg_sys::node_serde::NodePtr(self.including as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "exclusions",
+            &pg_sys::node_serde::NodePtr(self.exclusions as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexname",
+            &pg_sys::node_serde::CStringPtr(self.indexname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "indexspace",
+            &pg_sys::node_serde::CStringPtr(self.indexspace as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("reset_default_tblspc", &self.reset_default_tblspc)?;
+        state.serialize_field(
+            "access_method",
+            &pg_sys::node_serde::CStringPtr(self.access_method as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "where_clause",
+            &pg_sys::node_serde::NodePtr(self.where_clause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "pktable",
+            &pg_sys::node_serde::NodePtr(self.pktable as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "fk_attrs",
+            &pg_sys::node_serde::NodePtr(self.fk_attrs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "pk_attrs",
+            &pg_sys::node_serde::NodePtr(self.pk_attrs as *const pg_sys::Node),
+        )?;
+        state.serialize_field("fk_matchtype", &self.fk_matchtype)?;
+        state.serialize_field("fk_upd_action", &self.fk_upd_action)?;
+        state.serialize_field("fk_del_action", &self.fk_del_action)?;
+        state.serialize_field(
+            "old_conpfeqop",
+            &pg_sys::node_serde::NodePtr(self.old_conpfeqop as *const pg_sys::Node),
+        )?;
+        state.serialize_field("old_pktable_oid", &self.old_pktable_oid)?;
+        state.serialize_field("skip_validation", &self.skip_validation)?;
+        state.serialize_field("initially_valid", &self.initially_valid)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Constraint {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104644,6 +106906,25 @@ impl std::fmt::Display for Constraint {
 }
 impl pg_sys::seal::Sealed for ConstraintsSetStmt {}
 impl pg_sys::PgNode for ConstraintsSetStmt {}
+impl pg_sys::PgTaggedNode for ConstraintsSetStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ConstraintsSetStmt;
+}
+impl serde::Serialize for ConstraintsSetStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ConstraintsSetStmt", 3usize)?;
+        state.serialize_field("node", "ConstraintsSetStmt")?;
+        state.serialize_field(
+            "constraints",
+            &pg_sys::node_serde::NodePtr(self.constraints as *const pg_sys::Node),
+        )?;
+        state.serialize_field("deferred", &self.deferred)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ConstraintsSetStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104651,6 +106932,27 @@ impl std::fmt::Display for ConstraintsSetStmt {
 }
 impl pg_sys::seal::Sealed for ConvertRowtypeExpr {}
 impl pg_sys::PgNode for ConvertRowtypeExpr {}
+impl pg_sys::PgTaggedNode for ConvertRowtypeExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ConvertRowtypeExpr;
+}
+impl serde::Serialize for ConvertRowtypeExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ConvertRowtypeExpr", 5usize)?;
+        state.serialize_field("node", "ConvertRowtypeExpr")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field("resulttype", &self.resulttype)?;
+        state.serialize_field("convertformat", &self.convertformat)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ConvertRowtypeExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104658,6 +106960,46 @@ impl std::fmt::Display for ConvertRowtypeExpr {
 }
 impl pg_sys::seal::Sealed for CopyStmt {}
 impl pg_sys::PgNode for CopyStmt {}
+impl pg_sys::PgTaggedNode for CopyStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CopyStmt;
+}
+impl serde::Serialize for CopyStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CopyStmt", 9usize)?;
+        state.serialize_field("node", "CopyStmt")?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "query",
+            &pg_sys::node_serde::NodePtr(self.query as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "attlist",
+            &pg_sys::node_serde::NodePtr(self.attlist as *const pg_sys::Node),
+        )?;
+        state.serialize_field("is_from", &self.is_from)?;
+        state.serialize_field("is_program", &self.is_program)?;
+        state.serialize_field(
+            "filename",
+            &pg_sys::node_serde::CStringPtr(self.filename as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "whereClause",
+            &pg_sys::node_serde::NodePtr(self.whereClause as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CopyStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104665,6 +107007,29 @@ impl std::fmt::Display for CopyStmt {
 }
 impl pg_sys::seal::Sealed for CreateAmStmt {}
 impl pg_sys::PgNode for CreateAmStmt {}
+impl pg_sys::PgTaggedNode for CreateAmStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateAmStmt;
+}
+impl serde::Serialize for CreateAmStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateAmStmt", 4usize)?;
+        state.serialize_field("node", "CreateAmStmt")?;
+        state.serialize_field(
+            "amname",
+            &pg_sys::node_serde::CStringPtr(self.amname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "handler_name",
+            &pg_sys::node_serde::NodePtr(self.handler_name as *const pg_sys::Node),
+        )?;
+        state.serialize_field("amtype", &self.amtype)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateAmStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104672,6 +107037,34 @@ impl std::fmt::Display for CreateAmStmt {
 }
 impl pg_sys::seal::Sealed for CreateCastStmt {}
 impl pg_sys::PgNode for CreateCastStmt {}
+impl pg_sys::PgTaggedNode for CreateCastStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateCastStmt;
+}
+impl serde::Serialize for CreateCastStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateCastStmt", 6usize)?;
+        state.serialize_field("node", "CreateCastStmt")?;
+        state.serialize_field(
+            "sourcetype",
+            &pg_sys::node_serde::NodePtr(self.sourcetype as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "targettype",
+            &pg_sys::node_serde::NodePtr(self.targettype as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "func",
+            &pg_sys::node_serde::NodePtr(self.func as *const pg_sys::Node),
+        )?;
+        state.serialize_field("context", &self.context)?;
+        state.serialize_field("inout", &self.inout)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateCastStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104679,6 +107072,37 @@ impl std::fmt::Display for CreateCastStmt {
 }
 impl pg_sys::seal::Sealed for CreateConversionStmt {}
 impl pg_sys::PgNode for CreateConversionStmt {}
+impl pg_sys::PgTaggedNode for CreateConversionStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateConversionStmt;
+}
+impl serde::Serialize for CreateConversionStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateConversionStmt", 6usize)?;
+        state.serialize_field("node", "CreateConversionStmt")?;
+        state.serialize_field(
+            "conversion_name",
+            &pg_sys::node_serde::NodePtr(self.conversion_name as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "for_encoding_name",
+            &pg_sys::node_serde::CStringPtr(self.for_encoding_name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "to_encoding_name",
+            &pg_sys::node_serde::CStringPtr(self.to_encoding_name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "func_name",
+            &pg_sys::node_serde::NodePtr(self.func_name as *const pg_sys::Node),
+        )?;
+        state.serialize_field("def", &self.def)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateConversionStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104686,6 +107110,36 @@ impl std::fmt::Display for CreateConversionStmt {
 }
 impl pg_sys::seal::Sealed for CreateDomainStmt {}
 impl pg_sys::PgNode for CreateDomainStmt {}
+impl pg_sys::PgTaggedNode for CreateDomainStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateDomainStmt;
+}
+impl serde::Serialize for CreateDomainStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateDomainStmt", 5usize)?;
+        state.serialize_field("node", "CreateDomainStmt")?;
+        state.serialize_field(
+            "domainname",
+            &pg_sys::node_serde::NodePtr(self.domainname as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "typeName",
+            &pg_sys::node_serde::NodePtr(self.typeName as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "collClause",
+            &pg_sys::node_serde::NodePtr(self.collClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "constraints",
+            &pg_sys::node_serde::NodePtr(self.constraints as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateDomainStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104693,6 +107147,28 @@ impl std::fmt::Display for CreateDomainStmt {
 }
 impl pg_sys::seal::Sealed for CreateEnumStmt {}
 impl pg_sys::PgNode for CreateEnumStmt {}
+impl pg_sys::PgTaggedNode for CreateEnumStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateEnumStmt;
+}
+impl serde::Serialize for CreateEnumStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateEnumStmt", 3usize)?;
+        state.serialize_field("node", "CreateEnumStmt")?;
+        state.serialize_field(
+            "typeName",
+            &pg_sys::node_serde::NodePtr(self.typeName as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "vals",
+            &pg_sys::node_serde::NodePtr(self.vals as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateEnumStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104700,6 +107176,36 @@ impl std::fmt::Display for CreateEnumStmt {
 }
 impl pg_sys::seal::Sealed for CreateEventTrigStmt {}
 impl pg_sys::PgNode for CreateEventTrigStmt {}
+impl pg_sys::PgTaggedNode for CreateEventTrigStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateEventTrigStmt;
+}
+impl serde::Serialize for CreateEventTrigStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateEventTrigStmt", 5usize)?;
+        state.serialize_field("node", "CreateEventTrigStmt")?;
+        state.serialize_field(
+            "trigname",
+            &pg_sys::node_serde::CStringPtr(self.trigname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "eventname",
+            &pg_sys::node_serde::CStringPtr(self.eventname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "whenclause",
+            &pg_sys::node_serde::NodePtr(self.whenclause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "funcname",
+            &pg_sys::node_serde::NodePtr(self.funcname as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateEventTrigStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104707,6 +107213,29 @@ impl std::fmt::Display for CreateEventTrigStmt {
 }
 impl pg_sys::seal::Sealed for CreateExtensionStmt {}
 impl pg_sys::PgNode for CreateExtensionStmt {}
+impl pg_sys::PgTaggedNode for CreateExtensionStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateExtensionStmt;
+}
+impl serde::Serialize for CreateExtensionStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateExtensionStmt", 4usize)?;
+        state.serialize_field("node", "CreateExtensionStmt")?;
+        state.serialize_field(
+            "extname",
+            &pg_sys::node_serde::CStringPtr(self.extname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("if_not_exists", &self.if_not_exists)?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateExtensionStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104714,6 +107243,32 @@ impl std::fmt::Display for CreateExtensionStmt {
 }
 impl pg_sys::seal::Sealed for CreateFdwStmt {}
 impl pg_sys::PgNode for CreateFdwStmt {}
+impl pg_sys::PgTaggedNode for CreateFdwStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateFdwStmt;
+}
+impl serde::Serialize for CreateFdwStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateFdwStmt", 4usize)?;
+        state.serialize_field("node", "CreateFdwStmt")?;
+        state.serialize_field(
+            "fdwname",
+            &pg_sys::node_serde::CStringPtr(self.fdwname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "func_options",
+            &pg_sys::node_serde::NodePtr(self.func_options as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateFdwStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104721,6 +107276,41 @@ impl std::fmt::Display for CreateFdwStmt {
 }
 impl pg_sys::seal::Sealed for CreateForeignServerStmt {}
 impl pg_sys::PgNode for CreateForeignServerStmt {}
+impl pg_sys::PgTaggedNode for CreateForeignServerStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateForeignServerStmt;
+}
+impl serde::Serialize for CreateForeignServerStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateForeignServerStmt", 7usize)?;
+        state.serialize_field("node", "CreateForeignServerStmt")?;
+        state.serialize_field(
+            "servername",
+            &pg_sys::node_serde::CStringPtr(self.servername as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "servertype",
+            &pg_sys::node_serde::CStringPtr(self.servertype as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "version",
+            &pg_sys::node_serde::CStringPtr(self.version as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "fdwname",
+            &pg_sys::node_serde::CStringPtr(self.fdwname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("if_not_exists", &self.if_not_exists)?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateForeignServerStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104728,6 +107318,29 @@ impl std::fmt::Display for CreateForeignServerStmt {
 }
 impl pg_sys::seal::Sealed for CreateForeignTableStmt {}
 impl pg_sys::PgNode for CreateForeignTableStmt {}
+impl pg_sys::PgTaggedNode for CreateForeignTableStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateForeignTableStmt;
+}
+impl serde::Serialize for CreateForeignTableStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateForeignTableStmt", 4usize)?;
+        state.serialize_field("node", "CreateForeignTableStmt")?;
+        state.serialize_field("base", &self.base)?;
+        state.serialize_field(
+            "servername",
+            &pg_sys::node_serde::CStringPtr(self.servername as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateForeignTableStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104735,6 +107348,38 @@ impl std::fmt::Display for CreateForeignTableStmt {
 }
 impl pg_sys::seal::Sealed for CreateFunctionStmt {}
 impl pg_sys::PgNode for CreateFunctionStmt {}
+impl pg_sys::PgTaggedNode for CreateFunctionStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateFunctionStmt;
+}
+impl serde::Serialize for CreateFunctionStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateFunctionStmt", 7usize)?;
+        state.serialize_field("node", "CreateFunctionStmt")?;
+        state.serialize_field("is_procedure", &self.is_procedure)?;
+        state.serialize_field("replace", &self.replace)?;
+        state.serialize_field(
+            "funcname",
+            &pg_sys::node_serde::NodePtr(self.funcname as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "parameters",
+            &pg_sys::node_serde::NodePtr(self.parameters as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "returnType",
+            &pg_sys::node_serde::NodePtr(self.returnType as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateFunctionStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104742,6 +107387,38 @@ impl std::fmt::Display for CreateFunctionStmt {
 }
 impl pg_sys::seal::Sealed for CreateOpClassItem {}
 impl pg_sys::PgNode for CreateOpClassItem {}
+impl pg_sys::PgTaggedNode for CreateOpClassItem {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateOpClassItem;
+}
+impl serde::Serialize for CreateOpClassItem {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateOpClassItem", 7usize)?;
+        state.serialize_field("node", "CreateOpClassItem")?;
+        state.serialize_field("itemtype", &self.itemtype)?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::NodePtr(self.name as *const pg_sys::Node),
+        )?;
+        state.serialize_field("number", &self.number)?;
+        state.serialize_field(
+            "order_family",
+            &pg_sys::node_serde::NodePtr(self.order_family as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "class_args",
+            &pg_sys::node_serde::NodePtr(self.class_args as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "storedtype",
+            &pg_sys::node_serde::NodePtr(self.storedtype as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateOpClassItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104749,6 +107426,41 @@ impl std::fmt::Display for CreateOpClassItem {
 }
 impl pg_sys::seal::Sealed for CreateOpClassStmt {}
 impl pg_sys::PgNode for CreateOpClassStmt {}
+impl pg_sys::PgTaggedNode for CreateOpClassStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateOpClassStmt;
+}
+impl serde::Serialize for CreateOpClassStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateOpClassStmt", 7usize)?;
+        state.serialize_field("node", "CreateOpClassStmt")?;
+        state.serialize_field(
+            "opclassname",
+            &pg_sys::node_serde::NodePtr(self.opclassname as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "opfamilyname",
+            &pg_sys::node_serde::NodePtr(self.opfamilyname as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "amname",
+            &pg_sys::node_serde::CStringPtr(self.amname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "datatype",
+            &pg_sys::node_serde::NodePtr(self.datatype as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "items",
+            &pg_sys::node_serde::NodePtr(self.items as *const pg_sys::Node),
+        )?;
+        state.serialize_field("isDefault", &self.isDefault)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateOpClassStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104756,6 +107468,28 @@ impl std::fmt::Display for CreateOpClassStmt {
 }
 impl pg_sys::seal::Sealed for CreateOpFamilyStmt {}
 impl pg_sys::PgNode for CreateOpFamilyStmt {}
+impl pg_sys::PgTaggedNode for CreateOpFamilyStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateOpFamilyStmt;
+}
+impl serde::Serialize for CreateOpFamilyStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateOpFamilyStmt", 3usize)?;
+        state.serialize_field("node", "CreateOpFamilyStmt")?;
+        state.serialize_field(
+            "opfamilyname",
+            &pg_sys::node_serde::NodePtr(self.opfamilyname as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "amname",
+            &pg_sys::node_serde::CStringPtr(self.amname as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateOpFamilyStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104763,6 +107497,38 @@ impl std::fmt::Display for CreateOpFamilyStmt {
 }
 impl pg_sys::seal::Sealed for CreatePLangStmt {}
 impl pg_sys::PgNode for CreatePLangStmt {}
+impl pg_sys::PgTaggedNode for CreatePLangStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreatePLangStmt;
+}
+impl serde::Serialize for CreatePLangStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreatePLangStmt", 7usize)?;
+        state.serialize_field("node", "CreatePLangStmt")?;
+        state.serialize_field("replace", &self.replace)?;
+        state.serialize_field(
+            "plname",
+            &pg_sys::node_serde::CStringPtr(self.plname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "plhandler",
+            &pg_sys::node_serde::NodePtr(self.plhandler as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "plinline",
+            &pg_sys::node_serde::NodePtr(self.plinline as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "plvalidator",
+            &pg_sys::node_serde::NodePtr(self.plvalidator as *const pg_sys::Node),
+        )?;
+        state.serialize_field("pltrusted", &self.pltrusted)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreatePLangStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104770,6 +107536,45 @@ impl std::fmt::Display for CreatePLangStmt {
 }
 impl pg_sys::seal::Sealed for CreatePolicyStmt {}
 impl pg_sys::PgNode for CreatePolicyStmt {}
+impl pg_sys::PgTaggedNode for CreatePolicyStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreatePolicyStmt;
+}
+impl serde::Serialize for CreatePolicyStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreatePolicyStmt", 8usize)?;
+        state.serialize_field("node", "CreatePolicyStmt")?;
+        state.serialize_field(
+            "policy_name",
+            &pg_sys::node_serde::CStringPtr(self.policy_name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "table",
+            &pg_sys::node_serde::NodePtr(self.table as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "cmd_name",
+            &pg_sys::node_serde::CStringPtr(self.cmd_name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("permissive", &self.permissive)?;
+        state.serialize_field(
+            "roles",
+            &pg_sys::node_serde::NodePtr(self.roles as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "qual",
+            &pg_sys::node_serde::NodePtr(self.qual as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "with_check",
+            &pg_sys::node_serde::NodePtr(self.with_check as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreatePolicyStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104777,6 +107582,33 @@ impl std::fmt::Display for CreatePolicyStmt {
 }
 impl pg_sys::seal::Sealed for CreatePublicationStmt {}
 impl pg_sys::PgNode for CreatePublicationStmt {}
+impl pg_sys::PgTaggedNode for CreatePublicationStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreatePublicationStmt;
+}
+impl serde::Serialize for CreatePublicationStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreatePublicationStmt", 5usize)?;
+        state.serialize_field("node", "CreatePublicationStmt")?;
+        state.serialize_field(
+            "pubname",
+            &pg_sys::node_serde::CStringPtr(self.pubname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "tables",
+            &pg_sys::node_serde::NodePtr(self.tables as *const pg_sys::Node),
+        )?;
+        state.serialize_field("for_all_tables", &self.for_all_tables)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreatePublicationStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104784,6 +107616,28 @@ impl std::fmt::Display for CreatePublicationStmt {
 }
 impl pg_sys::seal::Sealed for CreateRangeStmt {}
 impl pg_sys::PgNode for CreateRangeStmt {}
+impl pg_sys::PgTaggedNode for CreateRangeStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateRangeStmt;
+}
+impl serde::Serialize for CreateRangeStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateRangeStmt", 3usize)?;
+        state.serialize_field("node", "CreateRangeStmt")?;
+        state.serialize_field(
+            "typeName",
+            &pg_sys::node_serde::NodePtr(self.typeName as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "params",
+            &pg_sys::node_serde::NodePtr(self.params as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateRangeStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104791,6 +107645,34 @@ impl std::fmt::Display for CreateRangeStmt {
 }
 impl pg_sys::seal::Sealed for CreateReplicationSlotCmd {}
 impl pg_sys::PgNode for CreateReplicationSlotCmd {}
+impl pg_sys::PgTaggedNode for CreateReplicationSlotCmd {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateReplicationSlotCmd;
+}
+impl serde::Serialize for CreateReplicationSlotCmd {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateReplicationSlotCmd", 6usize)?;
+        state.serialize_field("node", "CreateReplicationSlotCmd")?;
+        state.serialize_field(
+            "slotname",
+            &pg_sys::node_serde::CStringPtr(self.slotname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field(
+            "plugin",
+            &pg_sys::node_serde::CStringPtr(self.plugin as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("temporary", &self.temporary)?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateReplicationSlotCmd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104798,6 +107680,29 @@ impl std::fmt::Display for CreateReplicationSlotCmd {
 }
 impl pg_sys::seal::Sealed for CreateRoleStmt {}
 impl pg_sys::PgNode for CreateRoleStmt {}
+impl pg_sys::PgTaggedNode for CreateRoleStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateRoleStmt;
+}
+impl serde::Serialize for CreateRoleStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateRoleStmt", 4usize)?;
+        state.serialize_field("node", "CreateRoleStmt")?;
+        state.serialize_field("stmt_type", &self.stmt_type)?;
+        state.serialize_field(
+            "role",
+            &pg_sys::node_serde::CStringPtr(self.role as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateRoleStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104805,6 +107710,33 @@ impl std::fmt::Display for CreateRoleStmt {
 }
 impl pg_sys::seal::Sealed for CreateSchemaStmt {}
 impl pg_sys::PgNode for CreateSchemaStmt {}
+impl pg_sys::PgTaggedNode for CreateSchemaStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateSchemaStmt;
+}
+impl serde::Serialize for CreateSchemaStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateSchemaStmt", 5usize)?;
+        state.serialize_field("node", "CreateSchemaStmt")?;
+        state.serialize_field(
+            "schemaname",
+            &pg_sys::node_serde::CStringPtr(self.schemaname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "authrole",
+            &pg_sys::node_serde::NodePtr(self.authrole as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "schemaElts",
+            &pg_sys::node_serde::NodePtr(self.schemaElts as *const pg_sys::Node),
+        )?;
+        state.serialize_field("if_not_exists", &self.if_not_exists)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateSchemaStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104812,6 +107744,31 @@ impl std::fmt::Display for CreateSchemaStmt {
 }
 impl pg_sys::seal::Sealed for CreateSeqStmt {}
 impl pg_sys::PgNode for CreateSeqStmt {}
+impl pg_sys::PgTaggedNode for CreateSeqStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateSeqStmt;
+}
+impl serde::Serialize for CreateSeqStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateSeqStmt", 6usize)?;
+        state.serialize_field("node", "CreateSeqStmt")?;
+        state.serialize_field(
+            "sequence",
+            &pg_sys::node_serde::NodePtr(self.sequence as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field("ownerId", &self.ownerId)?;
+        state.serialize_field("for_identity", &self.for_identity)?;
+        state.serialize_field("if_not_exists", &self.if_not_exists)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateSeqStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104819,6 +107776,41 @@ impl std::fmt::Display for CreateSeqStmt {
 }
 impl pg_sys::seal::Sealed for CreateStatsStmt {}
 impl pg_sys::PgNode for CreateStatsStmt {}
+impl pg_sys::PgTaggedNode for CreateStatsStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateStatsStmt;
+}
+impl serde::Serialize for CreateStatsStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateStatsStmt", 7usize)?;
+        state.serialize_field("node", "CreateStatsStmt")?;
+        state.serialize_field(
+            "defnames",
+            &pg_sys::node_serde::NodePtr(self.defnames as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "stat_types",
+            &pg_sys::node_serde::NodePtr(self.stat_types as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "exprs",
+            &pg_sys::node_serde::NodePtr(self.exprs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "relations",
+            &pg_sys::node_serde::NodePtr(self.relations as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "stxcomment",
+            &pg_sys::node_serde::CStringPtr(self.stxcomment as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("if_not_exists", &self.if_not_exists)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateStatsStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104826,6 +107818,62 @@ impl std::fmt::Display for CreateStatsStmt {
 }
 impl pg_sys::seal::Sealed for CreateStmt {}
 impl pg_sys::PgNode for CreateStmt {}
+impl pg_sys::PgTaggedNode for CreateStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateStmt;
+}
+impl serde::Serialize for CreateStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateStmt", 13usize)?;
+        state.serialize_field("node", "CreateStmt")?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "tableElts",
+            &pg_sys::node_serde::NodePtr(self.tableElts as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "inhRelations",
+            &pg_sys::node_serde::NodePtr(self.inhRelations as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "partbound",
+            &pg_sys::node_serde::NodePtr(self.partbound as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "partspec",
+            &pg_sys::node_serde::NodePtr(self.partspec as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ofTypename",
+            &pg_sys::node_serde::NodePtr(self.ofTypename as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "constraints",
+            &pg_sys::node_serde::NodePtr(self.constraints as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field("oncommit", &self.oncommit)?;
+        state.serialize_field(
+            "tablespacename",
+            &pg_sys::node_serde::CStringPtr(self.tablespacename as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "accessMethod",
+            &pg_sys::node_serde::CStringPtr(self.accessMethod as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("if_not_exists", &self.if_not_exists)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104833,6 +107881,36 @@ impl std::fmt::Display for CreateStmt {
 }
 impl pg_sys::seal::Sealed for CreateSubscriptionStmt {}
 impl pg_sys::PgNode for CreateSubscriptionStmt {}
+impl pg_sys::PgTaggedNode for CreateSubscriptionStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateSubscriptionStmt;
+}
+impl serde::Serialize for CreateSubscriptionStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateSubscriptionStmt", 5usize)?;
+        state.serialize_field("node", "CreateSubscriptionStmt")?;
+        state.serialize_field(
+            "subname",
+            &pg_sys::node_serde::CStringPtr(self.subname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "conninfo",
+            &pg_sys::node_serde::CStringPtr(self.conninfo as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "publication",
+            &pg_sys::node_serde::NodePtr(self.publication as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateSubscriptionStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104840,6 +107918,31 @@ impl std::fmt::Display for CreateSubscriptionStmt {
 }
 impl pg_sys::seal::Sealed for CreateTableAsStmt {}
 impl pg_sys::PgNode for CreateTableAsStmt {}
+impl pg_sys::PgTaggedNode for CreateTableAsStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateTableAsStmt;
+}
+impl serde::Serialize for CreateTableAsStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateTableAsStmt", 6usize)?;
+        state.serialize_field("node", "CreateTableAsStmt")?;
+        state.serialize_field(
+            "query",
+            &pg_sys::node_serde::NodePtr(self.query as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "into",
+            &pg_sys::node_serde::NodePtr(self.into as *const pg_sys::Node),
+        )?;
+        state.serialize_field("relkind", &self.relkind)?;
+        state.serialize_field("is_select_into", &self.is_select_into)?;
+        state.serialize_field("if_not_exists", &self.if_not_exists)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateTableAsStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104847,6 +107950,36 @@ impl std::fmt::Display for CreateTableAsStmt {
 }
 impl pg_sys::seal::Sealed for CreateTableSpaceStmt {}
 impl pg_sys::PgNode for CreateTableSpaceStmt {}
+impl pg_sys::PgTaggedNode for CreateTableSpaceStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateTableSpaceStmt;
+}
+impl serde::Serialize for CreateTableSpaceStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateTableSpaceStmt", 5usize)?;
+        state.serialize_field("node", "CreateTableSpaceStmt")?;
+        state.serialize_field(
+            "tablespacename",
+            &pg_sys::node_serde::CStringPtr(self.tablespacename as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "owner",
+            &pg_sys::node_serde::NodePtr(self.owner as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "location",
+            &pg_sys::node_serde::CStringPtr(self.location as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateTableSpaceStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104854,6 +107987,37 @@ impl std::fmt::Display for CreateTableSpaceStmt {
 }
 impl pg_sys::seal::Sealed for CreateTransformStmt {}
 impl pg_sys::PgNode for CreateTransformStmt {}
+impl pg_sys::PgTaggedNode for CreateTransformStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateTransformStmt;
+}
+impl serde::Serialize for CreateTransformStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateTransformStmt", 6usize)?;
+        state.serialize_field("node", "CreateTransformStmt")?;
+        state.serialize_field("replace", &self.replace)?;
+        state.serialize_field(
+            "type_name",
+            &pg_sys::node_serde::NodePtr(self.type_name as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "lang",
+            &pg_sys::node_serde::CStringPtr(self.lang as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "fromsql",
+            &pg_sys::node_serde::NodePtr(self.fromsql as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "tosql",
+            &pg_sys::node_serde::NodePtr(self.tosql as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateTransformStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104861,6 +108025,58 @@ impl std::fmt::Display for CreateTransformStmt {
 }
 impl pg_sys::seal::Sealed for CreateTrigStmt {}
 impl pg_sys::PgNode for CreateTrigStmt {}
+impl pg_sys::PgTaggedNode for CreateTrigStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateTrigStmt;
+}
+impl serde::Serialize for CreateTrigStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateTrigStmt", 15usize)?;
+        state.serialize_field("node", "CreateTrigStmt")?;
+        state.serialize_field(
+            "trigname",
+            &pg_sys::node_serde::CStringPtr(self.trigname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "funcname",
+            &pg_sys::node_serde::NodePtr(self.funcname as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field("row", &self.row)?;
+        state.serialize_field("timing", &self.timing)?;
+        state.serialize_field("events", &self.events)?;
+        state.serialize_field(
+            "columns",
+            &pg_sys::node_serde::NodePtr(self.columns as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "whenClause",
+            &pg_sys::node_serde::NodePtr(self.whenClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field("isconstraint", &self.isconstraint)?;
+        state.serialize_field(
+            "transitionRels",
+            &pg_sys::node_serde::NodePtr(self.transitionRels as *const pg_sys::Node),
+        )?;
+        state.serialize_field("deferrable", &self.deferrable)?;
+        state.serialize_field("initdeferred", &self.initdeferred)?;
+        state.serialize_field(
+            "constrrel",
+            &pg_sys::node_serde::NodePtr(self.constrrel as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateTrigStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104868,6 +108084,33 @@ impl std::fmt::Display for CreateTrigStmt {
 }
 impl pg_sys::seal::Sealed for CreateUserMappingStmt {}
 impl pg_sys::PgNode for CreateUserMappingStmt {}
+impl pg_sys::PgTaggedNode for CreateUserMappingStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreateUserMappingStmt;
+}
+impl serde::Serialize for CreateUserMappingStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreateUserMappingStmt", 5usize)?;
+        state.serialize_field("node", "CreateUserMappingStmt")?;
+        state.serialize_field(
+            "user",
+            &pg_sys::node_serde::NodePtr(self.user as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "servername",
+            &pg_sys::node_serde::CStringPtr(self.servername as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("if_not_exists", &self.if_not_exists)?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreateUserMappingStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104875,6 +108118,28 @@ impl std::fmt::Display for CreateUserMappingStmt {
 }
 impl pg_sys::seal::Sealed for CreatedbStmt {}
 impl pg_sys::PgNode for CreatedbStmt {}
+impl pg_sys::PgTaggedNode for CreatedbStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CreatedbStmt;
+}
+impl serde::Serialize for CreatedbStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CreatedbStmt", 3usize)?;
+        state.serialize_field("node", "CreatedbStmt")?;
+        state.serialize_field(
+            "dbname",
+            &pg_sys::node_serde::CStringPtr(self.dbname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CreatedbStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104882,6 +108147,23 @@ impl std::fmt::Display for CreatedbStmt {
 }
 impl pg_sys::seal::Sealed for CteScan {}
 impl pg_sys::PgNode for CteScan {}
+impl pg_sys::PgTaggedNode for CteScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CteScan;
+}
+impl serde::Serialize for CteScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CteScan", 4usize)?;
+        state.serialize_field("node", "CteScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field("ctePlanId", &self.ctePlanId)?;
+        state.serialize_field("cteParam", &self.cteParam)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CteScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104889,6 +108171,32 @@ impl std::fmt::Display for CteScan {
 }
 impl pg_sys::seal::Sealed for CteScanState {}
 impl pg_sys::PgNode for CteScanState {}
+impl pg_sys::PgTaggedNode for CteScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CteScanState;
+}
+impl serde::Serialize for CteScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CteScanState", 7usize)?;
+        state.serialize_field("node", "CteScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field("eflags", &self.eflags)?;
+        state.serialize_field("readptr", &self.readptr)?;
+        state.serialize_field(
+            "cteplanstate",
+            &pg_sys::node_serde::NodePtr(self.cteplanstate as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "leader",
+            &pg_sys::node_serde::NodePtr(self.leader as *const pg_sys::Node),
+        )?;
+        state.serialize_field("eof_cte", &self.eof_cte)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CteScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104896,6 +108204,26 @@ impl std::fmt::Display for CteScanState {
 }
 impl pg_sys::seal::Sealed for CurrentOfExpr {}
 impl pg_sys::PgNode for CurrentOfExpr {}
+impl pg_sys::PgTaggedNode for CurrentOfExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CurrentOfExpr;
+}
+impl serde::Serialize for CurrentOfExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CurrentOfExpr", 4usize)?;
+        state.serialize_field("node", "CurrentOfExpr")?;
+        state.serialize_field("cvarno", &self.cvarno)?;
+        state.serialize_field(
+            "cursor_name",
+            &pg_sys::node_serde::CStringPtr(self.cursor_name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("cursor_param", &self.cursor_param)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CurrentOfExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104903,6 +108231,30 @@ impl std::fmt::Display for CurrentOfExpr {
 }
 impl pg_sys::seal::Sealed for CustomPath {}
 impl pg_sys::PgNode for CustomPath {}
+impl pg_sys::PgTaggedNode for CustomPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CustomPath;
+}
+impl serde::Serialize for CustomPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CustomPath", 5usize)?;
+        state.serialize_field("node", "CustomPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("flags", &self.flags)?;
+        state.serialize_field(
+            "custom_paths",
+            &pg_sys::node_serde::NodePtr(self.custom_paths as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "custom_private",
+            &pg_sys::node_serde::NodePtr(self.custom_private as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CustomPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104910,6 +108262,38 @@ impl std::fmt::Display for CustomPath {
 }
 impl pg_sys::seal::Sealed for CustomScan {}
 impl pg_sys::PgNode for CustomScan {}
+impl pg_sys::PgTaggedNode for CustomScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CustomScan;
+}
+impl serde::Serialize for CustomScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CustomScan", 7usize)?;
+        state.serialize_field("node", "CustomScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field("flags", &self.flags)?;
+        state.serialize_field(
+            "custom_plans",
+            &pg_sys::node_serde::NodePtr(self.custom_plans as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "custom_exprs",
+            &pg_sys::node_serde::NodePtr(self.custom_exprs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "custom_private",
+            &pg_sys::node_serde::NodePtr(self.custom_private as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "custom_scan_tlist",
+            &pg_sys::node_serde::NodePtr(self.custom_scan_tlist as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CustomScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104917,6 +108301,26 @@ impl std::fmt::Display for CustomScan {
 }
 impl pg_sys::seal::Sealed for CustomScanState {}
 impl pg_sys::PgNode for CustomScanState {}
+impl pg_sys::PgTaggedNode for CustomScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_CustomScanState;
+}
+impl serde::Serialize for CustomScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CustomScanState", 4usize)?;
+        state.serialize_field("node", "CustomScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field("flags", &self.flags)?;
+        state.serialize_field(
+            "custom_ps",
+            &pg_sys::node_serde::NodePtr(self.custom_ps as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for CustomScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104924,6 +108328,24 @@ impl std::fmt::Display for CustomScanState {
 }
 impl pg_sys::seal::Sealed for DeallocateStmt {}
 impl pg_sys::PgNode for DeallocateStmt {}
+impl pg_sys::PgTaggedNode for DeallocateStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DeallocateStmt;
+}
+impl serde::Serialize for DeallocateStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DeallocateStmt", 2usize)?;
+        state.serialize_field("node", "DeallocateStmt")?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DeallocateStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104931,6 +108353,29 @@ impl std::fmt::Display for DeallocateStmt {
 }
 impl pg_sys::seal::Sealed for DeclareCursorStmt {}
 impl pg_sys::PgNode for DeclareCursorStmt {}
+impl pg_sys::PgTaggedNode for DeclareCursorStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DeclareCursorStmt;
+}
+impl serde::Serialize for DeclareCursorStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DeclareCursorStmt", 4usize)?;
+        state.serialize_field("node", "DeclareCursorStmt")?;
+        state.serialize_field(
+            "portalname",
+            &pg_sys::node_serde::CStringPtr(self.portalname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("options", &self.options)?;
+        state.serialize_field(
+            "query",
+            &pg_sys::node_serde::NodePtr(self.query as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DeclareCursorStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104938,6 +108383,34 @@ impl std::fmt::Display for DeclareCursorStmt {
 }
 impl pg_sys::seal::Sealed for DefElem {}
 impl pg_sys::PgNode for DefElem {}
+impl pg_sys::PgTaggedNode for DefElem {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DefElem;
+}
+impl serde::Serialize for DefElem {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DefElem", 6usize)?;
+        state.serialize_field("node", "DefElem")?;
+        state.serialize_field(
+            "defnamespace",
+            &pg_sys::node_serde::CStringPtr(self.defnamespace as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "defname",
+            &pg_sys::node_serde::CStringPtr(self.defname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field("defaction", &self.defaction)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DefElem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104945,6 +108418,36 @@ impl std::fmt::Display for DefElem {
 }
 impl pg_sys::seal::Sealed for DefineStmt {}
 impl pg_sys::PgNode for DefineStmt {}
+impl pg_sys::PgTaggedNode for DefineStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DefineStmt;
+}
+impl serde::Serialize for DefineStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DefineStmt", 8usize)?;
+        state.serialize_field("node", "DefineStmt")?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field("oldstyle", &self.oldstyle)?;
+        state.serialize_field(
+            "defnames",
+            &pg_sys::node_serde::NodePtr(self.defnames as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "definition",
+            &pg_sys::node_serde::NodePtr(self.definition as *const pg_sys::Node),
+        )?;
+        state.serialize_field("if_not_exists", &self.if_not_exists)?;
+        state.serialize_field("replace", &self.replace)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DefineStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104952,6 +108455,40 @@ impl std::fmt::Display for DefineStmt {
 }
 impl pg_sys::seal::Sealed for DeleteStmt {}
 impl pg_sys::PgNode for DeleteStmt {}
+impl pg_sys::PgTaggedNode for DeleteStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DeleteStmt;
+}
+impl serde::Serialize for DeleteStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DeleteStmt", 6usize)?;
+        state.serialize_field("node", "DeleteStmt")?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "usingClause",
+            &pg_sys::node_serde::NodePtr(self.usingClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "whereClause",
+            &pg_sys::node_serde::NodePtr(self.whereClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "returningList",
+            &pg_sys::node_serde::NodePtr(self.returningList as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "withClause",
+            &pg_sys::node_serde::NodePtr(self.withClause as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DeleteStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104959,6 +108496,21 @@ impl std::fmt::Display for DeleteStmt {
 }
 impl pg_sys::seal::Sealed for DiscardStmt {}
 impl pg_sys::PgNode for DiscardStmt {}
+impl pg_sys::PgTaggedNode for DiscardStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DiscardStmt;
+}
+impl serde::Serialize for DiscardStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DiscardStmt", 2usize)?;
+        state.serialize_field("node", "DiscardStmt")?;
+        state.serialize_field("target", &self.target)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DiscardStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104966,6 +108518,24 @@ impl std::fmt::Display for DiscardStmt {
 }
 impl pg_sys::seal::Sealed for DoStmt {}
 impl pg_sys::PgNode for DoStmt {}
+impl pg_sys::PgTaggedNode for DoStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DoStmt;
+}
+impl serde::Serialize for DoStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DoStmt", 2usize)?;
+        state.serialize_field("node", "DoStmt")?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DoStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104973,6 +108543,33 @@ impl std::fmt::Display for DoStmt {
 }
 impl pg_sys::seal::Sealed for DomainConstraintState {}
 impl pg_sys::PgNode for DomainConstraintState {}
+impl pg_sys::PgTaggedNode for DomainConstraintState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DomainConstraintState;
+}
+impl serde::Serialize for DomainConstraintState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DomainConstraintState", 5usize)?;
+        state.serialize_field("node", "DomainConstraintState")?;
+        state.serialize_field("constrainttype", &self.constrainttype)?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "check_expr",
+            &pg_sys::node_serde::NodePtr(self.check_expr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "check_exprstate",
+            &pg_sys::node_serde::NodePtr(self.check_exprstate as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DomainConstraintState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104980,6 +108577,25 @@ impl std::fmt::Display for DomainConstraintState {
 }
 impl pg_sys::seal::Sealed for DropOwnedStmt {}
 impl pg_sys::PgNode for DropOwnedStmt {}
+impl pg_sys::PgTaggedNode for DropOwnedStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DropOwnedStmt;
+}
+impl serde::Serialize for DropOwnedStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DropOwnedStmt", 3usize)?;
+        state.serialize_field("node", "DropOwnedStmt")?;
+        state.serialize_field(
+            "roles",
+            &pg_sys::node_serde::NodePtr(self.roles as *const pg_sys::Node),
+        )?;
+        state.serialize_field("behavior", &self.behavior)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DropOwnedStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104987,6 +108603,25 @@ impl std::fmt::Display for DropOwnedStmt {
 }
 impl pg_sys::seal::Sealed for DropReplicationSlotCmd {}
 impl pg_sys::PgNode for DropReplicationSlotCmd {}
+impl pg_sys::PgTaggedNode for DropReplicationSlotCmd {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DropReplicationSlotCmd;
+}
+impl serde::Serialize for DropReplicationSlotCmd {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DropReplicationSlotCmd", 3usize)?;
+        state.serialize_field("node", "DropReplicationSlotCmd")?;
+        state.serialize_field(
+            "slotname",
+            &pg_sys::node_serde::CStringPtr(self.slotname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("wait", &self.wait)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DropReplicationSlotCmd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -104994,6 +108629,25 @@ impl std::fmt::Display for DropReplicationSlotCmd {
 }
 impl pg_sys::seal::Sealed for DropRoleStmt {}
 impl pg_sys::PgNode for DropRoleStmt {}
+impl pg_sys::PgTaggedNode for DropRoleStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DropRoleStmt;
+}
+impl serde::Serialize for DropRoleStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DropRoleStmt", 3usize)?;
+        state.serialize_field("node", "DropRoleStmt")?;
+        state.serialize_field(
+            "roles",
+            &pg_sys::node_serde::NodePtr(self.roles as *const pg_sys::Node),
+        )?;
+        state.serialize_field("missing_ok", &self.missing_ok)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DropRoleStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105001,6 +108655,28 @@ impl std::fmt::Display for DropRoleStmt {
 }
 impl pg_sys::seal::Sealed for DropStmt {}
 impl pg_sys::PgNode for DropStmt {}
+impl pg_sys::PgTaggedNode for DropStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DropStmt;
+}
+impl serde::Serialize for DropStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DropStmt", 6usize)?;
+        state.serialize_field("node", "DropStmt")?;
+        state.serialize_field(
+            "objects",
+            &pg_sys::node_serde::NodePtr(self.objects as *const pg_sys::Node),
+        )?;
+        state.serialize_field("removeType", &self.removeType)?;
+        state.serialize_field("behavior", &self.behavior)?;
+        state.serialize_field("missing_ok", &self.missing_ok)?;
+        state.serialize_field("concurrent", &self.concurrent)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DropStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105008,6 +108684,26 @@ impl std::fmt::Display for DropStmt {
 }
 impl pg_sys::seal::Sealed for DropSubscriptionStmt {}
 impl pg_sys::PgNode for DropSubscriptionStmt {}
+impl pg_sys::PgTaggedNode for DropSubscriptionStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DropSubscriptionStmt;
+}
+impl serde::Serialize for DropSubscriptionStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DropSubscriptionStmt", 4usize)?;
+        state.serialize_field("node", "DropSubscriptionStmt")?;
+        state.serialize_field(
+            "subname",
+            &pg_sys::node_serde::CStringPtr(self.subname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("missing_ok", &self.missing_ok)?;
+        state.serialize_field("behavior", &self.behavior)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DropSubscriptionStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105015,6 +108711,25 @@ impl std::fmt::Display for DropSubscriptionStmt {
 }
 impl pg_sys::seal::Sealed for DropTableSpaceStmt {}
 impl pg_sys::PgNode for DropTableSpaceStmt {}
+impl pg_sys::PgTaggedNode for DropTableSpaceStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DropTableSpaceStmt;
+}
+impl serde::Serialize for DropTableSpaceStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DropTableSpaceStmt", 3usize)?;
+        state.serialize_field("node", "DropTableSpaceStmt")?;
+        state.serialize_field(
+            "tablespacename",
+            &pg_sys::node_serde::CStringPtr(self.tablespacename as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("missing_ok", &self.missing_ok)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DropTableSpaceStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105022,6 +108737,29 @@ impl std::fmt::Display for DropTableSpaceStmt {
 }
 impl pg_sys::seal::Sealed for DropUserMappingStmt {}
 impl pg_sys::PgNode for DropUserMappingStmt {}
+impl pg_sys::PgTaggedNode for DropUserMappingStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DropUserMappingStmt;
+}
+impl serde::Serialize for DropUserMappingStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DropUserMappingStmt", 4usize)?;
+        state.serialize_field("node", "DropUserMappingStmt")?;
+        state.serialize_field(
+            "user",
+            &pg_sys::node_serde::NodePtr(self.user as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "servername",
+            &pg_sys::node_serde::CStringPtr(self.servername as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("missing_ok", &self.missing_ok)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DropUserMappingStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105029,6 +108767,25 @@ impl std::fmt::Display for DropUserMappingStmt {
 }
 impl pg_sys::seal::Sealed for DropdbStmt {}
 impl pg_sys::PgNode for DropdbStmt {}
+impl pg_sys::PgTaggedNode for DropdbStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_DropdbStmt;
+}
+impl serde::Serialize for DropdbStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DropdbStmt", 3usize)?;
+        state.serialize_field("node", "DropdbStmt")?;
+        state.serialize_field(
+            "dbname",
+            &pg_sys::node_serde::CStringPtr(self.dbname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("missing_ok", &self.missing_ok)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for DropdbStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105036,6 +108793,94 @@ impl std::fmt::Display for DropdbStmt {
 }
 impl pg_sys::seal::Sealed for EState {}
 impl pg_sys::PgNode for EState {}
+impl pg_sys::PgTaggedNode for EState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_EState;
+}
+impl serde::Serialize for EState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("EState", 27usize)?;
+        state.serialize_field("node", "EState")?;
+        state.serialize_field("es_direction", &self.es_direction)?;
+        state.serialize_field(
+            "es_range_table",
+            &pg_sys::node_serde::NodePtr(self.es_range_table as *const pg_sys::Node),
+        )?;
+        state.serialize_field("es_range_table_size", &self.es_range_table_size)?;
+        state.serialize_field(
+            "es_plannedstmt",
+            &pg_sys::node_serde::NodePtr(self.es_plannedstmt as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "es_sourceText",
+            &pg_sys::node_serde::CStringPtr(self.es_sourceText as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "es_junkFilter",
+            &pg_sys::node_serde::NodePtr(self.es_junkFilter as *const pg_sys::Node),
+        )?;
+        state.serialize_field("es_output_cid", &self.es_output_cid)?;
+        state.serialize_field(
+            "es_result_relations",
+            &pg_sys::node_serde::NodePtr(self.es_result_relations as *const pg_sys::Node),
+        )?;
+        state.serialize_field("es_num_result_relations", &self.es_num_result_relations)?;
+        state.serialize_field(
+            "es_result_relation_info",
+            &pg_sys::node_serde::NodePtr(self.es_result_relation_info as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "es_root_result_relations",
+            &pg_sys::node_serde::NodePtr(self.es_root_result_relations as *const pg_sys::Node),
+        )?;
+        state
+            .serialize_field("es_num_root_result_relations", &self.es_num_root_result_relations)?;
+        state.serialize_field(
+            "es_tuple_routing_result_relations",
+            &pg_sys::node_serde::NodePtr(
+                self.es_tuple_routing_result_relations as *const pg_sys::Node,
+            ),
+        )?;
+        state.serialize_field(
+            "es_trig_target_relations",
+            &pg_sys::node_serde::NodePtr(self.es_trig_target_relations as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "es_query_cxt",
+            &pg_sys::node_serde::NodePtr(self.es_query_cxt as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "es_tupleTable",
+            &pg_sys::node_serde::NodePtr(self.es_tupleTable as *const pg_sys::Node),
+        )?;
+        state.serialize_field("es_processed", &self.es_processed)?;
+        state.serialize_field("es_top_eflags", &self.es_top_eflags)?;
+        state.serialize_field("es_instrument", &self.es_instrument)?;
+        state.serialize_field("es_finished", &self.es_finished)?;
+        state.serialize_field(
+            "es_exprcontexts",
+            &pg_sys::node_serde::NodePtr(self.es_exprcontexts as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "es_subplanstates",
+            &pg_sys::node_serde::NodePtr(self.es_subplanstates as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "es_auxmodifytables",
+            &pg_sys::node_serde::NodePtr(self.es_auxmodifytables as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "es_per_tuple_exprcontext",
+            &pg_sys::node_serde::NodePtr(self.es_per_tuple_exprcontext as *const pg_sys::Node),
+        )?;
+        state.serialize_field("es_use_parallel_mode", &self.es_use_parallel_mode)?;
+        state.serialize_field("es_jit_flags", &self.es_jit_flags)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for EState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105043,6 +108888,48 @@ impl std::fmt::Display for EState {
 }
 impl pg_sys::seal::Sealed for EquivalenceClass {}
 impl pg_sys::PgNode for EquivalenceClass {}
+impl pg_sys::PgTaggedNode for EquivalenceClass {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_EquivalenceClass;
+}
+impl serde::Serialize for EquivalenceClass {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("EquivalenceClass", 14usize)?;
+        state.serialize_field("node", "EquivalenceClass")?;
+        state.serialize_field(
+            "ec_opfamilies",
+            &pg_sys::node_serde::NodePtr(self.ec_opfamilies as *const pg_sys::Node),
+        )?;
+        state.serialize_field("ec_collation", &self.ec_collation)?;
+        state.serialize_field(
+            "ec_members",
+            &pg_sys::node_serde::NodePtr(self.ec_members as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ec_sources",
+            &pg_sys::node_serde::NodePtr(self.ec_sources as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ec_derives",
+            &pg_sys::node_serde::NodePtr(self.ec_derives as *const pg_sys::Node),
+        )?;
+        state.serialize_field("ec_has_const", &self.ec_has_const)?;
+        state.serialize_field("ec_has_volatile", &self.ec_has_volatile)?;
+        state.serialize_field("ec_below_outer_join", &self.ec_below_outer_join)?;
+        state.serialize_field("ec_broken", &self.ec_broken)?;
+        state.serialize_field("ec_sortref", &self.ec_sortref)?;
+        state.serialize_field("ec_min_security", &self.ec_min_security)?;
+        state.serialize_field("ec_max_security", &self.ec_max_security)?;
+        state.serialize_field(
+            "ec_merged",
+            &pg_sys::node_serde::NodePtr(self.ec_merged as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for EquivalenceClass {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105050,6 +108937,27 @@ impl std::fmt::Display for EquivalenceClass {
 }
 impl pg_sys::seal::Sealed for EquivalenceMember {}
 impl pg_sys::PgNode for EquivalenceMember {}
+impl pg_sys::PgTaggedNode for EquivalenceMember {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_EquivalenceMember;
+}
+impl serde::Serialize for EquivalenceMember {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("EquivalenceMember", 5usize)?;
+        state.serialize_field("node", "EquivalenceMember")?;
+        state.serialize_field(
+            "em_expr",
+            &pg_sys::node_serde::NodePtr(self.em_expr as *const pg_sys::Node),
+        )?;
+        state.serialize_field("em_is_const", &self.em_is_const)?;
+        state.serialize_field("em_is_child", &self.em_is_child)?;
+        state.serialize_field("em_datatype", &self.em_datatype)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for EquivalenceMember {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105057,6 +108965,32 @@ impl std::fmt::Display for EquivalenceMember {
 }
 impl pg_sys::seal::Sealed for EventTriggerData {}
 impl pg_sys::PgNode for EventTriggerData {}
+impl pg_sys::PgTaggedNode for EventTriggerData {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_EventTriggerData;
+}
+impl serde::Serialize for EventTriggerData {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("EventTriggerData", 4usize)?;
+        state.serialize_field("node", "EventTriggerData")?;
+        state.serialize_field(
+            "event",
+            &pg_sys::node_serde::CStringPtr(self.event as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "parsetree",
+            &pg_sys::node_serde::NodePtr(self.parsetree as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "tag",
+            &pg_sys::node_serde::CStringPtr(self.tag as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for EventTriggerData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105064,6 +108998,28 @@ impl std::fmt::Display for EventTriggerData {
 }
 impl pg_sys::seal::Sealed for ExecuteStmt {}
 impl pg_sys::PgNode for ExecuteStmt {}
+impl pg_sys::PgTaggedNode for ExecuteStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ExecuteStmt;
+}
+impl serde::Serialize for ExecuteStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ExecuteStmt", 3usize)?;
+        state.serialize_field("node", "ExecuteStmt")?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "params",
+            &pg_sys::node_serde::NodePtr(self.params as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ExecuteStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105071,6 +109027,28 @@ impl std::fmt::Display for ExecuteStmt {
 }
 impl pg_sys::seal::Sealed for ExplainStmt {}
 impl pg_sys::PgNode for ExplainStmt {}
+impl pg_sys::PgTaggedNode for ExplainStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ExplainStmt;
+}
+impl serde::Serialize for ExplainStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ExplainStmt", 3usize)?;
+        state.serialize_field("node", "ExplainStmt")?;
+        state.serialize_field(
+            "query",
+            &pg_sys::node_serde::NodePtr(self.query as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ExplainStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105078,6 +109056,20 @@ impl std::fmt::Display for ExplainStmt {
 }
 impl pg_sys::seal::Sealed for Expr {}
 impl pg_sys::PgNode for Expr {}
+impl pg_sys::PgTaggedNode for Expr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Expr;
+}
+impl serde::Serialize for Expr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Expr", 1usize)?;
+        state.serialize_field("node", "Expr")?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Expr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105085,6 +109077,48 @@ impl std::fmt::Display for Expr {
 }
 impl pg_sys::seal::Sealed for ExprContext {}
 impl pg_sys::PgNode for ExprContext {}
+impl pg_sys::PgTaggedNode for ExprContext {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ExprContext;
+}
+impl serde::Serialize for ExprContext {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ExprContext", 11usize)?;
+        state.serialize_field("node", "ExprContext")?;
+        state.serialize_field(
+            "ecxt_scantuple",
+            &pg_sys::node_serde::NodePtr(self.ecxt_scantuple as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ecxt_innertuple",
+            &pg_sys::node_serde::NodePtr(self.ecxt_innertuple as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ecxt_outertuple",
+            &pg_sys::node_serde::NodePtr(self.ecxt_outertuple as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ecxt_per_query_memory",
+            &pg_sys::node_serde::NodePtr(self.ecxt_per_query_memory as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ecxt_per_tuple_memory",
+            &pg_sys::node_serde::NodePtr(self.ecxt_per_tuple_memory as *const pg_sys::Node),
+        )?;
+        state.serialize_field("caseValue_datum", &self.caseValue_datum.value())?;
+        state.serialize_field("caseValue_isNull", &self.caseValue_isNull)?;
+        state.serialize_field("domainValue_datum", &self.domainValue_datum.value())?;
+        state.serialize_field("domainValue_isNull", &self.domainValue_isNull)?;
+        state.serialize_field(
+            "ecxt_estate",
+            &pg_sys::node_serde::NodePtr(self.ecxt_estate as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ExprContext {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105092,6 +109126,37 @@ impl std::fmt::Display for ExprContext {
 }
 impl pg_sys::seal::Sealed for ExprState {}
 impl pg_sys::PgNode for ExprState {}
+impl pg_sys::PgTaggedNode for ExprState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ExprState;
+}
+impl serde::Serialize for ExprState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ExprState", 9usize)?;
+        state.serialize_field("node", "ExprState")?;
+        state.serialize_field("flags", &self.flags)?;
+        state.serialize_field("resnull", &self.resnull)?;
+        state.serialize_field("resvalue", &self.resvalue.value())?;
+        state.serialize_field(
+            "resultslot",
+            &pg_sys::node_serde::NodePtr(self.resultslot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "expr",
+            &pg_sys::node_serde::NodePtr(self.expr as *const pg_sys::Node),
+        )?;
+        state.serialize_field("steps_len", &self.steps_len)?;
+        state.serialize_field("steps_alloc", &self.steps_alloc)?;
+        state.serialize_field(
+            "parent",
+            &pg_sys::node_serde::NodePtr(self.parent as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ExprState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105099,6 +109164,24 @@ impl std::fmt::Display for ExprState {
 }
 impl pg_sys::seal::Sealed for ExtensibleNode {}
 impl pg_sys::PgNode for ExtensibleNode {}
+impl pg_sys::PgTaggedNode for ExtensibleNode {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ExtensibleNode;
+}
+impl serde::Serialize for ExtensibleNode {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ExtensibleNode", 2usize)?;
+        state.serialize_field("node", "ExtensibleNode")?;
+        state.serialize_field(
+            "extnodename",
+            &pg_sys::node_serde::CStringPtr(self.extnodename as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ExtensibleNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105106,6 +109189,20 @@ impl std::fmt::Display for ExtensibleNode {
 }
 impl pg_sys::seal::Sealed for FdwRoutine {}
 impl pg_sys::PgNode for FdwRoutine {}
+impl pg_sys::PgTaggedNode for FdwRoutine {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_FdwRoutine;
+}
+impl serde::Serialize for FdwRoutine {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("FdwRoutine", 1usize)?;
+        state.serialize_field("node", "FdwRoutine")?;
+        state.end()
+    }
+}
 impl std::fmt::Display for FdwRoutine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105113,6 +109210,27 @@ impl std::fmt::Display for FdwRoutine {
 }
 impl pg_sys::seal::Sealed for FetchStmt {}
 impl pg_sys::PgNode for FetchStmt {}
+impl pg_sys::PgTaggedNode for FetchStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_FetchStmt;
+}
+impl serde::Serialize for FetchStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("FetchStmt", 5usize)?;
+        state.serialize_field("node", "FetchStmt")?;
+        state.serialize_field("direction", &self.direction)?;
+        state.serialize_field("howMany", &self.howMany)?;
+        state.serialize_field(
+            "portalname",
+            &pg_sys::node_serde::CStringPtr(self.portalname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("ismove", &self.ismove)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for FetchStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105120,6 +109238,28 @@ impl std::fmt::Display for FetchStmt {
 }
 impl pg_sys::seal::Sealed for FieldSelect {}
 impl pg_sys::PgNode for FieldSelect {}
+impl pg_sys::PgTaggedNode for FieldSelect {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_FieldSelect;
+}
+impl serde::Serialize for FieldSelect {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("FieldSelect", 6usize)?;
+        state.serialize_field("node", "FieldSelect")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field("fieldnum", &self.fieldnum)?;
+        state.serialize_field("resulttype", &self.resulttype)?;
+        state.serialize_field("resulttypmod", &self.resulttypmod)?;
+        state.serialize_field("resultcollid", &self.resultcollid)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for FieldSelect {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105127,6 +109267,33 @@ impl std::fmt::Display for FieldSelect {
 }
 impl pg_sys::seal::Sealed for FieldStore {}
 impl pg_sys::PgNode for FieldStore {}
+impl pg_sys::PgTaggedNode for FieldStore {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_FieldStore;
+}
+impl serde::Serialize for FieldStore {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("FieldStore", 5usize)?;
+        state.serialize_field("node", "FieldStore")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "newvals",
+            &pg_sys::node_serde::NodePtr(self.newvals as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "fieldnums",
+            &pg_sys::node_serde::NodePtr(self.fieldnums as *const pg_sys::Node),
+        )?;
+        state.serialize_field("resulttype", &self.resulttype)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for FieldStore {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105134,6 +109301,24 @@ impl std::fmt::Display for FieldStore {
 }
 impl pg_sys::seal::Sealed for ForeignKeyCacheInfo {}
 impl pg_sys::PgNode for ForeignKeyCacheInfo {}
+impl pg_sys::PgTaggedNode for ForeignKeyCacheInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ForeignKeyCacheInfo;
+}
+impl serde::Serialize for ForeignKeyCacheInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ForeignKeyCacheInfo", 5usize)?;
+        state.serialize_field("node", "ForeignKeyCacheInfo")?;
+        state.serialize_field("conoid", &self.conoid)?;
+        state.serialize_field("conrelid", &self.conrelid)?;
+        state.serialize_field("confrelid", &self.confrelid)?;
+        state.serialize_field("nkeys", &self.nkeys)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ForeignKeyCacheInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105141,6 +109326,26 @@ impl std::fmt::Display for ForeignKeyCacheInfo {
 }
 impl pg_sys::seal::Sealed for ForeignKeyOptInfo {}
 impl pg_sys::PgNode for ForeignKeyOptInfo {}
+impl pg_sys::PgTaggedNode for ForeignKeyOptInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ForeignKeyOptInfo;
+}
+impl serde::Serialize for ForeignKeyOptInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ForeignKeyOptInfo", 7usize)?;
+        state.serialize_field("node", "ForeignKeyOptInfo")?;
+        state.serialize_field("con_relid", &self.con_relid)?;
+        state.serialize_field("ref_relid", &self.ref_relid)?;
+        state.serialize_field("nkeys", &self.nkeys)?;
+        state.serialize_field("nmatched_ec", &self.nmatched_ec)?;
+        state.serialize_field("nmatched_rcols", &self.nmatched_rcols)?;
+        state.serialize_field("nmatched_ri", &self.nmatched_ri)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ForeignKeyOptInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105148,6 +109353,29 @@ impl std::fmt::Display for ForeignKeyOptInfo {
 }
 impl pg_sys::seal::Sealed for ForeignPath {}
 impl pg_sys::PgNode for ForeignPath {}
+impl pg_sys::PgTaggedNode for ForeignPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ForeignPath;
+}
+impl serde::Serialize for ForeignPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ForeignPath", 4usize)?;
+        state.serialize_field("node", "ForeignPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "fdw_outerpath",
+            &pg_sys::node_serde::NodePtr(self.fdw_outerpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "fdw_private",
+            &pg_sys::node_serde::NodePtr(self.fdw_private as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ForeignPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105155,6 +109383,40 @@ impl std::fmt::Display for ForeignPath {
 }
 impl pg_sys::seal::Sealed for ForeignScan {}
 impl pg_sys::PgNode for ForeignScan {}
+impl pg_sys::PgTaggedNode for ForeignScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ForeignScan;
+}
+impl serde::Serialize for ForeignScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ForeignScan", 9usize)?;
+        state.serialize_field("node", "ForeignScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field("operation", &self.operation)?;
+        state.serialize_field("fs_server", &self.fs_server)?;
+        state.serialize_field(
+            "fdw_exprs",
+            &pg_sys::node_serde::NodePtr(self.fdw_exprs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "fdw_private",
+            &pg_sys::node_serde::NodePtr(self.fdw_private as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "fdw_scan_tlist",
+            &pg_sys::node_serde::NodePtr(self.fdw_scan_tlist as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "fdw_recheck_quals",
+            &pg_sys::node_serde::NodePtr(self.fdw_recheck_quals as *const pg_sys::Node),
+        )?;
+        state.serialize_field("fsSystemCol", &self.fsSystemCol)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ForeignScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105162,6 +109424,29 @@ impl std::fmt::Display for ForeignScan {
 }
 impl pg_sys::seal::Sealed for ForeignScanState {}
 impl pg_sys::PgNode for ForeignScanState {}
+impl pg_sys::PgTaggedNode for ForeignScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ForeignScanState;
+}
+impl serde::Serialize for ForeignScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ForeignScanState", 4usize)?;
+        state.serialize_field("node", "ForeignScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field(
+            "fdw_recheck_quals",
+            &pg_sys::node_serde::NodePtr(self.fdw_recheck_quals as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "fdwroutine",
+            &pg_sys::node_serde::NodePtr(self.fdwroutine as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ForeignScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105169,6 +109454,28 @@ impl std::fmt::Display for ForeignScanState {
 }
 impl pg_sys::seal::Sealed for FromExpr {}
 impl pg_sys::PgNode for FromExpr {}
+impl pg_sys::PgTaggedNode for FromExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_FromExpr;
+}
+impl serde::Serialize for FromExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("FromExpr", 3usize)?;
+        state.serialize_field("node", "FromExpr")?;
+        state.serialize_field(
+            "fromlist",
+            &pg_sys::node_serde::NodePtr(self.fromlist as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "quals",
+            &pg_sys::node_serde::NodePtr(self.quals as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for FromExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105176,6 +109483,45 @@ impl std::fmt::Display for FromExpr {
 }
 impl pg_sys::seal::Sealed for FuncCall {}
 impl pg_sys::PgNode for FuncCall {}
+impl pg_sys::PgTaggedNode for FuncCall {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_FuncCall;
+}
+impl serde::Serialize for FuncCall {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("FuncCall", 11usize)?;
+        state.serialize_field("node", "FuncCall")?;
+        state.serialize_field(
+            "funcname",
+            &pg_sys::node_serde::NodePtr(self.funcname as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "agg_order",
+            &pg_sys::node_serde::NodePtr(self.agg_order as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "agg_filter",
+            &pg_sys::node_serde::NodePtr(self.agg_filter as *const pg_sys::Node),
+        )?;
+        state.serialize_field("agg_within_group", &self.agg_within_group)?;
+        state.serialize_field("agg_star", &self.agg_star)?;
+        state.serialize_field("agg_distinct", &self.agg_distinct)?;
+        state.serialize_field("func_variadic", &self.func_variadic)?;
+        state.serialize_field(
+            "over",
+            &pg_sys::node_serde::NodePtr(self.over as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for FuncCall {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105183,6 +109529,32 @@ impl std::fmt::Display for FuncCall {
 }
 impl pg_sys::seal::Sealed for FuncExpr {}
 impl pg_sys::PgNode for FuncExpr {}
+impl pg_sys::PgTaggedNode for FuncExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_FuncExpr;
+}
+impl serde::Serialize for FuncExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("FuncExpr", 10usize)?;
+        state.serialize_field("node", "FuncExpr")?;
+        state.serialize_field("funcid", &self.funcid)?;
+        state.serialize_field("funcresulttype", &self.funcresulttype)?;
+        state.serialize_field("funcretset", &self.funcretset)?;
+        state.serialize_field("funcvariadic", &self.funcvariadic)?;
+        state.serialize_field("funcformat", &self.funcformat)?;
+        state.serialize_field("funccollid", &self.funccollid)?;
+        state.serialize_field("inputcollid", &self.inputcollid)?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for FuncExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105190,6 +109562,33 @@ impl std::fmt::Display for FuncExpr {
 }
 impl pg_sys::seal::Sealed for FunctionParameter {}
 impl pg_sys::PgNode for FunctionParameter {}
+impl pg_sys::PgTaggedNode for FunctionParameter {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_FunctionParameter;
+}
+impl serde::Serialize for FunctionParameter {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("FunctionParameter", 5usize)?;
+        state.serialize_field("node", "FunctionParameter")?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "argType",
+            &pg_sys::node_serde::NodePtr(self.argType as *const pg_sys::Node),
+        )?;
+        state.serialize_field("mode", &self.mode)?;
+        state.serialize_field(
+            "defexpr",
+            &pg_sys::node_serde::NodePtr(self.defexpr as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for FunctionParameter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105197,6 +109596,26 @@ impl std::fmt::Display for FunctionParameter {
 }
 impl pg_sys::seal::Sealed for FunctionScan {}
 impl pg_sys::PgNode for FunctionScan {}
+impl pg_sys::PgTaggedNode for FunctionScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_FunctionScan;
+}
+impl serde::Serialize for FunctionScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("FunctionScan", 4usize)?;
+        state.serialize_field("node", "FunctionScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field(
+            "functions",
+            &pg_sys::node_serde::NodePtr(self.functions as *const pg_sys::Node),
+        )?;
+        state.serialize_field("funcordinality", &self.funcordinality)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for FunctionScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105204,6 +109623,30 @@ impl std::fmt::Display for FunctionScan {
 }
 impl pg_sys::seal::Sealed for FunctionScanState {}
 impl pg_sys::PgNode for FunctionScanState {}
+impl pg_sys::PgTaggedNode for FunctionScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_FunctionScanState;
+}
+impl serde::Serialize for FunctionScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("FunctionScanState", 8usize)?;
+        state.serialize_field("node", "FunctionScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field("eflags", &self.eflags)?;
+        state.serialize_field("ordinality", &self.ordinality)?;
+        state.serialize_field("simple", &self.simple)?;
+        state.serialize_field("ordinal", &self.ordinal)?;
+        state.serialize_field("nfuncs", &self.nfuncs)?;
+        state.serialize_field(
+            "argcontext",
+            &pg_sys::node_serde::NodePtr(self.argcontext as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for FunctionScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105211,6 +109654,25 @@ impl std::fmt::Display for FunctionScanState {
 }
 impl pg_sys::seal::Sealed for Gather {}
 impl pg_sys::PgNode for Gather {}
+impl pg_sys::PgTaggedNode for Gather {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Gather;
+}
+impl serde::Serialize for Gather {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Gather", 6usize)?;
+        state.serialize_field("node", "Gather")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field("num_workers", &self.num_workers)?;
+        state.serialize_field("rescan_param", &self.rescan_param)?;
+        state.serialize_field("single_copy", &self.single_copy)?;
+        state.serialize_field("invisible", &self.invisible)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Gather {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105218,6 +109680,24 @@ impl std::fmt::Display for Gather {
 }
 impl pg_sys::seal::Sealed for GatherMerge {}
 impl pg_sys::PgNode for GatherMerge {}
+impl pg_sys::PgTaggedNode for GatherMerge {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GatherMerge;
+}
+impl serde::Serialize for GatherMerge {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GatherMerge", 5usize)?;
+        state.serialize_field("node", "GatherMerge")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field("num_workers", &self.num_workers)?;
+        state.serialize_field("rescan_param", &self.rescan_param)?;
+        state.serialize_field("numCols", &self.numCols)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GatherMerge {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105225,6 +109705,26 @@ impl std::fmt::Display for GatherMerge {
 }
 impl pg_sys::seal::Sealed for GatherMergePath {}
 impl pg_sys::PgNode for GatherMergePath {}
+impl pg_sys::PgTaggedNode for GatherMergePath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GatherMergePath;
+}
+impl serde::Serialize for GatherMergePath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GatherMergePath", 4usize)?;
+        state.serialize_field("node", "GatherMergePath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field("num_workers", &self.num_workers)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GatherMergePath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105232,6 +109732,28 @@ impl std::fmt::Display for GatherMergePath {
 }
 impl pg_sys::seal::Sealed for GatherMergeState {}
 impl pg_sys::PgNode for GatherMergeState {}
+impl pg_sys::PgTaggedNode for GatherMergeState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GatherMergeState;
+}
+impl serde::Serialize for GatherMergeState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GatherMergeState", 9usize)?;
+        state.serialize_field("node", "GatherMergeState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field("initialized", &self.initialized)?;
+        state.serialize_field("gm_initialized", &self.gm_initialized)?;
+        state.serialize_field("need_to_scan_locally", &self.need_to_scan_locally)?;
+        state.serialize_field("tuples_needed", &self.tuples_needed)?;
+        state.serialize_field("gm_nkeys", &self.gm_nkeys)?;
+        state.serialize_field("nworkers_launched", &self.nworkers_launched)?;
+        state.serialize_field("nreaders", &self.nreaders)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GatherMergeState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105239,6 +109761,27 @@ impl std::fmt::Display for GatherMergeState {
 }
 impl pg_sys::seal::Sealed for GatherPath {}
 impl pg_sys::PgNode for GatherPath {}
+impl pg_sys::PgTaggedNode for GatherPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GatherPath;
+}
+impl serde::Serialize for GatherPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GatherPath", 5usize)?;
+        state.serialize_field("node", "GatherPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field("single_copy", &self.single_copy)?;
+        state.serialize_field("num_workers", &self.num_workers)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GatherPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105246,6 +109789,31 @@ impl std::fmt::Display for GatherPath {
 }
 impl pg_sys::seal::Sealed for GatherState {}
 impl pg_sys::PgNode for GatherState {}
+impl pg_sys::PgTaggedNode for GatherState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GatherState;
+}
+impl serde::Serialize for GatherState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GatherState", 9usize)?;
+        state.serialize_field("node", "GatherState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field("initialized", &self.initialized)?;
+        state.serialize_field("need_to_scan_locally", &self.need_to_scan_locally)?;
+        state.serialize_field("tuples_needed", &self.tuples_needed)?;
+        state.serialize_field(
+            "funnel_slot",
+            &pg_sys::node_serde::NodePtr(self.funnel_slot as *const pg_sys::Node),
+        )?;
+        state.serialize_field("nworkers_launched", &self.nworkers_launched)?;
+        state.serialize_field("nreaders", &self.nreaders)?;
+        state.serialize_field("nextreader", &self.nextreader)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GatherState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105253,6 +109821,35 @@ impl std::fmt::Display for GatherState {
 }
 impl pg_sys::seal::Sealed for GrantRoleStmt {}
 impl pg_sys::PgNode for GrantRoleStmt {}
+impl pg_sys::PgTaggedNode for GrantRoleStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GrantRoleStmt;
+}
+impl serde::Serialize for GrantRoleStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GrantRoleStmt", 7usize)?;
+        state.serialize_field("node", "GrantRoleStmt")?;
+        state.serialize_field(
+            "granted_roles",
+            &pg_sys::node_serde::NodePtr(self.granted_roles as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "grantee_roles",
+            &pg_sys::node_serde::NodePtr(self.grantee_roles as *const pg_sys::Node),
+        )?;
+        state.serialize_field("is_grant", &self.is_grant)?;
+        state.serialize_field("admin_opt", &self.admin_opt)?;
+        state.serialize_field(
+            "grantor",
+            &pg_sys::node_serde::NodePtr(self.grantor as *const pg_sys::Node),
+        )?;
+        state.serialize_field("behavior", &self.behavior)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GrantRoleStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105260,6 +109857,37 @@ impl std::fmt::Display for GrantRoleStmt {
 }
 impl pg_sys::seal::Sealed for GrantStmt {}
 impl pg_sys::PgNode for GrantStmt {}
+impl pg_sys::PgTaggedNode for GrantStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GrantStmt;
+}
+impl serde::Serialize for GrantStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GrantStmt", 9usize)?;
+        state.serialize_field("node", "GrantStmt")?;
+        state.serialize_field("is_grant", &self.is_grant)?;
+        state.serialize_field("targtype", &self.targtype)?;
+        state.serialize_field("objtype", &self.objtype)?;
+        state.serialize_field(
+            "objects",
+            &pg_sys::node_serde::NodePtr(self.objects as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "privileges",
+            &pg_sys::node_serde::NodePtr(self.privileges as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "grantees",
+            &pg_sys::node_serde::NodePtr(self.grantees as *const pg_sys::Node),
+        )?;
+        state.serialize_field("grant_option", &self.grant_option)?;
+        state.serialize_field("behavior", &self.behavior)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GrantStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105267,6 +109895,22 @@ impl std::fmt::Display for GrantStmt {
 }
 impl pg_sys::seal::Sealed for Group {}
 impl pg_sys::PgNode for Group {}
+impl pg_sys::PgTaggedNode for Group {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Group;
+}
+impl serde::Serialize for Group {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Group", 3usize)?;
+        state.serialize_field("node", "Group")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field("numCols", &self.numCols)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Group {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105274,6 +109918,33 @@ impl std::fmt::Display for Group {
 }
 impl pg_sys::seal::Sealed for GroupPath {}
 impl pg_sys::PgNode for GroupPath {}
+impl pg_sys::PgTaggedNode for GroupPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GroupPath;
+}
+impl serde::Serialize for GroupPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GroupPath", 5usize)?;
+        state.serialize_field("node", "GroupPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "groupClause",
+            &pg_sys::node_serde::NodePtr(self.groupClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "qual",
+            &pg_sys::node_serde::NodePtr(self.qual as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GroupPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105281,6 +109952,25 @@ impl std::fmt::Display for GroupPath {
 }
 impl pg_sys::seal::Sealed for GroupResultPath {}
 impl pg_sys::PgNode for GroupResultPath {}
+impl pg_sys::PgTaggedNode for GroupResultPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GroupResultPath;
+}
+impl serde::Serialize for GroupResultPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GroupResultPath", 3usize)?;
+        state.serialize_field("node", "GroupResultPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "quals",
+            &pg_sys::node_serde::NodePtr(self.quals as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GroupResultPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105288,6 +109978,26 @@ impl std::fmt::Display for GroupResultPath {
 }
 impl pg_sys::seal::Sealed for GroupState {}
 impl pg_sys::PgNode for GroupState {}
+impl pg_sys::PgTaggedNode for GroupState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GroupState;
+}
+impl serde::Serialize for GroupState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GroupState", 4usize)?;
+        state.serialize_field("node", "GroupState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field(
+            "eqfunction",
+            &pg_sys::node_serde::NodePtr(self.eqfunction as *const pg_sys::Node),
+        )?;
+        state.serialize_field("grp_done", &self.grp_done)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GroupState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105295,6 +110005,34 @@ impl std::fmt::Display for GroupState {
 }
 impl pg_sys::seal::Sealed for GroupingFunc {}
 impl pg_sys::PgNode for GroupingFunc {}
+impl pg_sys::PgTaggedNode for GroupingFunc {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GroupingFunc;
+}
+impl serde::Serialize for GroupingFunc {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GroupingFunc", 6usize)?;
+        state.serialize_field("node", "GroupingFunc")?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "refs",
+            &pg_sys::node_serde::NodePtr(self.refs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "cols",
+            &pg_sys::node_serde::NodePtr(self.cols as *const pg_sys::Node),
+        )?;
+        state.serialize_field("agglevelsup", &self.agglevelsup)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GroupingFunc {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105302,6 +110040,26 @@ impl std::fmt::Display for GroupingFunc {
 }
 impl pg_sys::seal::Sealed for GroupingSet {}
 impl pg_sys::PgNode for GroupingSet {}
+impl pg_sys::PgTaggedNode for GroupingSet {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GroupingSet;
+}
+impl serde::Serialize for GroupingSet {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GroupingSet", 4usize)?;
+        state.serialize_field("node", "GroupingSet")?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field(
+            "content",
+            &pg_sys::node_serde::NodePtr(self.content as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GroupingSet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105309,6 +110067,25 @@ impl std::fmt::Display for GroupingSet {
 }
 impl pg_sys::seal::Sealed for GroupingSetData {}
 impl pg_sys::PgNode for GroupingSetData {}
+impl pg_sys::PgTaggedNode for GroupingSetData {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GroupingSetData;
+}
+impl serde::Serialize for GroupingSetData {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GroupingSetData", 3usize)?;
+        state.serialize_field("node", "GroupingSetData")?;
+        state.serialize_field(
+            "set",
+            &pg_sys::node_serde::NodePtr(self.set as *const pg_sys::Node),
+        )?;
+        state.serialize_field("numGroups", &self.numGroups)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GroupingSetData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105316,6 +110093,34 @@ impl std::fmt::Display for GroupingSetData {
 }
 impl pg_sys::seal::Sealed for GroupingSetsPath {}
 impl pg_sys::PgNode for GroupingSetsPath {}
+impl pg_sys::PgTaggedNode for GroupingSetsPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_GroupingSetsPath;
+}
+impl serde::Serialize for GroupingSetsPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GroupingSetsPath", 6usize)?;
+        state.serialize_field("node", "GroupingSetsPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field("aggstrategy", &self.aggstrategy)?;
+        state.serialize_field(
+            "rollups",
+            &pg_sys::node_serde::NodePtr(self.rollups as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "qual",
+            &pg_sys::node_serde::NodePtr(self.qual as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for GroupingSetsPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105323,6 +110128,29 @@ impl std::fmt::Display for GroupingSetsPath {
 }
 impl pg_sys::seal::Sealed for Hash {}
 impl pg_sys::PgNode for Hash {}
+impl pg_sys::PgTaggedNode for Hash {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Hash;
+}
+impl serde::Serialize for Hash {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Hash", 7usize)?;
+        state.serialize_field("node", "Hash")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field(
+            "hashkeys",
+            &pg_sys::node_serde::NodePtr(self.hashkeys as *const pg_sys::Node),
+        )?;
+        state.serialize_field("skewTable", &self.skewTable)?;
+        state.serialize_field("skewColumn", &self.skewColumn)?;
+        state.serialize_field("skewInherit", &self.skewInherit)?;
+        state.serialize_field("rows_total", &self.rows_total)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Hash {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105330,6 +110158,37 @@ impl std::fmt::Display for Hash {
 }
 impl pg_sys::seal::Sealed for HashJoin {}
 impl pg_sys::PgNode for HashJoin {}
+impl pg_sys::PgTaggedNode for HashJoin {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_HashJoin;
+}
+impl serde::Serialize for HashJoin {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("HashJoin", 6usize)?;
+        state.serialize_field("node", "HashJoin")?;
+        state.serialize_field("join", &self.join)?;
+        state.serialize_field(
+            "hashclauses",
+            &pg_sys::node_serde::NodePtr(self.hashclauses as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "hashoperators",
+            &pg_sys::node_serde::NodePtr(self.hashoperators as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "hashcollations",
+            &pg_sys::node_serde::NodePtr(self.hashcollations as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "hashkeys",
+            &pg_sys::node_serde::NodePtr(self.hashkeys as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for HashJoin {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105337,6 +110196,63 @@ impl std::fmt::Display for HashJoin {
 }
 impl pg_sys::seal::Sealed for HashJoinState {}
 impl pg_sys::PgNode for HashJoinState {}
+impl pg_sys::PgTaggedNode for HashJoinState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_HashJoinState;
+}
+impl serde::Serialize for HashJoinState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("HashJoinState", 17usize)?;
+        state.serialize_field("node", "HashJoinState")?;
+        state.serialize_field("js", &self.js)?;
+        state.serialize_field(
+            "hashclauses",
+            &pg_sys::node_serde::NodePtr(self.hashclauses as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "hj_OuterHashKeys",
+            &pg_sys::node_serde::NodePtr(self.hj_OuterHashKeys as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "hj_HashOperators",
+            &pg_sys::node_serde::NodePtr(self.hj_HashOperators as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "hj_Collations",
+            &pg_sys::node_serde::NodePtr(self.hj_Collations as *const pg_sys::Node),
+        )?;
+        state.serialize_field("hj_CurHashValue", &self.hj_CurHashValue)?;
+        state.serialize_field("hj_CurBucketNo", &self.hj_CurBucketNo)?;
+        state.serialize_field("hj_CurSkewBucketNo", &self.hj_CurSkewBucketNo)?;
+        state.serialize_field(
+            "hj_OuterTupleSlot",
+            &pg_sys::node_serde::NodePtr(self.hj_OuterTupleSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "hj_HashTupleSlot",
+            &pg_sys::node_serde::NodePtr(self.hj_HashTupleSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "hj_NullOuterTupleSlot",
+            &pg_sys::node_serde::NodePtr(self.hj_NullOuterTupleSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "hj_NullInnerTupleSlot",
+            &pg_sys::node_serde::NodePtr(self.hj_NullInnerTupleSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "hj_FirstOuterTupleSlot",
+            &pg_sys::node_serde::NodePtr(self.hj_FirstOuterTupleSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field("hj_JoinState", &self.hj_JoinState)?;
+        state.serialize_field("hj_MatchedOuter", &self.hj_MatchedOuter)?;
+        state.serialize_field("hj_OuterNotEmpty", &self.hj_OuterNotEmpty)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for HashJoinState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105344,6 +110260,27 @@ impl std::fmt::Display for HashJoinState {
 }
 impl pg_sys::seal::Sealed for HashPath {}
 impl pg_sys::PgNode for HashPath {}
+impl pg_sys::PgTaggedNode for HashPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_HashPath;
+}
+impl serde::Serialize for HashPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("HashPath", 5usize)?;
+        state.serialize_field("node", "HashPath")?;
+        state.serialize_field("jpath", &self.jpath)?;
+        state.serialize_field(
+            "path_hashclauses",
+            &pg_sys::node_serde::NodePtr(self.path_hashclauses as *const pg_sys::Node),
+        )?;
+        state.serialize_field("num_batches", &self.num_batches)?;
+        state.serialize_field("inner_rows_total", &self.inner_rows_total)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for HashPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105351,6 +110288,25 @@ impl std::fmt::Display for HashPath {
 }
 impl pg_sys::seal::Sealed for HashState {}
 impl pg_sys::PgNode for HashState {}
+impl pg_sys::PgTaggedNode for HashState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_HashState;
+}
+impl serde::Serialize for HashState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("HashState", 3usize)?;
+        state.serialize_field("node", "HashState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field(
+            "hashkeys",
+            &pg_sys::node_serde::NodePtr(self.hashkeys as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for HashState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105358,6 +110314,19 @@ impl std::fmt::Display for HashState {
 }
 impl pg_sys::seal::Sealed for HeapTupleTableSlot {}
 impl pg_sys::PgNode for HeapTupleTableSlot {}
+impl serde::Serialize for HeapTupleTableSlot {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("HeapTupleTableSlot", 3usize)?;
+        state.serialize_field("node", "HeapTupleTableSlot")?;
+        state.serialize_field("base", &self.base)?;
+        state.serialize_field("off", &self.off)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for HeapTupleTableSlot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105365,6 +110334,20 @@ impl std::fmt::Display for HeapTupleTableSlot {
 }
 impl pg_sys::seal::Sealed for IdentifySystemCmd {}
 impl pg_sys::PgNode for IdentifySystemCmd {}
+impl pg_sys::PgTaggedNode for IdentifySystemCmd {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_IdentifySystemCmd;
+}
+impl serde::Serialize for IdentifySystemCmd {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IdentifySystemCmd", 1usize)?;
+        state.serialize_field("node", "IdentifySystemCmd")?;
+        state.end()
+    }
+}
 impl std::fmt::Display for IdentifySystemCmd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105372,6 +110355,41 @@ impl std::fmt::Display for IdentifySystemCmd {
 }
 impl pg_sys::seal::Sealed for ImportForeignSchemaStmt {}
 impl pg_sys::PgNode for ImportForeignSchemaStmt {}
+impl pg_sys::PgTaggedNode for ImportForeignSchemaStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ImportForeignSchemaStmt;
+}
+impl serde::Serialize for ImportForeignSchemaStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ImportForeignSchemaStmt", 7usize)?;
+        state.serialize_field("node", "ImportForeignSchemaStmt")?;
+        state.serialize_field(
+            "server_name",
+            &pg_sys::node_serde::CStringPtr(self.server_name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "remote_schema",
+            &pg_sys::node_serde::CStringPtr(self.remote_schema as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "local_schema",
+            &pg_sys::node_serde::CStringPtr(self.local_schema as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("list_type", &self.list_type)?;
+        state.serialize_field(
+            "table_list",
+            &pg_sys::node_serde::NodePtr(self.table_list as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ImportForeignSchemaStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105379,6 +110397,36 @@ impl std::fmt::Display for ImportForeignSchemaStmt {
 }
 impl pg_sys::seal::Sealed for IndexAmRoutine {}
 impl pg_sys::PgNode for IndexAmRoutine {}
+impl pg_sys::PgTaggedNode for IndexAmRoutine {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_IndexAmRoutine;
+}
+impl serde::Serialize for IndexAmRoutine {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IndexAmRoutine", 17usize)?;
+        state.serialize_field("node", "IndexAmRoutine")?;
+        state.serialize_field("amstrategies", &self.amstrategies)?;
+        state.serialize_field("amsupport", &self.amsupport)?;
+        state.serialize_field("amcanorder", &self.amcanorder)?;
+        state.serialize_field("amcanorderbyop", &self.amcanorderbyop)?;
+        state.serialize_field("amcanbackward", &self.amcanbackward)?;
+        state.serialize_field("amcanunique", &self.amcanunique)?;
+        state.serialize_field("amcanmulticol", &self.amcanmulticol)?;
+        state.serialize_field("amoptionalkey", &self.amoptionalkey)?;
+        state.serialize_field("amsearcharray", &self.amsearcharray)?;
+        state.serialize_field("amsearchnulls", &self.amsearchnulls)?;
+        state.serialize_field("amstorage", &self.amstorage)?;
+        state.serialize_field("amclusterable", &self.amclusterable)?;
+        state.serialize_field("ampredlocks", &self.ampredlocks)?;
+        state.serialize_field("amcanparallel", &self.amcanparallel)?;
+        state.serialize_field("amcaninclude", &self.amcaninclude)?;
+        state.serialize_field("amkeytype", &self.amkeytype)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for IndexAmRoutine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105386,6 +110434,34 @@ impl std::fmt::Display for IndexAmRoutine {
 }
 impl pg_sys::seal::Sealed for IndexClause {}
 impl pg_sys::PgNode for IndexClause {}
+impl pg_sys::PgTaggedNode for IndexClause {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_IndexClause;
+}
+impl serde::Serialize for IndexClause {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IndexClause", 6usize)?;
+        state.serialize_field("node", "IndexClause")?;
+        state.serialize_field(
+            "rinfo",
+            &pg_sys::node_serde::NodePtr(self.rinfo as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexquals",
+            &pg_sys::node_serde::NodePtr(self.indexquals as *const pg_sys::Node),
+        )?;
+        state.serialize_field("lossy", &self.lossy)?;
+        state.serialize_field("indexcol", &self.indexcol)?;
+        state.serialize_field(
+            "indexcols",
+            &pg_sys::node_serde::NodePtr(self.indexcols as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for IndexClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105393,6 +110469,42 @@ impl std::fmt::Display for IndexClause {
 }
 impl pg_sys::seal::Sealed for IndexElem {}
 impl pg_sys::PgNode for IndexElem {}
+impl pg_sys::PgTaggedNode for IndexElem {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_IndexElem;
+}
+impl serde::Serialize for IndexElem {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IndexElem", 8usize)?;
+        state.serialize_field("node", "IndexElem")?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "expr",
+            &pg_sys::node_serde::NodePtr(self.expr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexcolname",
+            &pg_sys::node_serde::CStringPtr(self.indexcolname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "collation",
+            &pg_sys::node_serde::NodePtr(self.collation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "opclass",
+            &pg_sys::node_serde::NodePtr(self.opclass as *const pg_sys::Node),
+        )?;
+        state.serialize_field("ordering", &self.ordering)?;
+        state.serialize_field("nulls_ordering", &self.nulls_ordering)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for IndexElem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105400,6 +110512,48 @@ impl std::fmt::Display for IndexElem {
 }
 impl pg_sys::seal::Sealed for IndexInfo {}
 impl pg_sys::PgNode for IndexInfo {}
+impl pg_sys::PgTaggedNode for IndexInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_IndexInfo;
+}
+impl serde::Serialize for IndexInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IndexInfo", 14usize)?;
+        state.serialize_field("node", "IndexInfo")?;
+        state.serialize_field("ii_NumIndexAttrs", &self.ii_NumIndexAttrs)?;
+        state.serialize_field("ii_NumIndexKeyAttrs", &self.ii_NumIndexKeyAttrs)?;
+        state.serialize_field(
+            "ii_Expressions",
+            &pg_sys::node_serde::NodePtr(self.ii_Expressions as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ii_ExpressionsState",
+            &pg_sys::node_serde::NodePtr(self.ii_ExpressionsState as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ii_Predicate",
+            &pg_sys::node_serde::NodePtr(self.ii_Predicate as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ii_PredicateState",
+            &pg_sys::node_serde::NodePtr(self.ii_PredicateState as *const pg_sys::Node),
+        )?;
+        state.serialize_field("ii_Unique", &self.ii_Unique)?;
+        state.serialize_field("ii_ReadyForInserts", &self.ii_ReadyForInserts)?;
+        state.serialize_field("ii_Concurrent", &self.ii_Concurrent)?;
+        state.serialize_field("ii_BrokenHotChain", &self.ii_BrokenHotChain)?;
+        state.serialize_field("ii_ParallelWorkers", &self.ii_ParallelWorkers)?;
+        state.serialize_field("ii_Am", &self.ii_Am)?;
+        state.serialize_field(
+            "ii_Context",
+            &pg_sys::node_serde::NodePtr(self.ii_Context as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for IndexInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105407,6 +110561,39 @@ impl std::fmt::Display for IndexInfo {
 }
 impl pg_sys::seal::Sealed for IndexOnlyScan {}
 impl pg_sys::PgNode for IndexOnlyScan {}
+impl pg_sys::PgTaggedNode for IndexOnlyScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_IndexOnlyScan;
+}
+impl serde::Serialize for IndexOnlyScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IndexOnlyScan", 8usize)?;
+        state.serialize_field("node", "IndexOnlyScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field("indexid", &self.indexid)?;
+        state.serialize_field(
+            "indexqual",
+            &pg_sys::node_serde::NodePtr(self.indexqual as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexorderby",
+            &pg_sys::node_serde::NodePtr(self.indexorderby as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indextlist",
+            &pg_sys::node_serde::NodePtr(self.indextlist as *const pg_sys::Node),
+        )?;
+        state.serialize_field("indexorderdir", &self.indexorderdir)?;
+        state.serialize_field(
+            "recheckqual",
+            &pg_sys::node_serde::NodePtr(self.recheckqual as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for IndexOnlyScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105414,6 +110601,38 @@ impl std::fmt::Display for IndexOnlyScan {
 }
 impl pg_sys::seal::Sealed for IndexOnlyScanState {}
 impl pg_sys::PgNode for IndexOnlyScanState {}
+impl pg_sys::PgTaggedNode for IndexOnlyScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_IndexOnlyScanState;
+}
+impl serde::Serialize for IndexOnlyScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IndexOnlyScanState", 10usize)?;
+        state.serialize_field("node", "IndexOnlyScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field(
+            "recheckqual",
+            &pg_sys::node_serde::NodePtr(self.recheckqual as *const pg_sys::Node),
+        )?;
+        state.serialize_field("ioss_NumScanKeys", &self.ioss_NumScanKeys)?;
+        state.serialize_field("ioss_NumOrderByKeys", &self.ioss_NumOrderByKeys)?;
+        state.serialize_field("ioss_NumRuntimeKeys", &self.ioss_NumRuntimeKeys)?;
+        state.serialize_field("ioss_RuntimeKeysReady", &self.ioss_RuntimeKeysReady)?;
+        state.serialize_field(
+            "ioss_RuntimeContext",
+            &pg_sys::node_serde::NodePtr(self.ioss_RuntimeContext as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ioss_TableSlot",
+            &pg_sys::node_serde::NodePtr(self.ioss_TableSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field("ioss_VMBuffer", &self.ioss_VMBuffer)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for IndexOnlyScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105421,6 +110640,60 @@ impl std::fmt::Display for IndexOnlyScanState {
 }
 impl pg_sys::seal::Sealed for IndexOptInfo {}
 impl pg_sys::PgNode for IndexOptInfo {}
+impl pg_sys::PgTaggedNode for IndexOptInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_IndexOptInfo;
+}
+impl serde::Serialize for IndexOptInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IndexOptInfo", 26usize)?;
+        state.serialize_field("node", "IndexOptInfo")?;
+        state.serialize_field("indexoid", &self.indexoid)?;
+        state.serialize_field("reltablespace", &self.reltablespace)?;
+        state.serialize_field(
+            "rel",
+            &pg_sys::node_serde::NodePtr(self.rel as *const pg_sys::Node),
+        )?;
+        state.serialize_field("pages", &self.pages)?;
+        state.serialize_field("tuples", &self.tuples)?;
+        state.serialize_field("tree_height", &self.tree_height)?;
+        state.serialize_field("ncolumns", &self.ncolumns)?;
+        state.serialize_field("nkeycolumns", &self.nkeycolumns)?;
+        state.serialize_field("relam", &self.relam)?;
+        state.serialize_field(
+            "indexprs",
+            &pg_sys::node_serde::NodePtr(self.indexprs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indpred",
+            &pg_sys::node_serde::NodePtr(self.indpred as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indextlist",
+            &pg_sys::node_serde::NodePtr(self.indextlist as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indrestrictinfo",
+            &pg_sys::node_serde::NodePtr(self.indrestrictinfo as *const pg_sys::Node),
+        )?;
+        state.serialize_field("predOK", &self.predOK)?;
+        state.serialize_field("unique", &self.unique)?;
+        state.serialize_field("immediate", &self.immediate)?;
+        state.serialize_field("hypothetical", &self.hypothetical)?;
+        state.serialize_field("amcanorderbyop", &self.amcanorderbyop)?;
+        state.serialize_field("amoptionalkey", &self.amoptionalkey)?;
+        state.serialize_field("amsearcharray", &self.amsearcharray)?;
+        state.serialize_field("amsearchnulls", &self.amsearchnulls)?;
+        state.serialize_field("amhasgettuple", &self.amhasgettuple)?;
+        state.serialize_field("amhasgetbitmap", &self.amhasgetbitmap)?;
+        state.serialize_field("amcanparallel", &self.amcanparallel)?;
+        state.serialize_field("amcanmarkpos", &self.amcanmarkpos)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for IndexOptInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105428,6 +110701,40 @@ impl std::fmt::Display for IndexOptInfo {
 }
 impl pg_sys::seal::Sealed for IndexPath {}
 impl pg_sys::PgNode for IndexPath {}
+impl pg_sys::PgTaggedNode for IndexPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_IndexPath;
+}
+impl serde::Serialize for IndexPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IndexPath", 9usize)?;
+        state.serialize_field("node", "IndexPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "indexinfo",
+            &pg_sys::node_serde::NodePtr(self.indexinfo as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexclauses",
+            &pg_sys::node_serde::NodePtr(self.indexclauses as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexorderbys",
+            &pg_sys::node_serde::NodePtr(self.indexorderbys as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexorderbycols",
+            &pg_sys::node_serde::NodePtr(self.indexorderbycols as *const pg_sys::Node),
+        )?;
+        state.serialize_field("indexscandir", &self.indexscandir)?;
+        state.serialize_field("indextotalcost", &self.indextotalcost)?;
+        state.serialize_field("indexselectivity", &self.indexselectivity)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for IndexPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105435,6 +110742,43 @@ impl std::fmt::Display for IndexPath {
 }
 impl pg_sys::seal::Sealed for IndexScan {}
 impl pg_sys::PgNode for IndexScan {}
+impl pg_sys::PgTaggedNode for IndexScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_IndexScan;
+}
+impl serde::Serialize for IndexScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IndexScan", 9usize)?;
+        state.serialize_field("node", "IndexScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field("indexid", &self.indexid)?;
+        state.serialize_field(
+            "indexqual",
+            &pg_sys::node_serde::NodePtr(self.indexqual as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexqualorig",
+            &pg_sys::node_serde::NodePtr(self.indexqualorig as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexorderby",
+            &pg_sys::node_serde::NodePtr(self.indexorderby as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexorderbyorig",
+            &pg_sys::node_serde::NodePtr(self.indexorderbyorig as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexorderbyops",
+            &pg_sys::node_serde::NodePtr(self.indexorderbyops as *const pg_sys::Node),
+        )?;
+        state.serialize_field("indexorderdir", &self.indexorderdir)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for IndexScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105442,6 +110786,38 @@ impl std::fmt::Display for IndexScan {
 }
 impl pg_sys::seal::Sealed for IndexScanState {}
 impl pg_sys::PgNode for IndexScanState {}
+impl pg_sys::PgTaggedNode for IndexScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_IndexScanState;
+}
+impl serde::Serialize for IndexScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IndexScanState", 10usize)?;
+        state.serialize_field("node", "IndexScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field(
+            "indexqualorig",
+            &pg_sys::node_serde::NodePtr(self.indexqualorig as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexorderbyorig",
+            &pg_sys::node_serde::NodePtr(self.indexorderbyorig as *const pg_sys::Node),
+        )?;
+        state.serialize_field("iss_NumScanKeys", &self.iss_NumScanKeys)?;
+        state.serialize_field("iss_NumOrderByKeys", &self.iss_NumOrderByKeys)?;
+        state.serialize_field("iss_NumRuntimeKeys", &self.iss_NumRuntimeKeys)?;
+        state.serialize_field("iss_RuntimeKeysReady", &self.iss_RuntimeKeysReady)?;
+        state.serialize_field(
+            "iss_RuntimeContext",
+            &pg_sys::node_serde::NodePtr(self.iss_RuntimeContext as *const pg_sys::Node),
+        )?;
+        state.serialize_field("iss_ReachedEnd", &self.iss_ReachedEnd)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for IndexScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105449,6 +110825,71 @@ impl std::fmt::Display for IndexScanState {
 }
 impl pg_sys::seal::Sealed for IndexStmt {}
 impl pg_sys::PgNode for IndexStmt {}
+impl pg_sys::PgTaggedNode for IndexStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_IndexStmt;
+}
+impl serde::Serialize for IndexStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IndexStmt", 22usize)?;
+        state.serialize_field("node", "IndexStmt")?;
+        state.serialize_field(
+            "idxname",
+            &pg_sys::node_serde::CStringPtr(self.idxname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "accessMethod",
+            &pg_sys::node_serde::CStringPtr(self.accessMethod as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "tableSpace",
+            &pg_sys::node_serde::CStringPtr(self.tableSpace as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "indexParams",
+            &pg_sys::node_serde::NodePtr(self.indexParams as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexIncludingParams",
+            &pg_sys::node_serde::NodePtr(self.indexIncludingParams as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "whereClause",
+            &pg_sys::node_serde::NodePtr(self.whereClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "excludeOpNames",
+            &pg_sys::node_serde::NodePtr(self.excludeOpNames as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "idxcomment",
+            &pg_sys::node_serde::CStringPtr(self.idxcomment as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("indexOid", &self.indexOid)?;
+        state.serialize_field("oldNode", &self.oldNode)?;
+        state.serialize_field("unique", &self.unique)?;
+        state.serialize_field("primary", &self.primary)?;
+        state.serialize_field("isconstraint", &self.isconstraint)?;
+        state.serialize_field("deferrable", &self.deferrable)?;
+        state.serialize_field("initdeferred", &self.initdeferred)?;
+        state.serialize_field("transformed", &self.transformed)?;
+        state.serialize_field("concurrent", &self.concurrent)?;
+        state.serialize_field("if_not_exists", &self.if_not_exists)?;
+        state.serialize_field("reset_default_tblspc", &self.reset_default_tblspc)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for IndexStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105456,6 +110897,33 @@ impl std::fmt::Display for IndexStmt {
 }
 impl pg_sys::seal::Sealed for InferClause {}
 impl pg_sys::PgNode for InferClause {}
+impl pg_sys::PgTaggedNode for InferClause {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_InferClause;
+}
+impl serde::Serialize for InferClause {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("InferClause", 5usize)?;
+        state.serialize_field("node", "InferClause")?;
+        state.serialize_field(
+            "indexElems",
+            &pg_sys::node_serde::NodePtr(self.indexElems as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "whereClause",
+            &pg_sys::node_serde::NodePtr(self.whereClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "conname",
+            &pg_sys::node_serde::CStringPtr(self.conname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for InferClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105463,6 +110931,26 @@ impl std::fmt::Display for InferClause {
 }
 impl pg_sys::seal::Sealed for InferenceElem {}
 impl pg_sys::PgNode for InferenceElem {}
+impl pg_sys::PgTaggedNode for InferenceElem {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_InferenceElem;
+}
+impl serde::Serialize for InferenceElem {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("InferenceElem", 4usize)?;
+        state.serialize_field("node", "InferenceElem")?;
+        state.serialize_field(
+            "expr",
+            &pg_sys::node_serde::NodePtr(self.expr as *const pg_sys::Node),
+        )?;
+        state.serialize_field("infercollid", &self.infercollid)?;
+        state.serialize_field("inferopclass", &self.inferopclass)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for InferenceElem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105470,6 +110958,27 @@ impl std::fmt::Display for InferenceElem {
 }
 impl pg_sys::seal::Sealed for InlineCodeBlock {}
 impl pg_sys::PgNode for InlineCodeBlock {}
+impl pg_sys::PgTaggedNode for InlineCodeBlock {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_InlineCodeBlock;
+}
+impl serde::Serialize for InlineCodeBlock {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("InlineCodeBlock", 5usize)?;
+        state.serialize_field("node", "InlineCodeBlock")?;
+        state.serialize_field(
+            "source_text",
+            &pg_sys::node_serde::CStringPtr(self.source_text as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("langOid", &self.langOid)?;
+        state.serialize_field("langIsTrusted", &self.langIsTrusted)?;
+        state.serialize_field("atomic", &self.atomic)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for InlineCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105477,6 +110986,45 @@ impl std::fmt::Display for InlineCodeBlock {
 }
 impl pg_sys::seal::Sealed for InsertStmt {}
 impl pg_sys::PgNode for InsertStmt {}
+impl pg_sys::PgTaggedNode for InsertStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_InsertStmt;
+}
+impl serde::Serialize for InsertStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("InsertStmt", 8usize)?;
+        state.serialize_field("node", "InsertStmt")?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "cols",
+            &pg_sys::node_serde::NodePtr(self.cols as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "selectStmt",
+            &pg_sys::node_serde::NodePtr(self.selectStmt as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "onConflictClause",
+            &pg_sys::node_serde::NodePtr(self.onConflictClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "returningList",
+            &pg_sys::node_serde::NodePtr(self.returningList as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "withClause",
+            &pg_sys::node_serde::NodePtr(self.withClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field("override", &self.override_)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for InsertStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105484,6 +111032,46 @@ impl std::fmt::Display for InsertStmt {
 }
 impl pg_sys::seal::Sealed for IntoClause {}
 impl pg_sys::PgNode for IntoClause {}
+impl pg_sys::PgTaggedNode for IntoClause {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_IntoClause;
+}
+impl serde::Serialize for IntoClause {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IntoClause", 9usize)?;
+        state.serialize_field("node", "IntoClause")?;
+        state.serialize_field(
+            "rel",
+            &pg_sys::node_serde::NodePtr(self.rel as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "colNames",
+            &pg_sys::node_serde::NodePtr(self.colNames as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "accessMethod",
+            &pg_sys::node_serde::CStringPtr(self.accessMethod as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field("onCommit", &self.onCommit)?;
+        state.serialize_field(
+            "tableSpaceName",
+            &pg_sys::node_serde::CStringPtr(self.tableSpaceName as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "viewQuery",
+            &pg_sys::node_serde::NodePtr(self.viewQuery as *const pg_sys::Node),
+        )?;
+        state.serialize_field("skipData", &self.skipData)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for IntoClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105491,6 +111079,27 @@ impl std::fmt::Display for IntoClause {
 }
 impl pg_sys::seal::Sealed for Join {}
 impl pg_sys::PgNode for Join {}
+impl pg_sys::PgTaggedNode for Join {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Join;
+}
+impl serde::Serialize for Join {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Join", 5usize)?;
+        state.serialize_field("node", "Join")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field("jointype", &self.jointype)?;
+        state.serialize_field("inner_unique", &self.inner_unique)?;
+        state.serialize_field(
+            "joinqual",
+            &pg_sys::node_serde::NodePtr(self.joinqual as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Join {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105498,6 +111107,43 @@ impl std::fmt::Display for Join {
 }
 impl pg_sys::seal::Sealed for JoinExpr {}
 impl pg_sys::PgNode for JoinExpr {}
+impl pg_sys::PgTaggedNode for JoinExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_JoinExpr;
+}
+impl serde::Serialize for JoinExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("JoinExpr", 9usize)?;
+        state.serialize_field("node", "JoinExpr")?;
+        state.serialize_field("jointype", &self.jointype)?;
+        state.serialize_field("isNatural", &self.isNatural)?;
+        state.serialize_field(
+            "larg",
+            &pg_sys::node_serde::NodePtr(self.larg as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rarg",
+            &pg_sys::node_serde::NodePtr(self.rarg as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "usingClause",
+            &pg_sys::node_serde::NodePtr(self.usingClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "quals",
+            &pg_sys::node_serde::NodePtr(self.quals as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "alias",
+            &pg_sys::node_serde::NodePtr(self.alias as *const pg_sys::Node),
+        )?;
+        state.serialize_field("rtindex", &self.rtindex)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for JoinExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105505,6 +111151,32 @@ impl std::fmt::Display for JoinExpr {
 }
 impl pg_sys::seal::Sealed for JoinPath {}
 impl pg_sys::PgNode for JoinPath {}
+impl serde::Serialize for JoinPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("JoinPath", 7usize)?;
+        state.serialize_field("node", "JoinPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("jointype", &self.jointype)?;
+        state.serialize_field("inner_unique", &self.inner_unique)?;
+        state.serialize_field(
+            "outerjoinpath",
+            &pg_sys::node_serde::NodePtr(self.outerjoinpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "innerjoinpath",
+            &pg_sys::node_serde::NodePtr(self.innerjoinpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "joinrestrictinfo",
+            &pg_sys::node_serde::NodePtr(self.joinrestrictinfo as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for JoinPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105512,6 +111184,27 @@ impl std::fmt::Display for JoinPath {
 }
 impl pg_sys::seal::Sealed for JoinState {}
 impl pg_sys::PgNode for JoinState {}
+impl pg_sys::PgTaggedNode for JoinState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_JoinState;
+}
+impl serde::Serialize for JoinState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("JoinState", 5usize)?;
+        state.serialize_field("node", "JoinState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field("jointype", &self.jointype)?;
+        state.serialize_field("single_match", &self.single_match)?;
+        state.serialize_field(
+            "joinqual",
+            &pg_sys::node_serde::NodePtr(self.joinqual as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for JoinState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105519,6 +111212,29 @@ impl std::fmt::Display for JoinState {
 }
 impl pg_sys::seal::Sealed for JunkFilter {}
 impl pg_sys::PgNode for JunkFilter {}
+impl pg_sys::PgTaggedNode for JunkFilter {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_JunkFilter;
+}
+impl serde::Serialize for JunkFilter {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("JunkFilter", 4usize)?;
+        state.serialize_field("node", "JunkFilter")?;
+        state.serialize_field(
+            "jf_targetList",
+            &pg_sys::node_serde::NodePtr(self.jf_targetList as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "jf_resultSlot",
+            &pg_sys::node_serde::NodePtr(self.jf_resultSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field("jf_junkAttNo", &self.jf_junkAttNo)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for JunkFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105526,6 +111242,29 @@ impl std::fmt::Display for JunkFilter {
 }
 impl pg_sys::seal::Sealed for Limit {}
 impl pg_sys::PgNode for Limit {}
+impl pg_sys::PgTaggedNode for Limit {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Limit;
+}
+impl serde::Serialize for Limit {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Limit", 4usize)?;
+        state.serialize_field("node", "Limit")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field(
+            "limitOffset",
+            &pg_sys::node_serde::NodePtr(self.limitOffset as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "limitCount",
+            &pg_sys::node_serde::NodePtr(self.limitCount as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Limit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105533,6 +111272,33 @@ impl std::fmt::Display for Limit {
 }
 impl pg_sys::seal::Sealed for LimitPath {}
 impl pg_sys::PgNode for LimitPath {}
+impl pg_sys::PgTaggedNode for LimitPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_LimitPath;
+}
+impl serde::Serialize for LimitPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("LimitPath", 5usize)?;
+        state.serialize_field("node", "LimitPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "limitOffset",
+            &pg_sys::node_serde::NodePtr(self.limitOffset as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "limitCount",
+            &pg_sys::node_serde::NodePtr(self.limitCount as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for LimitPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105540,6 +111306,38 @@ impl std::fmt::Display for LimitPath {
 }
 impl pg_sys::seal::Sealed for LimitState {}
 impl pg_sys::PgNode for LimitState {}
+impl pg_sys::PgTaggedNode for LimitState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_LimitState;
+}
+impl serde::Serialize for LimitState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("LimitState", 10usize)?;
+        state.serialize_field("node", "LimitState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field(
+            "limitOffset",
+            &pg_sys::node_serde::NodePtr(self.limitOffset as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "limitCount",
+            &pg_sys::node_serde::NodePtr(self.limitCount as *const pg_sys::Node),
+        )?;
+        state.serialize_field("offset", &self.offset)?;
+        state.serialize_field("count", &self.count)?;
+        state.serialize_field("noCount", &self.noCount)?;
+        state.serialize_field("lstate", &self.lstate)?;
+        state.serialize_field("position", &self.position)?;
+        state.serialize_field(
+            "subSlot",
+            &pg_sys::node_serde::NodePtr(self.subSlot as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for LimitState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105547,6 +111345,9 @@ impl std::fmt::Display for LimitState {
 }
 impl pg_sys::seal::Sealed for List {}
 impl pg_sys::PgNode for List {}
+impl pg_sys::PgTaggedNode for List {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_List;
+}
 impl std::fmt::Display for List {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105554,6 +111355,24 @@ impl std::fmt::Display for List {
 }
 impl pg_sys::seal::Sealed for ListenStmt {}
 impl pg_sys::PgNode for ListenStmt {}
+impl pg_sys::PgTaggedNode for ListenStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ListenStmt;
+}
+impl serde::Serialize for ListenStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ListenStmt", 2usize)?;
+        state.serialize_field("node", "ListenStmt")?;
+        state.serialize_field(
+            "conditionname",
+            &pg_sys::node_serde::CStringPtr(self.conditionname as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ListenStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105561,6 +111380,24 @@ impl std::fmt::Display for ListenStmt {
 }
 impl pg_sys::seal::Sealed for LoadStmt {}
 impl pg_sys::PgNode for LoadStmt {}
+impl pg_sys::PgTaggedNode for LoadStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_LoadStmt;
+}
+impl serde::Serialize for LoadStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("LoadStmt", 2usize)?;
+        state.serialize_field("node", "LoadStmt")?;
+        state.serialize_field(
+            "filename",
+            &pg_sys::node_serde::CStringPtr(self.filename as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for LoadStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105568,6 +111405,26 @@ impl std::fmt::Display for LoadStmt {
 }
 impl pg_sys::seal::Sealed for LockRows {}
 impl pg_sys::PgNode for LockRows {}
+impl pg_sys::PgTaggedNode for LockRows {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_LockRows;
+}
+impl serde::Serialize for LockRows {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("LockRows", 4usize)?;
+        state.serialize_field("node", "LockRows")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field(
+            "rowMarks",
+            &pg_sys::node_serde::NodePtr(self.rowMarks as *const pg_sys::Node),
+        )?;
+        state.serialize_field("epqParam", &self.epqParam)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for LockRows {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105575,6 +111432,30 @@ impl std::fmt::Display for LockRows {
 }
 impl pg_sys::seal::Sealed for LockRowsPath {}
 impl pg_sys::PgNode for LockRowsPath {}
+impl pg_sys::PgTaggedNode for LockRowsPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_LockRowsPath;
+}
+impl serde::Serialize for LockRowsPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("LockRowsPath", 5usize)?;
+        state.serialize_field("node", "LockRowsPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rowMarks",
+            &pg_sys::node_serde::NodePtr(self.rowMarks as *const pg_sys::Node),
+        )?;
+        state.serialize_field("epqParam", &self.epqParam)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for LockRowsPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105582,6 +111463,25 @@ impl std::fmt::Display for LockRowsPath {
 }
 impl pg_sys::seal::Sealed for LockRowsState {}
 impl pg_sys::PgNode for LockRowsState {}
+impl pg_sys::PgTaggedNode for LockRowsState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_LockRowsState;
+}
+impl serde::Serialize for LockRowsState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("LockRowsState", 3usize)?;
+        state.serialize_field("node", "LockRowsState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field(
+            "lr_arowMarks",
+            &pg_sys::node_serde::NodePtr(self.lr_arowMarks as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for LockRowsState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105589,6 +111489,26 @@ impl std::fmt::Display for LockRowsState {
 }
 impl pg_sys::seal::Sealed for LockStmt {}
 impl pg_sys::PgNode for LockStmt {}
+impl pg_sys::PgTaggedNode for LockStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_LockStmt;
+}
+impl serde::Serialize for LockStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("LockStmt", 4usize)?;
+        state.serialize_field("node", "LockStmt")?;
+        state.serialize_field(
+            "relations",
+            &pg_sys::node_serde::NodePtr(self.relations as *const pg_sys::Node),
+        )?;
+        state.serialize_field("mode", &self.mode)?;
+        state.serialize_field("nowait", &self.nowait)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for LockStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105596,6 +111516,26 @@ impl std::fmt::Display for LockStmt {
 }
 impl pg_sys::seal::Sealed for LockingClause {}
 impl pg_sys::PgNode for LockingClause {}
+impl pg_sys::PgTaggedNode for LockingClause {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_LockingClause;
+}
+impl serde::Serialize for LockingClause {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("LockingClause", 4usize)?;
+        state.serialize_field("node", "LockingClause")?;
+        state.serialize_field(
+            "lockedRels",
+            &pg_sys::node_serde::NodePtr(self.lockedRels as *const pg_sys::Node),
+        )?;
+        state.serialize_field("strength", &self.strength)?;
+        state.serialize_field("waitPolicy", &self.waitPolicy)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for LockingClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105603,6 +111543,21 @@ impl std::fmt::Display for LockingClause {
 }
 impl pg_sys::seal::Sealed for Material {}
 impl pg_sys::PgNode for Material {}
+impl pg_sys::PgTaggedNode for Material {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Material;
+}
+impl serde::Serialize for Material {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Material", 2usize)?;
+        state.serialize_field("node", "Material")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Material {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105610,6 +111565,25 @@ impl std::fmt::Display for Material {
 }
 impl pg_sys::seal::Sealed for MaterialPath {}
 impl pg_sys::PgNode for MaterialPath {}
+impl pg_sys::PgTaggedNode for MaterialPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_MaterialPath;
+}
+impl serde::Serialize for MaterialPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MaterialPath", 3usize)?;
+        state.serialize_field("node", "MaterialPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MaterialPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105617,6 +111591,23 @@ impl std::fmt::Display for MaterialPath {
 }
 impl pg_sys::seal::Sealed for MaterialState {}
 impl pg_sys::PgNode for MaterialState {}
+impl pg_sys::PgTaggedNode for MaterialState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_MaterialState;
+}
+impl serde::Serialize for MaterialState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MaterialState", 4usize)?;
+        state.serialize_field("node", "MaterialState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field("eflags", &self.eflags)?;
+        state.serialize_field("eof_underlying", &self.eof_underlying)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MaterialState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105624,6 +111615,43 @@ impl std::fmt::Display for MaterialState {
 }
 impl pg_sys::seal::Sealed for MemoryContextData {}
 impl pg_sys::PgNode for MemoryContextData {}
+impl serde::Serialize for MemoryContextData {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MemoryContextData", 9usize)?;
+        state.serialize_field("node", "MemoryContextData")?;
+        state.serialize_field("isReset", &self.isReset)?;
+        state.serialize_field("allowInCritSection", &self.allowInCritSection)?;
+        state.serialize_field(
+            "parent",
+            &pg_sys::node_serde::NodePtr(self.parent as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "firstchild",
+            &pg_sys::node_serde::NodePtr(self.firstchild as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "prevchild",
+            &pg_sys::node_serde::NodePtr(self.prevchild as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "nextchild",
+            &pg_sys::node_serde::NodePtr(self.nextchild as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "ident",
+            &pg_sys::node_serde::CStringPtr(self.ident as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MemoryContextData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105631,6 +111659,30 @@ impl std::fmt::Display for MemoryContextData {
 }
 impl pg_sys::seal::Sealed for MergeAppend {}
 impl pg_sys::PgNode for MergeAppend {}
+impl pg_sys::PgTaggedNode for MergeAppend {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_MergeAppend;
+}
+impl serde::Serialize for MergeAppend {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MergeAppend", 5usize)?;
+        state.serialize_field("node", "MergeAppend")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field(
+            "mergeplans",
+            &pg_sys::node_serde::NodePtr(self.mergeplans as *const pg_sys::Node),
+        )?;
+        state.serialize_field("numCols", &self.numCols)?;
+        state.serialize_field(
+            "part_prune_info",
+            &pg_sys::node_serde::NodePtr(self.part_prune_info as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MergeAppend {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105638,6 +111690,30 @@ impl std::fmt::Display for MergeAppend {
 }
 impl pg_sys::seal::Sealed for MergeAppendPath {}
 impl pg_sys::PgNode for MergeAppendPath {}
+impl pg_sys::PgTaggedNode for MergeAppendPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_MergeAppendPath;
+}
+impl serde::Serialize for MergeAppendPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MergeAppendPath", 5usize)?;
+        state.serialize_field("node", "MergeAppendPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "partitioned_rels",
+            &pg_sys::node_serde::NodePtr(self.partitioned_rels as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "subpaths",
+            &pg_sys::node_serde::NodePtr(self.subpaths as *const pg_sys::Node),
+        )?;
+        state.serialize_field("limit_tuples", &self.limit_tuples)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MergeAppendPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105645,6 +111721,25 @@ impl std::fmt::Display for MergeAppendPath {
 }
 impl pg_sys::seal::Sealed for MergeAppendState {}
 impl pg_sys::PgNode for MergeAppendState {}
+impl pg_sys::PgTaggedNode for MergeAppendState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_MergeAppendState;
+}
+impl serde::Serialize for MergeAppendState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MergeAppendState", 6usize)?;
+        state.serialize_field("node", "MergeAppendState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field("ms_nplans", &self.ms_nplans)?;
+        state.serialize_field("ms_nkeys", &self.ms_nkeys)?;
+        state.serialize_field("ms_initialized", &self.ms_initialized)?;
+        state.serialize_field("ms_noopscan", &self.ms_noopscan)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MergeAppendState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105652,6 +111747,26 @@ impl std::fmt::Display for MergeAppendState {
 }
 impl pg_sys::seal::Sealed for MergeJoin {}
 impl pg_sys::PgNode for MergeJoin {}
+impl pg_sys::PgTaggedNode for MergeJoin {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_MergeJoin;
+}
+impl serde::Serialize for MergeJoin {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MergeJoin", 4usize)?;
+        state.serialize_field("node", "MergeJoin")?;
+        state.serialize_field("join", &self.join)?;
+        state.serialize_field("skip_mark_restore", &self.skip_mark_restore)?;
+        state.serialize_field(
+            "mergeclauses",
+            &pg_sys::node_serde::NodePtr(self.mergeclauses as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MergeJoin {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105659,6 +111774,58 @@ impl std::fmt::Display for MergeJoin {
 }
 impl pg_sys::seal::Sealed for MergeJoinState {}
 impl pg_sys::PgNode for MergeJoinState {}
+impl pg_sys::PgTaggedNode for MergeJoinState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_MergeJoinState;
+}
+impl serde::Serialize for MergeJoinState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MergeJoinState", 18usize)?;
+        state.serialize_field("node", "MergeJoinState")?;
+        state.serialize_field("js", &self.js)?;
+        state.serialize_field("mj_NumClauses", &self.mj_NumClauses)?;
+        state.serialize_field("mj_JoinState", &self.mj_JoinState)?;
+        state.serialize_field("mj_SkipMarkRestore", &self.mj_SkipMarkRestore)?;
+        state.serialize_field("mj_ExtraMarks", &self.mj_ExtraMarks)?;
+        state.serialize_field("mj_ConstFalseJoin", &self.mj_ConstFalseJoin)?;
+        state.serialize_field("mj_FillOuter", &self.mj_FillOuter)?;
+        state.serialize_field("mj_FillInner", &self.mj_FillInner)?;
+        state.serialize_field("mj_MatchedOuter", &self.mj_MatchedOuter)?;
+        state.serialize_field("mj_MatchedInner", &self.mj_MatchedInner)?;
+        state.serialize_field(
+            "mj_OuterTupleSlot",
+            &pg_sys::node_serde::NodePtr(self.mj_OuterTupleSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "mj_InnerTupleSlot",
+            &pg_sys::node_serde::NodePtr(self.mj_InnerTupleSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "mj_MarkedTupleSlot",
+            &pg_sys::node_serde::NodePtr(self.mj_MarkedTupleSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "mj_NullOuterTupleSlot",
+            &pg_sys::node_serde::NodePtr(self.mj_NullOuterTupleSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "mj_NullInnerTupleSlot",
+            &pg_sys::node_serde::NodePtr(self.mj_NullInnerTupleSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "mj_OuterEContext",
+            &pg_sys::node_serde::NodePtr(self.mj_OuterEContext as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "mj_InnerEContext",
+            &pg_sys::node_serde::NodePtr(self.mj_InnerEContext as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MergeJoinState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105666,6 +111833,35 @@ impl std::fmt::Display for MergeJoinState {
 }
 impl pg_sys::seal::Sealed for MergePath {}
 impl pg_sys::PgNode for MergePath {}
+impl pg_sys::PgTaggedNode for MergePath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_MergePath;
+}
+impl serde::Serialize for MergePath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MergePath", 7usize)?;
+        state.serialize_field("node", "MergePath")?;
+        state.serialize_field("jpath", &self.jpath)?;
+        state.serialize_field(
+            "path_mergeclauses",
+            &pg_sys::node_serde::NodePtr(self.path_mergeclauses as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "outersortkeys",
+            &pg_sys::node_serde::NodePtr(self.outersortkeys as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "innersortkeys",
+            &pg_sys::node_serde::NodePtr(self.innersortkeys as *const pg_sys::Node),
+        )?;
+        state.serialize_field("skip_mark_restore", &self.skip_mark_restore)?;
+        state.serialize_field("materialize_inner", &self.materialize_inner)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MergePath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105673,6 +111869,39 @@ impl std::fmt::Display for MergePath {
 }
 impl pg_sys::seal::Sealed for MinMaxAggInfo {}
 impl pg_sys::PgNode for MinMaxAggInfo {}
+impl pg_sys::PgTaggedNode for MinMaxAggInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_MinMaxAggInfo;
+}
+impl serde::Serialize for MinMaxAggInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MinMaxAggInfo", 8usize)?;
+        state.serialize_field("node", "MinMaxAggInfo")?;
+        state.serialize_field("aggfnoid", &self.aggfnoid)?;
+        state.serialize_field("aggsortop", &self.aggsortop)?;
+        state.serialize_field(
+            "target",
+            &pg_sys::node_serde::NodePtr(self.target as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "subroot",
+            &pg_sys::node_serde::NodePtr(self.subroot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "path",
+            &pg_sys::node_serde::NodePtr(self.path as *const pg_sys::Node),
+        )?;
+        state.serialize_field("pathcost", &self.pathcost)?;
+        state.serialize_field(
+            "param",
+            &pg_sys::node_serde::NodePtr(self.param as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MinMaxAggInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105680,6 +111909,29 @@ impl std::fmt::Display for MinMaxAggInfo {
 }
 impl pg_sys::seal::Sealed for MinMaxAggPath {}
 impl pg_sys::PgNode for MinMaxAggPath {}
+impl pg_sys::PgTaggedNode for MinMaxAggPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_MinMaxAggPath;
+}
+impl serde::Serialize for MinMaxAggPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MinMaxAggPath", 4usize)?;
+        state.serialize_field("node", "MinMaxAggPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "mmaggregates",
+            &pg_sys::node_serde::NodePtr(self.mmaggregates as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "quals",
+            &pg_sys::node_serde::NodePtr(self.quals as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MinMaxAggPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105687,6 +111939,29 @@ impl std::fmt::Display for MinMaxAggPath {
 }
 impl pg_sys::seal::Sealed for MinMaxExpr {}
 impl pg_sys::PgNode for MinMaxExpr {}
+impl pg_sys::PgTaggedNode for MinMaxExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_MinMaxExpr;
+}
+impl serde::Serialize for MinMaxExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MinMaxExpr", 7usize)?;
+        state.serialize_field("node", "MinMaxExpr")?;
+        state.serialize_field("minmaxtype", &self.minmaxtype)?;
+        state.serialize_field("minmaxcollid", &self.minmaxcollid)?;
+        state.serialize_field("inputcollid", &self.inputcollid)?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MinMaxExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105694,6 +111969,19 @@ impl std::fmt::Display for MinMaxExpr {
 }
 impl pg_sys::seal::Sealed for MinimalTupleTableSlot {}
 impl pg_sys::PgNode for MinimalTupleTableSlot {}
+impl serde::Serialize for MinimalTupleTableSlot {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MinimalTupleTableSlot", 3usize)?;
+        state.serialize_field("node", "MinimalTupleTableSlot")?;
+        state.serialize_field("base", &self.base)?;
+        state.serialize_field("off", &self.off)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MinimalTupleTableSlot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105701,6 +111989,71 @@ impl std::fmt::Display for MinimalTupleTableSlot {
 }
 impl pg_sys::seal::Sealed for ModifyTable {}
 impl pg_sys::PgNode for ModifyTable {}
+impl pg_sys::PgTaggedNode for ModifyTable {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ModifyTable;
+}
+impl serde::Serialize for ModifyTable {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ModifyTable", 22usize)?;
+        state.serialize_field("node", "ModifyTable")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field("operation", &self.operation)?;
+        state.serialize_field("canSetTag", &self.canSetTag)?;
+        state.serialize_field("nominalRelation", &self.nominalRelation)?;
+        state.serialize_field("rootRelation", &self.rootRelation)?;
+        state.serialize_field("partColsUpdated", &self.partColsUpdated)?;
+        state.serialize_field(
+            "resultRelations",
+            &pg_sys::node_serde::NodePtr(self.resultRelations as *const pg_sys::Node),
+        )?;
+        state.serialize_field("resultRelIndex", &self.resultRelIndex)?;
+        state.serialize_field("rootResultRelIndex", &self.rootResultRelIndex)?;
+        state.serialize_field(
+            "plans",
+            &pg_sys::node_serde::NodePtr(self.plans as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "withCheckOptionLists",
+            &pg_sys::node_serde::NodePtr(self.withCheckOptionLists as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "returningLists",
+            &pg_sys::node_serde::NodePtr(self.returningLists as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "fdwPrivLists",
+            &pg_sys::node_serde::NodePtr(self.fdwPrivLists as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rowMarks",
+            &pg_sys::node_serde::NodePtr(self.rowMarks as *const pg_sys::Node),
+        )?;
+        state.serialize_field("epqParam", &self.epqParam)?;
+        state.serialize_field("onConflictAction", &self.onConflictAction)?;
+        state.serialize_field(
+            "arbiterIndexes",
+            &pg_sys::node_serde::NodePtr(self.arbiterIndexes as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "onConflictSet",
+            &pg_sys::node_serde::NodePtr(self.onConflictSet as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "onConflictWhere",
+            &pg_sys::node_serde::NodePtr(self.onConflictWhere as *const pg_sys::Node),
+        )?;
+        state.serialize_field("exclRelRTI", &self.exclRelRTI)?;
+        state.serialize_field(
+            "exclRelTlist",
+            &pg_sys::node_serde::NodePtr(self.exclRelTlist as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ModifyTable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105708,6 +112061,55 @@ impl std::fmt::Display for ModifyTable {
 }
 impl pg_sys::seal::Sealed for ModifyTablePath {}
 impl pg_sys::PgNode for ModifyTablePath {}
+impl pg_sys::PgTaggedNode for ModifyTablePath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ModifyTablePath;
+}
+impl serde::Serialize for ModifyTablePath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ModifyTablePath", 15usize)?;
+        state.serialize_field("node", "ModifyTablePath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("operation", &self.operation)?;
+        state.serialize_field("canSetTag", &self.canSetTag)?;
+        state.serialize_field("nominalRelation", &self.nominalRelation)?;
+        state.serialize_field("rootRelation", &self.rootRelation)?;
+        state.serialize_field("partColsUpdated", &self.partColsUpdated)?;
+        state.serialize_field(
+            "resultRelations",
+            &pg_sys::node_serde::NodePtr(self.resultRelations as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "subpaths",
+            &pg_sys::node_serde::NodePtr(self.subpaths as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "subroots",
+            &pg_sys::node_serde::NodePtr(self.subroots as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "withCheckOptionLists",
+            &pg_sys::node_serde::NodePtr(self.withCheckOptionLists as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "returningLists",
+            &pg_sys::node_serde::NodePtr(self.returningLists as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rowMarks",
+            &pg_sys::node_serde::NodePtr(self.rowMarks as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "onconflict",
+            &pg_sys::node_serde::NodePtr(self.onconflict as *const pg_sys::Node),
+        )?;
+        state.serialize_field("epqParam", &self.epqParam)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ModifyTablePath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105715,6 +112117,43 @@ impl std::fmt::Display for ModifyTablePath {
 }
 impl pg_sys::seal::Sealed for ModifyTableState {}
 impl pg_sys::PgNode for ModifyTableState {}
+impl pg_sys::PgTaggedNode for ModifyTableState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ModifyTableState;
+}
+impl serde::Serialize for ModifyTableState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ModifyTableState", 12usize)?;
+        state.serialize_field("node", "ModifyTableState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field("operation", &self.operation)?;
+        state.serialize_field("canSetTag", &self.canSetTag)?;
+        state.serialize_field("mt_done", &self.mt_done)?;
+        state.serialize_field("mt_nplans", &self.mt_nplans)?;
+        state.serialize_field("mt_whichplan", &self.mt_whichplan)?;
+        state.serialize_field(
+            "resultRelInfo",
+            &pg_sys::node_serde::NodePtr(self.resultRelInfo as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rootResultRelInfo",
+            &pg_sys::node_serde::NodePtr(self.rootResultRelInfo as *const pg_sys::Node),
+        )?;
+        state.serialize_field("fireBSTriggers", &self.fireBSTriggers)?;
+        state.serialize_field(
+            "mt_excludedtlist",
+            &pg_sys::node_serde::NodePtr(self.mt_excludedtlist as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "mt_root_tuple_slot",
+            &pg_sys::node_serde::NodePtr(self.mt_root_tuple_slot as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ModifyTableState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105722,6 +112161,26 @@ impl std::fmt::Display for ModifyTableState {
 }
 impl pg_sys::seal::Sealed for MultiAssignRef {}
 impl pg_sys::PgNode for MultiAssignRef {}
+impl pg_sys::PgTaggedNode for MultiAssignRef {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_MultiAssignRef;
+}
+impl serde::Serialize for MultiAssignRef {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MultiAssignRef", 4usize)?;
+        state.serialize_field("node", "MultiAssignRef")?;
+        state.serialize_field(
+            "source",
+            &pg_sys::node_serde::NodePtr(self.source as *const pg_sys::Node),
+        )?;
+        state.serialize_field("colno", &self.colno)?;
+        state.serialize_field("ncolumns", &self.ncolumns)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for MultiAssignRef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105729,6 +112188,30 @@ impl std::fmt::Display for MultiAssignRef {
 }
 impl pg_sys::seal::Sealed for NamedArgExpr {}
 impl pg_sys::PgNode for NamedArgExpr {}
+impl pg_sys::PgTaggedNode for NamedArgExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_NamedArgExpr;
+}
+impl serde::Serialize for NamedArgExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("NamedArgExpr", 5usize)?;
+        state.serialize_field("node", "NamedArgExpr")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("argnumber", &self.argnumber)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for NamedArgExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105736,6 +112219,25 @@ impl std::fmt::Display for NamedArgExpr {
 }
 impl pg_sys::seal::Sealed for NamedTuplestoreScan {}
 impl pg_sys::PgNode for NamedTuplestoreScan {}
+impl pg_sys::PgTaggedNode for NamedTuplestoreScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_NamedTuplestoreScan;
+}
+impl serde::Serialize for NamedTuplestoreScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("NamedTuplestoreScan", 3usize)?;
+        state.serialize_field("node", "NamedTuplestoreScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field(
+            "enrname",
+            &pg_sys::node_serde::CStringPtr(self.enrname as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for NamedTuplestoreScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105743,6 +112245,22 @@ impl std::fmt::Display for NamedTuplestoreScan {
 }
 impl pg_sys::seal::Sealed for NamedTuplestoreScanState {}
 impl pg_sys::PgNode for NamedTuplestoreScanState {}
+impl pg_sys::PgTaggedNode for NamedTuplestoreScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_NamedTuplestoreScanState;
+}
+impl serde::Serialize for NamedTuplestoreScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("NamedTuplestoreScanState", 3usize)?;
+        state.serialize_field("node", "NamedTuplestoreScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field("readptr", &self.readptr)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for NamedTuplestoreScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105750,6 +112268,25 @@ impl std::fmt::Display for NamedTuplestoreScanState {
 }
 impl pg_sys::seal::Sealed for NestLoop {}
 impl pg_sys::PgNode for NestLoop {}
+impl pg_sys::PgTaggedNode for NestLoop {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_NestLoop;
+}
+impl serde::Serialize for NestLoop {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("NestLoop", 3usize)?;
+        state.serialize_field("node", "NestLoop")?;
+        state.serialize_field("join", &self.join)?;
+        state.serialize_field(
+            "nestParams",
+            &pg_sys::node_serde::NodePtr(self.nestParams as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for NestLoop {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105757,6 +112294,25 @@ impl std::fmt::Display for NestLoop {
 }
 impl pg_sys::seal::Sealed for NestLoopParam {}
 impl pg_sys::PgNode for NestLoopParam {}
+impl pg_sys::PgTaggedNode for NestLoopParam {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_NestLoopParam;
+}
+impl serde::Serialize for NestLoopParam {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("NestLoopParam", 3usize)?;
+        state.serialize_field("node", "NestLoopParam")?;
+        state.serialize_field("paramno", &self.paramno)?;
+        state.serialize_field(
+            "paramval",
+            &pg_sys::node_serde::NodePtr(self.paramval as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for NestLoopParam {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105764,6 +112320,27 @@ impl std::fmt::Display for NestLoopParam {
 }
 impl pg_sys::seal::Sealed for NestLoopState {}
 impl pg_sys::PgNode for NestLoopState {}
+impl pg_sys::PgTaggedNode for NestLoopState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_NestLoopState;
+}
+impl serde::Serialize for NestLoopState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("NestLoopState", 5usize)?;
+        state.serialize_field("node", "NestLoopState")?;
+        state.serialize_field("js", &self.js)?;
+        state.serialize_field("nl_NeedNewOuter", &self.nl_NeedNewOuter)?;
+        state.serialize_field("nl_MatchedOuter", &self.nl_MatchedOuter)?;
+        state.serialize_field(
+            "nl_NullInnerTupleSlot",
+            &pg_sys::node_serde::NodePtr(self.nl_NullInnerTupleSlot as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for NestLoopState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105771,6 +112348,22 @@ impl std::fmt::Display for NestLoopState {
 }
 impl pg_sys::seal::Sealed for NextValueExpr {}
 impl pg_sys::PgNode for NextValueExpr {}
+impl pg_sys::PgTaggedNode for NextValueExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_NextValueExpr;
+}
+impl serde::Serialize for NextValueExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("NextValueExpr", 3usize)?;
+        state.serialize_field("node", "NextValueExpr")?;
+        state.serialize_field("seqid", &self.seqid)?;
+        state.serialize_field("typeId", &self.typeId)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for NextValueExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105778,6 +112371,17 @@ impl std::fmt::Display for NextValueExpr {
 }
 impl pg_sys::seal::Sealed for Node {}
 impl pg_sys::PgNode for Node {}
+impl serde::Serialize for Node {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Node", 1usize)?;
+        state.serialize_field("node", "Node")?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Node {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105785,6 +112389,28 @@ impl std::fmt::Display for Node {
 }
 impl pg_sys::seal::Sealed for NotifyStmt {}
 impl pg_sys::PgNode for NotifyStmt {}
+impl pg_sys::PgTaggedNode for NotifyStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_NotifyStmt;
+}
+impl serde::Serialize for NotifyStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("NotifyStmt", 3usize)?;
+        state.serialize_field("node", "NotifyStmt")?;
+        state.serialize_field(
+            "conditionname",
+            &pg_sys::node_serde::CStringPtr(self.conditionname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "payload",
+            &pg_sys::node_serde::CStringPtr(self.payload as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for NotifyStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105792,6 +112418,27 @@ impl std::fmt::Display for NotifyStmt {
 }
 impl pg_sys::seal::Sealed for NullTest {}
 impl pg_sys::PgNode for NullTest {}
+impl pg_sys::PgTaggedNode for NullTest {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_NullTest;
+}
+impl serde::Serialize for NullTest {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("NullTest", 5usize)?;
+        state.serialize_field("node", "NullTest")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field("nulltesttype", &self.nulltesttype)?;
+        state.serialize_field("argisrow", &self.argisrow)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for NullTest {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105799,6 +112446,29 @@ impl std::fmt::Display for NullTest {
 }
 impl pg_sys::seal::Sealed for ObjectWithArgs {}
 impl pg_sys::PgNode for ObjectWithArgs {}
+impl pg_sys::PgTaggedNode for ObjectWithArgs {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ObjectWithArgs;
+}
+impl serde::Serialize for ObjectWithArgs {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ObjectWithArgs", 4usize)?;
+        state.serialize_field("node", "ObjectWithArgs")?;
+        state.serialize_field(
+            "objname",
+            &pg_sys::node_serde::NodePtr(self.objname as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "objargs",
+            &pg_sys::node_serde::NodePtr(self.objargs as *const pg_sys::Node),
+        )?;
+        state.serialize_field("args_unspecified", &self.args_unspecified)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ObjectWithArgs {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105806,6 +112476,34 @@ impl std::fmt::Display for ObjectWithArgs {
 }
 impl pg_sys::seal::Sealed for OnConflictClause {}
 impl pg_sys::PgNode for OnConflictClause {}
+impl pg_sys::PgTaggedNode for OnConflictClause {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_OnConflictClause;
+}
+impl serde::Serialize for OnConflictClause {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("OnConflictClause", 6usize)?;
+        state.serialize_field("node", "OnConflictClause")?;
+        state.serialize_field("action", &self.action)?;
+        state.serialize_field(
+            "infer",
+            &pg_sys::node_serde::NodePtr(self.infer as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "targetList",
+            &pg_sys::node_serde::NodePtr(self.targetList as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "whereClause",
+            &pg_sys::node_serde::NodePtr(self.whereClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for OnConflictClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105813,6 +112511,43 @@ impl std::fmt::Display for OnConflictClause {
 }
 impl pg_sys::seal::Sealed for OnConflictExpr {}
 impl pg_sys::PgNode for OnConflictExpr {}
+impl pg_sys::PgTaggedNode for OnConflictExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_OnConflictExpr;
+}
+impl serde::Serialize for OnConflictExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("OnConflictExpr", 9usize)?;
+        state.serialize_field("node", "OnConflictExpr")?;
+        state.serialize_field("action", &self.action)?;
+        state.serialize_field(
+            "arbiterElems",
+            &pg_sys::node_serde::NodePtr(self.arbiterElems as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "arbiterWhere",
+            &pg_sys::node_serde::NodePtr(self.arbiterWhere as *const pg_sys::Node),
+        )?;
+        state.serialize_field("constraint", &self.constraint)?;
+        state.serialize_field(
+            "onConflictSet",
+            &pg_sys::node_serde::NodePtr(self.onConflictSet as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "onConflictWhere",
+            &pg_sys::node_serde::NodePtr(self.onConflictWhere as *const pg_sys::Node),
+        )?;
+        state.serialize_field("exclRelIndex", &self.exclRelIndex)?;
+        state.serialize_field(
+            "exclRelTlist",
+            &pg_sys::node_serde::NodePtr(self.exclRelTlist as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for OnConflictExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105820,6 +112555,36 @@ impl std::fmt::Display for OnConflictExpr {
 }
 impl pg_sys::seal::Sealed for OnConflictSetState {}
 impl pg_sys::PgNode for OnConflictSetState {}
+impl pg_sys::PgTaggedNode for OnConflictSetState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_OnConflictSetState;
+}
+impl serde::Serialize for OnConflictSetState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("OnConflictSetState", 5usize)?;
+        state.serialize_field("node", "OnConflictSetState")?;
+        state.serialize_field(
+            "oc_Existing",
+            &pg_sys::node_serde::NodePtr(self.oc_Existing as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "oc_ProjSlot",
+            &pg_sys::node_serde::NodePtr(self.oc_ProjSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "oc_ProjInfo",
+            &pg_sys::node_serde::NodePtr(self.oc_ProjInfo as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "oc_WhereClause",
+            &pg_sys::node_serde::NodePtr(self.oc_WhereClause as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for OnConflictSetState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105827,6 +112592,31 @@ impl std::fmt::Display for OnConflictSetState {
 }
 impl pg_sys::seal::Sealed for OpExpr {}
 impl pg_sys::PgNode for OpExpr {}
+impl pg_sys::PgTaggedNode for OpExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_OpExpr;
+}
+impl serde::Serialize for OpExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("OpExpr", 9usize)?;
+        state.serialize_field("node", "OpExpr")?;
+        state.serialize_field("opno", &self.opno)?;
+        state.serialize_field("opfuncid", &self.opfuncid)?;
+        state.serialize_field("opresulttype", &self.opresulttype)?;
+        state.serialize_field("opretset", &self.opretset)?;
+        state.serialize_field("opcollid", &self.opcollid)?;
+        state.serialize_field("inputcollid", &self.inputcollid)?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for OpExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105834,6 +112624,26 @@ impl std::fmt::Display for OpExpr {
 }
 impl pg_sys::seal::Sealed for Param {}
 impl pg_sys::PgNode for Param {}
+impl pg_sys::PgTaggedNode for Param {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Param;
+}
+impl serde::Serialize for Param {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Param", 7usize)?;
+        state.serialize_field("node", "Param")?;
+        state.serialize_field("paramkind", &self.paramkind)?;
+        state.serialize_field("paramid", &self.paramid)?;
+        state.serialize_field("paramtype", &self.paramtype)?;
+        state.serialize_field("paramtypmod", &self.paramtypmod)?;
+        state.serialize_field("paramcollid", &self.paramcollid)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Param {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105841,6 +112651,25 @@ impl std::fmt::Display for Param {
 }
 impl pg_sys::seal::Sealed for ParamPathInfo {}
 impl pg_sys::PgNode for ParamPathInfo {}
+impl pg_sys::PgTaggedNode for ParamPathInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ParamPathInfo;
+}
+impl serde::Serialize for ParamPathInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ParamPathInfo", 3usize)?;
+        state.serialize_field("node", "ParamPathInfo")?;
+        state.serialize_field("ppi_rows", &self.ppi_rows)?;
+        state.serialize_field(
+            "ppi_clauses",
+            &pg_sys::node_serde::NodePtr(self.ppi_clauses as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ParamPathInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105848,6 +112677,22 @@ impl std::fmt::Display for ParamPathInfo {
 }
 impl pg_sys::seal::Sealed for ParamRef {}
 impl pg_sys::PgNode for ParamRef {}
+impl pg_sys::PgTaggedNode for ParamRef {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ParamRef;
+}
+impl serde::Serialize for ParamRef {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ParamRef", 3usize)?;
+        state.serialize_field("node", "ParamRef")?;
+        state.serialize_field("number", &self.number)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ParamRef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105855,6 +112700,37 @@ impl std::fmt::Display for ParamRef {
 }
 impl pg_sys::seal::Sealed for PartitionBoundSpec {}
 impl pg_sys::PgNode for PartitionBoundSpec {}
+impl pg_sys::PgTaggedNode for PartitionBoundSpec {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PartitionBoundSpec;
+}
+impl serde::Serialize for PartitionBoundSpec {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PartitionBoundSpec", 9usize)?;
+        state.serialize_field("node", "PartitionBoundSpec")?;
+        state.serialize_field("strategy", &self.strategy)?;
+        state.serialize_field("is_default", &self.is_default)?;
+        state.serialize_field("modulus", &self.modulus)?;
+        state.serialize_field("remainder", &self.remainder)?;
+        state.serialize_field(
+            "listdatums",
+            &pg_sys::node_serde::NodePtr(self.listdatums as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "lowerdatums",
+            &pg_sys::node_serde::NodePtr(self.lowerdatums as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "upperdatums",
+            &pg_sys::node_serde::NodePtr(self.upperdatums as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PartitionBoundSpec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105862,6 +112738,28 @@ impl std::fmt::Display for PartitionBoundSpec {
 }
 impl pg_sys::seal::Sealed for PartitionCmd {}
 impl pg_sys::PgNode for PartitionCmd {}
+impl pg_sys::PgTaggedNode for PartitionCmd {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PartitionCmd;
+}
+impl serde::Serialize for PartitionCmd {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PartitionCmd", 3usize)?;
+        state.serialize_field("node", "PartitionCmd")?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::NodePtr(self.name as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "bound",
+            &pg_sys::node_serde::NodePtr(self.bound as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PartitionCmd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105869,6 +112767,37 @@ impl std::fmt::Display for PartitionCmd {
 }
 impl pg_sys::seal::Sealed for PartitionElem {}
 impl pg_sys::PgNode for PartitionElem {}
+impl pg_sys::PgTaggedNode for PartitionElem {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PartitionElem;
+}
+impl serde::Serialize for PartitionElem {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PartitionElem", 6usize)?;
+        state.serialize_field("node", "PartitionElem")?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "expr",
+            &pg_sys::node_serde::NodePtr(self.expr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "collation",
+            &pg_sys::node_serde::NodePtr(self.collation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "opclass",
+            &pg_sys::node_serde::NodePtr(self.opclass as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PartitionElem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105876,6 +112805,24 @@ impl std::fmt::Display for PartitionElem {
 }
 impl pg_sys::seal::Sealed for PartitionPruneInfo {}
 impl pg_sys::PgNode for PartitionPruneInfo {}
+impl pg_sys::PgTaggedNode for PartitionPruneInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PartitionPruneInfo;
+}
+impl serde::Serialize for PartitionPruneInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PartitionPruneInfo", 2usize)?;
+        state.serialize_field("node", "PartitionPruneInfo")?;
+        state.serialize_field(
+            "prune_infos",
+            &pg_sys::node_serde::NodePtr(self.prune_infos as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PartitionPruneInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105883,6 +112830,18 @@ impl std::fmt::Display for PartitionPruneInfo {
 }
 impl pg_sys::seal::Sealed for PartitionPruneStep {}
 impl pg_sys::PgNode for PartitionPruneStep {}
+impl serde::Serialize for PartitionPruneStep {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PartitionPruneStep", 2usize)?;
+        state.serialize_field("node", "PartitionPruneStep")?;
+        state.serialize_field("step_id", &self.step_id)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PartitionPruneStep {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105890,6 +112849,26 @@ impl std::fmt::Display for PartitionPruneStep {
 }
 impl pg_sys::seal::Sealed for PartitionPruneStepCombine {}
 impl pg_sys::PgNode for PartitionPruneStepCombine {}
+impl pg_sys::PgTaggedNode for PartitionPruneStepCombine {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PartitionPruneStepCombine;
+}
+impl serde::Serialize for PartitionPruneStepCombine {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PartitionPruneStepCombine", 4usize)?;
+        state.serialize_field("node", "PartitionPruneStepCombine")?;
+        state.serialize_field("step", &self.step)?;
+        state.serialize_field("combineOp", &self.combineOp)?;
+        state.serialize_field(
+            "source_stepids",
+            &pg_sys::node_serde::NodePtr(self.source_stepids as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PartitionPruneStepCombine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105897,6 +112876,30 @@ impl std::fmt::Display for PartitionPruneStepCombine {
 }
 impl pg_sys::seal::Sealed for PartitionPruneStepOp {}
 impl pg_sys::PgNode for PartitionPruneStepOp {}
+impl pg_sys::PgTaggedNode for PartitionPruneStepOp {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PartitionPruneStepOp;
+}
+impl serde::Serialize for PartitionPruneStepOp {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PartitionPruneStepOp", 5usize)?;
+        state.serialize_field("node", "PartitionPruneStepOp")?;
+        state.serialize_field("step", &self.step)?;
+        state.serialize_field("opstrategy", &self.opstrategy)?;
+        state.serialize_field(
+            "exprs",
+            &pg_sys::node_serde::NodePtr(self.exprs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "cmpfns",
+            &pg_sys::node_serde::NodePtr(self.cmpfns as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PartitionPruneStepOp {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105904,6 +112907,26 @@ impl std::fmt::Display for PartitionPruneStepOp {
 }
 impl pg_sys::seal::Sealed for PartitionRangeDatum {}
 impl pg_sys::PgNode for PartitionRangeDatum {}
+impl pg_sys::PgTaggedNode for PartitionRangeDatum {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PartitionRangeDatum;
+}
+impl serde::Serialize for PartitionRangeDatum {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PartitionRangeDatum", 4usize)?;
+        state.serialize_field("node", "PartitionRangeDatum")?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field(
+            "value",
+            &pg_sys::node_serde::NodePtr(self.value as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PartitionRangeDatum {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105911,6 +112934,29 @@ impl std::fmt::Display for PartitionRangeDatum {
 }
 impl pg_sys::seal::Sealed for PartitionSpec {}
 impl pg_sys::PgNode for PartitionSpec {}
+impl pg_sys::PgTaggedNode for PartitionSpec {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PartitionSpec;
+}
+impl serde::Serialize for PartitionSpec {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PartitionSpec", 4usize)?;
+        state.serialize_field("node", "PartitionSpec")?;
+        state.serialize_field(
+            "strategy",
+            &pg_sys::node_serde::CStringPtr(self.strategy as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "partParams",
+            &pg_sys::node_serde::NodePtr(self.partParams as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PartitionSpec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105918,6 +112964,30 @@ impl std::fmt::Display for PartitionSpec {
 }
 impl pg_sys::seal::Sealed for PartitionedRelPruneInfo {}
 impl pg_sys::PgNode for PartitionedRelPruneInfo {}
+impl pg_sys::PgTaggedNode for PartitionedRelPruneInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PartitionedRelPruneInfo;
+}
+impl serde::Serialize for PartitionedRelPruneInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PartitionedRelPruneInfo", 5usize)?;
+        state.serialize_field("node", "PartitionedRelPruneInfo")?;
+        state.serialize_field("rtindex", &self.rtindex)?;
+        state.serialize_field("nparts", &self.nparts)?;
+        state.serialize_field(
+            "initial_pruning_steps",
+            &pg_sys::node_serde::NodePtr(self.initial_pruning_steps as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "exec_pruning_steps",
+            &pg_sys::node_serde::NodePtr(self.exec_pruning_steps as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PartitionedRelPruneInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105925,6 +112995,42 @@ impl std::fmt::Display for PartitionedRelPruneInfo {
 }
 impl pg_sys::seal::Sealed for Path {}
 impl pg_sys::PgNode for Path {}
+impl pg_sys::PgTaggedNode for Path {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Path;
+}
+impl serde::Serialize for Path {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Path", 11usize)?;
+        state.serialize_field("node", "Path")?;
+        state.serialize_field(
+            "parent",
+            &pg_sys::node_serde::NodePtr(self.parent as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "pathtarget",
+            &pg_sys::node_serde::NodePtr(self.pathtarget as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "param_info",
+            &pg_sys::node_serde::NodePtr(self.param_info as *const pg_sys::Node),
+        )?;
+        state.serialize_field("parallel_aware", &self.parallel_aware)?;
+        state.serialize_field("parallel_safe", &self.parallel_safe)?;
+        state.serialize_field("parallel_workers", &self.parallel_workers)?;
+        state.serialize_field("rows", &self.rows)?;
+        state.serialize_field("startup_cost", &self.startup_cost)?;
+        state.serialize_field("total_cost", &self.total_cost)?;
+        state.serialize_field(
+            "pathkeys",
+            &pg_sys::node_serde::NodePtr(self.pathkeys as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Path {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105932,6 +113038,27 @@ impl std::fmt::Display for Path {
 }
 impl pg_sys::seal::Sealed for PathKey {}
 impl pg_sys::PgNode for PathKey {}
+impl pg_sys::PgTaggedNode for PathKey {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PathKey;
+}
+impl serde::Serialize for PathKey {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PathKey", 5usize)?;
+        state.serialize_field("node", "PathKey")?;
+        state.serialize_field(
+            "pk_eclass",
+            &pg_sys::node_serde::NodePtr(self.pk_eclass as *const pg_sys::Node),
+        )?;
+        state.serialize_field("pk_opfamily", &self.pk_opfamily)?;
+        state.serialize_field("pk_strategy", &self.pk_strategy)?;
+        state.serialize_field("pk_nulls_first", &self.pk_nulls_first)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PathKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105939,6 +113066,25 @@ impl std::fmt::Display for PathKey {
 }
 impl pg_sys::seal::Sealed for PathTarget {}
 impl pg_sys::PgNode for PathTarget {}
+impl pg_sys::PgTaggedNode for PathTarget {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PathTarget;
+}
+impl serde::Serialize for PathTarget {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PathTarget", 3usize)?;
+        state.serialize_field("node", "PathTarget")?;
+        state.serialize_field(
+            "exprs",
+            &pg_sys::node_serde::NodePtr(self.exprs as *const pg_sys::Node),
+        )?;
+        state.serialize_field("width", &self.width)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PathTarget {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105946,6 +113092,26 @@ impl std::fmt::Display for PathTarget {
 }
 impl pg_sys::seal::Sealed for PlaceHolderInfo {}
 impl pg_sys::PgNode for PlaceHolderInfo {}
+impl pg_sys::PgTaggedNode for PlaceHolderInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PlaceHolderInfo;
+}
+impl serde::Serialize for PlaceHolderInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PlaceHolderInfo", 4usize)?;
+        state.serialize_field("node", "PlaceHolderInfo")?;
+        state.serialize_field("phid", &self.phid)?;
+        state.serialize_field(
+            "ph_var",
+            &pg_sys::node_serde::NodePtr(self.ph_var as *const pg_sys::Node),
+        )?;
+        state.serialize_field("ph_width", &self.ph_width)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PlaceHolderInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105953,6 +113119,26 @@ impl std::fmt::Display for PlaceHolderInfo {
 }
 impl pg_sys::seal::Sealed for PlaceHolderVar {}
 impl pg_sys::PgNode for PlaceHolderVar {}
+impl pg_sys::PgTaggedNode for PlaceHolderVar {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PlaceHolderVar;
+}
+impl serde::Serialize for PlaceHolderVar {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PlaceHolderVar", 4usize)?;
+        state.serialize_field("node", "PlaceHolderVar")?;
+        state.serialize_field(
+            "phexpr",
+            &pg_sys::node_serde::NodePtr(self.phexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field("phid", &self.phid)?;
+        state.serialize_field("phlevelsup", &self.phlevelsup)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PlaceHolderVar {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105960,6 +113146,47 @@ impl std::fmt::Display for PlaceHolderVar {
 }
 impl pg_sys::seal::Sealed for Plan {}
 impl pg_sys::PgNode for Plan {}
+impl pg_sys::PgTaggedNode for Plan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Plan;
+}
+impl serde::Serialize for Plan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Plan", 13usize)?;
+        state.serialize_field("node", "Plan")?;
+        state.serialize_field("startup_cost", &self.startup_cost)?;
+        state.serialize_field("total_cost", &self.total_cost)?;
+        state.serialize_field("plan_rows", &self.plan_rows)?;
+        state.serialize_field("plan_width", &self.plan_width)?;
+        state.serialize_field("parallel_aware", &self.parallel_aware)?;
+        state.serialize_field("parallel_safe", &self.parallel_safe)?;
+        state.serialize_field("plan_node_id", &self.plan_node_id)?;
+        state.serialize_field(
+            "targetlist",
+            &pg_sys::node_serde::NodePtr(self.targetlist as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "qual",
+            &pg_sys::node_serde::NodePtr(self.qual as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "lefttree",
+            &pg_sys::node_serde::NodePtr(self.lefttree as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "righttree",
+            &pg_sys::node_serde::NodePtr(self.righttree as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "initPlan",
+            &pg_sys::node_serde::NodePtr(self.initPlan as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Plan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105967,6 +113194,22 @@ impl std::fmt::Display for Plan {
 }
 impl pg_sys::seal::Sealed for PlanInvalItem {}
 impl pg_sys::PgNode for PlanInvalItem {}
+impl pg_sys::PgTaggedNode for PlanInvalItem {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PlanInvalItem;
+}
+impl serde::Serialize for PlanInvalItem {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PlanInvalItem", 3usize)?;
+        state.serialize_field("node", "PlanInvalItem")?;
+        state.serialize_field("cacheId", &self.cacheId)?;
+        state.serialize_field("hashValue", &self.hashValue)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PlanInvalItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105974,6 +113217,28 @@ impl std::fmt::Display for PlanInvalItem {
 }
 impl pg_sys::seal::Sealed for PlanRowMark {}
 impl pg_sys::PgNode for PlanRowMark {}
+impl pg_sys::PgTaggedNode for PlanRowMark {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PlanRowMark;
+}
+impl serde::Serialize for PlanRowMark {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PlanRowMark", 9usize)?;
+        state.serialize_field("node", "PlanRowMark")?;
+        state.serialize_field("rti", &self.rti)?;
+        state.serialize_field("prti", &self.prti)?;
+        state.serialize_field("rowmarkId", &self.rowmarkId)?;
+        state.serialize_field("markType", &self.markType)?;
+        state.serialize_field("allMarkTypes", &self.allMarkTypes)?;
+        state.serialize_field("strength", &self.strength)?;
+        state.serialize_field("waitPolicy", &self.waitPolicy)?;
+        state.serialize_field("isParent", &self.isParent)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PlanRowMark {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105981,6 +113246,68 @@ impl std::fmt::Display for PlanRowMark {
 }
 impl pg_sys::seal::Sealed for PlanState {}
 impl pg_sys::PgNode for PlanState {}
+impl pg_sys::PgTaggedNode for PlanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PlanState;
+}
+impl serde::Serialize for PlanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PlanState", 19usize)?;
+        state.serialize_field("node", "PlanState")?;
+        state.serialize_field(
+            "plan",
+            &pg_sys::node_serde::NodePtr(self.plan as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "state",
+            &pg_sys::node_serde::NodePtr(self.state as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "qual",
+            &pg_sys::node_serde::NodePtr(self.qual as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "lefttree",
+            &pg_sys::node_serde::NodePtr(self.lefttree as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "righttree",
+            &pg_sys::node_serde::NodePtr(self.righttree as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "initPlan",
+            &pg_sys::node_serde::NodePtr(self.initPlan as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "subPlan",
+            &pg_sys::node_serde::NodePtr(self.subPlan as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ps_ResultTupleSlot",
+            &pg_sys::node_serde::NodePtr(self.ps_ResultTupleSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ps_ExprContext",
+            &pg_sys::node_serde::NodePtr(self.ps_ExprContext as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ps_ProjInfo",
+            &pg_sys::node_serde::NodePtr(self.ps_ProjInfo as *const pg_sys::Node),
+        )?;
+        state.serialize_field("scanopsfixed", &self.scanopsfixed)?;
+        state.serialize_field("outeropsfixed", &self.outeropsfixed)?;
+        state.serialize_field("inneropsfixed", &self.inneropsfixed)?;
+        state.serialize_field("resultopsfixed", &self.resultopsfixed)?;
+        state.serialize_field("scanopsset", &self.scanopsset)?;
+        state.serialize_field("outeropsset", &self.outeropsset)?;
+        state.serialize_field("inneropsset", &self.inneropsset)?;
+        state.serialize_field("resultopsset", &self.resultopsset)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PlanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105988,6 +113315,71 @@ impl std::fmt::Display for PlanState {
 }
 impl pg_sys::seal::Sealed for PlannedStmt {}
 impl pg_sys::PgNode for PlannedStmt {}
+impl pg_sys::PgTaggedNode for PlannedStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PlannedStmt;
+}
+impl serde::Serialize for PlannedStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PlannedStmt", 22usize)?;
+        state.serialize_field("node", "PlannedStmt")?;
+        state.serialize_field("commandType", &self.commandType)?;
+        state.serialize_field("queryId", &self.queryId)?;
+        state.serialize_field("hasReturning", &self.hasReturning)?;
+        state.serialize_field("hasModifyingCTE", &self.hasModifyingCTE)?;
+        state.serialize_field("canSetTag", &self.canSetTag)?;
+        state.serialize_field("transientPlan", &self.transientPlan)?;
+        state.serialize_field("dependsOnRole", &self.dependsOnRole)?;
+        state.serialize_field("parallelModeNeeded", &self.parallelModeNeeded)?;
+        state.serialize_field("jitFlags", &self.jitFlags)?;
+        state.serialize_field(
+            "planTree",
+            &pg_sys::node_serde::NodePtr(self.planTree as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rtable",
+            &pg_sys::node_serde::NodePtr(self.rtable as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "resultRelations",
+            &pg_sys::node_serde::NodePtr(self.resultRelations as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rootResultRelations",
+            &pg_sys::node_serde::NodePtr(self.rootResultRelations as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "subplans",
+            &pg_sys::node_serde::NodePtr(self.subplans as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rowMarks",
+            &pg_sys::node_serde::NodePtr(self.rowMarks as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "relationOids",
+            &pg_sys::node_serde::NodePtr(self.relationOids as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "invalItems",
+            &pg_sys::node_serde::NodePtr(self.invalItems as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "paramExecTypes",
+            &pg_sys::node_serde::NodePtr(self.paramExecTypes as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "utilityStmt",
+            &pg_sys::node_serde::NodePtr(self.utilityStmt as *const pg_sys::Node),
+        )?;
+        state.serialize_field("stmt_location", &self.stmt_location)?;
+        state.serialize_field("stmt_len", &self.stmt_len)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PlannedStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -105995,6 +113387,64 @@ impl std::fmt::Display for PlannedStmt {
 }
 impl pg_sys::seal::Sealed for PlannerGlobal {}
 impl pg_sys::PgNode for PlannerGlobal {}
+impl pg_sys::PgTaggedNode for PlannerGlobal {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PlannerGlobal;
+}
+impl serde::Serialize for PlannerGlobal {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PlannerGlobal", 18usize)?;
+        state.serialize_field("node", "PlannerGlobal")?;
+        state.serialize_field(
+            "subplans",
+            &pg_sys::node_serde::NodePtr(self.subplans as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "subroots",
+            &pg_sys::node_serde::NodePtr(self.subroots as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "finalrtable",
+            &pg_sys::node_serde::NodePtr(self.finalrtable as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "finalrowmarks",
+            &pg_sys::node_serde::NodePtr(self.finalrowmarks as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "resultRelations",
+            &pg_sys::node_serde::NodePtr(self.resultRelations as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rootResultRelations",
+            &pg_sys::node_serde::NodePtr(self.rootResultRelations as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "relationOids",
+            &pg_sys::node_serde::NodePtr(self.relationOids as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "invalItems",
+            &pg_sys::node_serde::NodePtr(self.invalItems as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "paramExecTypes",
+            &pg_sys::node_serde::NodePtr(self.paramExecTypes as *const pg_sys::Node),
+        )?;
+        state.serialize_field("lastPHId", &self.lastPHId)?;
+        state.serialize_field("lastRowMarkId", &self.lastRowMarkId)?;
+        state.serialize_field("lastPlanNodeId", &self.lastPlanNodeId)?;
+        state.serialize_field("transientPlan", &self.transientPlan)?;
+        state.serialize_field("dependsOnRole", &self.dependsOnRole)?;
+        state.serialize_field("parallelModeOK", &self.parallelModeOK)?;
+        state.serialize_field("parallelModeNeeded", &self.parallelModeNeeded)?;
+        state.serialize_field("maxParallelHazard", &self.maxParallelHazard)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PlannerGlobal {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106002,6 +113452,155 @@ impl std::fmt::Display for PlannerGlobal {
 }
 impl pg_sys::seal::Sealed for PlannerInfo {}
 impl pg_sys::PgNode for PlannerInfo {}
+impl pg_sys::PgTaggedNode for PlannerInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PlannerInfo;
+}
+impl serde::Serialize for PlannerInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PlannerInfo", 46usize)?;
+        state.serialize_field("node", "PlannerInfo")?;
+        state.serialize_field(
+            "parse",
+            &pg_sys::node_serde::NodePtr(self.parse as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "glob",
+            &pg_sys::node_serde::NodePtr(self.glob as *const pg_sys::Node),
+        )?;
+        state.serialize_field("query_level", &self.query_level)?;
+        state.serialize_field(
+            "parent_root",
+            &pg_sys::node_serde::NodePtr(self.parent_root as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "plan_params",
+            &pg_sys::node_serde::NodePtr(self.plan_params as *const pg_sys::Node),
+        )?;
+        state.serialize_field("simple_rel_array_size", &self.simple_rel_array_size)?;
+        state.serialize_field(
+            "join_rel_list",
+            &pg_sys::node_serde::NodePtr(self.join_rel_list as *const pg_sys::Node),
+        )?;
+        state.serialize_field("join_cur_level", &self.join_cur_level)?;
+        state.serialize_field(
+            "init_plans",
+            &pg_sys::node_serde::NodePtr(self.init_plans as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "cte_plan_ids",
+            &pg_sys::node_serde::NodePtr(self.cte_plan_ids as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "multiexpr_params",
+            &pg_sys::node_serde::NodePtr(self.multiexpr_params as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "eq_classes",
+            &pg_sys::node_serde::NodePtr(self.eq_classes as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "canon_pathkeys",
+            &pg_sys::node_serde::NodePtr(self.canon_pathkeys as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "left_join_clauses",
+            &pg_sys::node_serde::NodePtr(self.left_join_clauses as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "right_join_clauses",
+            &pg_sys::node_serde::NodePtr(self.right_join_clauses as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "full_join_clauses",
+            &pg_sys::node_serde::NodePtr(self.full_join_clauses as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "join_info_list",
+            &pg_sys::node_serde::NodePtr(self.join_info_list as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "append_rel_list",
+            &pg_sys::node_serde::NodePtr(self.append_rel_list as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rowMarks",
+            &pg_sys::node_serde::NodePtr(self.rowMarks as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "placeholder_list",
+            &pg_sys::node_serde::NodePtr(self.placeholder_list as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "fkey_list",
+            &pg_sys::node_serde::NodePtr(self.fkey_list as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "query_pathkeys",
+            &pg_sys::node_serde::NodePtr(self.query_pathkeys as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "group_pathkeys",
+            &pg_sys::node_serde::NodePtr(self.group_pathkeys as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "window_pathkeys",
+            &pg_sys::node_serde::NodePtr(self.window_pathkeys as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "distinct_pathkeys",
+            &pg_sys::node_serde::NodePtr(self.distinct_pathkeys as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "sort_pathkeys",
+            &pg_sys::node_serde::NodePtr(self.sort_pathkeys as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "part_schemes",
+            &pg_sys::node_serde::NodePtr(self.part_schemes as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "initial_rels",
+            &pg_sys::node_serde::NodePtr(self.initial_rels as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "processed_tlist",
+            &pg_sys::node_serde::NodePtr(self.processed_tlist as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "minmax_aggs",
+            &pg_sys::node_serde::NodePtr(self.minmax_aggs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "planner_cxt",
+            &pg_sys::node_serde::NodePtr(self.planner_cxt as *const pg_sys::Node),
+        )?;
+        state.serialize_field("total_table_pages", &self.total_table_pages)?;
+        state.serialize_field("tuple_fraction", &self.tuple_fraction)?;
+        state.serialize_field("limit_tuples", &self.limit_tuples)?;
+        state.serialize_field("qual_security_level", &self.qual_security_level)?;
+        state.serialize_field("inhTargetKind", &self.inhTargetKind)?;
+        state.serialize_field("hasJoinRTEs", &self.hasJoinRTEs)?;
+        state.serialize_field("hasLateralRTEs", &self.hasLateralRTEs)?;
+        state.serialize_field("hasHavingQual", &self.hasHavingQual)?;
+        state.serialize_field("hasPseudoConstantQuals", &self.hasPseudoConstantQuals)?;
+        state.serialize_field("hasRecursion", &self.hasRecursion)?;
+        state.serialize_field("wt_param_id", &self.wt_param_id)?;
+        state.serialize_field(
+            "non_recursive_path",
+            &pg_sys::node_serde::NodePtr(self.non_recursive_path as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "curOuterParams",
+            &pg_sys::node_serde::NodePtr(self.curOuterParams as *const pg_sys::Node),
+        )?;
+        state.serialize_field("partColsUpdated", &self.partColsUpdated)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PlannerInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106009,6 +113608,25 @@ impl std::fmt::Display for PlannerInfo {
 }
 impl pg_sys::seal::Sealed for PlannerParamItem {}
 impl pg_sys::PgNode for PlannerParamItem {}
+impl pg_sys::PgTaggedNode for PlannerParamItem {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PlannerParamItem;
+}
+impl serde::Serialize for PlannerParamItem {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PlannerParamItem", 3usize)?;
+        state.serialize_field("node", "PlannerParamItem")?;
+        state.serialize_field(
+            "item",
+            &pg_sys::node_serde::NodePtr(self.item as *const pg_sys::Node),
+        )?;
+        state.serialize_field("paramId", &self.paramId)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PlannerParamItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106016,6 +113634,32 @@ impl std::fmt::Display for PlannerParamItem {
 }
 impl pg_sys::seal::Sealed for PrepareStmt {}
 impl pg_sys::PgNode for PrepareStmt {}
+impl pg_sys::PgTaggedNode for PrepareStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_PrepareStmt;
+}
+impl serde::Serialize for PrepareStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PrepareStmt", 4usize)?;
+        state.serialize_field("node", "PrepareStmt")?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "argtypes",
+            &pg_sys::node_serde::NodePtr(self.argtypes as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "query",
+            &pg_sys::node_serde::NodePtr(self.query as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for PrepareStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106023,6 +113667,21 @@ impl std::fmt::Display for PrepareStmt {
 }
 impl pg_sys::seal::Sealed for ProjectSet {}
 impl pg_sys::PgNode for ProjectSet {}
+impl pg_sys::PgTaggedNode for ProjectSet {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ProjectSet;
+}
+impl serde::Serialize for ProjectSet {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ProjectSet", 2usize)?;
+        state.serialize_field("node", "ProjectSet")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ProjectSet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106030,6 +113689,25 @@ impl std::fmt::Display for ProjectSet {
 }
 impl pg_sys::seal::Sealed for ProjectSetPath {}
 impl pg_sys::PgNode for ProjectSetPath {}
+impl pg_sys::PgTaggedNode for ProjectSetPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ProjectSetPath;
+}
+impl serde::Serialize for ProjectSetPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ProjectSetPath", 3usize)?;
+        state.serialize_field("node", "ProjectSetPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ProjectSetPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106037,6 +113715,27 @@ impl std::fmt::Display for ProjectSetPath {
 }
 impl pg_sys::seal::Sealed for ProjectSetState {}
 impl pg_sys::PgNode for ProjectSetState {}
+impl pg_sys::PgTaggedNode for ProjectSetState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ProjectSetState;
+}
+impl serde::Serialize for ProjectSetState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ProjectSetState", 5usize)?;
+        state.serialize_field("node", "ProjectSetState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field("nelems", &self.nelems)?;
+        state.serialize_field("pending_srf_tuples", &self.pending_srf_tuples)?;
+        state.serialize_field(
+            "argcontext",
+            &pg_sys::node_serde::NodePtr(self.argcontext as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ProjectSetState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106044,6 +113743,25 @@ impl std::fmt::Display for ProjectSetState {
 }
 impl pg_sys::seal::Sealed for ProjectionInfo {}
 impl pg_sys::PgNode for ProjectionInfo {}
+impl pg_sys::PgTaggedNode for ProjectionInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ProjectionInfo;
+}
+impl serde::Serialize for ProjectionInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ProjectionInfo", 3usize)?;
+        state.serialize_field("node", "ProjectionInfo")?;
+        state.serialize_field("pi_state", &self.pi_state)?;
+        state.serialize_field(
+            "pi_exprContext",
+            &pg_sys::node_serde::NodePtr(self.pi_exprContext as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ProjectionInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106051,6 +113769,26 @@ impl std::fmt::Display for ProjectionInfo {
 }
 impl pg_sys::seal::Sealed for ProjectionPath {}
 impl pg_sys::PgNode for ProjectionPath {}
+impl pg_sys::PgTaggedNode for ProjectionPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ProjectionPath;
+}
+impl serde::Serialize for ProjectionPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ProjectionPath", 4usize)?;
+        state.serialize_field("node", "ProjectionPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field("dummypp", &self.dummypp)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ProjectionPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106058,6 +113796,113 @@ impl std::fmt::Display for ProjectionPath {
 }
 impl pg_sys::seal::Sealed for Query {}
 impl pg_sys::PgNode for Query {}
+impl pg_sys::PgTaggedNode for Query {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Query;
+}
+impl serde::Serialize for Query {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Query", 37usize)?;
+        state.serialize_field("node", "Query")?;
+        state.serialize_field("commandType", &self.commandType)?;
+        state.serialize_field("querySource", &self.querySource)?;
+        state.serialize_field("queryId", &self.queryId)?;
+        state.serialize_field("canSetTag", &self.canSetTag)?;
+        state.serialize_field(
+            "utilityStmt",
+            &pg_sys::node_serde::NodePtr(self.utilityStmt as *const pg_sys::Node),
+        )?;
+        state.serialize_field("resultRelation", &self.resultRelation)?;
+        state.serialize_field("hasAggs", &self.hasAggs)?;
+        state.serialize_field("hasWindowFuncs", &self.hasWindowFuncs)?;
+        state.serialize_field("hasTargetSRFs", &self.hasTargetSRFs)?;
+        state.serialize_field("hasSubLinks", &self.hasSubLinks)?;
+        state.serialize_field("hasDistinctOn", &self.hasDistinctOn)?;
+        state.serialize_field("hasRecursive", &self.hasRecursive)?;
+        state.serialize_field("hasModifyingCTE", &self.hasModifyingCTE)?;
+        state.serialize_field("hasForUpdate", &self.hasForUpdate)?;
+        state.serialize_field("hasRowSecurity", &self.hasRowSecurity)?;
+        state.serialize_field(
+            "cteList",
+            &pg_sys::node_serde::NodePtr(self.cteList as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rtable",
+            &pg_sys::node_serde::NodePtr(self.rtable as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "jointree",
+            &pg_sys::node_serde::NodePtr(self.jointree as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "targetList",
+            &pg_sys::node_serde::NodePtr(self.targetList as *const pg_sys::Node),
+        )?;
+        state.serialize_field("override", &self.override_)?;
+        state.serialize_field(
+            "onConflict",
+            &pg_sys::node_serde::NodePtr(self.onConflict as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "returningList",
+            &pg_sys::node_serde::NodePtr(self.returningList as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "groupClause",
+            &pg_sys::node_serde::NodePtr(self.groupClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "groupingSets",
+            &pg_sys::node_serde::NodePtr(self.groupingSets as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "havingQual",
+            &pg_sys::node_serde::NodePtr(self.havingQual as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "windowClause",
+            &pg_sys::node_serde::NodePtr(self.windowClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "distinctClause",
+            &pg_sys::node_serde::NodePtr(self.distinctClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "sortClause",
+            &pg_sys::node_serde::NodePtr(self.sortClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "limitOffset",
+            &pg_sys::node_serde::NodePtr(self.limitOffset as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "limitCount",
+            &pg_sys::node_serde::NodePtr(self.limitCount as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rowMarks",
+            &pg_sys::node_serde::NodePtr(self.rowMarks as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "setOperations",
+            &pg_sys::node_serde::NodePtr(self.setOperations as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "constraintDeps",
+            &pg_sys::node_serde::NodePtr(self.constraintDeps as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "withCheckOptions",
+            &pg_sys::node_serde::NodePtr(self.withCheckOptions as *const pg_sys::Node),
+        )?;
+        state.serialize_field("stmt_location", &self.stmt_location)?;
+        state.serialize_field("stmt_len", &self.stmt_len)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Query {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106065,6 +113910,35 @@ impl std::fmt::Display for Query {
 }
 impl pg_sys::seal::Sealed for RangeFunction {}
 impl pg_sys::PgNode for RangeFunction {}
+impl pg_sys::PgTaggedNode for RangeFunction {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RangeFunction;
+}
+impl serde::Serialize for RangeFunction {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RangeFunction", 7usize)?;
+        state.serialize_field("node", "RangeFunction")?;
+        state.serialize_field("lateral", &self.lateral)?;
+        state.serialize_field("ordinality", &self.ordinality)?;
+        state.serialize_field("is_rowsfrom", &self.is_rowsfrom)?;
+        state.serialize_field(
+            "functions",
+            &pg_sys::node_serde::NodePtr(self.functions as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "alias",
+            &pg_sys::node_serde::NodePtr(self.alias as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "coldeflist",
+            &pg_sys::node_serde::NodePtr(self.coldeflist as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RangeFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106072,6 +113946,29 @@ impl std::fmt::Display for RangeFunction {
 }
 impl pg_sys::seal::Sealed for RangeSubselect {}
 impl pg_sys::PgNode for RangeSubselect {}
+impl pg_sys::PgTaggedNode for RangeSubselect {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RangeSubselect;
+}
+impl serde::Serialize for RangeSubselect {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RangeSubselect", 4usize)?;
+        state.serialize_field("node", "RangeSubselect")?;
+        state.serialize_field("lateral", &self.lateral)?;
+        state.serialize_field(
+            "subquery",
+            &pg_sys::node_serde::NodePtr(self.subquery as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "alias",
+            &pg_sys::node_serde::NodePtr(self.alias as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RangeSubselect {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106079,6 +113976,42 @@ impl std::fmt::Display for RangeSubselect {
 }
 impl pg_sys::seal::Sealed for RangeTableFunc {}
 impl pg_sys::PgNode for RangeTableFunc {}
+impl pg_sys::PgTaggedNode for RangeTableFunc {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RangeTableFunc;
+}
+impl serde::Serialize for RangeTableFunc {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RangeTableFunc", 8usize)?;
+        state.serialize_field("node", "RangeTableFunc")?;
+        state.serialize_field("lateral", &self.lateral)?;
+        state.serialize_field(
+            "docexpr",
+            &pg_sys::node_serde::NodePtr(self.docexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rowexpr",
+            &pg_sys::node_serde::NodePtr(self.rowexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "namespaces",
+            &pg_sys::node_serde::NodePtr(self.namespaces as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "columns",
+            &pg_sys::node_serde::NodePtr(self.columns as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "alias",
+            &pg_sys::node_serde::NodePtr(self.alias as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RangeTableFunc {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106086,6 +114019,39 @@ impl std::fmt::Display for RangeTableFunc {
 }
 impl pg_sys::seal::Sealed for RangeTableFuncCol {}
 impl pg_sys::PgNode for RangeTableFuncCol {}
+impl pg_sys::PgTaggedNode for RangeTableFuncCol {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RangeTableFuncCol;
+}
+impl serde::Serialize for RangeTableFuncCol {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RangeTableFuncCol", 8usize)?;
+        state.serialize_field("node", "RangeTableFuncCol")?;
+        state.serialize_field(
+            "colname",
+            &pg_sys::node_serde::CStringPtr(self.colname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "typeName",
+            &pg_sys::node_serde::NodePtr(self.typeName as *const pg_sys::Node),
+        )?;
+        state.serialize_field("for_ordinality", &self.for_ordinality)?;
+        state.serialize_field("is_not_null", &self.is_not_null)?;
+        state.serialize_field(
+            "colexpr",
+            &pg_sys::node_serde::NodePtr(self.colexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "coldefexpr",
+            &pg_sys::node_serde::NodePtr(self.coldefexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RangeTableFuncCol {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106093,6 +114059,37 @@ impl std::fmt::Display for RangeTableFuncCol {
 }
 impl pg_sys::seal::Sealed for RangeTableSample {}
 impl pg_sys::PgNode for RangeTableSample {}
+impl pg_sys::PgTaggedNode for RangeTableSample {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RangeTableSample;
+}
+impl serde::Serialize for RangeTableSample {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RangeTableSample", 6usize)?;
+        state.serialize_field("node", "RangeTableSample")?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "method",
+            &pg_sys::node_serde::NodePtr(self.method as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "repeatable",
+            &pg_sys::node_serde::NodePtr(self.repeatable as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RangeTableSample {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106100,6 +114097,91 @@ impl std::fmt::Display for RangeTableSample {
 }
 impl pg_sys::seal::Sealed for RangeTblEntry {}
 impl pg_sys::PgNode for RangeTblEntry {}
+impl pg_sys::PgTaggedNode for RangeTblEntry {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RangeTblEntry;
+}
+impl serde::Serialize for RangeTblEntry {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RangeTblEntry", 30usize)?;
+        state.serialize_field("node", "RangeTblEntry")?;
+        state.serialize_field("rtekind", &self.rtekind)?;
+        state.serialize_field("relid", &self.relid)?;
+        state.serialize_field("relkind", &self.relkind)?;
+        state.serialize_field("rellockmode", &self.rellockmode)?;
+        state.serialize_field(
+            "tablesample",
+            &pg_sys::node_serde::NodePtr(self.tablesample as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "subquery",
+            &pg_sys::node_serde::NodePtr(self.subquery as *const pg_sys::Node),
+        )?;
+        state.serialize_field("security_barrier", &self.security_barrier)?;
+        state.serialize_field("jointype", &self.jointype)?;
+        state.serialize_field(
+            "joinaliasvars",
+            &pg_sys::node_serde::NodePtr(self.joinaliasvars as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "functions",
+            &pg_sys::node_serde::NodePtr(self.functions as *const pg_sys::Node),
+        )?;
+        state.serialize_field("funcordinality", &self.funcordinality)?;
+        state.serialize_field(
+            "tablefunc",
+            &pg_sys::node_serde::NodePtr(self.tablefunc as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "values_lists",
+            &pg_sys::node_serde::NodePtr(self.values_lists as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ctename",
+            &pg_sys::node_serde::CStringPtr(self.ctename as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("ctelevelsup", &self.ctelevelsup)?;
+        state.serialize_field("self_reference", &self.self_reference)?;
+        state.serialize_field(
+            "coltypes",
+            &pg_sys::node_serde::NodePtr(self.coltypes as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "coltypmods",
+            &pg_sys::node_serde::NodePtr(self.coltypmods as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "colcollations",
+            &pg_sys::node_serde::NodePtr(self.colcollations as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "enrname",
+            &pg_sys::node_serde::CStringPtr(self.enrname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("enrtuples", &self.enrtuples)?;
+        state.serialize_field(
+            "alias",
+            &pg_sys::node_serde::NodePtr(self.alias as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "eref",
+            &pg_sys::node_serde::NodePtr(self.eref as *const pg_sys::Node),
+        )?;
+        state.serialize_field("lateral", &self.lateral)?;
+        state.serialize_field("inh", &self.inh)?;
+        state.serialize_field("inFromCl", &self.inFromCl)?;
+        state.serialize_field("requiredPerms", &self.requiredPerms)?;
+        state.serialize_field("checkAsUser", &self.checkAsUser)?;
+        state.serialize_field(
+            "securityQuals",
+            &pg_sys::node_serde::NodePtr(self.securityQuals as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RangeTblEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106107,6 +114189,41 @@ impl std::fmt::Display for RangeTblEntry {
 }
 impl pg_sys::seal::Sealed for RangeTblFunction {}
 impl pg_sys::PgNode for RangeTblFunction {}
+impl pg_sys::PgTaggedNode for RangeTblFunction {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RangeTblFunction;
+}
+impl serde::Serialize for RangeTblFunction {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RangeTblFunction", 7usize)?;
+        state.serialize_field("node", "RangeTblFunction")?;
+        state.serialize_field(
+            "funcexpr",
+            &pg_sys::node_serde::NodePtr(self.funcexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field("funccolcount", &self.funccolcount)?;
+        state.serialize_field(
+            "funccolnames",
+            &pg_sys::node_serde::NodePtr(self.funccolnames as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "funccoltypes",
+            &pg_sys::node_serde::NodePtr(self.funccoltypes as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "funccoltypmods",
+            &pg_sys::node_serde::NodePtr(self.funccoltypmods as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "funccolcollations",
+            &pg_sys::node_serde::NodePtr(self.funccolcollations as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RangeTblFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106114,6 +114231,21 @@ impl std::fmt::Display for RangeTblFunction {
 }
 impl pg_sys::seal::Sealed for RangeTblRef {}
 impl pg_sys::PgNode for RangeTblRef {}
+impl pg_sys::PgTaggedNode for RangeTblRef {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RangeTblRef;
+}
+impl serde::Serialize for RangeTblRef {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RangeTblRef", 2usize)?;
+        state.serialize_field("node", "RangeTblRef")?;
+        state.serialize_field("rtindex", &self.rtindex)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RangeTblRef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106121,6 +114253,39 @@ impl std::fmt::Display for RangeTblRef {
 }
 impl pg_sys::seal::Sealed for RangeVar {}
 impl pg_sys::PgNode for RangeVar {}
+impl pg_sys::PgTaggedNode for RangeVar {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RangeVar;
+}
+impl serde::Serialize for RangeVar {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RangeVar", 8usize)?;
+        state.serialize_field("node", "RangeVar")?;
+        state.serialize_field(
+            "catalogname",
+            &pg_sys::node_serde::CStringPtr(self.catalogname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "schemaname",
+            &pg_sys::node_serde::CStringPtr(self.schemaname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "relname",
+            &pg_sys::node_serde::CStringPtr(self.relname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("inh", &self.inh)?;
+        state.serialize_field("relpersistence", &self.relpersistence)?;
+        state.serialize_field(
+            "alias",
+            &pg_sys::node_serde::NodePtr(self.alias as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RangeVar {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106128,6 +114293,26 @@ impl std::fmt::Display for RangeVar {
 }
 impl pg_sys::seal::Sealed for RawStmt {}
 impl pg_sys::PgNode for RawStmt {}
+impl pg_sys::PgTaggedNode for RawStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RawStmt;
+}
+impl serde::Serialize for RawStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RawStmt", 4usize)?;
+        state.serialize_field("node", "RawStmt")?;
+        state.serialize_field(
+            "stmt",
+            &pg_sys::node_serde::NodePtr(self.stmt as *const pg_sys::Node),
+        )?;
+        state.serialize_field("stmt_location", &self.stmt_location)?;
+        state.serialize_field("stmt_len", &self.stmt_len)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RawStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106135,6 +114320,28 @@ impl std::fmt::Display for RawStmt {
 }
 impl pg_sys::seal::Sealed for ReassignOwnedStmt {}
 impl pg_sys::PgNode for ReassignOwnedStmt {}
+impl pg_sys::PgTaggedNode for ReassignOwnedStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ReassignOwnedStmt;
+}
+impl serde::Serialize for ReassignOwnedStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ReassignOwnedStmt", 3usize)?;
+        state.serialize_field("node", "ReassignOwnedStmt")?;
+        state.serialize_field(
+            "roles",
+            &pg_sys::node_serde::NodePtr(self.roles as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "newrole",
+            &pg_sys::node_serde::NodePtr(self.newrole as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ReassignOwnedStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106142,6 +114349,24 @@ impl std::fmt::Display for ReassignOwnedStmt {
 }
 impl pg_sys::seal::Sealed for RecursiveUnion {}
 impl pg_sys::PgNode for RecursiveUnion {}
+impl pg_sys::PgTaggedNode for RecursiveUnion {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RecursiveUnion;
+}
+impl serde::Serialize for RecursiveUnion {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RecursiveUnion", 5usize)?;
+        state.serialize_field("node", "RecursiveUnion")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field("wtParam", &self.wtParam)?;
+        state.serialize_field("numCols", &self.numCols)?;
+        state.serialize_field("numGroups", &self.numGroups)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RecursiveUnion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106149,6 +114374,35 @@ impl std::fmt::Display for RecursiveUnion {
 }
 impl pg_sys::seal::Sealed for RecursiveUnionPath {}
 impl pg_sys::PgNode for RecursiveUnionPath {}
+impl pg_sys::PgTaggedNode for RecursiveUnionPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RecursiveUnionPath;
+}
+impl serde::Serialize for RecursiveUnionPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RecursiveUnionPath", 7usize)?;
+        state.serialize_field("node", "RecursiveUnionPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "leftpath",
+            &pg_sys::node_serde::NodePtr(self.leftpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rightpath",
+            &pg_sys::node_serde::NodePtr(self.rightpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "distinctList",
+            &pg_sys::node_serde::NodePtr(self.distinctList as *const pg_sys::Node),
+        )?;
+        state.serialize_field("wtParam", &self.wtParam)?;
+        state.serialize_field("numGroups", &self.numGroups)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RecursiveUnionPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106156,6 +114410,31 @@ impl std::fmt::Display for RecursiveUnionPath {
 }
 impl pg_sys::seal::Sealed for RecursiveUnionState {}
 impl pg_sys::PgNode for RecursiveUnionState {}
+impl pg_sys::PgTaggedNode for RecursiveUnionState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RecursiveUnionState;
+}
+impl serde::Serialize for RecursiveUnionState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RecursiveUnionState", 6usize)?;
+        state.serialize_field("node", "RecursiveUnionState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field("recursing", &self.recursing)?;
+        state.serialize_field("intermediate_empty", &self.intermediate_empty)?;
+        state.serialize_field(
+            "tempContext",
+            &pg_sys::node_serde::NodePtr(self.tempContext as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "tableContext",
+            &pg_sys::node_serde::NodePtr(self.tableContext as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RecursiveUnionState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106163,6 +114442,26 @@ impl std::fmt::Display for RecursiveUnionState {
 }
 impl pg_sys::seal::Sealed for RefreshMatViewStmt {}
 impl pg_sys::PgNode for RefreshMatViewStmt {}
+impl pg_sys::PgTaggedNode for RefreshMatViewStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RefreshMatViewStmt;
+}
+impl serde::Serialize for RefreshMatViewStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RefreshMatViewStmt", 4usize)?;
+        state.serialize_field("node", "RefreshMatViewStmt")?;
+        state.serialize_field("concurrent", &self.concurrent)?;
+        state.serialize_field("skipData", &self.skipData)?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RefreshMatViewStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106170,6 +114469,31 @@ impl std::fmt::Display for RefreshMatViewStmt {
 }
 impl pg_sys::seal::Sealed for ReindexStmt {}
 impl pg_sys::PgNode for ReindexStmt {}
+impl pg_sys::PgTaggedNode for ReindexStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ReindexStmt;
+}
+impl serde::Serialize for ReindexStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ReindexStmt", 6usize)?;
+        state.serialize_field("node", "ReindexStmt")?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("options", &self.options)?;
+        state.serialize_field("concurrent", &self.concurrent)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ReindexStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106177,6 +114501,121 @@ impl std::fmt::Display for ReindexStmt {
 }
 impl pg_sys::seal::Sealed for RelOptInfo {}
 impl pg_sys::PgNode for RelOptInfo {}
+impl pg_sys::PgTaggedNode for RelOptInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RelOptInfo;
+}
+impl serde::Serialize for RelOptInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RelOptInfo", 42usize)?;
+        state.serialize_field("node", "RelOptInfo")?;
+        state.serialize_field("reloptkind", &self.reloptkind)?;
+        state.serialize_field("rows", &self.rows)?;
+        state.serialize_field("consider_startup", &self.consider_startup)?;
+        state.serialize_field("consider_param_startup", &self.consider_param_startup)?;
+        state.serialize_field("consider_parallel", &self.consider_parallel)?;
+        state.serialize_field(
+            "reltarget",
+            &pg_sys::node_serde::NodePtr(self.reltarget as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "pathlist",
+            &pg_sys::node_serde::NodePtr(self.pathlist as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ppilist",
+            &pg_sys::node_serde::NodePtr(self.ppilist as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "partial_pathlist",
+            &pg_sys::node_serde::NodePtr(self.partial_pathlist as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "cheapest_startup_path",
+            &pg_sys::node_serde::NodePtr(self.cheapest_startup_path as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "cheapest_total_path",
+            &pg_sys::node_serde::NodePtr(self.cheapest_total_path as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "cheapest_unique_path",
+            &pg_sys::node_serde::NodePtr(self.cheapest_unique_path as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "cheapest_parameterized_paths",
+            &pg_sys::node_serde::NodePtr(self.cheapest_parameterized_paths as *const pg_sys::Node),
+        )?;
+        state.serialize_field("relid", &self.relid)?;
+        state.serialize_field("reltablespace", &self.reltablespace)?;
+        state.serialize_field("rtekind", &self.rtekind)?;
+        state.serialize_field("min_attr", &self.min_attr)?;
+        state.serialize_field("max_attr", &self.max_attr)?;
+        state.serialize_field(
+            "lateral_vars",
+            &pg_sys::node_serde::NodePtr(self.lateral_vars as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "indexlist",
+            &pg_sys::node_serde::NodePtr(self.indexlist as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "statlist",
+            &pg_sys::node_serde::NodePtr(self.statlist as *const pg_sys::Node),
+        )?;
+        state.serialize_field("pages", &self.pages)?;
+        state.serialize_field("tuples", &self.tuples)?;
+        state.serialize_field("allvisfrac", &self.allvisfrac)?;
+        state.serialize_field(
+            "subroot",
+            &pg_sys::node_serde::NodePtr(self.subroot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "subplan_params",
+            &pg_sys::node_serde::NodePtr(self.subplan_params as *const pg_sys::Node),
+        )?;
+        state.serialize_field("rel_parallel_workers", &self.rel_parallel_workers)?;
+        state.serialize_field("serverid", &self.serverid)?;
+        state.serialize_field("userid", &self.userid)?;
+        state.serialize_field("useridiscurrent", &self.useridiscurrent)?;
+        state.serialize_field(
+            "fdwroutine",
+            &pg_sys::node_serde::NodePtr(self.fdwroutine as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "unique_for_rels",
+            &pg_sys::node_serde::NodePtr(self.unique_for_rels as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "non_unique_for_rels",
+            &pg_sys::node_serde::NodePtr(self.non_unique_for_rels as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "baserestrictinfo",
+            &pg_sys::node_serde::NodePtr(self.baserestrictinfo as *const pg_sys::Node),
+        )?;
+        state.serialize_field("baserestrict_min_security", &self.baserestrict_min_security)?;
+        state.serialize_field(
+            "joininfo",
+            &pg_sys::node_serde::NodePtr(self.joininfo as *const pg_sys::Node),
+        )?;
+        state.serialize_field("has_eclass_joins", &self.has_eclass_joins)?;
+        state.serialize_field("consider_partitionwise_join", &self.consider_partitionwise_join)?;
+        state.serialize_field("nparts", &self.nparts)?;
+        state.serialize_field(
+            "partition_qual",
+            &pg_sys::node_serde::NodePtr(self.partition_qual as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "partitioned_child_rels",
+            &pg_sys::node_serde::NodePtr(self.partitioned_child_rels as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RelOptInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106184,6 +114623,29 @@ impl std::fmt::Display for RelOptInfo {
 }
 impl pg_sys::seal::Sealed for RelabelType {}
 impl pg_sys::PgNode for RelabelType {}
+impl pg_sys::PgTaggedNode for RelabelType {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RelabelType;
+}
+impl serde::Serialize for RelabelType {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RelabelType", 7usize)?;
+        state.serialize_field("node", "RelabelType")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field("resulttype", &self.resulttype)?;
+        state.serialize_field("resulttypmod", &self.resulttypmod)?;
+        state.serialize_field("resultcollid", &self.resultcollid)?;
+        state.serialize_field("relabelformat", &self.relabelformat)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RelabelType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106191,6 +114653,40 @@ impl std::fmt::Display for RelabelType {
 }
 impl pg_sys::seal::Sealed for RenameStmt {}
 impl pg_sys::PgNode for RenameStmt {}
+impl pg_sys::PgTaggedNode for RenameStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RenameStmt;
+}
+impl serde::Serialize for RenameStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RenameStmt", 9usize)?;
+        state.serialize_field("node", "RenameStmt")?;
+        state.serialize_field("renameType", &self.renameType)?;
+        state.serialize_field("relationType", &self.relationType)?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "object",
+            &pg_sys::node_serde::NodePtr(self.object as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "subname",
+            &pg_sys::node_serde::CStringPtr(self.subname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "newname",
+            &pg_sys::node_serde::CStringPtr(self.newname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("behavior", &self.behavior)?;
+        state.serialize_field("missing_ok", &self.missing_ok)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RenameStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106198,6 +114694,25 @@ impl std::fmt::Display for RenameStmt {
 }
 impl pg_sys::seal::Sealed for ReplicaIdentityStmt {}
 impl pg_sys::PgNode for ReplicaIdentityStmt {}
+impl pg_sys::PgTaggedNode for ReplicaIdentityStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ReplicaIdentityStmt;
+}
+impl serde::Serialize for ReplicaIdentityStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ReplicaIdentityStmt", 3usize)?;
+        state.serialize_field("node", "ReplicaIdentityStmt")?;
+        state.serialize_field("identity_type", &self.identity_type)?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ReplicaIdentityStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106205,6 +114720,33 @@ impl std::fmt::Display for ReplicaIdentityStmt {
 }
 impl pg_sys::seal::Sealed for ResTarget {}
 impl pg_sys::PgNode for ResTarget {}
+impl pg_sys::PgTaggedNode for ResTarget {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ResTarget;
+}
+impl serde::Serialize for ResTarget {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ResTarget", 5usize)?;
+        state.serialize_field("node", "ResTarget")?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "indirection",
+            &pg_sys::node_serde::NodePtr(self.indirection as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "val",
+            &pg_sys::node_serde::NodePtr(self.val as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ResTarget {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106212,6 +114754,70 @@ impl std::fmt::Display for ResTarget {
 }
 impl pg_sys::seal::Sealed for RestrictInfo {}
 impl pg_sys::PgNode for RestrictInfo {}
+impl pg_sys::PgTaggedNode for RestrictInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RestrictInfo;
+}
+impl serde::Serialize for RestrictInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RestrictInfo", 24usize)?;
+        state.serialize_field("node", "RestrictInfo")?;
+        state.serialize_field(
+            "clause",
+            &pg_sys::node_serde::NodePtr(self.clause as *const pg_sys::Node),
+        )?;
+        state.serialize_field("is_pushed_down", &self.is_pushed_down)?;
+        state.serialize_field("outerjoin_delayed", &self.outerjoin_delayed)?;
+        state.serialize_field("can_join", &self.can_join)?;
+        state.serialize_field("pseudoconstant", &self.pseudoconstant)?;
+        state.serialize_field("leakproof", &self.leakproof)?;
+        state.serialize_field("security_level", &self.security_level)?;
+        state.serialize_field(
+            "orclause",
+            &pg_sys::node_serde::NodePtr(self.orclause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "parent_ec",
+            &pg_sys::node_serde::NodePtr(self.parent_ec as *const pg_sys::Node),
+        )?;
+        state.serialize_field("norm_selec", &self.norm_selec)?;
+        state.serialize_field("outer_selec", &self.outer_selec)?;
+        state.serialize_field(
+            "mergeopfamilies",
+            &pg_sys::node_serde::NodePtr(self.mergeopfamilies as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "left_ec",
+            &pg_sys::node_serde::NodePtr(self.left_ec as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "right_ec",
+            &pg_sys::node_serde::NodePtr(self.right_ec as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "left_em",
+            &pg_sys::node_serde::NodePtr(self.left_em as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "right_em",
+            &pg_sys::node_serde::NodePtr(self.right_em as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "scansel_cache",
+            &pg_sys::node_serde::NodePtr(self.scansel_cache as *const pg_sys::Node),
+        )?;
+        state.serialize_field("outer_is_left", &self.outer_is_left)?;
+        state.serialize_field("hashjoinoperator", &self.hashjoinoperator)?;
+        state.serialize_field("left_bucketsize", &self.left_bucketsize)?;
+        state.serialize_field("right_bucketsize", &self.right_bucketsize)?;
+        state.serialize_field("left_mcvfreq", &self.left_mcvfreq)?;
+        state.serialize_field("right_mcvfreq", &self.right_mcvfreq)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RestrictInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106219,6 +114825,25 @@ impl std::fmt::Display for RestrictInfo {
 }
 impl pg_sys::seal::Sealed for Result {}
 impl pg_sys::PgNode for Result {}
+impl pg_sys::PgTaggedNode for Result {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Result;
+}
+impl serde::Serialize for Result {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Result", 3usize)?;
+        state.serialize_field("node", "Result")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field(
+            "resconstantqual",
+            &pg_sys::node_serde::NodePtr(self.resconstantqual as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Result {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106226,6 +114851,79 @@ impl std::fmt::Display for Result {
 }
 impl pg_sys::seal::Sealed for ResultRelInfo {}
 impl pg_sys::PgNode for ResultRelInfo {}
+impl pg_sys::PgTaggedNode for ResultRelInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ResultRelInfo;
+}
+impl serde::Serialize for ResultRelInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ResultRelInfo", 18usize)?;
+        state.serialize_field("node", "ResultRelInfo")?;
+        state.serialize_field("ri_RangeTableIndex", &self.ri_RangeTableIndex)?;
+        state.serialize_field("ri_NumIndices", &self.ri_NumIndices)?;
+        state.serialize_field(
+            "ri_ReturningSlot",
+            &pg_sys::node_serde::NodePtr(self.ri_ReturningSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ri_TrigOldSlot",
+            &pg_sys::node_serde::NodePtr(self.ri_TrigOldSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ri_TrigNewSlot",
+            &pg_sys::node_serde::NodePtr(self.ri_TrigNewSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ri_FdwRoutine",
+            &pg_sys::node_serde::NodePtr(self.ri_FdwRoutine as *const pg_sys::Node),
+        )?;
+        state.serialize_field("ri_usesFdwDirectModify", &self.ri_usesFdwDirectModify)?;
+        state.serialize_field(
+            "ri_WithCheckOptions",
+            &pg_sys::node_serde::NodePtr(self.ri_WithCheckOptions as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ri_WithCheckOptionExprs",
+            &pg_sys::node_serde::NodePtr(self.ri_WithCheckOptionExprs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ri_junkFilter",
+            &pg_sys::node_serde::NodePtr(self.ri_junkFilter as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ri_returningList",
+            &pg_sys::node_serde::NodePtr(self.ri_returningList as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ri_projectReturning",
+            &pg_sys::node_serde::NodePtr(self.ri_projectReturning as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ri_onConflictArbiterIndexes",
+            &pg_sys::node_serde::NodePtr(self.ri_onConflictArbiterIndexes as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ri_onConflict",
+            &pg_sys::node_serde::NodePtr(self.ri_onConflict as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ri_PartitionCheck",
+            &pg_sys::node_serde::NodePtr(self.ri_PartitionCheck as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ri_PartitionCheckExpr",
+            &pg_sys::node_serde::NodePtr(self.ri_PartitionCheckExpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ri_RootResultRelInfo",
+            &pg_sys::node_serde::NodePtr(self.ri_RootResultRelInfo as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ResultRelInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106233,6 +114931,27 @@ impl std::fmt::Display for ResultRelInfo {
 }
 impl pg_sys::seal::Sealed for ResultState {}
 impl pg_sys::PgNode for ResultState {}
+impl pg_sys::PgTaggedNode for ResultState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ResultState;
+}
+impl serde::Serialize for ResultState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ResultState", 5usize)?;
+        state.serialize_field("node", "ResultState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field(
+            "resconstantqual",
+            &pg_sys::node_serde::NodePtr(self.resconstantqual as *const pg_sys::Node),
+        )?;
+        state.serialize_field("rs_done", &self.rs_done)?;
+        state.serialize_field("rs_checkqual", &self.rs_checkqual)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ResultState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106240,6 +114959,27 @@ impl std::fmt::Display for ResultState {
 }
 impl pg_sys::seal::Sealed for ReturnSetInfo {}
 impl pg_sys::PgNode for ReturnSetInfo {}
+impl pg_sys::PgTaggedNode for ReturnSetInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ReturnSetInfo;
+}
+impl serde::Serialize for ReturnSetInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ReturnSetInfo", 5usize)?;
+        state.serialize_field("node", "ReturnSetInfo")?;
+        state.serialize_field(
+            "econtext",
+            &pg_sys::node_serde::NodePtr(self.econtext as *const pg_sys::Node),
+        )?;
+        state.serialize_field("allowedModes", &self.allowedModes)?;
+        state.serialize_field("returnMode", &self.returnMode)?;
+        state.serialize_field("isDone", &self.isDone)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ReturnSetInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106247,6 +114987,26 @@ impl std::fmt::Display for ReturnSetInfo {
 }
 impl pg_sys::seal::Sealed for RoleSpec {}
 impl pg_sys::PgNode for RoleSpec {}
+impl pg_sys::PgTaggedNode for RoleSpec {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RoleSpec;
+}
+impl serde::Serialize for RoleSpec {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RoleSpec", 4usize)?;
+        state.serialize_field("node", "RoleSpec")?;
+        state.serialize_field("roletype", &self.roletype)?;
+        state.serialize_field(
+            "rolename",
+            &pg_sys::node_serde::CStringPtr(self.rolename as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RoleSpec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106254,6 +115014,35 @@ impl std::fmt::Display for RoleSpec {
 }
 impl pg_sys::seal::Sealed for RollupData {}
 impl pg_sys::PgNode for RollupData {}
+impl pg_sys::PgTaggedNode for RollupData {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RollupData;
+}
+impl serde::Serialize for RollupData {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RollupData", 7usize)?;
+        state.serialize_field("node", "RollupData")?;
+        state.serialize_field(
+            "groupClause",
+            &pg_sys::node_serde::NodePtr(self.groupClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "gsets",
+            &pg_sys::node_serde::NodePtr(self.gsets as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "gsets_data",
+            &pg_sys::node_serde::NodePtr(self.gsets_data as *const pg_sys::Node),
+        )?;
+        state.serialize_field("numGroups", &self.numGroups)?;
+        state.serialize_field("hashable", &self.hashable)?;
+        state.serialize_field("is_hashed", &self.is_hashed)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RollupData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106261,6 +115050,41 @@ impl std::fmt::Display for RollupData {
 }
 impl pg_sys::seal::Sealed for RowCompareExpr {}
 impl pg_sys::PgNode for RowCompareExpr {}
+impl pg_sys::PgTaggedNode for RowCompareExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RowCompareExpr;
+}
+impl serde::Serialize for RowCompareExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RowCompareExpr", 7usize)?;
+        state.serialize_field("node", "RowCompareExpr")?;
+        state.serialize_field("rctype", &self.rctype)?;
+        state.serialize_field(
+            "opnos",
+            &pg_sys::node_serde::NodePtr(self.opnos as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "opfamilies",
+            &pg_sys::node_serde::NodePtr(self.opfamilies as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "inputcollids",
+            &pg_sys::node_serde::NodePtr(self.inputcollids as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "largs",
+            &pg_sys::node_serde::NodePtr(self.largs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rargs",
+            &pg_sys::node_serde::NodePtr(self.rargs as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RowCompareExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106268,6 +115092,31 @@ impl std::fmt::Display for RowCompareExpr {
 }
 impl pg_sys::seal::Sealed for RowExpr {}
 impl pg_sys::PgNode for RowExpr {}
+impl pg_sys::PgTaggedNode for RowExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RowExpr;
+}
+impl serde::Serialize for RowExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RowExpr", 6usize)?;
+        state.serialize_field("node", "RowExpr")?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field("row_typeid", &self.row_typeid)?;
+        state.serialize_field("row_format", &self.row_format)?;
+        state.serialize_field(
+            "colnames",
+            &pg_sys::node_serde::NodePtr(self.colnames as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RowExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106275,6 +115124,24 @@ impl std::fmt::Display for RowExpr {
 }
 impl pg_sys::seal::Sealed for RowMarkClause {}
 impl pg_sys::PgNode for RowMarkClause {}
+impl pg_sys::PgTaggedNode for RowMarkClause {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RowMarkClause;
+}
+impl serde::Serialize for RowMarkClause {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RowMarkClause", 5usize)?;
+        state.serialize_field("node", "RowMarkClause")?;
+        state.serialize_field("rti", &self.rti)?;
+        state.serialize_field("strength", &self.strength)?;
+        state.serialize_field("waitPolicy", &self.waitPolicy)?;
+        state.serialize_field("pushedDown", &self.pushedDown)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RowMarkClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106282,6 +115149,39 @@ impl std::fmt::Display for RowMarkClause {
 }
 impl pg_sys::seal::Sealed for RuleStmt {}
 impl pg_sys::PgNode for RuleStmt {}
+impl pg_sys::PgTaggedNode for RuleStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_RuleStmt;
+}
+impl serde::Serialize for RuleStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RuleStmt", 8usize)?;
+        state.serialize_field("node", "RuleStmt")?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rulename",
+            &pg_sys::node_serde::CStringPtr(self.rulename as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "whereClause",
+            &pg_sys::node_serde::NodePtr(self.whereClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field("event", &self.event)?;
+        state.serialize_field("instead", &self.instead)?;
+        state.serialize_field(
+            "actions",
+            &pg_sys::node_serde::NodePtr(self.actions as *const pg_sys::Node),
+        )?;
+        state.serialize_field("replace", &self.replace)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for RuleStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106289,6 +115189,20 @@ impl std::fmt::Display for RuleStmt {
 }
 impl pg_sys::seal::Sealed for SQLCmd {}
 impl pg_sys::PgNode for SQLCmd {}
+impl pg_sys::PgTaggedNode for SQLCmd {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SQLCmd;
+}
+impl serde::Serialize for SQLCmd {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SQLCmd", 1usize)?;
+        state.serialize_field("node", "SQLCmd")?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SQLCmd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106296,6 +115210,24 @@ impl std::fmt::Display for SQLCmd {
 }
 impl pg_sys::seal::Sealed for SQLValueFunction {}
 impl pg_sys::PgNode for SQLValueFunction {}
+impl pg_sys::PgTaggedNode for SQLValueFunction {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SQLValueFunction;
+}
+impl serde::Serialize for SQLValueFunction {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SQLValueFunction", 5usize)?;
+        state.serialize_field("node", "SQLValueFunction")?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field("type", &self.type_)?;
+        state.serialize_field("typmod", &self.typmod)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SQLValueFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106303,6 +115235,25 @@ impl std::fmt::Display for SQLValueFunction {
 }
 impl pg_sys::seal::Sealed for SampleScan {}
 impl pg_sys::PgNode for SampleScan {}
+impl pg_sys::PgTaggedNode for SampleScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SampleScan;
+}
+impl serde::Serialize for SampleScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SampleScan", 3usize)?;
+        state.serialize_field("node", "SampleScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field(
+            "tablesample",
+            &pg_sys::node_serde::NodePtr(self.tablesample as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SampleScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106310,6 +115261,36 @@ impl std::fmt::Display for SampleScan {
 }
 impl pg_sys::seal::Sealed for SampleScanState {}
 impl pg_sys::PgNode for SampleScanState {}
+impl pg_sys::PgTaggedNode for SampleScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SampleScanState;
+}
+impl serde::Serialize for SampleScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SampleScanState", 11usize)?;
+        state.serialize_field("node", "SampleScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "repeatable",
+            &pg_sys::node_serde::NodePtr(self.repeatable as *const pg_sys::Node),
+        )?;
+        state.serialize_field("use_bulkread", &self.use_bulkread)?;
+        state.serialize_field("use_pagemode", &self.use_pagemode)?;
+        state.serialize_field("begun", &self.begun)?;
+        state.serialize_field("seed", &self.seed)?;
+        state.serialize_field("donetuples", &self.donetuples)?;
+        state.serialize_field("haveblock", &self.haveblock)?;
+        state.serialize_field("done", &self.done)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SampleScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106317,6 +115298,29 @@ impl std::fmt::Display for SampleScanState {
 }
 impl pg_sys::seal::Sealed for ScalarArrayOpExpr {}
 impl pg_sys::PgNode for ScalarArrayOpExpr {}
+impl pg_sys::PgTaggedNode for ScalarArrayOpExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ScalarArrayOpExpr;
+}
+impl serde::Serialize for ScalarArrayOpExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ScalarArrayOpExpr", 7usize)?;
+        state.serialize_field("node", "ScalarArrayOpExpr")?;
+        state.serialize_field("opno", &self.opno)?;
+        state.serialize_field("opfuncid", &self.opfuncid)?;
+        state.serialize_field("useOr", &self.useOr)?;
+        state.serialize_field("inputcollid", &self.inputcollid)?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ScalarArrayOpExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106324,6 +115328,22 @@ impl std::fmt::Display for ScalarArrayOpExpr {
 }
 impl pg_sys::seal::Sealed for Scan {}
 impl pg_sys::PgNode for Scan {}
+impl pg_sys::PgTaggedNode for Scan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Scan;
+}
+impl serde::Serialize for Scan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Scan", 3usize)?;
+        state.serialize_field("node", "Scan")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field("scanrelid", &self.scanrelid)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Scan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106331,6 +115351,25 @@ impl std::fmt::Display for Scan {
 }
 impl pg_sys::seal::Sealed for ScanState {}
 impl pg_sys::PgNode for ScanState {}
+impl pg_sys::PgTaggedNode for ScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ScanState;
+}
+impl serde::Serialize for ScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ScanState", 3usize)?;
+        state.serialize_field("node", "ScanState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field(
+            "ss_ScanTupleSlot",
+            &pg_sys::node_serde::NodePtr(self.ss_ScanTupleSlot as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106338,6 +115377,33 @@ impl std::fmt::Display for ScanState {
 }
 impl pg_sys::seal::Sealed for SecLabelStmt {}
 impl pg_sys::PgNode for SecLabelStmt {}
+impl pg_sys::PgTaggedNode for SecLabelStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SecLabelStmt;
+}
+impl serde::Serialize for SecLabelStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SecLabelStmt", 5usize)?;
+        state.serialize_field("node", "SecLabelStmt")?;
+        state.serialize_field("objtype", &self.objtype)?;
+        state.serialize_field(
+            "object",
+            &pg_sys::node_serde::NodePtr(self.object as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "provider",
+            &pg_sys::node_serde::CStringPtr(self.provider as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "label",
+            &pg_sys::node_serde::CStringPtr(self.label as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SecLabelStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106345,6 +115411,86 @@ impl std::fmt::Display for SecLabelStmt {
 }
 impl pg_sys::seal::Sealed for SelectStmt {}
 impl pg_sys::PgNode for SelectStmt {}
+impl pg_sys::PgTaggedNode for SelectStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SelectStmt;
+}
+impl serde::Serialize for SelectStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SelectStmt", 19usize)?;
+        state.serialize_field("node", "SelectStmt")?;
+        state.serialize_field(
+            "distinctClause",
+            &pg_sys::node_serde::NodePtr(self.distinctClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "intoClause",
+            &pg_sys::node_serde::NodePtr(self.intoClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "targetList",
+            &pg_sys::node_serde::NodePtr(self.targetList as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "fromClause",
+            &pg_sys::node_serde::NodePtr(self.fromClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "whereClause",
+            &pg_sys::node_serde::NodePtr(self.whereClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "groupClause",
+            &pg_sys::node_serde::NodePtr(self.groupClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "havingClause",
+            &pg_sys::node_serde::NodePtr(self.havingClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "windowClause",
+            &pg_sys::node_serde::NodePtr(self.windowClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "valuesLists",
+            &pg_sys::node_serde::NodePtr(self.valuesLists as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "sortClause",
+            &pg_sys::node_serde::NodePtr(self.sortClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "limitOffset",
+            &pg_sys::node_serde::NodePtr(self.limitOffset as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "limitCount",
+            &pg_sys::node_serde::NodePtr(self.limitCount as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "lockingClause",
+            &pg_sys::node_serde::NodePtr(self.lockingClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "withClause",
+            &pg_sys::node_serde::NodePtr(self.withClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field("all", &self.all)?;
+        state.serialize_field(
+            "larg",
+            &pg_sys::node_serde::NodePtr(self.larg as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rarg",
+            &pg_sys::node_serde::NodePtr(self.rarg as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SelectStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106352,6 +115498,21 @@ impl std::fmt::Display for SelectStmt {
 }
 impl pg_sys::seal::Sealed for SeqScanState {}
 impl pg_sys::PgNode for SeqScanState {}
+impl pg_sys::PgTaggedNode for SeqScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SeqScanState;
+}
+impl serde::Serialize for SeqScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SeqScanState", 2usize)?;
+        state.serialize_field("node", "SeqScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SeqScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106359,6 +115520,40 @@ impl std::fmt::Display for SeqScanState {
 }
 impl pg_sys::seal::Sealed for SetExprState {}
 impl pg_sys::PgNode for SetExprState {}
+impl pg_sys::PgTaggedNode for SetExprState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SetExprState;
+}
+impl serde::Serialize for SetExprState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SetExprState", 9usize)?;
+        state.serialize_field("node", "SetExprState")?;
+        state.serialize_field(
+            "expr",
+            &pg_sys::node_serde::NodePtr(self.expr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "elidedFuncState",
+            &pg_sys::node_serde::NodePtr(self.elidedFuncState as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "funcResultSlot",
+            &pg_sys::node_serde::NodePtr(self.funcResultSlot as *const pg_sys::Node),
+        )?;
+        state.serialize_field("funcReturnsTuple", &self.funcReturnsTuple)?;
+        state.serialize_field("funcReturnsSet", &self.funcReturnsSet)?;
+        state.serialize_field("setArgsValid", &self.setArgsValid)?;
+        state.serialize_field("shutdown_reg", &self.shutdown_reg)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SetExprState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106366,6 +115561,27 @@ impl std::fmt::Display for SetExprState {
 }
 impl pg_sys::seal::Sealed for SetOp {}
 impl pg_sys::PgNode for SetOp {}
+impl pg_sys::PgTaggedNode for SetOp {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SetOp;
+}
+impl serde::Serialize for SetOp {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SetOp", 8usize)?;
+        state.serialize_field("node", "SetOp")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field("cmd", &self.cmd)?;
+        state.serialize_field("strategy", &self.strategy)?;
+        state.serialize_field("numCols", &self.numCols)?;
+        state.serialize_field("flagColIdx", &self.flagColIdx)?;
+        state.serialize_field("firstFlag", &self.firstFlag)?;
+        state.serialize_field("numGroups", &self.numGroups)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SetOp {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106373,6 +115589,34 @@ impl std::fmt::Display for SetOp {
 }
 impl pg_sys::seal::Sealed for SetOpPath {}
 impl pg_sys::PgNode for SetOpPath {}
+impl pg_sys::PgTaggedNode for SetOpPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SetOpPath;
+}
+impl serde::Serialize for SetOpPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SetOpPath", 9usize)?;
+        state.serialize_field("node", "SetOpPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field("cmd", &self.cmd)?;
+        state.serialize_field("strategy", &self.strategy)?;
+        state.serialize_field(
+            "distinctList",
+            &pg_sys::node_serde::NodePtr(self.distinctList as *const pg_sys::Node),
+        )?;
+        state.serialize_field("flagColIdx", &self.flagColIdx)?;
+        state.serialize_field("firstFlag", &self.firstFlag)?;
+        state.serialize_field("numGroups", &self.numGroups)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SetOpPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106380,6 +115624,32 @@ impl std::fmt::Display for SetOpPath {
 }
 impl pg_sys::seal::Sealed for SetOpState {}
 impl pg_sys::PgNode for SetOpState {}
+impl pg_sys::PgTaggedNode for SetOpState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SetOpState;
+}
+impl serde::Serialize for SetOpState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SetOpState", 7usize)?;
+        state.serialize_field("node", "SetOpState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field(
+            "eqfunction",
+            &pg_sys::node_serde::NodePtr(self.eqfunction as *const pg_sys::Node),
+        )?;
+        state.serialize_field("setop_done", &self.setop_done)?;
+        state.serialize_field("numOutput", &self.numOutput)?;
+        state.serialize_field(
+            "tableContext",
+            &pg_sys::node_serde::NodePtr(self.tableContext as *const pg_sys::Node),
+        )?;
+        state.serialize_field("table_filled", &self.table_filled)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SetOpState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106387,6 +115657,46 @@ impl std::fmt::Display for SetOpState {
 }
 impl pg_sys::seal::Sealed for SetOperationStmt {}
 impl pg_sys::PgNode for SetOperationStmt {}
+impl pg_sys::PgTaggedNode for SetOperationStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SetOperationStmt;
+}
+impl serde::Serialize for SetOperationStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SetOperationStmt", 9usize)?;
+        state.serialize_field("node", "SetOperationStmt")?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field("all", &self.all)?;
+        state.serialize_field(
+            "larg",
+            &pg_sys::node_serde::NodePtr(self.larg as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rarg",
+            &pg_sys::node_serde::NodePtr(self.rarg as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "colTypes",
+            &pg_sys::node_serde::NodePtr(self.colTypes as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "colTypmods",
+            &pg_sys::node_serde::NodePtr(self.colTypmods as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "colCollations",
+            &pg_sys::node_serde::NodePtr(self.colCollations as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "groupClauses",
+            &pg_sys::node_serde::NodePtr(self.groupClauses as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SetOperationStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106394,6 +115704,24 @@ impl std::fmt::Display for SetOperationStmt {
 }
 impl pg_sys::seal::Sealed for SetToDefault {}
 impl pg_sys::PgNode for SetToDefault {}
+impl pg_sys::PgTaggedNode for SetToDefault {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SetToDefault;
+}
+impl serde::Serialize for SetToDefault {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SetToDefault", 5usize)?;
+        state.serialize_field("node", "SetToDefault")?;
+        state.serialize_field("typeId", &self.typeId)?;
+        state.serialize_field("typeMod", &self.typeMod)?;
+        state.serialize_field("collation", &self.collation)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SetToDefault {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106401,6 +115729,22 @@ impl std::fmt::Display for SetToDefault {
 }
 impl pg_sys::seal::Sealed for Sort {}
 impl pg_sys::PgNode for Sort {}
+impl pg_sys::PgTaggedNode for Sort {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Sort;
+}
+impl serde::Serialize for Sort {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Sort", 3usize)?;
+        state.serialize_field("node", "Sort")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field("numCols", &self.numCols)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Sort {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106408,6 +115752,31 @@ impl std::fmt::Display for Sort {
 }
 impl pg_sys::seal::Sealed for SortBy {}
 impl pg_sys::PgNode for SortBy {}
+impl pg_sys::PgTaggedNode for SortBy {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SortBy;
+}
+impl serde::Serialize for SortBy {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SortBy", 6usize)?;
+        state.serialize_field("node", "SortBy")?;
+        state.serialize_field(
+            "node",
+            &pg_sys::node_serde::NodePtr(self.node as *const pg_sys::Node),
+        )?;
+        state.serialize_field("sortby_dir", &self.sortby_dir)?;
+        state.serialize_field("sortby_nulls", &self.sortby_nulls)?;
+        state.serialize_field(
+            "useOp",
+            &pg_sys::node_serde::NodePtr(self.useOp as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SortBy {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106415,6 +115784,25 @@ impl std::fmt::Display for SortBy {
 }
 impl pg_sys::seal::Sealed for SortGroupClause {}
 impl pg_sys::PgNode for SortGroupClause {}
+impl pg_sys::PgTaggedNode for SortGroupClause {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SortGroupClause;
+}
+impl serde::Serialize for SortGroupClause {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SortGroupClause", 6usize)?;
+        state.serialize_field("node", "SortGroupClause")?;
+        state.serialize_field("tleSortGroupRef", &self.tleSortGroupRef)?;
+        state.serialize_field("eqop", &self.eqop)?;
+        state.serialize_field("sortop", &self.sortop)?;
+        state.serialize_field("nulls_first", &self.nulls_first)?;
+        state.serialize_field("hashable", &self.hashable)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SortGroupClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106422,6 +115810,25 @@ impl std::fmt::Display for SortGroupClause {
 }
 impl pg_sys::seal::Sealed for SortPath {}
 impl pg_sys::PgNode for SortPath {}
+impl pg_sys::PgTaggedNode for SortPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SortPath;
+}
+impl serde::Serialize for SortPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SortPath", 3usize)?;
+        state.serialize_field("node", "SortPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SortPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106429,6 +115836,28 @@ impl std::fmt::Display for SortPath {
 }
 impl pg_sys::seal::Sealed for SortState {}
 impl pg_sys::PgNode for SortState {}
+impl pg_sys::PgTaggedNode for SortState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SortState;
+}
+impl serde::Serialize for SortState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SortState", 9usize)?;
+        state.serialize_field("node", "SortState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field("randomAccess", &self.randomAccess)?;
+        state.serialize_field("bounded", &self.bounded)?;
+        state.serialize_field("bound", &self.bound)?;
+        state.serialize_field("sort_Done", &self.sort_Done)?;
+        state.serialize_field("bounded_Done", &self.bounded_Done)?;
+        state.serialize_field("bound_Done", &self.bound_Done)?;
+        state.serialize_field("am_worker", &self.am_worker)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SortState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106436,6 +115865,33 @@ impl std::fmt::Display for SortState {
 }
 impl pg_sys::seal::Sealed for SpecialJoinInfo {}
 impl pg_sys::PgNode for SpecialJoinInfo {}
+impl pg_sys::PgTaggedNode for SpecialJoinInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SpecialJoinInfo;
+}
+impl serde::Serialize for SpecialJoinInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SpecialJoinInfo", 8usize)?;
+        state.serialize_field("node", "SpecialJoinInfo")?;
+        state.serialize_field("jointype", &self.jointype)?;
+        state.serialize_field("lhs_strict", &self.lhs_strict)?;
+        state.serialize_field("delay_upper_joins", &self.delay_upper_joins)?;
+        state.serialize_field("semi_can_btree", &self.semi_can_btree)?;
+        state.serialize_field("semi_can_hash", &self.semi_can_hash)?;
+        state.serialize_field(
+            "semi_operators",
+            &pg_sys::node_serde::NodePtr(self.semi_operators as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "semi_rhs_exprs",
+            &pg_sys::node_serde::NodePtr(self.semi_rhs_exprs as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SpecialJoinInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106443,6 +115899,31 @@ impl std::fmt::Display for SpecialJoinInfo {
 }
 impl pg_sys::seal::Sealed for StartReplicationCmd {}
 impl pg_sys::PgNode for StartReplicationCmd {}
+impl pg_sys::PgTaggedNode for StartReplicationCmd {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_StartReplicationCmd;
+}
+impl serde::Serialize for StartReplicationCmd {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("StartReplicationCmd", 6usize)?;
+        state.serialize_field("node", "StartReplicationCmd")?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field(
+            "slotname",
+            &pg_sys::node_serde::CStringPtr(self.slotname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("timeline", &self.timeline)?;
+        state.serialize_field("startpoint", &self.startpoint)?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for StartReplicationCmd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106450,6 +115931,26 @@ impl std::fmt::Display for StartReplicationCmd {
 }
 impl pg_sys::seal::Sealed for StatisticExtInfo {}
 impl pg_sys::PgNode for StatisticExtInfo {}
+impl pg_sys::PgTaggedNode for StatisticExtInfo {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_StatisticExtInfo;
+}
+impl serde::Serialize for StatisticExtInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("StatisticExtInfo", 4usize)?;
+        state.serialize_field("node", "StatisticExtInfo")?;
+        state.serialize_field("statOid", &self.statOid)?;
+        state.serialize_field(
+            "rel",
+            &pg_sys::node_serde::NodePtr(self.rel as *const pg_sys::Node),
+        )?;
+        state.serialize_field("kind", &self.kind)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for StatisticExtInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106457,6 +115958,35 @@ impl std::fmt::Display for StatisticExtInfo {
 }
 impl pg_sys::seal::Sealed for SubLink {}
 impl pg_sys::PgNode for SubLink {}
+impl pg_sys::PgTaggedNode for SubLink {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SubLink;
+}
+impl serde::Serialize for SubLink {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SubLink", 7usize)?;
+        state.serialize_field("node", "SubLink")?;
+        state.serialize_field("subLinkType", &self.subLinkType)?;
+        state.serialize_field("subLinkId", &self.subLinkId)?;
+        state.serialize_field(
+            "testexpr",
+            &pg_sys::node_serde::NodePtr(self.testexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "operName",
+            &pg_sys::node_serde::NodePtr(self.operName as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "subselect",
+            &pg_sys::node_serde::NodePtr(self.subselect as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SubLink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106464,6 +115994,54 @@ impl std::fmt::Display for SubLink {
 }
 impl pg_sys::seal::Sealed for SubPlan {}
 impl pg_sys::PgNode for SubPlan {}
+impl pg_sys::PgTaggedNode for SubPlan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SubPlan;
+}
+impl serde::Serialize for SubPlan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SubPlan", 17usize)?;
+        state.serialize_field("node", "SubPlan")?;
+        state.serialize_field("subLinkType", &self.subLinkType)?;
+        state.serialize_field(
+            "testexpr",
+            &pg_sys::node_serde::NodePtr(self.testexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "paramIds",
+            &pg_sys::node_serde::NodePtr(self.paramIds as *const pg_sys::Node),
+        )?;
+        state.serialize_field("plan_id", &self.plan_id)?;
+        state.serialize_field(
+            "plan_name",
+            &pg_sys::node_serde::CStringPtr(self.plan_name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("firstColType", &self.firstColType)?;
+        state.serialize_field("firstColTypmod", &self.firstColTypmod)?;
+        state.serialize_field("firstColCollation", &self.firstColCollation)?;
+        state.serialize_field("useHashTable", &self.useHashTable)?;
+        state.serialize_field("unknownEqFalse", &self.unknownEqFalse)?;
+        state.serialize_field("parallel_safe", &self.parallel_safe)?;
+        state.serialize_field(
+            "setParam",
+            &pg_sys::node_serde::NodePtr(self.setParam as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "parParam",
+            &pg_sys::node_serde::NodePtr(self.parParam as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field("startup_cost", &self.startup_cost)?;
+        state.serialize_field("per_call_cost", &self.per_call_cost)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SubPlan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106471,6 +116049,68 @@ impl std::fmt::Display for SubPlan {
 }
 impl pg_sys::seal::Sealed for SubPlanState {}
 impl pg_sys::PgNode for SubPlanState {}
+impl pg_sys::PgTaggedNode for SubPlanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SubPlanState;
+}
+impl serde::Serialize for SubPlanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SubPlanState", 16usize)?;
+        state.serialize_field("node", "SubPlanState")?;
+        state.serialize_field(
+            "subplan",
+            &pg_sys::node_serde::NodePtr(self.subplan as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "planstate",
+            &pg_sys::node_serde::NodePtr(self.planstate as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "parent",
+            &pg_sys::node_serde::NodePtr(self.parent as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "testexpr",
+            &pg_sys::node_serde::NodePtr(self.testexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field("curArray", &self.curArray.value())?;
+        state.serialize_field(
+            "projLeft",
+            &pg_sys::node_serde::NodePtr(self.projLeft as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "projRight",
+            &pg_sys::node_serde::NodePtr(self.projRight as *const pg_sys::Node),
+        )?;
+        state.serialize_field("havehashrows", &self.havehashrows)?;
+        state.serialize_field("havenullrows", &self.havenullrows)?;
+        state.serialize_field(
+            "hashtablecxt",
+            &pg_sys::node_serde::NodePtr(self.hashtablecxt as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "hashtempcxt",
+            &pg_sys::node_serde::NodePtr(self.hashtempcxt as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "innerecontext",
+            &pg_sys::node_serde::NodePtr(self.innerecontext as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "cur_eq_comp",
+            &pg_sys::node_serde::NodePtr(self.cur_eq_comp as *const pg_sys::Node),
+        )?;
+        state.serialize_field("numCols", &self.numCols)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SubPlanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106478,6 +116118,25 @@ impl std::fmt::Display for SubPlanState {
 }
 impl pg_sys::seal::Sealed for SubqueryScan {}
 impl pg_sys::PgNode for SubqueryScan {}
+impl pg_sys::PgTaggedNode for SubqueryScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SubqueryScan;
+}
+impl serde::Serialize for SubqueryScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SubqueryScan", 3usize)?;
+        state.serialize_field("node", "SubqueryScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field(
+            "subplan",
+            &pg_sys::node_serde::NodePtr(self.subplan as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SubqueryScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106485,6 +116144,25 @@ impl std::fmt::Display for SubqueryScan {
 }
 impl pg_sys::seal::Sealed for SubqueryScanPath {}
 impl pg_sys::PgNode for SubqueryScanPath {}
+impl pg_sys::PgTaggedNode for SubqueryScanPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SubqueryScanPath;
+}
+impl serde::Serialize for SubqueryScanPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SubqueryScanPath", 3usize)?;
+        state.serialize_field("node", "SubqueryScanPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SubqueryScanPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106492,6 +116170,25 @@ impl std::fmt::Display for SubqueryScanPath {
 }
 impl pg_sys::seal::Sealed for SubqueryScanState {}
 impl pg_sys::PgNode for SubqueryScanState {}
+impl pg_sys::PgTaggedNode for SubqueryScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SubqueryScanState;
+}
+impl serde::Serialize for SubqueryScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SubqueryScanState", 3usize)?;
+        state.serialize_field("node", "SubqueryScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field(
+            "subplan",
+            &pg_sys::node_serde::NodePtr(self.subplan as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SubqueryScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106499,6 +116196,40 @@ impl std::fmt::Display for SubqueryScanState {
 }
 impl pg_sys::seal::Sealed for SubscriptingRef {}
 impl pg_sys::PgNode for SubscriptingRef {}
+impl pg_sys::PgTaggedNode for SubscriptingRef {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SubscriptingRef;
+}
+impl serde::Serialize for SubscriptingRef {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SubscriptingRef", 9usize)?;
+        state.serialize_field("node", "SubscriptingRef")?;
+        state.serialize_field("refcontainertype", &self.refcontainertype)?;
+        state.serialize_field("refelemtype", &self.refelemtype)?;
+        state.serialize_field("reftypmod", &self.reftypmod)?;
+        state.serialize_field("refcollid", &self.refcollid)?;
+        state.serialize_field(
+            "refupperindexpr",
+            &pg_sys::node_serde::NodePtr(self.refupperindexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "reflowerindexpr",
+            &pg_sys::node_serde::NodePtr(self.reflowerindexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "refexpr",
+            &pg_sys::node_serde::NodePtr(self.refexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "refassgnexpr",
+            &pg_sys::node_serde::NodePtr(self.refassgnexpr as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SubscriptingRef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106506,6 +116237,31 @@ impl std::fmt::Display for SubscriptingRef {
 }
 impl pg_sys::seal::Sealed for SupportRequestCost {}
 impl pg_sys::PgNode for SupportRequestCost {}
+impl pg_sys::PgTaggedNode for SupportRequestCost {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SupportRequestCost;
+}
+impl serde::Serialize for SupportRequestCost {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SupportRequestCost", 6usize)?;
+        state.serialize_field("node", "SupportRequestCost")?;
+        state.serialize_field(
+            "root",
+            &pg_sys::node_serde::NodePtr(self.root as *const pg_sys::Node),
+        )?;
+        state.serialize_field("funcid", &self.funcid)?;
+        state.serialize_field(
+            "node",
+            &pg_sys::node_serde::NodePtr(self.node as *const pg_sys::Node),
+        )?;
+        state.serialize_field("startup", &self.startup)?;
+        state.serialize_field("per_tuple", &self.per_tuple)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SupportRequestCost {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106513,6 +116269,38 @@ impl std::fmt::Display for SupportRequestCost {
 }
 impl pg_sys::seal::Sealed for SupportRequestIndexCondition {}
 impl pg_sys::PgNode for SupportRequestIndexCondition {}
+impl pg_sys::PgTaggedNode for SupportRequestIndexCondition {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SupportRequestIndexCondition;
+}
+impl serde::Serialize for SupportRequestIndexCondition {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SupportRequestIndexCondition", 10usize)?;
+        state.serialize_field("node", "SupportRequestIndexCondition")?;
+        state.serialize_field(
+            "root",
+            &pg_sys::node_serde::NodePtr(self.root as *const pg_sys::Node),
+        )?;
+        state.serialize_field("funcid", &self.funcid)?;
+        state.serialize_field(
+            "node",
+            &pg_sys::node_serde::NodePtr(self.node as *const pg_sys::Node),
+        )?;
+        state.serialize_field("indexarg", &self.indexarg)?;
+        state.serialize_field(
+            "index",
+            &pg_sys::node_serde::NodePtr(self.index as *const pg_sys::Node),
+        )?;
+        state.serialize_field("indexcol", &self.indexcol)?;
+        state.serialize_field("opfamily", &self.opfamily)?;
+        state.serialize_field("indexcollation", &self.indexcollation)?;
+        state.serialize_field("lossy", &self.lossy)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SupportRequestIndexCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106520,6 +116308,30 @@ impl std::fmt::Display for SupportRequestIndexCondition {
 }
 impl pg_sys::seal::Sealed for SupportRequestRows {}
 impl pg_sys::PgNode for SupportRequestRows {}
+impl pg_sys::PgTaggedNode for SupportRequestRows {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SupportRequestRows;
+}
+impl serde::Serialize for SupportRequestRows {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SupportRequestRows", 5usize)?;
+        state.serialize_field("node", "SupportRequestRows")?;
+        state.serialize_field(
+            "root",
+            &pg_sys::node_serde::NodePtr(self.root as *const pg_sys::Node),
+        )?;
+        state.serialize_field("funcid", &self.funcid)?;
+        state.serialize_field(
+            "node",
+            &pg_sys::node_serde::NodePtr(self.node as *const pg_sys::Node),
+        )?;
+        state.serialize_field("rows", &self.rows)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SupportRequestRows {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106527,6 +116339,38 @@ impl std::fmt::Display for SupportRequestRows {
 }
 impl pg_sys::seal::Sealed for SupportRequestSelectivity {}
 impl pg_sys::PgNode for SupportRequestSelectivity {}
+impl pg_sys::PgTaggedNode for SupportRequestSelectivity {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SupportRequestSelectivity;
+}
+impl serde::Serialize for SupportRequestSelectivity {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SupportRequestSelectivity", 10usize)?;
+        state.serialize_field("node", "SupportRequestSelectivity")?;
+        state.serialize_field(
+            "root",
+            &pg_sys::node_serde::NodePtr(self.root as *const pg_sys::Node),
+        )?;
+        state.serialize_field("funcid", &self.funcid)?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field("inputcollid", &self.inputcollid)?;
+        state.serialize_field("is_join", &self.is_join)?;
+        state.serialize_field("varRelid", &self.varRelid)?;
+        state.serialize_field("jointype", &self.jointype)?;
+        state.serialize_field(
+            "sjinfo",
+            &pg_sys::node_serde::NodePtr(self.sjinfo as *const pg_sys::Node),
+        )?;
+        state.serialize_field("selectivity", &self.selectivity)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SupportRequestSelectivity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106534,6 +116378,28 @@ impl std::fmt::Display for SupportRequestSelectivity {
 }
 impl pg_sys::seal::Sealed for SupportRequestSimplify {}
 impl pg_sys::PgNode for SupportRequestSimplify {}
+impl pg_sys::PgTaggedNode for SupportRequestSimplify {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_SupportRequestSimplify;
+}
+impl serde::Serialize for SupportRequestSimplify {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SupportRequestSimplify", 3usize)?;
+        state.serialize_field("node", "SupportRequestSimplify")?;
+        state.serialize_field(
+            "root",
+            &pg_sys::node_serde::NodePtr(self.root as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "fcall",
+            &pg_sys::node_serde::NodePtr(self.fcall as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for SupportRequestSimplify {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106541,6 +116407,20 @@ impl std::fmt::Display for SupportRequestSimplify {
 }
 impl pg_sys::seal::Sealed for TableAmRoutine {}
 impl pg_sys::PgNode for TableAmRoutine {}
+impl pg_sys::PgTaggedNode for TableAmRoutine {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TableAmRoutine;
+}
+impl serde::Serialize for TableAmRoutine {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TableAmRoutine", 1usize)?;
+        state.serialize_field("node", "TableAmRoutine")?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TableAmRoutine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106548,6 +116428,62 @@ impl std::fmt::Display for TableAmRoutine {
 }
 impl pg_sys::seal::Sealed for TableFunc {}
 impl pg_sys::PgNode for TableFunc {}
+impl pg_sys::PgTaggedNode for TableFunc {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TableFunc;
+}
+impl serde::Serialize for TableFunc {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TableFunc", 13usize)?;
+        state.serialize_field("node", "TableFunc")?;
+        state.serialize_field(
+            "ns_uris",
+            &pg_sys::node_serde::NodePtr(self.ns_uris as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ns_names",
+            &pg_sys::node_serde::NodePtr(self.ns_names as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "docexpr",
+            &pg_sys::node_serde::NodePtr(self.docexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rowexpr",
+            &pg_sys::node_serde::NodePtr(self.rowexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "colnames",
+            &pg_sys::node_serde::NodePtr(self.colnames as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "coltypes",
+            &pg_sys::node_serde::NodePtr(self.coltypes as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "coltypmods",
+            &pg_sys::node_serde::NodePtr(self.coltypmods as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "colcollations",
+            &pg_sys::node_serde::NodePtr(self.colcollations as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "colexprs",
+            &pg_sys::node_serde::NodePtr(self.colexprs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "coldefexprs",
+            &pg_sys::node_serde::NodePtr(self.coldefexprs as *const pg_sys::Node),
+        )?;
+        state.serialize_field("ordinalitycol", &self.ordinalitycol)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TableFunc {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106555,6 +116491,25 @@ impl std::fmt::Display for TableFunc {
 }
 impl pg_sys::seal::Sealed for TableFuncScan {}
 impl pg_sys::PgNode for TableFuncScan {}
+impl pg_sys::PgTaggedNode for TableFuncScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TableFuncScan;
+}
+impl serde::Serialize for TableFuncScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TableFuncScan", 3usize)?;
+        state.serialize_field("node", "TableFuncScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field(
+            "tablefunc",
+            &pg_sys::node_serde::NodePtr(self.tablefunc as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TableFuncScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106562,6 +116517,50 @@ impl std::fmt::Display for TableFuncScan {
 }
 impl pg_sys::seal::Sealed for TableFuncScanState {}
 impl pg_sys::PgNode for TableFuncScanState {}
+impl pg_sys::PgTaggedNode for TableFuncScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TableFuncScanState;
+}
+impl serde::Serialize for TableFuncScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TableFuncScanState", 10usize)?;
+        state.serialize_field("node", "TableFuncScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field(
+            "docexpr",
+            &pg_sys::node_serde::NodePtr(self.docexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rowexpr",
+            &pg_sys::node_serde::NodePtr(self.rowexpr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "colexprs",
+            &pg_sys::node_serde::NodePtr(self.colexprs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "coldefexprs",
+            &pg_sys::node_serde::NodePtr(self.coldefexprs as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ns_names",
+            &pg_sys::node_serde::NodePtr(self.ns_names as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ns_uris",
+            &pg_sys::node_serde::NodePtr(self.ns_uris as *const pg_sys::Node),
+        )?;
+        state.serialize_field("ordinal", &self.ordinal)?;
+        state.serialize_field(
+            "perTableCxt",
+            &pg_sys::node_serde::NodePtr(self.perTableCxt as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TableFuncScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106569,6 +116568,26 @@ impl std::fmt::Display for TableFuncScanState {
 }
 impl pg_sys::seal::Sealed for TableLikeClause {}
 impl pg_sys::PgNode for TableLikeClause {}
+impl pg_sys::PgTaggedNode for TableLikeClause {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TableLikeClause;
+}
+impl serde::Serialize for TableLikeClause {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TableLikeClause", 4usize)?;
+        state.serialize_field("node", "TableLikeClause")?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field("options", &self.options)?;
+        state.serialize_field("relationOid", &self.relationOid)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TableLikeClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106576,6 +116595,29 @@ impl std::fmt::Display for TableLikeClause {
 }
 impl pg_sys::seal::Sealed for TableSampleClause {}
 impl pg_sys::PgNode for TableSampleClause {}
+impl pg_sys::PgTaggedNode for TableSampleClause {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TableSampleClause;
+}
+impl serde::Serialize for TableSampleClause {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TableSampleClause", 4usize)?;
+        state.serialize_field("node", "TableSampleClause")?;
+        state.serialize_field("tsmhandler", &self.tsmhandler)?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "repeatable",
+            &pg_sys::node_serde::NodePtr(self.repeatable as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TableSampleClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106583,6 +116625,33 @@ impl std::fmt::Display for TableSampleClause {
 }
 impl pg_sys::seal::Sealed for TargetEntry {}
 impl pg_sys::PgNode for TargetEntry {}
+impl pg_sys::PgTaggedNode for TargetEntry {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TargetEntry;
+}
+impl serde::Serialize for TargetEntry {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TargetEntry", 8usize)?;
+        state.serialize_field("node", "TargetEntry")?;
+        state.serialize_field(
+            "expr",
+            &pg_sys::node_serde::NodePtr(self.expr as *const pg_sys::Node),
+        )?;
+        state.serialize_field("resno", &self.resno)?;
+        state.serialize_field(
+            "resname",
+            &pg_sys::node_serde::CStringPtr(self.resname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("ressortgroupref", &self.ressortgroupref)?;
+        state.serialize_field("resorigtbl", &self.resorigtbl)?;
+        state.serialize_field("resorigcol", &self.resorigcol)?;
+        state.serialize_field("resjunk", &self.resjunk)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TargetEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106590,6 +116659,25 @@ impl std::fmt::Display for TargetEntry {
 }
 impl pg_sys::seal::Sealed for TidPath {}
 impl pg_sys::PgNode for TidPath {}
+impl pg_sys::PgTaggedNode for TidPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TidPath;
+}
+impl serde::Serialize for TidPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TidPath", 3usize)?;
+        state.serialize_field("node", "TidPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "tidquals",
+            &pg_sys::node_serde::NodePtr(self.tidquals as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TidPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106597,6 +116685,25 @@ impl std::fmt::Display for TidPath {
 }
 impl pg_sys::seal::Sealed for TidScan {}
 impl pg_sys::PgNode for TidScan {}
+impl pg_sys::PgTaggedNode for TidScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TidScan;
+}
+impl serde::Serialize for TidScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TidScan", 3usize)?;
+        state.serialize_field("node", "TidScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field(
+            "tidquals",
+            &pg_sys::node_serde::NodePtr(self.tidquals as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TidScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106604,6 +116711,28 @@ impl std::fmt::Display for TidScan {
 }
 impl pg_sys::seal::Sealed for TidScanState {}
 impl pg_sys::PgNode for TidScanState {}
+impl pg_sys::PgTaggedNode for TidScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TidScanState;
+}
+impl serde::Serialize for TidScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TidScanState", 6usize)?;
+        state.serialize_field("node", "TidScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field(
+            "tss_tidexprs",
+            &pg_sys::node_serde::NodePtr(self.tss_tidexprs as *const pg_sys::Node),
+        )?;
+        state.serialize_field("tss_isCurrentOf", &self.tss_isCurrentOf)?;
+        state.serialize_field("tss_NumTids", &self.tss_NumTids)?;
+        state.serialize_field("tss_TidPtr", &self.tss_TidPtr)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TidScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106611,6 +116740,21 @@ impl std::fmt::Display for TidScanState {
 }
 impl pg_sys::seal::Sealed for TimeLineHistoryCmd {}
 impl pg_sys::PgNode for TimeLineHistoryCmd {}
+impl pg_sys::PgTaggedNode for TimeLineHistoryCmd {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TimeLineHistoryCmd;
+}
+impl serde::Serialize for TimeLineHistoryCmd {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TimeLineHistoryCmd", 2usize)?;
+        state.serialize_field("node", "TimeLineHistoryCmd")?;
+        state.serialize_field("timeline", &self.timeline)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TimeLineHistoryCmd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106618,6 +116762,34 @@ impl std::fmt::Display for TimeLineHistoryCmd {
 }
 impl pg_sys::seal::Sealed for TransactionStmt {}
 impl pg_sys::PgNode for TransactionStmt {}
+impl pg_sys::PgTaggedNode for TransactionStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TransactionStmt;
+}
+impl serde::Serialize for TransactionStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TransactionStmt", 6usize)?;
+        state.serialize_field("node", "TransactionStmt")?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "savepoint_name",
+            &pg_sys::node_serde::CStringPtr(self.savepoint_name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "gid",
+            &pg_sys::node_serde::CStringPtr(self.gid as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("chain", &self.chain)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TransactionStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106625,6 +116797,29 @@ impl std::fmt::Display for TransactionStmt {
 }
 impl pg_sys::seal::Sealed for TriggerData {}
 impl pg_sys::PgNode for TriggerData {}
+impl pg_sys::PgTaggedNode for TriggerData {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TriggerData;
+}
+impl serde::Serialize for TriggerData {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TriggerData", 4usize)?;
+        state.serialize_field("node", "TriggerData")?;
+        state.serialize_field("tg_event", &self.tg_event)?;
+        state.serialize_field(
+            "tg_trigslot",
+            &pg_sys::node_serde::NodePtr(self.tg_trigslot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "tg_newslot",
+            &pg_sys::node_serde::NodePtr(self.tg_newslot as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TriggerData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106632,6 +116827,26 @@ impl std::fmt::Display for TriggerData {
 }
 impl pg_sys::seal::Sealed for TriggerTransition {}
 impl pg_sys::PgNode for TriggerTransition {}
+impl pg_sys::PgTaggedNode for TriggerTransition {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TriggerTransition;
+}
+impl serde::Serialize for TriggerTransition {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TriggerTransition", 4usize)?;
+        state.serialize_field("node", "TriggerTransition")?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field("isNew", &self.isNew)?;
+        state.serialize_field("isTable", &self.isTable)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TriggerTransition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106639,6 +116854,26 @@ impl std::fmt::Display for TriggerTransition {
 }
 impl pg_sys::seal::Sealed for TruncateStmt {}
 impl pg_sys::PgNode for TruncateStmt {}
+impl pg_sys::PgTaggedNode for TruncateStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TruncateStmt;
+}
+impl serde::Serialize for TruncateStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TruncateStmt", 4usize)?;
+        state.serialize_field("node", "TruncateStmt")?;
+        state.serialize_field(
+            "relations",
+            &pg_sys::node_serde::NodePtr(self.relations as *const pg_sys::Node),
+        )?;
+        state.serialize_field("restart_seqs", &self.restart_seqs)?;
+        state.serialize_field("behavior", &self.behavior)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TruncateStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106646,6 +116881,27 @@ impl std::fmt::Display for TruncateStmt {
 }
 impl pg_sys::seal::Sealed for TupleTableSlot {}
 impl pg_sys::PgNode for TupleTableSlot {}
+impl pg_sys::PgTaggedNode for TupleTableSlot {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TupleTableSlot;
+}
+impl serde::Serialize for TupleTableSlot {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TupleTableSlot", 5usize)?;
+        state.serialize_field("node", "TupleTableSlot")?;
+        state.serialize_field("tts_flags", &self.tts_flags)?;
+        state.serialize_field("tts_nvalid", &self.tts_nvalid)?;
+        state.serialize_field(
+            "tts_mcxt",
+            &pg_sys::node_serde::NodePtr(self.tts_mcxt as *const pg_sys::Node),
+        )?;
+        state.serialize_field("tts_tableOid", &self.tts_tableOid)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TupleTableSlot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106653,6 +116909,29 @@ impl std::fmt::Display for TupleTableSlot {
 }
 impl pg_sys::seal::Sealed for TypeCast {}
 impl pg_sys::PgNode for TypeCast {}
+impl pg_sys::PgTaggedNode for TypeCast {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TypeCast;
+}
+impl serde::Serialize for TypeCast {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TypeCast", 4usize)?;
+        state.serialize_field("node", "TypeCast")?;
+        state.serialize_field(
+            "arg",
+            &pg_sys::node_serde::NodePtr(self.arg as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "typeName",
+            &pg_sys::node_serde::NodePtr(self.typeName as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TypeCast {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106660,6 +116939,37 @@ impl std::fmt::Display for TypeCast {
 }
 impl pg_sys::seal::Sealed for TypeName {}
 impl pg_sys::PgNode for TypeName {}
+impl pg_sys::PgTaggedNode for TypeName {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_TypeName;
+}
+impl serde::Serialize for TypeName {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("TypeName", 9usize)?;
+        state.serialize_field("node", "TypeName")?;
+        state.serialize_field(
+            "names",
+            &pg_sys::node_serde::NodePtr(self.names as *const pg_sys::Node),
+        )?;
+        state.serialize_field("typeOid", &self.typeOid)?;
+        state.serialize_field("setof", &self.setof)?;
+        state.serialize_field("pct_type", &self.pct_type)?;
+        state.serialize_field(
+            "typmods",
+            &pg_sys::node_serde::NodePtr(self.typmods as *const pg_sys::Node),
+        )?;
+        state.serialize_field("typemod", &self.typemod)?;
+        state.serialize_field(
+            "arrayBounds",
+            &pg_sys::node_serde::NodePtr(self.arrayBounds as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for TypeName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106667,6 +116977,22 @@ impl std::fmt::Display for TypeName {
 }
 impl pg_sys::seal::Sealed for Unique {}
 impl pg_sys::PgNode for Unique {}
+impl pg_sys::PgTaggedNode for Unique {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Unique;
+}
+impl serde::Serialize for Unique {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Unique", 3usize)?;
+        state.serialize_field("node", "Unique")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field("numCols", &self.numCols)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Unique {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106674,6 +117000,34 @@ impl std::fmt::Display for Unique {
 }
 impl pg_sys::seal::Sealed for UniquePath {}
 impl pg_sys::PgNode for UniquePath {}
+impl pg_sys::PgTaggedNode for UniquePath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_UniquePath;
+}
+impl serde::Serialize for UniquePath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("UniquePath", 6usize)?;
+        state.serialize_field("node", "UniquePath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field("umethod", &self.umethod)?;
+        state.serialize_field(
+            "in_operators",
+            &pg_sys::node_serde::NodePtr(self.in_operators as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "uniq_exprs",
+            &pg_sys::node_serde::NodePtr(self.uniq_exprs as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for UniquePath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106681,6 +117035,25 @@ impl std::fmt::Display for UniquePath {
 }
 impl pg_sys::seal::Sealed for UniqueState {}
 impl pg_sys::PgNode for UniqueState {}
+impl pg_sys::PgTaggedNode for UniqueState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_UniqueState;
+}
+impl serde::Serialize for UniqueState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("UniqueState", 3usize)?;
+        state.serialize_field("node", "UniqueState")?;
+        state.serialize_field("ps", &self.ps)?;
+        state.serialize_field(
+            "eqfunction",
+            &pg_sys::node_serde::NodePtr(self.eqfunction as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for UniqueState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106688,6 +117061,24 @@ impl std::fmt::Display for UniqueState {
 }
 impl pg_sys::seal::Sealed for UnlistenStmt {}
 impl pg_sys::PgNode for UnlistenStmt {}
+impl pg_sys::PgTaggedNode for UnlistenStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_UnlistenStmt;
+}
+impl serde::Serialize for UnlistenStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("UnlistenStmt", 2usize)?;
+        state.serialize_field("node", "UnlistenStmt")?;
+        state.serialize_field(
+            "conditionname",
+            &pg_sys::node_serde::CStringPtr(self.conditionname as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for UnlistenStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106695,6 +117086,44 @@ impl std::fmt::Display for UnlistenStmt {
 }
 impl pg_sys::seal::Sealed for UpdateStmt {}
 impl pg_sys::PgNode for UpdateStmt {}
+impl pg_sys::PgTaggedNode for UpdateStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_UpdateStmt;
+}
+impl serde::Serialize for UpdateStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("UpdateStmt", 7usize)?;
+        state.serialize_field("node", "UpdateStmt")?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "targetList",
+            &pg_sys::node_serde::NodePtr(self.targetList as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "whereClause",
+            &pg_sys::node_serde::NodePtr(self.whereClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "fromClause",
+            &pg_sys::node_serde::NodePtr(self.fromClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "returningList",
+            &pg_sys::node_serde::NodePtr(self.returningList as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "withClause",
+            &pg_sys::node_serde::NodePtr(self.withClause as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for UpdateStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106702,6 +117131,26 @@ impl std::fmt::Display for UpdateStmt {
 }
 impl pg_sys::seal::Sealed for UpperUniquePath {}
 impl pg_sys::PgNode for UpperUniquePath {}
+impl pg_sys::PgTaggedNode for UpperUniquePath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_UpperUniquePath;
+}
+impl serde::Serialize for UpperUniquePath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("UpperUniquePath", 4usize)?;
+        state.serialize_field("node", "UpperUniquePath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field("numkeys", &self.numkeys)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for UpperUniquePath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106709,6 +117158,29 @@ impl std::fmt::Display for UpperUniquePath {
 }
 impl pg_sys::seal::Sealed for VacuumRelation {}
 impl pg_sys::PgNode for VacuumRelation {}
+impl pg_sys::PgTaggedNode for VacuumRelation {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_VacuumRelation;
+}
+impl serde::Serialize for VacuumRelation {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("VacuumRelation", 4usize)?;
+        state.serialize_field("node", "VacuumRelation")?;
+        state.serialize_field(
+            "relation",
+            &pg_sys::node_serde::NodePtr(self.relation as *const pg_sys::Node),
+        )?;
+        state.serialize_field("oid", &self.oid)?;
+        state.serialize_field(
+            "va_cols",
+            &pg_sys::node_serde::NodePtr(self.va_cols as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for VacuumRelation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106716,6 +117188,29 @@ impl std::fmt::Display for VacuumRelation {
 }
 impl pg_sys::seal::Sealed for VacuumStmt {}
 impl pg_sys::PgNode for VacuumStmt {}
+impl pg_sys::PgTaggedNode for VacuumStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_VacuumStmt;
+}
+impl serde::Serialize for VacuumStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("VacuumStmt", 4usize)?;
+        state.serialize_field("node", "VacuumStmt")?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "rels",
+            &pg_sys::node_serde::NodePtr(self.rels as *const pg_sys::Node),
+        )?;
+        state.serialize_field("is_vacuumcmd", &self.is_vacuumcmd)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for VacuumStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106723,6 +117218,9 @@ impl std::fmt::Display for VacuumStmt {
 }
 impl pg_sys::seal::Sealed for Value {}
 impl pg_sys::PgNode for Value {}
+impl pg_sys::PgTaggedNode for Value {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Value;
+}
 impl std::fmt::Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106730,6 +117228,25 @@ impl std::fmt::Display for Value {
 }
 impl pg_sys::seal::Sealed for ValuesScan {}
 impl pg_sys::PgNode for ValuesScan {}
+impl pg_sys::PgTaggedNode for ValuesScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ValuesScan;
+}
+impl serde::Serialize for ValuesScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ValuesScan", 3usize)?;
+        state.serialize_field("node", "ValuesScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field(
+            "values_lists",
+            &pg_sys::node_serde::NodePtr(self.values_lists as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ValuesScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106737,6 +117254,27 @@ impl std::fmt::Display for ValuesScan {
 }
 impl pg_sys::seal::Sealed for ValuesScanState {}
 impl pg_sys::PgNode for ValuesScanState {}
+impl pg_sys::PgTaggedNode for ValuesScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ValuesScanState;
+}
+impl serde::Serialize for ValuesScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ValuesScanState", 5usize)?;
+        state.serialize_field("node", "ValuesScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field(
+            "rowcontext",
+            &pg_sys::node_serde::NodePtr(self.rowcontext as *const pg_sys::Node),
+        )?;
+        state.serialize_field("array_len", &self.array_len)?;
+        state.serialize_field("curr_idx", &self.curr_idx)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ValuesScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106744,6 +117282,29 @@ impl std::fmt::Display for ValuesScanState {
 }
 impl pg_sys::seal::Sealed for Var {}
 impl pg_sys::PgNode for Var {}
+impl pg_sys::PgTaggedNode for Var {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_Var;
+}
+impl serde::Serialize for Var {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Var", 10usize)?;
+        state.serialize_field("node", "Var")?;
+        state.serialize_field("varno", &self.varno)?;
+        state.serialize_field("varattno", &self.varattno)?;
+        state.serialize_field("vartype", &self.vartype)?;
+        state.serialize_field("vartypmod", &self.vartypmod)?;
+        state.serialize_field("varcollid", &self.varcollid)?;
+        state.serialize_field("varlevelsup", &self.varlevelsup)?;
+        state.serialize_field("varnoold", &self.varnoold)?;
+        state.serialize_field("varoattno", &self.varoattno)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for Var {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106751,6 +117312,30 @@ impl std::fmt::Display for Var {
 }
 impl pg_sys::seal::Sealed for VariableSetStmt {}
 impl pg_sys::PgNode for VariableSetStmt {}
+impl pg_sys::PgTaggedNode for VariableSetStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_VariableSetStmt;
+}
+impl serde::Serialize for VariableSetStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("VariableSetStmt", 5usize)?;
+        state.serialize_field("node", "VariableSetStmt")?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field("is_local", &self.is_local)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for VariableSetStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106758,6 +117343,24 @@ impl std::fmt::Display for VariableSetStmt {
 }
 impl pg_sys::seal::Sealed for VariableShowStmt {}
 impl pg_sys::PgNode for VariableShowStmt {}
+impl pg_sys::PgTaggedNode for VariableShowStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_VariableShowStmt;
+}
+impl serde::Serialize for VariableShowStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("VariableShowStmt", 2usize)?;
+        state.serialize_field("node", "VariableShowStmt")?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for VariableShowStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106765,6 +117368,38 @@ impl std::fmt::Display for VariableShowStmt {
 }
 impl pg_sys::seal::Sealed for ViewStmt {}
 impl pg_sys::PgNode for ViewStmt {}
+impl pg_sys::PgTaggedNode for ViewStmt {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_ViewStmt;
+}
+impl serde::Serialize for ViewStmt {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ViewStmt", 7usize)?;
+        state.serialize_field("node", "ViewStmt")?;
+        state.serialize_field(
+            "view",
+            &pg_sys::node_serde::NodePtr(self.view as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "aliases",
+            &pg_sys::node_serde::NodePtr(self.aliases as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "query",
+            &pg_sys::node_serde::NodePtr(self.query as *const pg_sys::Node),
+        )?;
+        state.serialize_field("replace", &self.replace)?;
+        state.serialize_field(
+            "options",
+            &pg_sys::node_serde::NodePtr(self.options as *const pg_sys::Node),
+        )?;
+        state.serialize_field("withCheckOption", &self.withCheckOption)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for ViewStmt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106772,6 +117407,22 @@ impl std::fmt::Display for ViewStmt {
 }
 impl pg_sys::seal::Sealed for VirtualTupleTableSlot {}
 impl pg_sys::PgNode for VirtualTupleTableSlot {}
+impl serde::Serialize for VirtualTupleTableSlot {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("VirtualTupleTableSlot", 3usize)?;
+        state.serialize_field("node", "VirtualTupleTableSlot")?;
+        state.serialize_field("base", &self.base)?;
+        state.serialize_field(
+            "data",
+            &pg_sys::node_serde::CStringPtr(self.data as *const std::os::raw::c_char),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for VirtualTupleTableSlot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106779,6 +117430,38 @@ impl std::fmt::Display for VirtualTupleTableSlot {
 }
 impl pg_sys::seal::Sealed for WindowAgg {}
 impl pg_sys::PgNode for WindowAgg {}
+impl pg_sys::PgTaggedNode for WindowAgg {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_WindowAgg;
+}
+impl serde::Serialize for WindowAgg {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WindowAgg", 13usize)?;
+        state.serialize_field("node", "WindowAgg")?;
+        state.serialize_field("plan", &self.plan)?;
+        state.serialize_field("winref", &self.winref)?;
+        state.serialize_field("partNumCols", &self.partNumCols)?;
+        state.serialize_field("ordNumCols", &self.ordNumCols)?;
+        state.serialize_field("frameOptions", &self.frameOptions)?;
+        state.serialize_field(
+            "startOffset",
+            &pg_sys::node_serde::NodePtr(self.startOffset as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "endOffset",
+            &pg_sys::node_serde::NodePtr(self.endOffset as *const pg_sys::Node),
+        )?;
+        state.serialize_field("startInRangeFunc", &self.startInRangeFunc)?;
+        state.serialize_field("endInRangeFunc", &self.endInRangeFunc)?;
+        state.serialize_field("inRangeColl", &self.inRangeColl)?;
+        state.serialize_field("inRangeAsc", &self.inRangeAsc)?;
+        state.serialize_field("inRangeNullsFirst", &self.inRangeNullsFirst)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for WindowAgg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106786,6 +117469,29 @@ impl std::fmt::Display for WindowAgg {
 }
 impl pg_sys::seal::Sealed for WindowAggPath {}
 impl pg_sys::PgNode for WindowAggPath {}
+impl pg_sys::PgTaggedNode for WindowAggPath {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_WindowAggPath;
+}
+impl serde::Serialize for WindowAggPath {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WindowAggPath", 4usize)?;
+        state.serialize_field("node", "WindowAggPath")?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field(
+            "subpath",
+            &pg_sys::node_serde::NodePtr(self.subpath as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "winclause",
+            &pg_sys::node_serde::NodePtr(self.winclause as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for WindowAggPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106793,6 +117499,111 @@ impl std::fmt::Display for WindowAggPath {
 }
 impl pg_sys::seal::Sealed for WindowAggState {}
 impl pg_sys::PgNode for WindowAggState {}
+impl pg_sys::PgTaggedNode for WindowAggState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_WindowAggState;
+}
+impl serde::Serialize for WindowAggState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WindowAggState", 47usize)?;
+        state.serialize_field("node", "WindowAggState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field(
+            "funcs",
+            &pg_sys::node_serde::NodePtr(self.funcs as *const pg_sys::Node),
+        )?;
+        state.serialize_field("numfuncs", &self.numfuncs)?;
+        state.serialize_field("numaggs", &self.numaggs)?;
+        state.serialize_field(
+            "partEqfunction",
+            &pg_sys::node_serde::NodePtr(self.partEqfunction as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "ordEqfunction",
+            &pg_sys::node_serde::NodePtr(self.ordEqfunction as *const pg_sys::Node),
+        )?;
+        state.serialize_field("current_ptr", &self.current_ptr)?;
+        state.serialize_field("framehead_ptr", &self.framehead_ptr)?;
+        state.serialize_field("frametail_ptr", &self.frametail_ptr)?;
+        state.serialize_field("grouptail_ptr", &self.grouptail_ptr)?;
+        state.serialize_field("spooled_rows", &self.spooled_rows)?;
+        state.serialize_field("currentpos", &self.currentpos)?;
+        state.serialize_field("frameheadpos", &self.frameheadpos)?;
+        state.serialize_field("frametailpos", &self.frametailpos)?;
+        state.serialize_field("aggregatedbase", &self.aggregatedbase)?;
+        state.serialize_field("aggregatedupto", &self.aggregatedupto)?;
+        state.serialize_field("frameOptions", &self.frameOptions)?;
+        state.serialize_field(
+            "startOffset",
+            &pg_sys::node_serde::NodePtr(self.startOffset as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "endOffset",
+            &pg_sys::node_serde::NodePtr(self.endOffset as *const pg_sys::Node),
+        )?;
+        state.serialize_field("startOffsetValue", &self.startOffsetValue.value())?;
+        state.serialize_field("endOffsetValue", &self.endOffsetValue.value())?;
+        state.serialize_field("inRangeColl", &self.inRangeColl)?;
+        state.serialize_field("inRangeAsc", &self.inRangeAsc)?;
+        state.serialize_field("inRangeNullsFirst", &self.inRangeNullsFirst)?;
+        state.serialize_field("currentgroup", &self.currentgroup)?;
+        state.serialize_field("frameheadgroup", &self.frameheadgroup)?;
+        state.serialize_field("frametailgroup", &self.frametailgroup)?;
+        state.serialize_field("groupheadpos", &self.groupheadpos)?;
+        state.serialize_field("grouptailpos", &self.grouptailpos)?;
+        state.serialize_field(
+            "partcontext",
+            &pg_sys::node_serde::NodePtr(self.partcontext as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "aggcontext",
+            &pg_sys::node_serde::NodePtr(self.aggcontext as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "curaggcontext",
+            &pg_sys::node_serde::NodePtr(self.curaggcontext as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "tmpcontext",
+            &pg_sys::node_serde::NodePtr(self.tmpcontext as *const pg_sys::Node),
+        )?;
+        state.serialize_field("all_first", &self.all_first)?;
+        state.serialize_field("all_done", &self.all_done)?;
+        state.serialize_field("partition_spooled", &self.partition_spooled)?;
+        state.serialize_field("more_partitions", &self.more_partitions)?;
+        state.serialize_field("framehead_valid", &self.framehead_valid)?;
+        state.serialize_field("frametail_valid", &self.frametail_valid)?;
+        state.serialize_field("grouptail_valid", &self.grouptail_valid)?;
+        state.serialize_field(
+            "first_part_slot",
+            &pg_sys::node_serde::NodePtr(self.first_part_slot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "framehead_slot",
+            &pg_sys::node_serde::NodePtr(self.framehead_slot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "frametail_slot",
+            &pg_sys::node_serde::NodePtr(self.frametail_slot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "agg_row_slot",
+            &pg_sys::node_serde::NodePtr(self.agg_row_slot as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "temp_slot_1",
+            &pg_sys::node_serde::NodePtr(self.temp_slot_1 as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "temp_slot_2",
+            &pg_sys::node_serde::NodePtr(self.temp_slot_2 as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for WindowAggState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106800,6 +117611,52 @@ impl std::fmt::Display for WindowAggState {
 }
 impl pg_sys::seal::Sealed for WindowClause {}
 impl pg_sys::PgNode for WindowClause {}
+impl pg_sys::PgTaggedNode for WindowClause {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_WindowClause;
+}
+impl serde::Serialize for WindowClause {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WindowClause", 15usize)?;
+        state.serialize_field("node", "WindowClause")?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "refname",
+            &pg_sys::node_serde::CStringPtr(self.refname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "partitionClause",
+            &pg_sys::node_serde::NodePtr(self.partitionClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "orderClause",
+            &pg_sys::node_serde::NodePtr(self.orderClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field("frameOptions", &self.frameOptions)?;
+        state.serialize_field(
+            "startOffset",
+            &pg_sys::node_serde::NodePtr(self.startOffset as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "endOffset",
+            &pg_sys::node_serde::NodePtr(self.endOffset as *const pg_sys::Node),
+        )?;
+        state.serialize_field("startInRangeFunc", &self.startInRangeFunc)?;
+        state.serialize_field("endInRangeFunc", &self.endInRangeFunc)?;
+        state.serialize_field("inRangeColl", &self.inRangeColl)?;
+        state.serialize_field("inRangeAsc", &self.inRangeAsc)?;
+        state.serialize_field("inRangeNullsFirst", &self.inRangeNullsFirst)?;
+        state.serialize_field("winref", &self.winref)?;
+        state.serialize_field("copiedOrder", &self.copiedOrder)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for WindowClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106807,6 +117664,46 @@ impl std::fmt::Display for WindowClause {
 }
 impl pg_sys::seal::Sealed for WindowDef {}
 impl pg_sys::PgNode for WindowDef {}
+impl pg_sys::PgTaggedNode for WindowDef {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_WindowDef;
+}
+impl serde::Serialize for WindowDef {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WindowDef", 9usize)?;
+        state.serialize_field("node", "WindowDef")?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "refname",
+            &pg_sys::node_serde::CStringPtr(self.refname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "partitionClause",
+            &pg_sys::node_serde::NodePtr(self.partitionClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "orderClause",
+            &pg_sys::node_serde::NodePtr(self.orderClause as *const pg_sys::Node),
+        )?;
+        state.serialize_field("frameOptions", &self.frameOptions)?;
+        state.serialize_field(
+            "startOffset",
+            &pg_sys::node_serde::NodePtr(self.startOffset as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "endOffset",
+            &pg_sys::node_serde::NodePtr(self.endOffset as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for WindowDef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106814,6 +117711,36 @@ impl std::fmt::Display for WindowDef {
 }
 impl pg_sys::seal::Sealed for WindowFunc {}
 impl pg_sys::PgNode for WindowFunc {}
+impl pg_sys::PgTaggedNode for WindowFunc {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_WindowFunc;
+}
+impl serde::Serialize for WindowFunc {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WindowFunc", 11usize)?;
+        state.serialize_field("node", "WindowFunc")?;
+        state.serialize_field("winfnoid", &self.winfnoid)?;
+        state.serialize_field("wintype", &self.wintype)?;
+        state.serialize_field("wincollid", &self.wincollid)?;
+        state.serialize_field("inputcollid", &self.inputcollid)?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "aggfilter",
+            &pg_sys::node_serde::NodePtr(self.aggfilter as *const pg_sys::Node),
+        )?;
+        state.serialize_field("winref", &self.winref)?;
+        state.serialize_field("winstar", &self.winstar)?;
+        state.serialize_field("winagg", &self.winagg)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for WindowFunc {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106821,6 +117748,33 @@ impl std::fmt::Display for WindowFunc {
 }
 impl pg_sys::seal::Sealed for WindowFuncExprState {}
 impl pg_sys::PgNode for WindowFuncExprState {}
+impl pg_sys::PgTaggedNode for WindowFuncExprState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_WindowFuncExprState;
+}
+impl serde::Serialize for WindowFuncExprState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WindowFuncExprState", 5usize)?;
+        state.serialize_field("node", "WindowFuncExprState")?;
+        state.serialize_field(
+            "wfunc",
+            &pg_sys::node_serde::NodePtr(self.wfunc as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "aggfilter",
+            &pg_sys::node_serde::NodePtr(self.aggfilter as *const pg_sys::Node),
+        )?;
+        state.serialize_field("wfuncno", &self.wfuncno)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for WindowFuncExprState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106828,6 +117782,34 @@ impl std::fmt::Display for WindowFuncExprState {
 }
 impl pg_sys::seal::Sealed for WithCheckOption {}
 impl pg_sys::PgNode for WithCheckOption {}
+impl pg_sys::PgTaggedNode for WithCheckOption {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_WithCheckOption;
+}
+impl serde::Serialize for WithCheckOption {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WithCheckOption", 6usize)?;
+        state.serialize_field("node", "WithCheckOption")?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field(
+            "relname",
+            &pg_sys::node_serde::CStringPtr(self.relname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "polname",
+            &pg_sys::node_serde::CStringPtr(self.polname as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "qual",
+            &pg_sys::node_serde::NodePtr(self.qual as *const pg_sys::Node),
+        )?;
+        state.serialize_field("cascaded", &self.cascaded)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for WithCheckOption {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106835,6 +117817,26 @@ impl std::fmt::Display for WithCheckOption {
 }
 impl pg_sys::seal::Sealed for WithClause {}
 impl pg_sys::PgNode for WithClause {}
+impl pg_sys::PgTaggedNode for WithClause {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_WithClause;
+}
+impl serde::Serialize for WithClause {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WithClause", 4usize)?;
+        state.serialize_field("node", "WithClause")?;
+        state.serialize_field(
+            "ctes",
+            &pg_sys::node_serde::NodePtr(self.ctes as *const pg_sys::Node),
+        )?;
+        state.serialize_field("recursive", &self.recursive)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for WithClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106842,6 +117844,22 @@ impl std::fmt::Display for WithClause {
 }
 impl pg_sys::seal::Sealed for WorkTableScan {}
 impl pg_sys::PgNode for WorkTableScan {}
+impl pg_sys::PgTaggedNode for WorkTableScan {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_WorkTableScan;
+}
+impl serde::Serialize for WorkTableScan {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WorkTableScan", 3usize)?;
+        state.serialize_field("node", "WorkTableScan")?;
+        state.serialize_field("scan", &self.scan)?;
+        state.serialize_field("wtParam", &self.wtParam)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for WorkTableScan {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106849,6 +117867,25 @@ impl std::fmt::Display for WorkTableScan {
 }
 impl pg_sys::seal::Sealed for WorkTableScanState {}
 impl pg_sys::PgNode for WorkTableScanState {}
+impl pg_sys::PgTaggedNode for WorkTableScanState {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_WorkTableScanState;
+}
+impl serde::Serialize for WorkTableScanState {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WorkTableScanState", 3usize)?;
+        state.serialize_field("node", "WorkTableScanState")?;
+        state.serialize_field("ss", &self.ss)?;
+        state.serialize_field(
+            "rustate",
+            &pg_sys::node_serde::NodePtr(self.rustate as *const pg_sys::Node),
+        )?;
+        state.end()
+    }
+}
 impl std::fmt::Display for WorkTableScanState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106856,6 +117893,41 @@ impl std::fmt::Display for WorkTableScanState {
 }
 impl pg_sys::seal::Sealed for XmlExpr {}
 impl pg_sys::PgNode for XmlExpr {}
+impl pg_sys::PgTaggedNode for XmlExpr {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_XmlExpr;
+}
+impl serde::Serialize for XmlExpr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("XmlExpr", 10usize)?;
+        state.serialize_field("node", "XmlExpr")?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field(
+            "name",
+            &pg_sys::node_serde::CStringPtr(self.name as *const std::os::raw::c_char),
+        )?;
+        state.serialize_field(
+            "named_args",
+            &pg_sys::node_serde::NodePtr(self.named_args as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "arg_names",
+            &pg_sys::node_serde::NodePtr(self.arg_names as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "args",
+            &pg_sys::node_serde::NodePtr(self.args as *const pg_sys::Node),
+        )?;
+        state.serialize_field("xmloption", &self.xmloption)?;
+        state.serialize_field("type", &self.type_)?;
+        state.serialize_field("typmod", &self.typmod)?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for XmlExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
@@ -106863,8 +117935,1198 @@ impl std::fmt::Display for XmlExpr {
 }
 impl pg_sys::seal::Sealed for XmlSerialize {}
 impl pg_sys::PgNode for XmlSerialize {}
+impl pg_sys::PgTaggedNode for XmlSerialize {
+    const NODE_TAG: pg_sys::NodeTag = pg_sys::NodeTag_T_XmlSerialize;
+}
+impl serde::Serialize for XmlSerialize {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("XmlSerialize", 5usize)?;
+        state.serialize_field("node", "XmlSerialize")?;
+        state.serialize_field("xmloption", &self.xmloption)?;
+        state.serialize_field(
+            "expr",
+            &pg_sys::node_serde::NodePtr(self.expr as *const pg_sys::Node),
+        )?;
+        state.serialize_field(
+            "typeName",
+            &pg_sys::node_serde::NodePtr(self.typeName as *const pg_sys::Node),
+        )?;
+        state.serialize_field("location", &self.location)?;
+        state.end()
+    }
+}
 impl std::fmt::Display for XmlSerialize {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_node())
     }
 }
+pub(crate) unsafe fn serialize_node<S: serde::Serializer>(
+    node: *const pg_sys::Node,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match (*node).type_ {
+        pg_sys::NodeTag_T_A_ArrayExpr => {
+            serde::Serialize::serialize(&*node.cast::<A_ArrayExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_A_Const => {
+            serde::Serialize::serialize(&*node.cast::<A_Const>(), serializer)
+        }
+        pg_sys::NodeTag_T_A_Expr => {
+            serde::Serialize::serialize(&*node.cast::<A_Expr>(), serializer)
+        }
+        pg_sys::NodeTag_T_A_Indices => {
+            serde::Serialize::serialize(&*node.cast::<A_Indices>(), serializer)
+        }
+        pg_sys::NodeTag_T_A_Indirection => {
+            serde::Serialize::serialize(&*node.cast::<A_Indirection>(), serializer)
+        }
+        pg_sys::NodeTag_T_A_Star => {
+            serde::Serialize::serialize(&*node.cast::<A_Star>(), serializer)
+        }
+        pg_sys::NodeTag_T_AccessPriv => {
+            serde::Serialize::serialize(&*node.cast::<AccessPriv>(), serializer)
+        }
+        pg_sys::NodeTag_T_Agg => serde::Serialize::serialize(&*node.cast::<Agg>(), serializer),
+        pg_sys::NodeTag_T_AggPath => {
+            serde::Serialize::serialize(&*node.cast::<AggPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_AggState => {
+            serde::Serialize::serialize(&*node.cast::<AggState>(), serializer)
+        }
+        pg_sys::NodeTag_T_Aggref => {
+            serde::Serialize::serialize(&*node.cast::<Aggref>(), serializer)
+        }
+        pg_sys::NodeTag_T_AggrefExprState => {
+            serde::Serialize::serialize(&*node.cast::<AggrefExprState>(), serializer)
+        }
+        pg_sys::NodeTag_T_Alias => serde::Serialize::serialize(&*node.cast::<Alias>(), serializer),
+        pg_sys::NodeTag_T_AlterCollationStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterCollationStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterDatabaseSetStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterDatabaseSetStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterDatabaseStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterDatabaseStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterDefaultPrivilegesStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterDefaultPrivilegesStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterDomainStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterDomainStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterEnumStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterEnumStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterEventTrigStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterEventTrigStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterExtensionContentsStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterExtensionContentsStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterExtensionStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterExtensionStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterFdwStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterFdwStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterForeignServerStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterForeignServerStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterFunctionStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterFunctionStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterObjectDependsStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterObjectDependsStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterObjectSchemaStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterObjectSchemaStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterOpFamilyStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterOpFamilyStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterOperatorStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterOperatorStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterOwnerStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterOwnerStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterPolicyStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterPolicyStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterPublicationStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterPublicationStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterRoleSetStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterRoleSetStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterRoleStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterRoleStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterSeqStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterSeqStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterSubscriptionStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterSubscriptionStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterSystemStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterSystemStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterTSConfigurationStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterTSConfigurationStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterTSDictionaryStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterTSDictionaryStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterTableCmd => {
+            serde::Serialize::serialize(&*node.cast::<AlterTableCmd>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterTableMoveAllStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterTableMoveAllStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterTableSpaceOptionsStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterTableSpaceOptionsStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterTableStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterTableStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlterUserMappingStmt => {
+            serde::Serialize::serialize(&*node.cast::<AlterUserMappingStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlternativeSubPlan => {
+            serde::Serialize::serialize(&*node.cast::<AlternativeSubPlan>(), serializer)
+        }
+        pg_sys::NodeTag_T_AlternativeSubPlanState => {
+            serde::Serialize::serialize(&*node.cast::<AlternativeSubPlanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_Append => {
+            serde::Serialize::serialize(&*node.cast::<Append>(), serializer)
+        }
+        pg_sys::NodeTag_T_AppendPath => {
+            serde::Serialize::serialize(&*node.cast::<AppendPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_AppendRelInfo => {
+            serde::Serialize::serialize(&*node.cast::<AppendRelInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_AppendState => {
+            serde::Serialize::serialize(&*node.cast::<AppendState>(), serializer)
+        }
+        pg_sys::NodeTag_T_ArrayCoerceExpr => {
+            serde::Serialize::serialize(&*node.cast::<ArrayCoerceExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_ArrayExpr => {
+            serde::Serialize::serialize(&*node.cast::<ArrayExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_BaseBackupCmd => {
+            serde::Serialize::serialize(&*node.cast::<BaseBackupCmd>(), serializer)
+        }
+        pg_sys::NodeTag_T_BitmapAnd => {
+            serde::Serialize::serialize(&*node.cast::<BitmapAnd>(), serializer)
+        }
+        pg_sys::NodeTag_T_BitmapAndPath => {
+            serde::Serialize::serialize(&*node.cast::<BitmapAndPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_BitmapAndState => {
+            serde::Serialize::serialize(&*node.cast::<BitmapAndState>(), serializer)
+        }
+        pg_sys::NodeTag_T_BitmapHeapPath => {
+            serde::Serialize::serialize(&*node.cast::<BitmapHeapPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_BitmapHeapScan => {
+            serde::Serialize::serialize(&*node.cast::<BitmapHeapScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_BitmapHeapScanState => {
+            serde::Serialize::serialize(&*node.cast::<BitmapHeapScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_BitmapIndexScan => {
+            serde::Serialize::serialize(&*node.cast::<BitmapIndexScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_BitmapIndexScanState => {
+            serde::Serialize::serialize(&*node.cast::<BitmapIndexScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_BitmapOr => {
+            serde::Serialize::serialize(&*node.cast::<BitmapOr>(), serializer)
+        }
+        pg_sys::NodeTag_T_BitmapOrPath => {
+            serde::Serialize::serialize(&*node.cast::<BitmapOrPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_BitmapOrState => {
+            serde::Serialize::serialize(&*node.cast::<BitmapOrState>(), serializer)
+        }
+        pg_sys::NodeTag_T_BoolExpr => {
+            serde::Serialize::serialize(&*node.cast::<BoolExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_BooleanTest => {
+            serde::Serialize::serialize(&*node.cast::<BooleanTest>(), serializer)
+        }
+        pg_sys::NodeTag_T_CallContext => {
+            serde::Serialize::serialize(&*node.cast::<CallContext>(), serializer)
+        }
+        pg_sys::NodeTag_T_CallStmt => {
+            serde::Serialize::serialize(&*node.cast::<CallStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CaseExpr => {
+            serde::Serialize::serialize(&*node.cast::<CaseExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_CaseTestExpr => {
+            serde::Serialize::serialize(&*node.cast::<CaseTestExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_CaseWhen => {
+            serde::Serialize::serialize(&*node.cast::<CaseWhen>(), serializer)
+        }
+        pg_sys::NodeTag_T_CheckPointStmt => {
+            serde::Serialize::serialize(&*node.cast::<CheckPointStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_ClosePortalStmt => {
+            serde::Serialize::serialize(&*node.cast::<ClosePortalStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_ClusterStmt => {
+            serde::Serialize::serialize(&*node.cast::<ClusterStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CoalesceExpr => {
+            serde::Serialize::serialize(&*node.cast::<CoalesceExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_CoerceToDomain => {
+            serde::Serialize::serialize(&*node.cast::<CoerceToDomain>(), serializer)
+        }
+        pg_sys::NodeTag_T_CoerceToDomainValue => {
+            serde::Serialize::serialize(&*node.cast::<CoerceToDomainValue>(), serializer)
+        }
+        pg_sys::NodeTag_T_CoerceViaIO => {
+            serde::Serialize::serialize(&*node.cast::<CoerceViaIO>(), serializer)
+        }
+        pg_sys::NodeTag_T_CollateClause => {
+            serde::Serialize::serialize(&*node.cast::<CollateClause>(), serializer)
+        }
+        pg_sys::NodeTag_T_CollateExpr => {
+            serde::Serialize::serialize(&*node.cast::<CollateExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_ColumnDef => {
+            serde::Serialize::serialize(&*node.cast::<ColumnDef>(), serializer)
+        }
+        pg_sys::NodeTag_T_ColumnRef => {
+            serde::Serialize::serialize(&*node.cast::<ColumnRef>(), serializer)
+        }
+        pg_sys::NodeTag_T_CommentStmt => {
+            serde::Serialize::serialize(&*node.cast::<CommentStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CommonTableExpr => {
+            serde::Serialize::serialize(&*node.cast::<CommonTableExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_CompositeTypeStmt => {
+            serde::Serialize::serialize(&*node.cast::<CompositeTypeStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_Const => serde::Serialize::serialize(&*node.cast::<Const>(), serializer),
+        pg_sys::NodeTag_T_Constraint => {
+            serde::Serialize::serialize(&*node.cast::<Constraint>(), serializer)
+        }
+        pg_sys::NodeTag_T_ConstraintsSetStmt => {
+            serde::Serialize::serialize(&*node.cast::<ConstraintsSetStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_ConvertRowtypeExpr => {
+            serde::Serialize::serialize(&*node.cast::<ConvertRowtypeExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_CopyStmt => {
+            serde::Serialize::serialize(&*node.cast::<CopyStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateAmStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateAmStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateCastStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateCastStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateConversionStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateConversionStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateDomainStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateDomainStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateEnumStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateEnumStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateEventTrigStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateEventTrigStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateExtensionStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateExtensionStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateFdwStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateFdwStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateForeignServerStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateForeignServerStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateForeignTableStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateForeignTableStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateFunctionStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateFunctionStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateOpClassItem => {
+            serde::Serialize::serialize(&*node.cast::<CreateOpClassItem>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateOpClassStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateOpClassStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateOpFamilyStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateOpFamilyStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreatePLangStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreatePLangStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreatePolicyStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreatePolicyStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreatePublicationStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreatePublicationStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateRangeStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateRangeStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateReplicationSlotCmd => {
+            serde::Serialize::serialize(&*node.cast::<CreateReplicationSlotCmd>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateRoleStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateRoleStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateSchemaStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateSchemaStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateSeqStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateSeqStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateStatsStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateStatsStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateSubscriptionStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateSubscriptionStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateTableAsStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateTableAsStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateTableSpaceStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateTableSpaceStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateTransformStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateTransformStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateTrigStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateTrigStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreateUserMappingStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreateUserMappingStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CreatedbStmt => {
+            serde::Serialize::serialize(&*node.cast::<CreatedbStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_CteScan => {
+            serde::Serialize::serialize(&*node.cast::<CteScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_CteScanState => {
+            serde::Serialize::serialize(&*node.cast::<CteScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_CurrentOfExpr => {
+            serde::Serialize::serialize(&*node.cast::<CurrentOfExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_CustomPath => {
+            serde::Serialize::serialize(&*node.cast::<CustomPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_CustomScan => {
+            serde::Serialize::serialize(&*node.cast::<CustomScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_CustomScanState => {
+            serde::Serialize::serialize(&*node.cast::<CustomScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_DeallocateStmt => {
+            serde::Serialize::serialize(&*node.cast::<DeallocateStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_DeclareCursorStmt => {
+            serde::Serialize::serialize(&*node.cast::<DeclareCursorStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_DefElem => {
+            serde::Serialize::serialize(&*node.cast::<DefElem>(), serializer)
+        }
+        pg_sys::NodeTag_T_DefineStmt => {
+            serde::Serialize::serialize(&*node.cast::<DefineStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_DeleteStmt => {
+            serde::Serialize::serialize(&*node.cast::<DeleteStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_DiscardStmt => {
+            serde::Serialize::serialize(&*node.cast::<DiscardStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_DoStmt => {
+            serde::Serialize::serialize(&*node.cast::<DoStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_DomainConstraintState => {
+            serde::Serialize::serialize(&*node.cast::<DomainConstraintState>(), serializer)
+        }
+        pg_sys::NodeTag_T_DropOwnedStmt => {
+            serde::Serialize::serialize(&*node.cast::<DropOwnedStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_DropReplicationSlotCmd => {
+            serde::Serialize::serialize(&*node.cast::<DropReplicationSlotCmd>(), serializer)
+        }
+        pg_sys::NodeTag_T_DropRoleStmt => {
+            serde::Serialize::serialize(&*node.cast::<DropRoleStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_DropStmt => {
+            serde::Serialize::serialize(&*node.cast::<DropStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_DropSubscriptionStmt => {
+            serde::Serialize::serialize(&*node.cast::<DropSubscriptionStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_DropTableSpaceStmt => {
+            serde::Serialize::serialize(&*node.cast::<DropTableSpaceStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_DropUserMappingStmt => {
+            serde::Serialize::serialize(&*node.cast::<DropUserMappingStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_DropdbStmt => {
+            serde::Serialize::serialize(&*node.cast::<DropdbStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_EState => {
+            serde::Serialize::serialize(&*node.cast::<EState>(), serializer)
+        }
+        pg_sys::NodeTag_T_EquivalenceClass => {
+            serde::Serialize::serialize(&*node.cast::<EquivalenceClass>(), serializer)
+        }
+        pg_sys::NodeTag_T_EquivalenceMember => {
+            serde::Serialize::serialize(&*node.cast::<EquivalenceMember>(), serializer)
+        }
+        pg_sys::NodeTag_T_EventTriggerData => {
+            serde::Serialize::serialize(&*node.cast::<EventTriggerData>(), serializer)
+        }
+        pg_sys::NodeTag_T_ExecuteStmt => {
+            serde::Serialize::serialize(&*node.cast::<ExecuteStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_ExplainStmt => {
+            serde::Serialize::serialize(&*node.cast::<ExplainStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_Expr => serde::Serialize::serialize(&*node.cast::<Expr>(), serializer),
+        pg_sys::NodeTag_T_ExprContext => {
+            serde::Serialize::serialize(&*node.cast::<ExprContext>(), serializer)
+        }
+        pg_sys::NodeTag_T_ExprState => {
+            serde::Serialize::serialize(&*node.cast::<ExprState>(), serializer)
+        }
+        pg_sys::NodeTag_T_ExtensibleNode => {
+            serde::Serialize::serialize(&*node.cast::<ExtensibleNode>(), serializer)
+        }
+        pg_sys::NodeTag_T_FdwRoutine => {
+            serde::Serialize::serialize(&*node.cast::<FdwRoutine>(), serializer)
+        }
+        pg_sys::NodeTag_T_FetchStmt => {
+            serde::Serialize::serialize(&*node.cast::<FetchStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_FieldSelect => {
+            serde::Serialize::serialize(&*node.cast::<FieldSelect>(), serializer)
+        }
+        pg_sys::NodeTag_T_FieldStore => {
+            serde::Serialize::serialize(&*node.cast::<FieldStore>(), serializer)
+        }
+        pg_sys::NodeTag_T_ForeignKeyCacheInfo => {
+            serde::Serialize::serialize(&*node.cast::<ForeignKeyCacheInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_ForeignKeyOptInfo => {
+            serde::Serialize::serialize(&*node.cast::<ForeignKeyOptInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_ForeignPath => {
+            serde::Serialize::serialize(&*node.cast::<ForeignPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_ForeignScan => {
+            serde::Serialize::serialize(&*node.cast::<ForeignScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_ForeignScanState => {
+            serde::Serialize::serialize(&*node.cast::<ForeignScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_FromExpr => {
+            serde::Serialize::serialize(&*node.cast::<FromExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_FuncCall => {
+            serde::Serialize::serialize(&*node.cast::<FuncCall>(), serializer)
+        }
+        pg_sys::NodeTag_T_FuncExpr => {
+            serde::Serialize::serialize(&*node.cast::<FuncExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_FunctionParameter => {
+            serde::Serialize::serialize(&*node.cast::<FunctionParameter>(), serializer)
+        }
+        pg_sys::NodeTag_T_FunctionScan => {
+            serde::Serialize::serialize(&*node.cast::<FunctionScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_FunctionScanState => {
+            serde::Serialize::serialize(&*node.cast::<FunctionScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_Gather => {
+            serde::Serialize::serialize(&*node.cast::<Gather>(), serializer)
+        }
+        pg_sys::NodeTag_T_GatherMerge => {
+            serde::Serialize::serialize(&*node.cast::<GatherMerge>(), serializer)
+        }
+        pg_sys::NodeTag_T_GatherMergePath => {
+            serde::Serialize::serialize(&*node.cast::<GatherMergePath>(), serializer)
+        }
+        pg_sys::NodeTag_T_GatherMergeState => {
+            serde::Serialize::serialize(&*node.cast::<GatherMergeState>(), serializer)
+        }
+        pg_sys::NodeTag_T_GatherPath => {
+            serde::Serialize::serialize(&*node.cast::<GatherPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_GatherState => {
+            serde::Serialize::serialize(&*node.cast::<GatherState>(), serializer)
+        }
+        pg_sys::NodeTag_T_GrantRoleStmt => {
+            serde::Serialize::serialize(&*node.cast::<GrantRoleStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_GrantStmt => {
+            serde::Serialize::serialize(&*node.cast::<GrantStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_Group => serde::Serialize::serialize(&*node.cast::<Group>(), serializer),
+        pg_sys::NodeTag_T_GroupPath => {
+            serde::Serialize::serialize(&*node.cast::<GroupPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_GroupResultPath => {
+            serde::Serialize::serialize(&*node.cast::<GroupResultPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_GroupState => {
+            serde::Serialize::serialize(&*node.cast::<GroupState>(), serializer)
+        }
+        pg_sys::NodeTag_T_GroupingFunc => {
+            serde::Serialize::serialize(&*node.cast::<GroupingFunc>(), serializer)
+        }
+        pg_sys::NodeTag_T_GroupingSet => {
+            serde::Serialize::serialize(&*node.cast::<GroupingSet>(), serializer)
+        }
+        pg_sys::NodeTag_T_GroupingSetData => {
+            serde::Serialize::serialize(&*node.cast::<GroupingSetData>(), serializer)
+        }
+        pg_sys::NodeTag_T_GroupingSetsPath => {
+            serde::Serialize::serialize(&*node.cast::<GroupingSetsPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_Hash => serde::Serialize::serialize(&*node.cast::<Hash>(), serializer),
+        pg_sys::NodeTag_T_HashJoin => {
+            serde::Serialize::serialize(&*node.cast::<HashJoin>(), serializer)
+        }
+        pg_sys::NodeTag_T_HashJoinState => {
+            serde::Serialize::serialize(&*node.cast::<HashJoinState>(), serializer)
+        }
+        pg_sys::NodeTag_T_HashPath => {
+            serde::Serialize::serialize(&*node.cast::<HashPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_HashState => {
+            serde::Serialize::serialize(&*node.cast::<HashState>(), serializer)
+        }
+        pg_sys::NodeTag_T_IdentifySystemCmd => {
+            serde::Serialize::serialize(&*node.cast::<IdentifySystemCmd>(), serializer)
+        }
+        pg_sys::NodeTag_T_ImportForeignSchemaStmt => {
+            serde::Serialize::serialize(&*node.cast::<ImportForeignSchemaStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_IndexAmRoutine => {
+            serde::Serialize::serialize(&*node.cast::<IndexAmRoutine>(), serializer)
+        }
+        pg_sys::NodeTag_T_IndexClause => {
+            serde::Serialize::serialize(&*node.cast::<IndexClause>(), serializer)
+        }
+        pg_sys::NodeTag_T_IndexElem => {
+            serde::Serialize::serialize(&*node.cast::<IndexElem>(), serializer)
+        }
+        pg_sys::NodeTag_T_IndexInfo => {
+            serde::Serialize::serialize(&*node.cast::<IndexInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_IndexOnlyScan => {
+            serde::Serialize::serialize(&*node.cast::<IndexOnlyScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_IndexOnlyScanState => {
+            serde::Serialize::serialize(&*node.cast::<IndexOnlyScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_IndexOptInfo => {
+            serde::Serialize::serialize(&*node.cast::<IndexOptInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_IndexPath => {
+            serde::Serialize::serialize(&*node.cast::<IndexPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_IndexScan => {
+            serde::Serialize::serialize(&*node.cast::<IndexScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_IndexScanState => {
+            serde::Serialize::serialize(&*node.cast::<IndexScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_IndexStmt => {
+            serde::Serialize::serialize(&*node.cast::<IndexStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_InferClause => {
+            serde::Serialize::serialize(&*node.cast::<InferClause>(), serializer)
+        }
+        pg_sys::NodeTag_T_InferenceElem => {
+            serde::Serialize::serialize(&*node.cast::<InferenceElem>(), serializer)
+        }
+        pg_sys::NodeTag_T_InlineCodeBlock => {
+            serde::Serialize::serialize(&*node.cast::<InlineCodeBlock>(), serializer)
+        }
+        pg_sys::NodeTag_T_InsertStmt => {
+            serde::Serialize::serialize(&*node.cast::<InsertStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_IntoClause => {
+            serde::Serialize::serialize(&*node.cast::<IntoClause>(), serializer)
+        }
+        pg_sys::NodeTag_T_Join => serde::Serialize::serialize(&*node.cast::<Join>(), serializer),
+        pg_sys::NodeTag_T_JoinExpr => {
+            serde::Serialize::serialize(&*node.cast::<JoinExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_JoinState => {
+            serde::Serialize::serialize(&*node.cast::<JoinState>(), serializer)
+        }
+        pg_sys::NodeTag_T_JunkFilter => {
+            serde::Serialize::serialize(&*node.cast::<JunkFilter>(), serializer)
+        }
+        pg_sys::NodeTag_T_Limit => serde::Serialize::serialize(&*node.cast::<Limit>(), serializer),
+        pg_sys::NodeTag_T_LimitPath => {
+            serde::Serialize::serialize(&*node.cast::<LimitPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_LimitState => {
+            serde::Serialize::serialize(&*node.cast::<LimitState>(), serializer)
+        }
+        pg_sys::NodeTag_T_List => serde::Serialize::serialize(&*node.cast::<List>(), serializer),
+        pg_sys::NodeTag_T_ListenStmt => {
+            serde::Serialize::serialize(&*node.cast::<ListenStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_LoadStmt => {
+            serde::Serialize::serialize(&*node.cast::<LoadStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_LockRows => {
+            serde::Serialize::serialize(&*node.cast::<LockRows>(), serializer)
+        }
+        pg_sys::NodeTag_T_LockRowsPath => {
+            serde::Serialize::serialize(&*node.cast::<LockRowsPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_LockRowsState => {
+            serde::Serialize::serialize(&*node.cast::<LockRowsState>(), serializer)
+        }
+        pg_sys::NodeTag_T_LockStmt => {
+            serde::Serialize::serialize(&*node.cast::<LockStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_LockingClause => {
+            serde::Serialize::serialize(&*node.cast::<LockingClause>(), serializer)
+        }
+        pg_sys::NodeTag_T_Material => {
+            serde::Serialize::serialize(&*node.cast::<Material>(), serializer)
+        }
+        pg_sys::NodeTag_T_MaterialPath => {
+            serde::Serialize::serialize(&*node.cast::<MaterialPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_MaterialState => {
+            serde::Serialize::serialize(&*node.cast::<MaterialState>(), serializer)
+        }
+        pg_sys::NodeTag_T_MergeAppend => {
+            serde::Serialize::serialize(&*node.cast::<MergeAppend>(), serializer)
+        }
+        pg_sys::NodeTag_T_MergeAppendPath => {
+            serde::Serialize::serialize(&*node.cast::<MergeAppendPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_MergeAppendState => {
+            serde::Serialize::serialize(&*node.cast::<MergeAppendState>(), serializer)
+        }
+        pg_sys::NodeTag_T_MergeJoin => {
+            serde::Serialize::serialize(&*node.cast::<MergeJoin>(), serializer)
+        }
+        pg_sys::NodeTag_T_MergeJoinState => {
+            serde::Serialize::serialize(&*node.cast::<MergeJoinState>(), serializer)
+        }
+        pg_sys::NodeTag_T_MergePath => {
+            serde::Serialize::serialize(&*node.cast::<MergePath>(), serializer)
+        }
+        pg_sys::NodeTag_T_MinMaxAggInfo => {
+            serde::Serialize::serialize(&*node.cast::<MinMaxAggInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_MinMaxAggPath => {
+            serde::Serialize::serialize(&*node.cast::<MinMaxAggPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_MinMaxExpr => {
+            serde::Serialize::serialize(&*node.cast::<MinMaxExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_ModifyTable => {
+            serde::Serialize::serialize(&*node.cast::<ModifyTable>(), serializer)
+        }
+        pg_sys::NodeTag_T_ModifyTablePath => {
+            serde::Serialize::serialize(&*node.cast::<ModifyTablePath>(), serializer)
+        }
+        pg_sys::NodeTag_T_ModifyTableState => {
+            serde::Serialize::serialize(&*node.cast::<ModifyTableState>(), serializer)
+        }
+        pg_sys::NodeTag_T_MultiAssignRef => {
+            serde::Serialize::serialize(&*node.cast::<MultiAssignRef>(), serializer)
+        }
+        pg_sys::NodeTag_T_NamedArgExpr => {
+            serde::Serialize::serialize(&*node.cast::<NamedArgExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_NamedTuplestoreScan => {
+            serde::Serialize::serialize(&*node.cast::<NamedTuplestoreScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_NamedTuplestoreScanState => {
+            serde::Serialize::serialize(&*node.cast::<NamedTuplestoreScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_NestLoop => {
+            serde::Serialize::serialize(&*node.cast::<NestLoop>(), serializer)
+        }
+        pg_sys::NodeTag_T_NestLoopParam => {
+            serde::Serialize::serialize(&*node.cast::<NestLoopParam>(), serializer)
+        }
+        pg_sys::NodeTag_T_NestLoopState => {
+            serde::Serialize::serialize(&*node.cast::<NestLoopState>(), serializer)
+        }
+        pg_sys::NodeTag_T_NextValueExpr => {
+            serde::Serialize::serialize(&*node.cast::<NextValueExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_NotifyStmt => {
+            serde::Serialize::serialize(&*node.cast::<NotifyStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_NullTest => {
+            serde::Serialize::serialize(&*node.cast::<NullTest>(), serializer)
+        }
+        pg_sys::NodeTag_T_ObjectWithArgs => {
+            serde::Serialize::serialize(&*node.cast::<ObjectWithArgs>(), serializer)
+        }
+        pg_sys::NodeTag_T_OnConflictClause => {
+            serde::Serialize::serialize(&*node.cast::<OnConflictClause>(), serializer)
+        }
+        pg_sys::NodeTag_T_OnConflictExpr => {
+            serde::Serialize::serialize(&*node.cast::<OnConflictExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_OnConflictSetState => {
+            serde::Serialize::serialize(&*node.cast::<OnConflictSetState>(), serializer)
+        }
+        pg_sys::NodeTag_T_OpExpr => {
+            serde::Serialize::serialize(&*node.cast::<OpExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_Param => serde::Serialize::serialize(&*node.cast::<Param>(), serializer),
+        pg_sys::NodeTag_T_ParamPathInfo => {
+            serde::Serialize::serialize(&*node.cast::<ParamPathInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_ParamRef => {
+            serde::Serialize::serialize(&*node.cast::<ParamRef>(), serializer)
+        }
+        pg_sys::NodeTag_T_PartitionBoundSpec => {
+            serde::Serialize::serialize(&*node.cast::<PartitionBoundSpec>(), serializer)
+        }
+        pg_sys::NodeTag_T_PartitionCmd => {
+            serde::Serialize::serialize(&*node.cast::<PartitionCmd>(), serializer)
+        }
+        pg_sys::NodeTag_T_PartitionElem => {
+            serde::Serialize::serialize(&*node.cast::<PartitionElem>(), serializer)
+        }
+        pg_sys::NodeTag_T_PartitionPruneInfo => {
+            serde::Serialize::serialize(&*node.cast::<PartitionPruneInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_PartitionPruneStepCombine => {
+            serde::Serialize::serialize(&*node.cast::<PartitionPruneStepCombine>(), serializer)
+        }
+        pg_sys::NodeTag_T_PartitionPruneStepOp => {
+            serde::Serialize::serialize(&*node.cast::<PartitionPruneStepOp>(), serializer)
+        }
+        pg_sys::NodeTag_T_PartitionRangeDatum => {
+            serde::Serialize::serialize(&*node.cast::<PartitionRangeDatum>(), serializer)
+        }
+        pg_sys::NodeTag_T_PartitionSpec => {
+            serde::Serialize::serialize(&*node.cast::<PartitionSpec>(), serializer)
+        }
+        pg_sys::NodeTag_T_PartitionedRelPruneInfo => {
+            serde::Serialize::serialize(&*node.cast::<PartitionedRelPruneInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_Path => serde::Serialize::serialize(&*node.cast::<Path>(), serializer),
+        pg_sys::NodeTag_T_PathKey => {
+            serde::Serialize::serialize(&*node.cast::<PathKey>(), serializer)
+        }
+        pg_sys::NodeTag_T_PathTarget => {
+            serde::Serialize::serialize(&*node.cast::<PathTarget>(), serializer)
+        }
+        pg_sys::NodeTag_T_PlaceHolderInfo => {
+            serde::Serialize::serialize(&*node.cast::<PlaceHolderInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_PlaceHolderVar => {
+            serde::Serialize::serialize(&*node.cast::<PlaceHolderVar>(), serializer)
+        }
+        pg_sys::NodeTag_T_Plan => serde::Serialize::serialize(&*node.cast::<Plan>(), serializer),
+        pg_sys::NodeTag_T_PlanInvalItem => {
+            serde::Serialize::serialize(&*node.cast::<PlanInvalItem>(), serializer)
+        }
+        pg_sys::NodeTag_T_PlanRowMark => {
+            serde::Serialize::serialize(&*node.cast::<PlanRowMark>(), serializer)
+        }
+        pg_sys::NodeTag_T_PlanState => {
+            serde::Serialize::serialize(&*node.cast::<PlanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_PlannedStmt => {
+            serde::Serialize::serialize(&*node.cast::<PlannedStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_PlannerGlobal => {
+            serde::Serialize::serialize(&*node.cast::<PlannerGlobal>(), serializer)
+        }
+        pg_sys::NodeTag_T_PlannerInfo => {
+            serde::Serialize::serialize(&*node.cast::<PlannerInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_PlannerParamItem => {
+            serde::Serialize::serialize(&*node.cast::<PlannerParamItem>(), serializer)
+        }
+        pg_sys::NodeTag_T_PrepareStmt => {
+            serde::Serialize::serialize(&*node.cast::<PrepareStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_ProjectSet => {
+            serde::Serialize::serialize(&*node.cast::<ProjectSet>(), serializer)
+        }
+        pg_sys::NodeTag_T_ProjectSetPath => {
+            serde::Serialize::serialize(&*node.cast::<ProjectSetPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_ProjectSetState => {
+            serde::Serialize::serialize(&*node.cast::<ProjectSetState>(), serializer)
+        }
+        pg_sys::NodeTag_T_ProjectionInfo => {
+            serde::Serialize::serialize(&*node.cast::<ProjectionInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_ProjectionPath => {
+            serde::Serialize::serialize(&*node.cast::<ProjectionPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_Query => serde::Serialize::serialize(&*node.cast::<Query>(), serializer),
+        pg_sys::NodeTag_T_RangeFunction => {
+            serde::Serialize::serialize(&*node.cast::<RangeFunction>(), serializer)
+        }
+        pg_sys::NodeTag_T_RangeSubselect => {
+            serde::Serialize::serialize(&*node.cast::<RangeSubselect>(), serializer)
+        }
+        pg_sys::NodeTag_T_RangeTableFunc => {
+            serde::Serialize::serialize(&*node.cast::<RangeTableFunc>(), serializer)
+        }
+        pg_sys::NodeTag_T_RangeTableFuncCol => {
+            serde::Serialize::serialize(&*node.cast::<RangeTableFuncCol>(), serializer)
+        }
+        pg_sys::NodeTag_T_RangeTableSample => {
+            serde::Serialize::serialize(&*node.cast::<RangeTableSample>(), serializer)
+        }
+        pg_sys::NodeTag_T_RangeTblEntry => {
+            serde::Serialize::serialize(&*node.cast::<RangeTblEntry>(), serializer)
+        }
+        pg_sys::NodeTag_T_RangeTblFunction => {
+            serde::Serialize::serialize(&*node.cast::<RangeTblFunction>(), serializer)
+        }
+        pg_sys::NodeTag_T_RangeTblRef => {
+            serde::Serialize::serialize(&*node.cast::<RangeTblRef>(), serializer)
+        }
+        pg_sys::NodeTag_T_RangeVar => {
+            serde::Serialize::serialize(&*node.cast::<RangeVar>(), serializer)
+        }
+        pg_sys::NodeTag_T_RawStmt => {
+            serde::Serialize::serialize(&*node.cast::<RawStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_ReassignOwnedStmt => {
+            serde::Serialize::serialize(&*node.cast::<ReassignOwnedStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_RecursiveUnion => {
+            serde::Serialize::serialize(&*node.cast::<RecursiveUnion>(), serializer)
+        }
+        pg_sys::NodeTag_T_RecursiveUnionPath => {
+            serde::Serialize::serialize(&*node.cast::<RecursiveUnionPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_RecursiveUnionState => {
+            serde::Serialize::serialize(&*node.cast::<RecursiveUnionState>(), serializer)
+        }
+        pg_sys::NodeTag_T_RefreshMatViewStmt => {
+            serde::Serialize::serialize(&*node.cast::<RefreshMatViewStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_ReindexStmt => {
+            serde::Serialize::serialize(&*node.cast::<ReindexStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_RelOptInfo => {
+            serde::Serialize::serialize(&*node.cast::<RelOptInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_RelabelType => {
+            serde::Serialize::serialize(&*node.cast::<RelabelType>(), serializer)
+        }
+        pg_sys::NodeTag_T_RenameStmt => {
+            serde::Serialize::serialize(&*node.cast::<RenameStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_ReplicaIdentityStmt => {
+            serde::Serialize::serialize(&*node.cast::<ReplicaIdentityStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_ResTarget => {
+            serde::Serialize::serialize(&*node.cast::<ResTarget>(), serializer)
+        }
+        pg_sys::NodeTag_T_RestrictInfo => {
+            serde::Serialize::serialize(&*node.cast::<RestrictInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_Result => {
+            serde::Serialize::serialize(&*node.cast::<Result>(), serializer)
+        }
+        pg_sys::NodeTag_T_ResultRelInfo => {
+            serde::Serialize::serialize(&*node.cast::<ResultRelInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_ResultState => {
+            serde::Serialize::serialize(&*node.cast::<ResultState>(), serializer)
+        }
+        pg_sys::NodeTag_T_ReturnSetInfo => {
+            serde::Serialize::serialize(&*node.cast::<ReturnSetInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_RoleSpec => {
+            serde::Serialize::serialize(&*node.cast::<RoleSpec>(), serializer)
+        }
+        pg_sys::NodeTag_T_RollupData => {
+            serde::Serialize::serialize(&*node.cast::<RollupData>(), serializer)
+        }
+        pg_sys::NodeTag_T_RowCompareExpr => {
+            serde::Serialize::serialize(&*node.cast::<RowCompareExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_RowExpr => {
+            serde::Serialize::serialize(&*node.cast::<RowExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_RowMarkClause => {
+            serde::Serialize::serialize(&*node.cast::<RowMarkClause>(), serializer)
+        }
+        pg_sys::NodeTag_T_RuleStmt => {
+            serde::Serialize::serialize(&*node.cast::<RuleStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_SQLCmd => {
+            serde::Serialize::serialize(&*node.cast::<SQLCmd>(), serializer)
+        }
+        pg_sys::NodeTag_T_SQLValueFunction => {
+            serde::Serialize::serialize(&*node.cast::<SQLValueFunction>(), serializer)
+        }
+        pg_sys::NodeTag_T_SampleScan => {
+            serde::Serialize::serialize(&*node.cast::<SampleScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_SampleScanState => {
+            serde::Serialize::serialize(&*node.cast::<SampleScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_ScalarArrayOpExpr => {
+            serde::Serialize::serialize(&*node.cast::<ScalarArrayOpExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_Scan => serde::Serialize::serialize(&*node.cast::<Scan>(), serializer),
+        pg_sys::NodeTag_T_ScanState => {
+            serde::Serialize::serialize(&*node.cast::<ScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_SecLabelStmt => {
+            serde::Serialize::serialize(&*node.cast::<SecLabelStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_SelectStmt => {
+            serde::Serialize::serialize(&*node.cast::<SelectStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_SeqScanState => {
+            serde::Serialize::serialize(&*node.cast::<SeqScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_SetExprState => {
+            serde::Serialize::serialize(&*node.cast::<SetExprState>(), serializer)
+        }
+        pg_sys::NodeTag_T_SetOp => serde::Serialize::serialize(&*node.cast::<SetOp>(), serializer),
+        pg_sys::NodeTag_T_SetOpPath => {
+            serde::Serialize::serialize(&*node.cast::<SetOpPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_SetOpState => {
+            serde::Serialize::serialize(&*node.cast::<SetOpState>(), serializer)
+        }
+        pg_sys::NodeTag_T_SetOperationStmt => {
+            serde::Serialize::serialize(&*node.cast::<SetOperationStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_SetToDefault => {
+            serde::Serialize::serialize(&*node.cast::<SetToDefault>(), serializer)
+        }
+        pg_sys::NodeTag_T_Sort => serde::Serialize::serialize(&*node.cast::<Sort>(), serializer),
+        pg_sys::NodeTag_T_SortBy => {
+            serde::Serialize::serialize(&*node.cast::<SortBy>(), serializer)
+        }
+        pg_sys::NodeTag_T_SortGroupClause => {
+            serde::Serialize::serialize(&*node.cast::<SortGroupClause>(), serializer)
+        }
+        pg_sys::NodeTag_T_SortPath => {
+            serde::Serialize::serialize(&*node.cast::<SortPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_SortState => {
+            serde::Serialize::serialize(&*node.cast::<SortState>(), serializer)
+        }
+        pg_sys::NodeTag_T_SpecialJoinInfo => {
+            serde::Serialize::serialize(&*node.cast::<SpecialJoinInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_StartReplicationCmd => {
+            serde::Serialize::serialize(&*node.cast::<StartReplicationCmd>(), serializer)
+        }
+        pg_sys::NodeTag_T_StatisticExtInfo => {
+            serde::Serialize::serialize(&*node.cast::<StatisticExtInfo>(), serializer)
+        }
+        pg_sys::NodeTag_T_SubLink => {
+            serde::Serialize::serialize(&*node.cast::<SubLink>(), serializer)
+        }
+        pg_sys::NodeTag_T_SubPlan => {
+            serde::Serialize::serialize(&*node.cast::<SubPlan>(), serializer)
+        }
+        pg_sys::NodeTag_T_SubPlanState => {
+            serde::Serialize::serialize(&*node.cast::<SubPlanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_SubqueryScan => {
+            serde::Serialize::serialize(&*node.cast::<SubqueryScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_SubqueryScanPath => {
+            serde::Serialize::serialize(&*node.cast::<SubqueryScanPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_SubqueryScanState => {
+            serde::Serialize::serialize(&*node.cast::<SubqueryScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_SubscriptingRef => {
+            serde::Serialize::serialize(&*node.cast::<SubscriptingRef>(), serializer)
+        }
+        pg_sys::NodeTag_T_SupportRequestCost => {
+            serde::Serialize::serialize(&*node.cast::<SupportRequestCost>(), serializer)
+        }
+        pg_sys::NodeTag_T_SupportRequestIndexCondition => {
+            serde::Serialize::serialize(&*node.cast::<SupportRequestIndexCondition>(), serializer)
+        }
+        pg_sys::NodeTag_T_SupportRequestRows => {
+            serde::Serialize::serialize(&*node.cast::<SupportRequestRows>(), serializer)
+        }
+        pg_sys::NodeTag_T_SupportRequestSelectivity => {
+            serde::Serialize::serialize(&*node.cast::<SupportRequestSelectivity>(), serializer)
+        }
+        pg_sys::NodeTag_T_SupportRequestSimplify => {
+            serde::Serialize::serialize(&*node.cast::<SupportRequestSimplify>(), serializer)
+        }
+        pg_sys::NodeTag_T_TableAmRoutine => {
+            serde::Serialize::serialize(&*node.cast::<TableAmRoutine>(), serializer)
+        }
+        pg_sys::NodeTag_T_TableFunc => {
+            serde::Serialize::serialize(&*node.cast::<TableFunc>(), serializer)
+        }
+        pg_sys::NodeTag_T_TableFuncScan => {
+            serde::Serialize::serialize(&*node.cast::<TableFuncScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_TableFuncScanState => {
+            serde::Serialize::serialize(&*node.cast::<TableFuncScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_TableLikeClause => {
+            serde::Serialize::serialize(&*node.cast::<TableLikeClause>(), serializer)
+        }
+        pg_sys::NodeTag_T_TableSampleClause => {
+            serde::Serialize::serialize(&*node.cast::<TableSampleClause>(), serializer)
+        }
+        pg_sys::NodeTag_T_TargetEntry => {
+            serde::Serialize::serialize(&*node.cast::<TargetEntry>(), serializer)
+        }
+        pg_sys::NodeTag_T_TidPath => {
+            serde::Serialize::serialize(&*node.cast::<TidPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_TidScan => {
+            serde::Serialize::serialize(&*node.cast::<TidScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_TidScanState => {
+            serde::Serialize::serialize(&*node.cast::<TidScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_TimeLineHistoryCmd => {
+            serde::Serialize::serialize(&*node.cast::<TimeLineHistoryCmd>(), serializer)
+        }
+        pg_sys::NodeTag_T_TransactionStmt => {
+            serde::Serialize::serialize(&*node.cast::<TransactionStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_TriggerData => {
+            serde::Serialize::serialize(&*node.cast::<TriggerData>(), serializer)
+        }
+        pg_sys::NodeTag_T_TriggerTransition => {
+            serde::Serialize::serialize(&*node.cast::<TriggerTransition>(), serializer)
+        }
+        pg_sys::NodeTag_T_TruncateStmt => {
+            serde::Serialize::serialize(&*node.cast::<TruncateStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_TupleTableSlot => {
+            serde::Serialize::serialize(&*node.cast::<TupleTableSlot>(), serializer)
+        }
+        pg_sys::NodeTag_T_TypeCast => {
+            serde::Serialize::serialize(&*node.cast::<TypeCast>(), serializer)
+        }
+        pg_sys::NodeTag_T_TypeName => {
+            serde::Serialize::serialize(&*node.cast::<TypeName>(), serializer)
+        }
+        pg_sys::NodeTag_T_Unique => {
+            serde::Serialize::serialize(&*node.cast::<Unique>(), serializer)
+        }
+        pg_sys::NodeTag_T_UniquePath => {
+            serde::Serialize::serialize(&*node.cast::<UniquePath>(), serializer)
+        }
+        pg_sys::NodeTag_T_UniqueState => {
+            serde::Serialize::serialize(&*node.cast::<UniqueState>(), serializer)
+        }
+        pg_sys::NodeTag_T_UnlistenStmt => {
+            serde::Serialize::serialize(&*node.cast::<UnlistenStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_UpdateStmt => {
+            serde::Serialize::serialize(&*node.cast::<UpdateStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_UpperUniquePath => {
+            serde::Serialize::serialize(&*node.cast::<UpperUniquePath>(), serializer)
+        }
+        pg_sys::NodeTag_T_VacuumRelation => {
+            serde::Serialize::serialize(&*node.cast::<VacuumRelation>(), serializer)
+        }
+        pg_sys::NodeTag_T_VacuumStmt => {
+            serde::Serialize::serialize(&*node.cast::<VacuumStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_Value => serde::Serialize::serialize(&*node.cast::<Value>(), serializer),
+        pg_sys::NodeTag_T_ValuesScan => {
+            serde::Serialize::serialize(&*node.cast::<ValuesScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_ValuesScanState => {
+            serde::Serialize::serialize(&*node.cast::<ValuesScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_Var => serde::Serialize::serialize(&*node.cast::<Var>(), serializer),
+        pg_sys::NodeTag_T_VariableSetStmt => {
+            serde::Serialize::serialize(&*node.cast::<VariableSetStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_VariableShowStmt => {
+            serde::Serialize::serialize(&*node.cast::<VariableShowStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_ViewStmt => {
+            serde::Serialize::serialize(&*node.cast::<ViewStmt>(), serializer)
+        }
+        pg_sys::NodeTag_T_WindowAgg => {
+            serde::Serialize::serialize(&*node.cast::<WindowAgg>(), serializer)
+        }
+        pg_sys::NodeTag_T_WindowAggPath => {
+            serde::Serialize::serialize(&*node.cast::<WindowAggPath>(), serializer)
+        }
+        pg_sys::NodeTag_T_WindowAggState => {
+            serde::Serialize::serialize(&*node.cast::<WindowAggState>(), serializer)
+        }
+        pg_sys::NodeTag_T_WindowClause => {
+            serde::Serialize::serialize(&*node.cast::<WindowClause>(), serializer)
+        }
+        pg_sys::NodeTag_T_WindowDef => {
+            serde::Serialize::serialize(&*node.cast::<WindowDef>(), serializer)
+        }
+        pg_sys::NodeTag_T_WindowFunc => {
+            serde::Serialize::serialize(&*node.cast::<WindowFunc>(), serializer)
+        }
+        pg_sys::NodeTag_T_WindowFuncExprState => {
+            serde::Serialize::serialize(&*node.cast::<WindowFuncExprState>(), serializer)
+        }
+        pg_sys::NodeTag_T_WithCheckOption => {
+            serde::Serialize::serialize(&*node.cast::<WithCheckOption>(), serializer)
+        }
+        pg_sys::NodeTag_T_WithClause => {
+            serde::Serialize::serialize(&*node.cast::<WithClause>(), serializer)
+        }
+        pg_sys::NodeTag_T_WorkTableScan => {
+            serde::Serialize::serialize(&*node.cast::<WorkTableScan>(), serializer)
+        }
+        pg_sys::NodeTag_T_WorkTableScanState => {
+            serde::Serialize::serialize(&*node.cast::<WorkTableScanState>(), serializer)
+        }
+        pg_sys::NodeTag_T_XmlExpr => {
+            serde::Serialize::serialize(&*node.cast::<XmlExpr>(), serializer)
+        }
+        pg_sys::NodeTag_T_XmlSerialize => {
+            serde::Serialize::serialize(&*node.cast::<XmlSerialize>(), serializer)
+        }
+        _ => pg_sys::node_serde::serialize_untyped_node(node, serializer),
+    }
+}
@@ -70,6 +70,10 @@ impl From<isize> for PgLogLevel {
             PgLogLevel::DEBUG2
         } else if i == PgLogLevel::DEBUG1 as isize {
             PgLogLevel::DEBUG1
+        } else if i == PgLogLevel::LOG as isize {
+            PgLogLevel::LOG
+        } else if i == PgLogLevel::LOG_SERVER_ONLY as isize {
+            PgLogLevel::LOG_SERVER_ONLY
         } else if i == PgLogLevel::INFO as isize {
             PgLogLevel::INFO
         } else if i == PgLogLevel::NOTICE as isize {
@@ -12,6 +12,7 @@ pub mod datum;
 pub mod elog;
 pub mod errcodes;
 pub mod ffi;
+pub mod node_serde;
 pub mod oids;
 pub mod panic;
 pub mod pg_try;
@@ -0,0 +1,188 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Support for the `serde::Serialize` impls that `build.rs` generates for `Node` structs
+//!
+//! Each node serializes as a struct named by a `"node"` field, followed by the fields whose
+//! types can be represented generically: scalars, `Datum`s, strings, and other nodes.
+//! Pointers to nodes are followed and serialized as whatever type the pointed-to node's tag
+//! says it is, so serializing a `Query` or a `PlannedStmt` dumps the entire tree.  A pointer back
+//! to a node that's already being serialized, further up the tree, serializes as a `Ref` instead.
+
+use crate as pg_sys;
+use serde::ser::{SerializeSeq, SerializeStruct};
+use serde::{Serialize, Serializer};
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+thread_local! {
+    /// The nodes being serialized, from the root of the tree down
+    static ANCESTORS: RefCell<Vec<*const pg_sys::Node>> = RefCell::new(Vec::new());
+}
+
+/// A possibly-null pointer to a `Node` that serializes as the node it points to, or as `None`
+///
+/// In trees with cycles, such as the planner's `RelOptInfo`s and `Path`s, a pointer back to one
+/// of the node's ancestors serializes as `{"node": "Ref", "up": n}`, where `n` is how many levels
+/// up the tree that ancestor is: `0` for the node holding the pointer, `1` for its parent, and so
+/// on.  Nodes that are merely shared between branches of the tree are serialized each time.
+#[derive(Debug, Copy, Clone)]
+pub struct NodePtr(pub(crate) *const pg_sys::Node);
+
+impl NodePtr {
+    /// # Safety
+    ///
+    /// `node` must be null or point to a valid `Node` whose tree remains valid for as long
+    /// as the returned `NodePtr` is used.
+    pub unsafe fn new(node: *const pg_sys::Node) -> Self {
+        NodePtr(node)
+    }
+}
+
+impl Serialize for NodePtr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.is_null() {
+            return serializer.serialize_none();
+        }
+
+        let up = ANCESTORS.with(|ancestors| {
+            let ancestors = ancestors.borrow();
+            ancestors.iter().rev().position(|&ancestor| ancestor == self.0)
+        });
+        if let Some(up) = up {
+            let mut state = serializer.serialize_struct("Ref", 2)?;
+            state.serialize_field("node", "Ref")?;
+            state.serialize_field("up", &up)?;
+            return state.end();
+        }
+
+        ANCESTORS.with(|ancestors| ancestors.borrow_mut().push(self.0));
+        let _ancestor = PopAncestor;
+
+        // SAFETY: NodePtrs are only created for valid nodes, or from the fields of a node
+        // (which are themselves valid)
+        unsafe {
+            pg_sys::check_stack_depth();
+            pg_sys::serialize_node(self.0, serializer)
+        }
+    }
+}
+
+/// Pops the node pushed onto [`ANCESTORS`] once it's serialized, or an error unwinds past it
+struct PopAncestor;
+
+impl Drop for PopAncestor {
+    fn drop(&mut self) {
+        ANCESTORS.with(|ancestors| ancestors.borrow_mut().pop());
+    }
+}
+
+/// A possibly-null `char *` that serializes as a string, or as `None`
+pub(crate) struct CStringPtr(pub(crate) *const c_char);
+
+impl Serialize for CStringPtr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.is_null() {
+            serializer.serialize_none()
+        } else {
+            // SAFETY: strings in a node are null-terminated
+            serializer.serialize_str(&unsafe { CStr::from_ptr(self.0) }.to_string_lossy())
+        }
+    }
+}
+
+/// Serialize the nodes whose tag doesn't name a struct of their own
+pub(crate) unsafe fn serialize_untyped_node<S: Serializer>(
+    node: *const pg_sys::Node,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match (*node).type_ {
+        pg_sys::NodeTag_T_IntList | pg_sys::NodeTag_T_OidList => {
+            (*node.cast::<pg_sys::List>()).serialize(serializer)
+        }
+        #[cfg(any(feature = "pg11", feature = "pg12", feature = "pg13", feature = "pg14"))]
+        pg_sys::NodeTag_T_Integer
+        | pg_sys::NodeTag_T_Float
+        | pg_sys::NodeTag_T_String
+        | pg_sys::NodeTag_T_BitString
+        | pg_sys::NodeTag_T_Null => (*node.cast::<pg_sys::Value>()).serialize(serializer),
+        tag => {
+            // there's nothing more we can say about it
+            let mut state = serializer.serialize_struct("Node", 2)?;
+            state.serialize_field("node", "Node")?;
+            state.serialize_field("tag", &tag)?;
+            state.end()
+        }
+    }
+}
+
+/// A `List`, `IntList`, or `OidList` serializes as a sequence of its elements
+impl Serialize for pg_sys::List {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.length as usize))?;
+
+        // SAFETY: a List has `length` cells, which hold nodes, ints, or oids according to its tag
+        unsafe {
+            #[cfg(any(feature = "pg11", feature = "pg12"))]
+            let cells = {
+                let mut cells = Vec::with_capacity(self.length as usize);
+                let mut cell = self.head;
+                while !cell.is_null() {
+                    cells.push((*cell).data);
+                    cell = (*cell).next;
+                }
+                cells
+            };
+            #[cfg(any(feature = "pg13", feature = "pg14", feature = "pg15"))]
+            let cells: &[pg_sys::ListCell] = if self.elements.is_null() {
+                &[]
+            } else {
+                std::slice::from_raw_parts(self.elements, self.length as usize)
+            };
+
+            for cell in cells.iter() {
+                match self.type_ {
+                    pg_sys::NodeTag_T_IntList => seq.serialize_element(&cell.int_value)?,
+                    pg_sys::NodeTag_T_OidList => seq.serialize_element(&cell.oid_value)?,
+                    _ => seq.serialize_element(&NodePtr(cell.ptr_value.cast()))?,
+                }
+            }
+        }
+
+        seq.end()
+    }
+}
+
+/// Before Postgres 15 a `Value` is an `Integer`, `Float`, `String`, `BitString`, or `Null`
+/// depending on its tag.  It serializes the same way as those nodes do in Postgres 15.
+#[cfg(any(feature = "pg11", feature = "pg12", feature = "pg13", feature = "pg14"))]
+impl Serialize for pg_sys::Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (node, field) = match self.type_ {
+            pg_sys::NodeTag_T_Integer => ("Integer", Some("ival")),
+            pg_sys::NodeTag_T_Float => ("Float", Some("fval")),
+            pg_sys::NodeTag_T_String => ("String", Some("sval")),
+            pg_sys::NodeTag_T_BitString => ("BitString", Some("bsval")),
+            _ => ("Null", None),
+        };
+
+        let mut state = serializer.serialize_struct(node, if field.is_some() { 2 } else { 1 })?;
+        state.serialize_field("node", node)?;
+        // SAFETY: the union holds an integer for `Integer`s, and a string otherwise
+        unsafe {
+            match field {
+                Some("ival") => state.serialize_field("ival", &self.val.ival)?,
+                Some(field) => state.serialize_field(field, &CStringPtr(self.val.str_))?,
+                None => {}
+            }
+        }
+        state.end()
+    }
+}
@@ -74,6 +74,7 @@ pub struct ErrorReport {
     pub(crate) sqlerrcode: PgSqlErrorCode,
     pub(crate) message: String,
     pub(crate) detail: Option<String>,
+    pub(crate) hint: Option<String>,
     pub(crate) location: ErrorReportLocation,
 }
 
@@ -101,6 +102,27 @@ impl ErrorReportWithLevel {
         self.inner.message()
     }
 
+    /// Returns the level at which this error report was raised
+    pub fn level(&self) -> PgLogLevel {
+        self.level
+    }
+
+    /// Returns the SQLSTATE of this error report
+    pub fn sql_error_code(&self) -> PgSqlErrorCode {
+        self.inner.sqlerrcode
+    }
+
+    /// Returns the detail message of this error report, if any
+    pub fn detail(&self) -> Option<&str> {
+        self.inner.detail.as_deref()
+    }
+
+    /// Returns the hint of this error report, if any.  Only errors raised from within Postgres
+    /// carry a hint
+    pub fn hint(&self) -> Option<&str> {
+        self.inner.hint.as_deref()
+    }
+
     fn context_message(&self) -> String {
         self.inner.location.to_string()
     }
@@ -120,7 +142,7 @@ impl ErrorReport {
         let mut location: ErrorReportLocation = Location::caller().into();
         location.funcname = Some(funcname.to_string());
 
-        Self { sqlerrcode, message: message.into(), detail: None, location }
+        Self { sqlerrcode, message: message.into(), detail: None, hint: None, location }
     }
 
     /// Create a [PgErrorReport] which can be raised via Rust's [std::panic::panic_any()] or as
@@ -132,7 +154,7 @@ impl ErrorReport {
         message: S,
         location: ErrorReportLocation,
     ) -> Self {
-        Self { sqlerrcode, message: message.into(), detail: None, location }
+        Self { sqlerrcode, message: message.into(), detail: None, hint: None, location }
     }
 
     /// Set the `detail` property, whose default is `None`
@@ -15,6 +15,7 @@ pub enum ExternArgs {
     ParallelSafe,
     ParallelUnsafe,
     ParallelRestricted,
+    Materialize,
     Error(String),
     Schema(String),
     Name(String),
@@ -34,6 +35,7 @@ impl core::fmt::Display for ExternArgs {
             ExternArgs::ParallelSafe => write!(f, "PARALLEL SAFE"),
             ExternArgs::ParallelUnsafe => write!(f, "PARALLEL UNSAFE"),
             ExternArgs::ParallelRestricted => write!(f, "PARALLEL RESTRICTED"),
+            ExternArgs::Materialize => Ok(()),
             ExternArgs::Error(_) => Ok(()),
             ExternArgs::NoGuard => Ok(()),
             ExternArgs::Schema(_) => Ok(()),
@@ -57,6 +59,7 @@ impl ToTokens for ExternArgs {
             ExternArgs::ParallelSafe => tokens.append(format_ident!("ParallelSafe")),
             ExternArgs::ParallelUnsafe => tokens.append(format_ident!("ParallelUnsafe")),
             ExternArgs::ParallelRestricted => tokens.append(format_ident!("ParallelRestricted")),
+            ExternArgs::Materialize => tokens.append(format_ident!("Materialize")),
             ExternArgs::Error(_s) => {
                 tokens.append_all(
                     quote! {
@@ -124,6 +127,7 @@ pub fn parse_extern_attributes(attr: TokenStream) -> HashSet<ExternArgs> {
                     "parallel_safe" => args.insert(ExternArgs::ParallelSafe),
                     "parallel_unsafe" => args.insert(ExternArgs::ParallelUnsafe),
                     "parallel_restricted" => args.insert(ExternArgs::ParallelRestricted),
+                    "materialize" => args.insert(ExternArgs::Materialize),
                     "error" => {
                         let _punc = itr.next().unwrap();
                         let literal = itr.next().unwrap();
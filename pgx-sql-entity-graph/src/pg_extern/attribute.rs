@@ -34,6 +34,7 @@ pub enum Attribute {
     ParallelSafe,
     ParallelUnsafe,
     ParallelRestricted,
+    Materialize,
     Error(syn::LitStr),
     Schema(syn::LitStr),
     Name(syn::LitStr),
@@ -67,6 +68,9 @@ impl Attribute {
             Attribute::ParallelRestricted => {
                 quote! { ::pgx::pgx_sql_entity_graph::ExternArgs::ParallelRestricted }
             }
+            Attribute::Materialize => {
+                quote! { ::pgx::pgx_sql_entity_graph::ExternArgs::Materialize }
+            }
             Attribute::Error(s) => {
                 quote! { ::pgx::pgx_sql_entity_graph::ExternArgs::Error(String::from(#s)) }
             }
@@ -110,6 +114,9 @@ impl ToTokens for Attribute {
             Attribute::ParallelRestricted => {
                 quote! { parallel_restricted }
             }
+            Attribute::Materialize => {
+                quote! { materialize }
+            }
             Attribute::Error(s) => {
                 quote! { error = #s }
             }
@@ -149,6 +156,7 @@ impl Parse for Attribute {
             "parallel_safe" => Self::ParallelSafe,
             "parallel_unsafe" => Self::ParallelUnsafe,
             "parallel_restricted" => Self::ParallelRestricted,
+            "materialize" => Self::Materialize,
             "error" => {
                 let _eq: Token![=] = input.parse()?;
                 let literal: syn::LitStr = input.parse()?;
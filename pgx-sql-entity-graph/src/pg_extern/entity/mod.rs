@@ -49,6 +49,16 @@ pub struct PgExternEntity {
     pub search_path: Option<Vec<&'static str>>,
     pub operator: Option<PgOperatorEntity>,
     pub to_sql_config: ToSqlConfigEntity,
+    pub kind: PgExternKind,
+}
+
+/// Whether a [`PgExternEntity`] is created as a `FUNCTION` or a `PROCEDURE`
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PgExternKind {
+    /// A `#[pg_extern]`, created with `CREATE FUNCTION`
+    Function,
+    /// A `#[pg_procedure]`, created with `CREATE PROCEDURE` and invoked with `CALL`
+    Procedure,
 }
 
 impl std::hash::Hash for PgExternEntity {
@@ -85,7 +95,10 @@ impl From<PgExternEntity> for SqlGraphEntity {
 
 impl SqlGraphIdentifier for PgExternEntity {
     fn dot_identifier(&self) -> String {
-        format!("fn {}", self.name)
+        match self.kind {
+            PgExternKind::Function => format!("fn {}", self.name),
+            PgExternKind::Procedure => format!("procedure {}", self.name),
+        }
     }
     fn rust_identifier(&self) -> String {
         self.full_path.to_string()
@@ -110,8 +123,9 @@ impl ToSql for PgExternEntity {
         let self_index = context.externs[self];
         let mut extern_attrs = self.extern_attrs.clone();
         // if we already have a STRICT marker we do not need to add it
-        // presume we can upgrade, then disprove it
-        let mut strict_upgrade = !extern_attrs.iter().any(|i| i == &ExternArgs::Strict);
+        // presume we can upgrade, then disprove it.  Procedures can't be `STRICT` at all
+        let mut strict_upgrade = self.kind == PgExternKind::Function
+            && !extern_attrs.iter().any(|i| i == &ExternArgs::Strict);
         if strict_upgrade {
             // It may be possible to infer a `STRICT` marker though.
             // But we can only do that if the user hasn't used `Option<T>` or `pgx::Internal`
@@ -132,13 +146,17 @@ impl ToSql for PgExternEntity {
 
         let fn_sql = format!(
             "\
-                                CREATE {or_replace} FUNCTION {schema}\"{name}\"({arguments}) {returns}\n\
+                                CREATE {or_replace} {kind} {schema}\"{name}\"({arguments}) {returns}\n\
                                 {extern_attrs}\
                                 {search_path}\
                                 LANGUAGE c /* Rust */\n\
                                 AS '{module_pathname}', '{unaliased_name}_wrapper';\
                             ",
             or_replace = if extern_attrs.contains(&ExternArgs::CreateOrReplace) { "OR REPLACE" } else { "" },
+            kind = match self.kind {
+                PgExternKind::Function => "FUNCTION",
+                PgExternKind::Procedure => "PROCEDURE",
+            },
             schema = self
                 .schema
                 .map(|schema| format!("{}.", schema))
@@ -275,6 +293,8 @@ impl ToSql for PgExternEntity {
                 Default::default()
             },
             returns = match &self.fn_return {
+                // procedures don't have a return type
+                PgExternReturnEntity::None if self.kind == PgExternKind::Procedure => String::new(),
                 PgExternReturnEntity::None => String::from("RETURNS void"),
                 PgExternReturnEntity::Type { ty } => {
                     let graph_index = context
@@ -26,7 +26,7 @@ pub use argument::PgExternArgument;
 pub use operator::PgOperator;
 pub use returning::NameMacro;
 
-use crate::ToSqlConfig;
+use crate::{PgExternKind, ToSqlConfig};
 use attribute::Attribute;
 use operator::{PgxOperatorAttributeWithIdent, PgxOperatorOpName};
 use search_path::SearchPathList;
@@ -78,6 +78,7 @@ pub struct PgExtern {
     inputs: Vec<PgExternArgument>,
     input_types: Vec<syn::Type>,
     returns: Returning,
+    kind: PgExternKind,
 }
 
 impl PgExtern {
@@ -118,6 +119,16 @@ impl PgExtern {
         let inputs = Self::inputs(&func)?;
         let input_types = Self::input_types(&func)?;
         let returns = Returning::try_from(&func.sig.output)?;
+
+        if attrs.iter().any(|attr| matches!(attr, Attribute::Materialize))
+            && !matches!(returns, Returning::SetOf { .. } | Returning::Iterated { .. })
+        {
+            return Err(syn::Error::new(
+                func.sig.output.span(),
+                "`materialize` requires the function to return a `SetOfIterator` or `TableIterator`",
+            ));
+        }
+
         Ok(CodeEnrichment(Self {
             attrs,
             func,
@@ -127,9 +138,61 @@ impl PgExtern {
             inputs,
             input_types,
             returns,
+            kind: PgExternKind::Function,
         }))
     }
 
+    /// Parse a `#[pg_procedure]`, which is created with `CREATE PROCEDURE` rather than
+    /// `CREATE FUNCTION`.
+    ///
+    /// Procedures can't return a value, and don't accept the attributes that only make sense for
+    /// functions, such as volatility, strictness, parallel safety, cost, or operator declarations.
+    pub fn procedure(
+        attr: TokenStream2,
+        item: TokenStream2,
+    ) -> Result<CodeEnrichment<Self>, syn::Error> {
+        let CodeEnrichment(mut procedure) = Self::new(attr, item)?;
+
+        if !matches!(procedure.returns, Returning::None) {
+            return Err(syn::Error::new(
+                procedure.func.sig.output.span(),
+                "`#[pg_procedure]` functions cannot return a value",
+            ));
+        }
+
+        if procedure.operator.is_some() {
+            return Err(syn::Error::new(
+                procedure.func.sig.span(),
+                "`#[pg_procedure]` functions cannot be operators",
+            ));
+        }
+
+        for attr in &procedure.attrs {
+            match attr {
+                Attribute::Immutable
+                | Attribute::Strict
+                | Attribute::Stable
+                | Attribute::Volatile
+                | Attribute::ParallelSafe
+                | Attribute::ParallelUnsafe
+                | Attribute::ParallelRestricted
+                | Attribute::Cost(_) => {
+                    return Err(syn::Error::new(
+                        procedure.func.sig.span(),
+                        format!(
+                            "`{}` is not a valid attribute for `#[pg_procedure]`",
+                            quote! { #attr }
+                        ),
+                    ))
+                }
+                _ => (),
+            }
+        }
+
+        procedure.kind = PgExternKind::Procedure;
+        Ok(CodeEnrichment(procedure))
+    }
+
     fn input_types(func: &syn::ItemFn) -> syn::Result<Vec<syn::Type>> {
         func.sig
             .inputs
@@ -303,6 +366,15 @@ impl PgExtern {
             }
         };
 
+        let kind = match self.kind {
+            PgExternKind::Function => {
+                quote! { ::pgx::pgx_sql_entity_graph::PgExternKind::Function }
+            }
+            PgExternKind::Procedure => {
+                quote! { ::pgx::pgx_sql_entity_graph::PgExternKind::Procedure }
+            }
+        };
+
         let sql_graph_entity_fn_name =
             syn::Ident::new(&format!("__pgx_internals_fn_{}", ident), Span::call_site());
         quote_spanned! { self.func.sig.span() =>
@@ -332,6 +404,7 @@ impl PgExtern {
                     #[allow(clippy::or_fun_call)]
                     operator: None #( .unwrap_or_else(|| Some(#operator)) )*,
                     to_sql_config: #to_sql_config,
+                    kind: #kind,
                 };
                 ::pgx::pgx_sql_entity_graph::SqlGraphEntity::Function(submission)
             }
@@ -361,9 +434,20 @@ impl PgExtern {
         );
         let func_generics = &self.func.sig.generics;
         let is_raw = self.extern_attrs().contains(&Attribute::Raw);
+        let is_materialize = self.extern_attrs().contains(&Attribute::Materialize);
         // We use a `_` prefix to make functions with no args more satisfied during linting.
         let fcinfo_ident = syn::Ident::new("_fcinfo", self.func.sig.ident.span());
 
+        // `stable` and `immutable` functions get read-only SPI connections while they run
+        let read_only = self.kind == PgExternKind::Function
+            && self
+                .attrs
+                .iter()
+                .any(|attr| matches!(attr, Attribute::Immutable | Attribute::Stable));
+        let read_only_scope = quote_spanned! { self.func.sig.span() =>
+            let _read_only_scope = ::pgx::spi::ReadOnlyScope::enter(#read_only);
+        };
+
         let args = &self.inputs;
         let arg_pats = args
             .iter()
@@ -405,6 +489,8 @@ impl PgExtern {
                   #[doc(hidden)]
                   #[::pgx::pgx_macros::pg_guard]
                   pub unsafe extern "C" fn #func_name_wrapper #func_generics(#fcinfo_ident: ::pgx::pg_sys::FunctionCallInfo) {
+                      #read_only_scope
+
                       #(
                           #arg_fetches
                       )*
@@ -446,6 +532,8 @@ impl PgExtern {
                     #[doc(hidden)]
                     #[::pgx::pgx_macros::pg_guard]
                     pub unsafe extern "C" fn #func_name_wrapper #func_generics(#fcinfo_ident: ::pgx::pg_sys::FunctionCallInfo) -> ::pgx::pg_sys::Datum {
+                        #read_only_scope
+
                         #(
                             #arg_fetches
                         )*
@@ -471,12 +559,39 @@ impl PgExtern {
                     }
                 };
 
+                if is_materialize {
+                    return quote_spanned! { self.func.sig.span() =>
+                        #[no_mangle]
+                        #[doc(hidden)]
+                        #[::pgx::pgx_macros::pg_guard]
+                        #[warn(unsafe_op_in_unsafe_fn)]
+                        pub unsafe extern "C" fn #func_name_wrapper #func_generics(#fcinfo_ident: ::pgx::pg_sys::FunctionCallInfo) -> ::pgx::pg_sys::Datum {
+                            #read_only_scope
+
+                            // SAFETY: every row is stored before we return, so the arguments only
+                            // need to live as long as this call
+                            unsafe {
+                                ::pgx::fcinfo::srf_materialize(
+                                    #fcinfo_ident,
+                                    || {
+                                        #( #arg_fetches )*
+                                        #result_handler
+                                    },
+                                    |#result_ident| [::pgx::datum::IntoDatum::into_datum(#result_ident)],
+                                )
+                            }
+                        }
+                    };
+                }
+
                 quote_spanned! { self.func.sig.span() =>
                     #[no_mangle]
                     #[doc(hidden)]
                     #[::pgx::pgx_macros::pg_guard]
                     #[warn(unsafe_op_in_unsafe_fn)]
                     pub unsafe extern "C" fn #func_name_wrapper #func_generics(#fcinfo_ident: ::pgx::pg_sys::FunctionCallInfo) -> ::pgx::pg_sys::Datum {
+                        #read_only_scope
+
                         struct IteratorHolder<'__pgx_internal_lifetime, T: std::panic::UnwindSafe + std::panic::RefUnwindSafe> {
                             iter: *mut ::pgx::iter::SetOfIterator<'__pgx_internal_lifetime, T>,
                         }
@@ -574,12 +689,40 @@ impl PgExtern {
                     }
                 };
 
+                if is_materialize {
+                    let retval_tuple_indexes = (0..retval_tys.len()).map(syn::Index::from);
+                    return quote_spanned! { self.func.sig.span() =>
+                        #[no_mangle]
+                        #[doc(hidden)]
+                        #[::pgx::pgx_macros::pg_guard]
+                        #[warn(unsafe_op_in_unsafe_fn)]
+                        pub unsafe extern "C" fn #func_name_wrapper #func_generics(#fcinfo_ident: ::pgx::pg_sys::FunctionCallInfo) -> ::pgx::pg_sys::Datum {
+                            #read_only_scope
+
+                            // SAFETY: every row is stored before we return, so the arguments only
+                            // need to live as long as this call
+                            unsafe {
+                                ::pgx::fcinfo::srf_materialize(
+                                    #fcinfo_ident,
+                                    || {
+                                        #( #arg_fetches )*
+                                        #result_handler
+                                    },
+                                    |#result_ident| [#( ::pgx::datum::IntoDatum::into_datum(#result_ident.#retval_tuple_indexes) ),*],
+                                )
+                            }
+                        }
+                    };
+                }
+
                 quote_spanned! { self.func.sig.span() =>
                     #[no_mangle]
                     #[doc(hidden)]
                     #[::pgx::pgx_macros::pg_guard]
                     #[warn(unsafe_op_in_unsafe_fn)]
                     pub unsafe extern "C" fn #func_name_wrapper #func_generics(#fcinfo_ident: ::pgx::pg_sys::FunctionCallInfo) -> ::pgx::pg_sys::Datum {
+                        #read_only_scope
+
                         struct IteratorHolder<'__pgx_internal_lifetime, T: std::panic::UnwindSafe + std::panic::RefUnwindSafe> {
                             iter: *mut ::pgx::iter::TableIterator<'__pgx_internal_lifetime, T>,
                         }
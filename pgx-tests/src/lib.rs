@@ -23,6 +23,8 @@ pub mod pg_test {
     }
 
     pub fn postgresql_conf_options() -> Vec<&'static str> {
-        vec![]
+        // enough worker slots for the parallel tests to always get theirs, alongside the
+        // background workers other tests launch at the same time
+        vec!["max_worker_processes = 32", "max_parallel_workers = 16"]
     }
 }
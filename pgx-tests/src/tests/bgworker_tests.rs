@@ -80,6 +80,19 @@ pub extern "C" fn bgworker_return_value(arg: pg_sys::Datum) {
     })
 }
 
+#[pg_guard]
+#[no_mangle]
+/// Reports how many numbers it was given, and their sum, unless it was given none
+pub extern "C" fn bgworker_payload(_arg: pg_sys::Datum) {
+    use pgx::bgworkers::*;
+    let numbers = BackgroundWorker::payload::<Vec<i64>>().expect("no payload");
+    if !numbers.is_empty() {
+        BackgroundWorker::set_result(&(numbers.len(), numbers.iter().sum::<i64>()))
+            .expect("couldn't report the result");
+        assert_eq!(BackgroundWorker::set_result(&(0usize, 0i64)), Err(SetResultError::AlreadySent));
+    }
+}
+
 #[cfg(any(test, feature = "pg_test"))]
 #[pgx::pg_schema]
 mod tests {
@@ -88,6 +101,7 @@ mod tests {
 
     use pgx::bgworkers::*;
     use pgx::prelude::*;
+    use pgx::shm_mq::ShmMqError;
     use pgx::{pg_sys, IntoDatum};
 
     #[pg_test]
@@ -161,4 +175,50 @@ mod tests {
                 .expect("no return value from the worker")
         );
     }
+
+    #[pg_test]
+    fn test_dynamic_bgworker_payload() {
+        // the first fits in the worker's `extra`, the second needs dynamic shared memory
+        for numbers in [vec![1i64, 2, 3], (1..=10_000).collect::<Vec<i64>>()] {
+            let mut worker = BackgroundWorkerBuilder::new("dynamic_bgworker")
+                .set_library("pgx_tests")
+                .set_function("bgworker_payload")
+                .with_payload(&numbers)
+                .enable_result()
+                .set_notify_pid(unsafe { pg_sys::MyProcPid })
+                .load_dynamic();
+            assert_eq!(
+                worker.wait_for_result::<(usize, i64)>(),
+                Ok((numbers.len(), numbers.iter().sum()))
+            );
+            // the worker's second result was refused
+            assert_eq!(worker.wait_for_result::<(usize, i64)>(), Err(ShmMqError::Detached));
+            worker.wait_for_shutdown().expect("aborted shutdown");
+        }
+    }
+
+    #[pg_test]
+    fn test_dynamic_bgworker_no_result() {
+        let mut worker = BackgroundWorkerBuilder::new("dynamic_bgworker")
+            .set_library("pgx_tests")
+            .set_function("bgworker_payload")
+            .with_payload(Vec::<i64>::new())
+            .enable_result()
+            .set_notify_pid(unsafe { pg_sys::MyProcPid })
+            .load_dynamic();
+        assert_eq!(worker.wait_for_result::<(usize, i64)>(), Err(ShmMqError::Detached));
+        worker.wait_for_shutdown().expect("aborted shutdown");
+    }
+
+    #[pg_test(
+        error = "`with_payload()` can't be combined with `set_restart_time()` for a dynamic background worker"
+    )]
+    fn test_dynamic_bgworker_payload_restart() {
+        BackgroundWorkerBuilder::new("dynamic_bgworker")
+            .set_library("pgx_tests")
+            .set_function("bgworker_payload")
+            .with_payload(vec![1i64])
+            .set_restart_time(Some(std::time::Duration::from_secs(1)))
+            .load_dynamic();
+    }
 }
@@ -0,0 +1,114 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+use pgx::bgworkers::BackgroundWorker;
+use pgx::dsm::{DsaArea, DsaHandle, DsaPointer, DsmHandle, DsmSegment};
+use pgx::prelude::*;
+use pgx::FromDatum;
+
+#[pg_guard]
+#[no_mangle]
+/// Adds one to every byte of the segment it's given the handle of
+pub extern "C" fn dsm_bgworker(arg: pg_sys::Datum) {
+    let handle = unsafe { DsmHandle::from_datum(arg, false) }.expect("invalid handle");
+    let mut segment = DsmSegment::attach(handle).expect("the segment is gone");
+    for byte in unsafe { segment.as_mut_slice() } {
+        *byte += 1;
+    }
+}
+
+#[pg_guard]
+#[no_mangle]
+/// Adds one to the `i64` in the area it's given the handle of, at the pointer in its `bgw_extra`
+pub extern "C" fn dsa_bgworker(arg: pg_sys::Datum) {
+    let handle = unsafe { DsaHandle::from_datum(arg, false) }.expect("invalid handle");
+    let dp = DsaPointer(BackgroundWorker::get_extra().parse().expect("invalid pointer"));
+    let area = DsaArea::attach(handle);
+    unsafe { *(area.get_address(dp) as *mut i64) += 1 };
+}
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgx_tests;
+
+    use pgx::bgworkers::*;
+    use pgx::dsm::*;
+    use pgx::prelude::*;
+    use pgx::IntoDatum;
+
+    #[pg_test]
+    fn test_dsm_segment() {
+        let mut segment = DsmSegment::create(1024);
+        assert_eq!(segment.len(), 1024);
+        unsafe { segment.as_mut_slice() }.fill(41);
+
+        let worker = BackgroundWorkerBuilder::new("dsm_bgworker")
+            .set_library("pgx_tests")
+            .set_function("dsm_bgworker")
+            .set_argument(segment.handle().into_datum())
+            .enable_shmem_access(None)
+            .set_notify_pid(unsafe { pg_sys::MyProcPid })
+            .load_dynamic();
+        worker.wait_for_shutdown().expect("aborted shutdown");
+
+        assert!(unsafe { segment.as_slice() }.iter().all(|byte| *byte == 42));
+    }
+
+    #[pg_test]
+    fn test_dsa_area() {
+        let area = DsaArea::create();
+
+        let dp = area.allocate_zeroed(std::mem::size_of::<i64>());
+        assert!(dp.is_valid());
+        let value = area.get_address(dp) as *mut i64;
+        unsafe {
+            assert_eq!(*value, 0);
+            *value = 42;
+            assert_eq!(*(area.get_address(dp) as *mut i64), 42);
+        }
+
+        // allocations can be larger than the area's first segment
+        let big = area.allocate(16 * 1024 * 1024);
+        assert!(big.is_valid());
+        assert_ne!(big, dp);
+        unsafe {
+            area.free(big);
+            area.free(dp);
+        }
+
+        area.set_size_limit(1024 * 1024);
+        assert_eq!(area.try_allocate(16 * 1024 * 1024), None);
+    }
+
+    #[pg_test]
+    fn test_dsa_area_attach() {
+        let area = DsaArea::create();
+        let dp = area.allocate_zeroed(std::mem::size_of::<i64>());
+        unsafe { *(area.get_address(dp) as *mut i64) = 41 };
+
+        let handle = area.handle();
+        assert_eq!(
+            unsafe { DsaHandle::from_datum(handle.into_datum().unwrap(), false) },
+            Some(handle)
+        );
+
+        let worker = BackgroundWorkerBuilder::new("dsa_bgworker")
+            .set_library("pgx_tests")
+            .set_function("dsa_bgworker")
+            .set_argument(handle.into_datum())
+            .set_extra(&dp.0.to_string())
+            .enable_shmem_access(None)
+            .set_notify_pid(unsafe { pg_sys::MyProcPid })
+            .load_dynamic();
+        worker.wait_for_shutdown().expect("aborted shutdown");
+
+        assert_eq!(unsafe { *(area.get_address(dp) as *mut i64) }, 42);
+    }
+}
@@ -405,6 +405,22 @@ mod returning {
         }
     }
 
+    mod set_of {
+        use super::*;
+
+        #[pg_extern(materialize)]
+        fn materialized_dogs(
+            names: Vec<String>,
+        ) -> SetOfIterator<'static, ::pgx::composite_type!("Dog")> {
+            SetOfIterator::new(names.into_iter().enumerate().map(|(idx, name)| {
+                let mut dog = PgHeapTuple::new_composite_type(DOG_COMPOSITE_TYPE).unwrap();
+                dog.set_by_name("name", name).unwrap();
+                dog.set_by_name("scritches", idx as i32).unwrap();
+                dog
+            }))
+        }
+    }
+
     // Returning VariadicArray/Array isn't supported, use a Vec.
 }
 
@@ -785,6 +801,26 @@ mod tests {
         assert_eq!(retval.get_by_name("scritches").unwrap(), Some(1));
     }
 
+    #[pg_test]
+    fn test_materialized_dogs() {
+        let dogs = Spi::connect(|client| {
+            let table = client.select(
+                "SELECT name, scritches FROM materialized_dogs(ARRAY['Nami', 'Brandy'])",
+                None,
+                None,
+            );
+            Ok(Some(
+                table
+                    .map(|row| (row["name"].value::<String>(), row["scritches"].value::<i32>()))
+                    .collect::<Vec<_>>(),
+            ))
+        });
+        assert_eq!(
+            dogs,
+            Some(vec![(Some("Nami".to_string()), Some(0)), (Some("Brandy".to_string()), Some(1)),])
+        );
+    }
+
     #[pg_test]
     fn test_scritch() {
         let retval = Spi::get_one::<PgHeapTuple<'_, AllocatedByRust>>(
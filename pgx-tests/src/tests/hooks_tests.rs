@@ -116,4 +116,459 @@ mod tests {
         // TODO:  it'd be nice to also test that .commit() and .abort() also get called
         //    but I don't see how to do that since we're running *inside* a transaction here
     }
+
+    #[pg_test]
+    unsafe fn test_emit_log() {
+        struct RedactingHook {
+            messages: Vec<(String, Option<String>, PgLogLevel, PgSqlErrorCode)>,
+        }
+        impl PgHooks for RedactingHook {
+            fn emit_log(&mut self, error: &mut ErrorDataWrapper) {
+                let message = error.message().unwrap_or_default().to_string();
+                if !message.contains("hunter2") {
+                    return;
+                }
+
+                error.set_message(&message.replace("hunter2", "*******"));
+                error.set_detail(Some("redacted"));
+                error.set_sqlstate(PgSqlErrorCode::ERRCODE_WARNING);
+                error.set_output_to_server(false);
+
+                assert!(!error.output_to_server());
+                self.messages.push((
+                    error.message().unwrap().to_string(),
+                    error.detail().map(|detail| detail.to_string()),
+                    error.elevel(),
+                    error.sqlstate(),
+                ));
+            }
+        }
+
+        static mut HOOK: RedactingHook = RedactingHook { messages: Vec::new() };
+        pgx::hooks::register_hook(&mut HOOK);
+        warning!("the password is hunter2");
+
+        assert_eq!(
+            HOOK.messages,
+            vec![(
+                "the password is *******".to_string(),
+                Some("redacted".to_string()),
+                PgLogLevel::WARNING,
+                PgSqlErrorCode::ERRCODE_WARNING
+            )]
+        );
+    }
+
+    #[pg_test]
+    unsafe fn test_emit_log_panic() {
+        static mut CALLS: u32 = 0;
+
+        emit_log::register(|error, _next| {
+            if error.message().unwrap_or_default().contains("panic in emit_log") {
+                CALLS += 1;
+                panic!("emit_log handler panicked");
+            }
+            HookResult::new(())
+        });
+
+        // the panic is swallowed rather than turned into an ERROR
+        warning!("panic in emit_log");
+        warning!("panic in emit_log");
+        assert_eq!(CALLS, 2);
+    }
+
+    struct PasswordPolicyHook {
+        checked: Vec<(String, bool, bool)>,
+    }
+    impl PgHooks for PasswordPolicyHook {
+        fn check_password(
+            &mut self,
+            username: &str,
+            password: Password,
+            valid_until: Option<TimestampWithTimeZone>,
+            prev_hook: fn(&str, Password, Option<TimestampWithTimeZone>) -> HookResult<()>,
+        ) -> HookResult<()> {
+            let is_plaintext = matches!(password, Password::Plaintext(_));
+            self.checked.push((username.to_string(), is_plaintext, valid_until.is_some()));
+            if let Password::Plaintext(password) = password {
+                if !password.chars().any(|c| c.is_ascii_digit()) {
+                    error!("password must contain a digit");
+                }
+            }
+            prev_hook(username, password, valid_until)
+        }
+    }
+
+    static mut PASSWORD_HOOK: PasswordPolicyHook = PasswordPolicyHook { checked: Vec::new() };
+
+    #[pg_test]
+    unsafe fn test_check_password() {
+        pgx::hooks::register_hook(&mut PASSWORD_HOOK);
+        Spi::run("CREATE ROLE pgx_password_ok PASSWORD 'secret1'");
+        Spi::run("ALTER ROLE pgx_password_ok PASSWORD 'secret2' VALID UNTIL '2100-01-01'");
+        assert_eq!(
+            PASSWORD_HOOK.checked,
+            vec![
+                ("pgx_password_ok".to_string(), true, false),
+                ("pgx_password_ok".to_string(), true, true),
+            ]
+        );
+    }
+
+    #[pg_test(error = "password must contain a digit")]
+    unsafe fn test_check_password_rejected() {
+        pgx::hooks::register_hook(&mut PASSWORD_HOOK);
+        Spi::run("CREATE ROLE pgx_password_weak PASSWORD 'secret'");
+    }
+
+    #[pg_test]
+    unsafe fn test_object_access() {
+        struct AuditHook {
+            events: Vec<(&'static str, pg_sys::Oid, pg_sys::Oid, i32)>,
+        }
+        impl PgHooks for AuditHook {
+            fn object_access(
+                &mut self,
+                event: ObjectAccessEvent,
+                class_id: pg_sys::Oid,
+                object_id: pg_sys::Oid,
+                sub_id: i32,
+                prev_hook: fn(ObjectAccessEvent, pg_sys::Oid, pg_sys::Oid, i32) -> HookResult<()>,
+            ) -> HookResult<()> {
+                let kind = match &event {
+                    ObjectAccessEvent::PostCreate { .. } => "create",
+                    ObjectAccessEvent::PostAlter { .. } => "alter",
+                    ObjectAccessEvent::Drop { .. } => "drop",
+                    _ => "other",
+                };
+                self.events.push((kind, class_id, object_id, sub_id));
+                prev_hook(event, class_id, object_id, sub_id)
+            }
+        }
+
+        static mut HOOK: AuditHook = AuditHook { events: Vec::new() };
+        pgx::hooks::register_hook(&mut HOOK);
+
+        Spi::run("CREATE TABLE tests.audited (id int)");
+        let relid = Spi::get_one::<pg_sys::Oid>("SELECT 'tests.audited'::regclass::oid").unwrap();
+        Spi::run("ALTER TABLE tests.audited ADD COLUMN name text");
+        Spi::run("DROP TABLE tests.audited");
+
+        let relation_events = HOOK
+            .events
+            .iter()
+            .filter(|(_, class_id, object_id, _)| {
+                *class_id == pg_sys::RelationRelationId && *object_id == relid
+            })
+            .map(|(kind, _, _, sub_id)| (*kind, *sub_id))
+            .collect::<Vec<_>>();
+        assert!(relation_events.contains(&("create", 0)));
+        assert!(relation_events.contains(&("create", 2)));
+        assert_eq!(relation_events.last(), Some(&("drop", 0)));
+    }
+
+    #[pg_test]
+    unsafe fn test_planner_path_hooks() {
+        #[derive(Default)]
+        struct PathHook {
+            relations: Vec<pg_sys::Oid>,
+            base_rels: u32,
+            joins: u32,
+            upper_stages: Vec<pg_sys::UpperRelationKind>,
+        }
+        impl PgHooks for PathHook {
+            fn get_relation_info(
+                &mut self,
+                root: PgBox<pg_sys::PlannerInfo>,
+                relation_oid: pg_sys::Oid,
+                inhparent: bool,
+                rel: PgBox<pg_sys::RelOptInfo>,
+                prev_hook: fn(
+                    PgBox<pg_sys::PlannerInfo>,
+                    pg_sys::Oid,
+                    bool,
+                    PgBox<pg_sys::RelOptInfo>,
+                ) -> HookResult<()>,
+            ) -> HookResult<()> {
+                self.relations.push(relation_oid);
+                prev_hook(root, relation_oid, inhparent, rel)
+            }
+
+            fn set_rel_pathlist(
+                &mut self,
+                root: PgBox<pg_sys::PlannerInfo>,
+                rel: PgBox<pg_sys::RelOptInfo>,
+                rti: pg_sys::Index,
+                rte: PgBox<pg_sys::RangeTblEntry>,
+                prev_hook: fn(
+                    PgBox<pg_sys::PlannerInfo>,
+                    PgBox<pg_sys::RelOptInfo>,
+                    pg_sys::Index,
+                    PgBox<pg_sys::RangeTblEntry>,
+                ) -> HookResult<()>,
+            ) -> HookResult<()> {
+                self.base_rels += 1;
+                prev_hook(root, rel, rti, rte)
+            }
+
+            fn set_join_pathlist(
+                &mut self,
+                root: PgBox<pg_sys::PlannerInfo>,
+                joinrel: PgBox<pg_sys::RelOptInfo>,
+                outerrel: PgBox<pg_sys::RelOptInfo>,
+                innerrel: PgBox<pg_sys::RelOptInfo>,
+                jointype: pg_sys::JoinType,
+                extra: PgBox<pg_sys::JoinPathExtraData>,
+                prev_hook: fn(
+                    PgBox<pg_sys::PlannerInfo>,
+                    PgBox<pg_sys::RelOptInfo>,
+                    PgBox<pg_sys::RelOptInfo>,
+                    PgBox<pg_sys::RelOptInfo>,
+                    pg_sys::JoinType,
+                    PgBox<pg_sys::JoinPathExtraData>,
+                ) -> HookResult<()>,
+            ) -> HookResult<()> {
+                self.joins += 1;
+                prev_hook(root, joinrel, outerrel, innerrel, jointype, extra)
+            }
+
+            fn create_upper_paths(
+                &mut self,
+                root: PgBox<pg_sys::PlannerInfo>,
+                stage: pg_sys::UpperRelationKind,
+                input_rel: PgBox<pg_sys::RelOptInfo>,
+                output_rel: PgBox<pg_sys::RelOptInfo>,
+                extra: *mut std::os::raw::c_void,
+                prev_hook: fn(
+                    PgBox<pg_sys::PlannerInfo>,
+                    pg_sys::UpperRelationKind,
+                    PgBox<pg_sys::RelOptInfo>,
+                    PgBox<pg_sys::RelOptInfo>,
+                    *mut std::os::raw::c_void,
+                ) -> HookResult<()>,
+            ) -> HookResult<()> {
+                self.upper_stages.push(stage);
+                prev_hook(root, stage, input_rel, output_rel, extra)
+            }
+        }
+
+        Spi::run("CREATE TABLE tests.path_hooks (id int)");
+        let relid =
+            Spi::get_one::<pg_sys::Oid>("SELECT 'tests.path_hooks'::regclass::oid").unwrap();
+
+        static mut HOOK: Option<PathHook> = None;
+        HOOK = Some(PathHook::default());
+        pgx::hooks::register_hook(HOOK.as_mut().unwrap());
+        Spi::run("SELECT count(*) FROM tests.path_hooks a JOIN tests.path_hooks b USING (id)");
+
+        let hook = HOOK.as_ref().unwrap();
+        assert_eq!(hook.relations, vec![relid, relid]);
+        assert_eq!(hook.base_rels, 2);
+        assert!(hook.joins > 0);
+        assert!(hook.upper_stages.contains(&pg_sys::UpperRelationKind_UPPERREL_GROUP_AGG));
+        assert!(hook.upper_stages.contains(&pg_sys::UpperRelationKind_UPPERREL_FINAL));
+    }
+
+    #[pg_test]
+    unsafe fn test_explain_one_query() {
+        struct ExplainHook {}
+        impl PgHooks for ExplainHook {
+            fn explain_one_query(
+                &mut self,
+                query: PgBox<pg_sys::Query>,
+                cursor_options: i32,
+                into: PgBox<pg_sys::IntoClause>,
+                es: &mut ExplainStateWrapper,
+                query_string: &std::ffi::CStr,
+                params: PgBox<pg_sys::ParamListInfoData>,
+                query_env: PgBox<pg_sys::QueryEnvironment>,
+                prev_hook: fn(
+                    PgBox<pg_sys::Query>,
+                    i32,
+                    PgBox<pg_sys::IntoClause>,
+                    &mut ExplainStateWrapper,
+                    &std::ffi::CStr,
+                    PgBox<pg_sys::ParamListInfoData>,
+                    PgBox<pg_sys::QueryEnvironment>,
+                ) -> HookResult<()>,
+            ) -> HookResult<()> {
+                let result =
+                    prev_hook(query, cursor_options, into, es, query_string, params, query_env);
+                es.explain_group("Pgx", None, |es| {
+                    es.explain_property_text("Audited By", "pgx");
+                    es.explain_group("Statistics", Some("Statistics"), |es| {
+                        es.explain_property_integer("Rows Seen", Some("rows"), 42);
+                    });
+                });
+                result
+            }
+        }
+
+        static mut HOOK: ExplainHook = ExplainHook {};
+        pgx::hooks::register_hook(&mut HOOK);
+
+        let lines = Spi::connect(|client| {
+            let table = client.select("EXPLAIN (COSTS OFF) SELECT 1", None, None);
+            Ok(Some(table.map(|row| row[1].value::<String>().unwrap()).collect::<Vec<_>>()))
+        })
+        .unwrap();
+        assert_eq!(lines, vec!["Result", "Audited By: pgx", "Statistics:", "  Rows Seen: 42 rows"]);
+
+        let json = Spi::get_one::<pgx::Json>("EXPLAIN (FORMAT JSON) SELECT 1").unwrap().0;
+        assert_eq!(json[1]["Audited By"], "pgx");
+        assert_eq!(json[1]["Statistics"]["Rows Seen"], 42);
+    }
+
+    #[pg_test]
+    unsafe fn test_chained_hooks() {
+        static mut EVENTS: Vec<&str> = Vec::new();
+
+        executor_start::register(|query_desc, eflags, next| {
+            EVENTS.push("first start");
+            next(query_desc, eflags)
+        });
+        executor_start::register(|query_desc, eflags, next| {
+            EVENTS.push("second start");
+            next(query_desc, eflags)
+        });
+        executor_end::register(|query_desc, next| {
+            let result = next(query_desc);
+            EVENTS.push("end");
+            result
+        });
+
+        Spi::run("SELECT 1");
+        assert_eq!(EVENTS, vec!["first start", "second start", "end"]);
+
+        // hooks nobody registered a handler for are left alone
+        assert!(pg_sys::ExecutorRun_hook.is_none());
+    }
+
+    #[pg_test]
+    unsafe fn test_reentrant_hook() {
+        static mut OUTER: u32 = 0;
+        static mut INNER: u32 = 0;
+
+        executor_start::register(|query_desc, eflags, next| {
+            OUTER += 1;
+            // this query starts an executor of its own, which skips this handler
+            Spi::get_one::<i32>("SELECT 1");
+            next(query_desc, eflags)
+        });
+        executor_start::register(|query_desc, eflags, next| {
+            INNER += 1;
+            next(query_desc, eflags)
+        });
+
+        Spi::run("SELECT 2");
+        assert_eq!(OUTER, 1);
+        assert_eq!(INNER, 2);
+    }
+
+    #[pg_test]
+    unsafe fn test_fmgr_hook() {
+        struct CallStarted;
+
+        struct FmgrHook {
+            fn_oid: pg_sys::Oid,
+            events: Vec<(FmgrHookEvent, pg_sys::Oid, bool)>,
+        }
+        impl PgHooks for FmgrHook {
+            fn needs_fmgr_hook(
+                &mut self,
+                fn_oid: pg_sys::Oid,
+                prev_hook: fn(fn_oid: pg_sys::Oid) -> HookResult<bool>,
+            ) -> HookResult<bool> {
+                if fn_oid == self.fn_oid {
+                    HookResult::new(true)
+                } else {
+                    prev_hook(fn_oid)
+                }
+            }
+
+            fn fmgr_hook(
+                &mut self,
+                event: FmgrHookEvent,
+                flinfo: PgBox<pg_sys::FmgrInfo>,
+                state: &mut FmgrHookState,
+                prev_hook: fn(
+                    FmgrHookEvent,
+                    PgBox<pg_sys::FmgrInfo>,
+                    &mut FmgrHookState,
+                ) -> HookResult<()>,
+            ) -> HookResult<()> {
+                if event == FmgrHookEvent::Start {
+                    state.insert(CallStarted);
+                }
+                self.events.push((event, flinfo.fn_oid, state.get::<CallStarted>().is_some()));
+                prev_hook(event, flinfo, state)
+            }
+        }
+
+        // builtin functions never go through fmgr_hook, so hook a SQL function instead
+        Spi::run("CREATE FUNCTION hooked_upper(text) RETURNS text LANGUAGE sql AS $$ SELECT upper($1) $$");
+        let fn_oid =
+            Spi::get_one::<pg_sys::Oid>("SELECT 'hooked_upper(text)'::regprocedure::oid").unwrap();
+        static mut HOOK: FmgrHook = FmgrHook { fn_oid: 0, events: Vec::new() };
+        HOOK.fn_oid = fn_oid;
+        pgx::hooks::register_hook(&mut HOOK);
+
+        assert_eq!(
+            Spi::get_one::<String>("SELECT hooked_upper('hooked')"),
+            Some("HOOKED".to_string())
+        );
+        assert_eq!(
+            HOOK.events,
+            vec![(FmgrHookEvent::Start, fn_oid, true), (FmgrHookEvent::End, fn_oid, true)]
+        );
+    }
+
+    #[pg_test]
+    unsafe fn test_fmgr_hook_refuse() {
+        struct Attempted;
+        static mut FN_OID: pg_sys::Oid = pg_sys::InvalidOid;
+        static mut REFUSE: bool = true;
+        static mut EVENTS: Vec<(FmgrHookEvent, bool)> = Vec::new();
+
+        needs_fmgr_hook::register(|fn_oid, next| {
+            if fn_oid == FN_OID {
+                HookResult::new(true)
+            } else {
+                next(fn_oid)
+            }
+        });
+        fmgr_hook::register(|event, flinfo, state, next| {
+            EVENTS.push((event, state.get::<Attempted>().is_some()));
+            if event == FmgrHookEvent::Start {
+                state.insert(Attempted);
+                if REFUSE {
+                    REFUSE = false;
+                    error!("call refused");
+                }
+            }
+            next(event, flinfo, state)
+        });
+
+        Spi::run("CREATE FUNCTION refused_upper(text) RETURNS text LANGUAGE sql AS $$ SELECT upper($1) $$");
+        FN_OID =
+            Spi::get_one::<pg_sys::Oid>("SELECT 'refused_upper(text)'::regprocedure::oid").unwrap();
+
+        Spi::run(
+            "DO $$ BEGIN PERFORM refused_upper('refused'); EXCEPTION WHEN OTHERS THEN NULL; END $$",
+        );
+        assert_eq!(
+            Spi::get_one::<String>("SELECT refused_upper('allowed')"),
+            Some("ALLOWED".to_string())
+        );
+
+        // the refused call's state was freed, rather than handed to the next call
+        assert_eq!(
+            EVENTS,
+            vec![
+                (FmgrHookEvent::Start, false),
+                (FmgrHookEvent::Start, false),
+                (FmgrHookEvent::End, true)
+            ]
+        );
+    }
 }
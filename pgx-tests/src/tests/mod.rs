@@ -17,6 +17,7 @@ mod cfg_tests;
 mod datetime_tests;
 mod default_arg_value_tests;
 mod derive_pgtype_lifetimes;
+mod dsm_tests;
 mod enum_type_tests;
 mod fcinfo_tests;
 mod guc_tests;
@@ -29,15 +30,20 @@ mod lifetime_tests;
 mod log_tests;
 mod memcxt_tests;
 mod name_tests;
+mod node_tests;
 mod numeric_tests;
+mod parallel_tests;
 mod pg_extern_tests;
 mod pg_guard_tests;
 mod pg_try_tests;
 mod pgbox_tests;
 mod pgx_module_qualification;
 mod postgres_type_tests;
+mod procedure_tests;
 mod range_tests;
+mod scheduler_tests;
 mod schema_tests;
+mod shm_mq_tests;
 mod shmem_tests;
 mod spi_tests;
 mod srf_tests;
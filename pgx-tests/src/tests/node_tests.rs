@@ -0,0 +1,177 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgx_tests;
+
+    use pgx::hooks::{executor_start, post_parse_analyze};
+    use pgx::nodes::*;
+    use pgx::prelude::*;
+    use pgx::PgList;
+
+    /// Collects how each column is referred to, as a name in raw parse trees and by how many
+    /// query levels up it is in analyzed ones
+    #[derive(Default)]
+    struct Columns {
+        columns: Vec<String>,
+    }
+    impl NodeVisitor for Columns {
+        fn visit(&mut self, node: PgBox<pg_sys::Node>) -> Visit {
+            if let Some(var) = node.downcast::<pg_sys::Var>() {
+                self.columns.push(var.varlevelsup.to_string());
+            } else if let Some(column_ref) = node.downcast::<pg_sys::ColumnRef>() {
+                let name = unsafe {
+                    let fields = PgList::<pg_sys::Node>::from_pg(column_ref.fields);
+                    node_to_string(fields.head().unwrap()).unwrap()
+                };
+                self.columns.push(name.to_string());
+            }
+            Visit::Continue
+        }
+    }
+
+    #[pg_test]
+    unsafe fn test_walk_raw_tree() {
+        let sql = std::ffi::CString::new("SELECT a, b FROM t WHERE c = 1").unwrap();
+        let parsetree = pg_sys::pg_parse_query(sql.as_ptr());
+
+        let mut visitor = Columns::default();
+        assert!(!walk_raw_tree(parsetree as *mut pg_sys::Node, &mut visitor));
+        assert_eq!(visitor.columns.len(), 3);
+        assert!(visitor.columns[0].contains("\"a\""));
+        assert!(visitor.columns[2].contains("\"c\""));
+    }
+
+    #[pg_test]
+    unsafe fn test_walk_tree() {
+        static mut VARS: Vec<String> = Vec::new();
+        post_parse_analyze::register(|pstate, query, jumble_state, next| {
+            let mut visitor = Columns::default();
+            walk_tree(query.as_ptr() as *mut pg_sys::Node, &mut visitor);
+            VARS = visitor.columns;
+            next(pstate, query, jumble_state)
+        });
+
+        Spi::run(
+            "SELECT relname FROM pg_class
+              WHERE oid = 1259 AND EXISTS (SELECT 1 WHERE relkind = 'r')",
+        );
+        // relkind is a reference to the outer query, from within the sub-query
+        assert_eq!(VARS, vec!["0", "0", "1"]);
+    }
+
+    #[pg_test]
+    unsafe fn test_walk_tree_stop() {
+        struct FirstConst {
+            visited: usize,
+        }
+        impl NodeVisitor for FirstConst {
+            fn visit(&mut self, node: PgBox<pg_sys::Node>) -> Visit {
+                self.visited += 1;
+                if node.is_a::<pg_sys::Const>() {
+                    Visit::Stop
+                } else {
+                    Visit::Continue
+                }
+            }
+        }
+
+        static mut STOPPED: Option<bool> = None;
+        post_parse_analyze::register(|pstate, query, jumble_state, next| {
+            let mut visitor = FirstConst { visited: 0 };
+            STOPPED = Some(walk_tree(query.as_ptr() as *mut pg_sys::Node, &mut visitor));
+            next(pstate, query, jumble_state)
+        });
+
+        Spi::run("SELECT 1, 2");
+        assert_eq!(STOPPED, Some(true));
+    }
+
+    #[pg_test]
+    unsafe fn test_mutate_tree() {
+        struct FortyOneIsFortyTwo;
+        impl NodeMutator for FortyOneIsFortyTwo {
+            fn mutate(&mut self, node: PgBox<pg_sys::Node>) -> Mutate {
+                match node.downcast::<pg_sys::Const>() {
+                    Some(c) if c.consttype == pg_sys::INT4OID && c.constvalue.value() == 41 => {
+                        Mutate::Replace(unsafe {
+                            pg_sys::makeConst(
+                                pg_sys::INT4OID,
+                                -1,
+                                pg_sys::InvalidOid,
+                                4,
+                                42.into(),
+                                false,
+                                true,
+                            ) as *mut pg_sys::Node
+                        })
+                    }
+                    _ => Mutate::Continue,
+                }
+            }
+        }
+
+        post_parse_analyze::register(|pstate, mut query, jumble_state, next| {
+            let mutated = mutate_tree(query.as_ptr() as *mut pg_sys::Node, &mut FortyOneIsFortyTwo);
+            *query = *(mutated as *mut pg_sys::Query);
+            next(pstate, query, jumble_state)
+        });
+
+        assert_eq!(Spi::get_one::<i32>("SELECT 41"), Some(42));
+        assert_eq!(Spi::get_one::<i32>("SELECT 40 + 1"), Some(41));
+    }
+
+    #[pg_test]
+    unsafe fn test_node_to_json() {
+        static mut QUERY: Option<serde_json::Value> = None;
+        static mut PLAN: Option<serde_json::Value> = None;
+        post_parse_analyze::register(|pstate, query, jumble_state, next| {
+            QUERY = Some(node_to_json(query.as_ptr() as *mut pg_sys::Node));
+            next(pstate, query, jumble_state)
+        });
+        executor_start::register(|query_desc, eflags, next| {
+            PLAN = Some(node_to_json(query_desc.plannedstmt as *mut pg_sys::Node));
+            next(query_desc, eflags)
+        });
+
+        Spi::run("SELECT relname FROM pg_class WHERE oid = 1259");
+
+        let query = QUERY.take().unwrap();
+        assert_eq!(query["node"], "Query");
+        assert_eq!(query["commandType"], pg_sys::CmdType_CMD_SELECT);
+        assert_eq!(query["rtable"][0]["node"], "RangeTblEntry");
+        assert_eq!(query["rtable"][0]["relid"], pg_sys::RelationRelationId);
+        let quals = &query["jointree"]["quals"];
+        assert_eq!(quals["node"], "OpExpr");
+        assert_eq!(quals["args"][0]["node"], "Var");
+        assert_eq!(quals["args"][1]["node"], "Const");
+        assert_eq!(quals["args"][1]["constvalue"], 1259);
+        assert_eq!(query["targetList"][0]["resname"], "relname");
+
+        let plan = PLAN.take().unwrap();
+        assert_eq!(plan["node"], "PlannedStmt");
+        assert!(plan["planTree"]["node"].is_string());
+        assert_eq!(plan["rtable"][0]["relid"], pg_sys::RelationRelationId);
+
+        assert_eq!(node_to_json(std::ptr::null_mut()), serde_json::Value::Null);
+    }
+
+    #[pg_test]
+    unsafe fn test_node_to_json_cycle() {
+        let list = pg_sys::lappend(std::ptr::null_mut(), pg_sys::makeInteger(1).cast());
+        let list = pg_sys::lappend(list, list.cast());
+
+        let json = node_to_json(list.cast());
+        assert_eq!(json[0]["ival"], 1);
+        assert_eq!(json[1], serde_json::json!({"node": "Ref", "up": 0}));
+    }
+}
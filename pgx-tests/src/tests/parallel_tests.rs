@@ -0,0 +1,72 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+use pgx::dsm::{DsmHandle, DsmSegment};
+use pgx::parallel::ParallelWorker;
+use pgx::prelude::*;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ParallelTestState {
+    segment: DsmHandle,
+    fail: bool,
+}
+
+#[pg_guard]
+#[no_mangle]
+/// Sets its byte of the segment in the state to its worker number plus one
+pub extern "C" fn parallel_test_worker(seg: *mut pg_sys::dsm_segment, toc: *mut pg_sys::shm_toc) {
+    let worker = unsafe { ParallelWorker::new(seg, toc) };
+    let state = worker.state::<ParallelTestState>();
+    if state.fail {
+        panic!("parallel worker {} failed", worker.number());
+    }
+
+    let mut segment = DsmSegment::attach(state.segment).expect("the segment is gone");
+    let bytes = unsafe { segment.as_mut_slice() };
+    bytes[worker.number()] = worker.number() as u8 + 1;
+}
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgx_tests;
+
+    use super::ParallelTestState;
+    use pgx::dsm::DsmSegment;
+    use pgx::parallel::ParallelContext;
+    use pgx::prelude::*;
+
+    #[pg_test]
+    fn test_parallel_context() {
+        let mut segment = DsmSegment::create(8);
+        unsafe { segment.as_mut_slice() }.fill(0);
+
+        let state = ParallelTestState { segment: segment.handle(), fail: false };
+        let mut pcxt = ParallelContext::new("pgx_tests", "parallel_test_worker", 2, &state);
+        // the test cluster has enough worker slots for this, see `postgresql_conf_options()`
+        let launched = pcxt.launch();
+        assert!((1..=2).contains(&launched), "launched {} parallel workers", launched);
+        pcxt.wait();
+
+        let bytes = unsafe { segment.as_slice() };
+        for (i, byte) in bytes.iter().enumerate() {
+            let expected = if i < launched { i as u8 + 1 } else { 0 };
+            assert_eq!(*byte, expected);
+        }
+    }
+
+    #[pg_test(error = "parallel worker 0 failed")]
+    fn test_parallel_worker_error() {
+        let segment = DsmSegment::create(8);
+        let state = ParallelTestState { segment: segment.handle(), fail: true };
+        let mut pcxt = ParallelContext::new("pgx_tests", "parallel_test_worker", 1, &state);
+        assert_eq!(pcxt.launch(), 1, "no parallel worker was launched to fail");
+        pcxt.wait();
+    }
+}
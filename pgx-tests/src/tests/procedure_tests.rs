@@ -0,0 +1,65 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgx_tests;
+
+    use pgx::prelude::*;
+
+    #[pg_procedure]
+    fn insert_procedure_rows(fcinfo: pg_sys::FunctionCallInfo, count: i32) {
+        unsafe {
+            Spi::connect_nonatomic(fcinfo, |client| {
+                for i in 0..count {
+                    client.update("INSERT INTO tests.procedure_rows VALUES ($1)", None, (i,));
+                }
+                Ok(Some(()))
+            });
+        }
+    }
+
+    #[pg_procedure]
+    fn commit_procedure_rows(fcinfo: pg_sys::FunctionCallInfo) {
+        unsafe {
+            Spi::connect_nonatomic(fcinfo, |client| {
+                client.update("INSERT INTO tests.procedure_rows VALUES (42)", None, ());
+                client.commit();
+                Ok(Some(()))
+            });
+        }
+    }
+
+    #[pg_test]
+    fn test_procedure_is_a_procedure() {
+        let result = Spi::get_one::<bool>(
+            "SELECT prokind = 'p' FROM pg_proc WHERE proname = 'insert_procedure_rows'",
+        )
+        .expect("failed to get SPI result");
+        assert!(result)
+    }
+
+    #[pg_test]
+    fn test_call_procedure() {
+        Spi::run("CREATE TABLE tests.procedure_rows (id int)");
+        Spi::run("CALL tests.insert_procedure_rows(3)");
+
+        let count = Spi::get_one::<i64>("SELECT count(*) FROM tests.procedure_rows");
+        assert_eq!(count, Some(3));
+    }
+
+    // `#[pg_test]`s run inside a transaction, so the procedure can't control it
+    #[pg_test(error = "invalid transaction termination")]
+    fn test_commit_in_atomic_context() {
+        Spi::run("CREATE TABLE tests.procedure_rows (id int)");
+        Spi::run("CALL tests.commit_procedure_rows()");
+    }
+}
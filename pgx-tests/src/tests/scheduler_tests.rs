@@ -0,0 +1,110 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+use pgx::prelude::*;
+
+#[pg_guard]
+#[no_mangle]
+/// Counts up in `tests.scheduler_test` every 100ms, until it's told to stop
+pub extern "C" fn scheduler_bgworker(_arg: pg_sys::Datum) {
+    use pgx::bgworkers::Scheduler;
+    use std::time::Duration;
+
+    let mut created = false;
+    Scheduler::new()
+        .connect(
+            Some(crate::framework::get_pg_dbname()),
+            Some(crate::framework::get_pg_user().as_str()),
+        )
+        .every("count", Duration::from_millis(100), move || {
+            if !created {
+                Spi::run("CREATE TABLE tests.scheduler_test (v INTEGER);");
+                Spi::run("INSERT INTO tests.scheduler_test VALUES (0);");
+                created = true;
+            }
+            Spi::run("UPDATE tests.scheduler_test SET v = v + 1;");
+        })
+        .cron("never", "0 0 30 2 *", || panic!("February 30th came around"))
+        .run();
+}
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgx_tests;
+
+    use pgx::bgworkers::*;
+    use pgx::prelude::*;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[pg_test]
+    fn test_scheduler() {
+        let worker = BackgroundWorkerBuilder::new("scheduler_bgworker")
+            .set_library("pgx_tests")
+            .set_function("scheduler_bgworker")
+            .enable_spi_access()
+            .set_notify_pid(unsafe { pg_sys::MyProcPid })
+            .load_dynamic();
+        worker.wait_for_startup().expect("no PID from the worker");
+        std::thread::sleep(Duration::from_secs(1));
+        worker.terminate().wait_for_shutdown().expect("aborted shutdown");
+
+        let count =
+            Spi::get_one::<i32>("SELECT v FROM tests.scheduler_test;").expect("the job never ran");
+        assert!(count >= 2, "the job only ran {} times", count);
+    }
+
+    #[pg_test]
+    fn test_cron_schedule() {
+        // Friday 2023-01-06 17:50 UTC, after hours until Monday 2023-01-09 09:00
+        let schedule = Schedule::cron("*/15 9-17 * * mon-fri").unwrap();
+        assert_eq!(schedule.next_after(at(1673027400)), Some(at(1673254800)));
+        assert_eq!(schedule.next_after(at(1673027400 - 330)), Some(at(1673027400 - 300)));
+
+        // from 2023-01-01, the next leap day
+        let schedule = Schedule::cron("0 0 29 feb *").unwrap();
+        assert_eq!(schedule.next_after(at(1672531200)), Some(at(1709164800)));
+
+        // either the 13th or a Friday, so Friday 2023-01-06
+        let schedule = Schedule::cron("0 0 13 * 5").unwrap();
+        assert_eq!(schedule.next_after(at(1672531200)), Some(at(1672963200)));
+
+        // Sunday, both ways
+        assert_eq!(Schedule::cron("0 0 * * 7"), Schedule::cron("0 0 * * sun"));
+        assert_eq!(Schedule::cron("@weekly"), Schedule::cron("0 0 * * 0"));
+
+        assert_eq!(Schedule::cron("0 0 30 2 *").unwrap().next_after(at(1672531200)), None);
+        assert_eq!(
+            Schedule::Every(Duration::from_secs(60)).next_after(at(1672531200)),
+            Some(at(1672531260))
+        );
+    }
+
+    #[pg_test]
+    fn test_cron_schedule_invalid() {
+        assert_eq!(Schedule::cron("* * *"), Err(CronParseError::WrongFieldCount(3)));
+        assert_eq!(
+            Schedule::cron("60 * * * *"),
+            Err(CronParseError::InvalidField("60".to_string()))
+        );
+        assert_eq!(Schedule::cron("* * 0 * *"), Err(CronParseError::InvalidField("0".to_string())));
+        assert_eq!(
+            Schedule::cron("*/0 * * * *"),
+            Err(CronParseError::InvalidField("*/0".to_string()))
+        );
+        assert_eq!(
+            Schedule::cron("* 5-1 * * *"),
+            Err(CronParseError::InvalidField("5-1".to_string()))
+        );
+    }
+}
@@ -0,0 +1,89 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+use pgx::dsm::DsmHandle;
+use pgx::prelude::*;
+use pgx::shm_mq::{Receiver, Sender};
+use pgx::FromDatum;
+
+#[pg_guard]
+#[no_mangle]
+/// Sends a thousand messages through the queue it's given the handle of
+pub extern "C" fn shm_mq_bgworker(arg: pg_sys::Datum) {
+    let handle = unsafe { DsmHandle::from_datum(arg, false) }.expect("invalid handle");
+    let mut sender = Sender::<(i32, String)>::attach(handle).expect("the queue is gone");
+    for i in 0..1000 {
+        sender.send(&(i, i.to_string())).expect("failed to send");
+    }
+}
+
+#[pg_guard]
+#[no_mangle]
+/// Receives one message through the queue it's given the handle of, which must be 4000 `x`s
+pub extern "C" fn shm_mq_receiver_bgworker(arg: pg_sys::Datum) {
+    let handle = unsafe { DsmHandle::from_datum(arg, false) }.expect("invalid handle");
+    let mut receiver = Receiver::<String>::attach(handle).expect("the queue is gone");
+    assert_eq!(receiver.recv().expect("failed to receive"), "x".repeat(4000));
+}
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgx_tests;
+
+    use pgx::bgworkers::*;
+    use pgx::prelude::*;
+    use pgx::shm_mq::*;
+    use pgx::IntoDatum;
+
+    #[pg_test]
+    fn test_shm_mq() {
+        // small enough that the worker has to wait for us to make room
+        let mut receiver = Receiver::<(i32, String)>::create(1024);
+        assert_eq!(receiver.try_recv(), Err(ShmMqError::WouldBlock));
+
+        let worker = BackgroundWorkerBuilder::new("shm_mq_bgworker")
+            .set_library("pgx_tests")
+            .set_function("shm_mq_bgworker")
+            .set_argument(receiver.handle().into_datum())
+            .enable_shmem_access(None)
+            .set_notify_pid(unsafe { pg_sys::MyProcPid })
+            .load_dynamic();
+        receiver.set_worker(&worker);
+
+        let messages = receiver.iter().collect::<Vec<_>>();
+        assert_eq!(messages.len(), 1000);
+        assert!(messages
+            .iter()
+            .enumerate()
+            .all(|(i, (n, s))| *n == i as i32 && *s == i.to_string()));
+        assert_eq!(receiver.recv(), Err(ShmMqError::Detached));
+
+        worker.wait_for_shutdown().expect("aborted shutdown");
+    }
+
+    #[pg_test]
+    fn test_shm_mq_try_send_partial() {
+        // too small for the message, which can't be received until the worker attaches anyway
+        let mut sender = Sender::<String>::create(1024);
+        assert_eq!(sender.try_send(&"x".repeat(4000)), Ok(SendStatus::Partial));
+
+        let worker = BackgroundWorkerBuilder::new("shm_mq_receiver_bgworker")
+            .set_library("pgx_tests")
+            .set_function("shm_mq_receiver_bgworker")
+            .set_argument(sender.handle().into_datum())
+            .enable_shmem_access(None)
+            .set_notify_pid(unsafe { pg_sys::MyProcPid })
+            .load_dynamic();
+        sender.set_worker(&worker);
+
+        assert_eq!(sender.flush(), Ok(()));
+        worker.wait_for_shutdown().expect("aborted shutdown");
+    }
+}
@@ -7,13 +7,70 @@ All rights reserved.
 Use of this source code is governed by the MIT license that can be found in the LICENSE file.
 */
 use pgx::prelude::*;
-use pgx::{pg_shmem_init, PgAtomic, PgSharedMemoryInitialization};
+use pgx::{pg_shmem_init, PgAtomic, PgSharedHashMap, PgSharedMemoryInitialization};
 use std::sync::atomic::AtomicBool;
 
 static ATOMIC: PgAtomic<AtomicBool> = PgAtomic::new();
+static HASH_MAP: PgSharedHashMap<i32, i64> = PgSharedHashMap::with_partitions(4, 2);
 
 #[pg_guard]
 pub extern "C" fn _PG_init() {
     // This ensures that this functionality works across PostgreSQL versions
     pg_shmem_init!(ATOMIC);
+    pg_shmem_init!(HASH_MAP);
+}
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pgx::pg_schema]
+mod tests {
+    #[allow(unused_imports)]
+    use crate as pgx_tests;
+
+    use super::HASH_MAP;
+    use pgx::prelude::*;
+    use pgx::PgSharedHashMapError;
+
+    #[pg_test]
+    fn test_shared_hash_map() {
+        assert!(HASH_MAP.is_empty());
+        assert_eq!(HASH_MAP.insert(1, 10), Ok(None));
+        assert_eq!(HASH_MAP.insert(2, 20), Ok(None));
+        assert_eq!(HASH_MAP.insert(1, 11), Ok(Some(10)));
+        assert_eq!(HASH_MAP.get(&1), Some(11));
+        assert_eq!(HASH_MAP.get(&3), None);
+        assert_eq!(HASH_MAP.len(), 2);
+
+        let mut entries = HASH_MAP.iter().collect::<Vec<_>>();
+        entries.sort();
+        assert_eq!(entries, vec![(1, 11), (2, 20)]);
+
+        assert_eq!(HASH_MAP.insert(3, 30), Ok(None));
+        assert_eq!(HASH_MAP.insert(4, 40), Ok(None));
+        assert_eq!(HASH_MAP.insert(5, 50), Err(PgSharedHashMapError::HashMapFull));
+
+        assert_eq!(HASH_MAP.update(4, 0, |value| std::mem::replace(value, 41)), Ok(40));
+        assert_eq!(HASH_MAP.get(&4), Some(41));
+        assert_eq!(HASH_MAP.update(5, 0, |_| ()), Err(PgSharedHashMapError::HashMapFull));
+
+        // the iterator is a snapshot, so the map can be changed while iterating
+        for (key, value) in HASH_MAP.iter() {
+            assert_eq!(HASH_MAP.insert(key, value + 1), Ok(Some(value)));
+        }
+        assert_eq!(HASH_MAP.get(&1), Some(12));
+
+        assert_eq!(HASH_MAP.remove(&2), Some(21));
+        assert_eq!(HASH_MAP.remove(&2), None);
+        assert!(!HASH_MAP.contains_key(&2));
+        assert_eq!(
+            HASH_MAP.update(2, 7, |value| {
+                *value += 1;
+                *value
+            }),
+            Ok(8)
+        );
+        for key in [1, 2, 3, 4] {
+            HASH_MAP.remove(&key);
+        }
+        assert!(HASH_MAP.is_empty());
+    }
 }
@@ -14,6 +14,7 @@ mod tests {
     use crate as pgx_tests;
 
     use pgx::prelude::*;
+    use pgx::spi::{CursorOptions, FetchDirection, FromSpiRowError, SpiError};
 
     #[pg_test(error = "syntax error at or near \"THIS\"")]
     fn test_spi_failure() {
@@ -243,6 +244,97 @@ mod tests {
         });
     }
 
+    #[pg_test]
+    fn test_scroll_cursor() {
+        Spi::connect(|client| {
+            let mut cursor = client.open_cursor_with_options(
+                "SELECT * FROM generate_series(1, 10)",
+                None,
+                CursorOptions::SCROLL,
+            );
+            assert_eq!(Some(1), cursor.fetch(2).first().get_one::<i32>());
+            assert_eq!(
+                Some(1),
+                cursor.fetch_direction(FetchDirection::Backward, 1).first().get_one::<i32>()
+            );
+            assert_eq!(
+                Some(7),
+                cursor.fetch_direction(FetchDirection::Absolute, 7).first().get_one::<i32>()
+            );
+            assert_eq!(
+                Some(5),
+                cursor.fetch_direction(FetchDirection::Relative, -2).first().get_one::<i32>()
+            );
+            assert_eq!(
+                Some(10),
+                cursor.fetch_direction(FetchDirection::Absolute, -1).first().get_one::<i32>()
+            );
+            Ok(None::<()>)
+        });
+    }
+
+    #[pg_test]
+    fn test_cursor_move() {
+        Spi::connect(|client| {
+            let mut cursor = client.open_cursor_with_options(
+                "SELECT * FROM generate_series(1, 10)",
+                None,
+                CursorOptions::SCROLL,
+            );
+            assert_eq!(3, cursor.move_forward(3));
+            assert_eq!(Some(4), cursor.fetch(1).first().get_one::<i32>());
+            assert_eq!(2, cursor.move_backward(2));
+            assert_eq!(Some(3), cursor.fetch(1).first().get_one::<i32>());
+            cursor.move_absolute(0);
+            assert_eq!(Some(1), cursor.fetch(1).first().get_one::<i32>());
+            cursor.move_relative(5);
+            assert_eq!(Some(7), cursor.fetch(1).first().get_one::<i32>());
+            Ok(None::<()>)
+        });
+    }
+
+    #[pg_test]
+    fn test_no_scroll_cursor_backward() {
+        Spi::connect(|client| {
+            let mut cursor = client.open_cursor_with_options(
+                "SELECT * FROM generate_series(1, 10)",
+                None,
+                CursorOptions::NO_SCROLL,
+            );
+            cursor.fetch(2);
+            let result = cursor.try_fetch_direction(FetchDirection::Backward, 1);
+            assert!(matches!(
+                result,
+                Err(SpiError::PostgresError {
+                    sqlstate: PgSqlErrorCode::ERRCODE_OBJECT_NOT_IN_PREREQUISITE_STATE,
+                    ..
+                })
+            ));
+            Ok(None::<()>)
+        });
+    }
+
+    #[pg_test]
+    fn test_prepared_scroll_cursor() {
+        Spi::connect(|client| {
+            let stmt = client.prepare_cursor(
+                "SELECT * FROM generate_series(1, $1)",
+                &[PgBuiltInOids::INT4OID.oid()],
+                CursorOptions::SCROLL,
+            );
+            let mut cursor = client.open_cursor(&stmt, (5,));
+            assert_eq!(
+                Some(5),
+                cursor.fetch_direction(FetchDirection::Absolute, 5).first().get_one::<i32>()
+            );
+            assert_eq!(
+                Some(4),
+                cursor.fetch_direction(FetchDirection::Backward, 1).first().get_one::<i32>()
+            );
+            Ok(None::<()>)
+        });
+    }
+
     #[pg_test(error = "syntax error at or near \"THIS\"")]
     fn test_cursor_failure() {
         Spi::execute(|client| {
@@ -298,6 +390,267 @@ mod tests {
         });
     }
 
+    #[pg_test]
+    fn test_spi_typed_args() {
+        let (a, b) =
+            Spi::get_two_with_args::<i32, String>("SELECT $1 + 1, $2 || '!'", (41_i32, "hello"));
+        assert_eq!(Some(42), a);
+        assert_eq!(Some("hello!".to_string()), b);
+    }
+
+    #[pg_test]
+    fn test_spi_typed_null_arg() {
+        let is_null = Spi::get_one_with_args::<bool>(
+            "SELECT $1 IS NULL AND $2 = 'name'",
+            (None::<pgx::Uuid>, "name"),
+        );
+        assert_eq!(Some(true), is_null);
+    }
+
+    #[pg_test]
+    fn test_spi_slice_args() {
+        let sum = Spi::connect(|client| {
+            Ok(client.select("SELECT $1 + $2 + $3", None, &[1_i64, 2, 3][..]).first().get_one())
+        });
+        assert_eq!(Some(6_i64), sum);
+    }
+
+    #[pg_test]
+    fn test_prepared_statement() {
+        Spi::execute(|client| {
+            let stmt = client.prepare("SELECT $1::int + 1", &[PgBuiltInOids::INT4OID.oid()]);
+            assert_eq!(1, stmt.nargs());
+            assert_eq!(Some(PgBuiltInOids::INT4OID.oid()), stmt.arg_type_oid(0));
+
+            for i in 0..10_i32 {
+                let rc = client.select(&stmt, Some(1), (i,)).first();
+                assert_eq!(Some(i + 1), rc.get_one::<i32>());
+            }
+        });
+    }
+
+    #[pg_test]
+    fn test_prepared_statement_cursor() {
+        Spi::execute(|client| {
+            let stmt = client
+                .prepare("SELECT * FROM generate_series(1, $1)", &[PgBuiltInOids::INT4OID.oid()]);
+            let mut cursor = client.open_cursor(&stmt, (10_i32,));
+
+            fn sum_all(table: pgx::SpiTupleTable) -> i32 {
+                table.map(|r| r.by_ordinal(1).unwrap().value::<i32>().unwrap()).sum()
+            }
+            assert_eq!(sum_all(cursor.fetch(5)), 1 + 2 + 3 + 4 + 5);
+            assert_eq!(sum_all(cursor.fetch(5)), 6 + 7 + 8 + 9 + 10);
+        });
+    }
+
+    #[pg_test]
+    fn test_owned_prepared_statement() {
+        let stmt = Spi::connect(|client| {
+            Ok(Some(client.prepare("SELECT $1::text", &[PgBuiltInOids::TEXTOID.oid()]).keep()))
+        })
+        .unwrap();
+
+        let rc =
+            Spi::connect(|client| Ok(client.select(&stmt, Some(1), ("pgx",)).first().get_one()));
+        assert_eq!(Some("pgx".to_string()), rc);
+    }
+
+    #[pg_test]
+    fn test_prepared_statement_cache() {
+        let query = "SELECT $1::bigint * 2";
+        let args = [PgBuiltInOids::INT8OID.oid()];
+
+        let (a, b) = Spi::connect(|client| {
+            let a = client.prepare_cached(query, &args);
+            Ok(Some((a, client.prepare_cached(query, &args))))
+        })
+        .unwrap();
+        assert!(std::rc::Rc::ptr_eq(&a, &b));
+
+        let rc =
+            Spi::connect(|client| Ok(client.select(&*b, Some(1), (21_i64,)).first().get_one()));
+        assert_eq!(Some(42_i64), rc);
+
+        Spi::forget_prepared(query, &args);
+        let c = Spi::connect(|client| Ok(Some(client.prepare_cached(query, &args)))).unwrap();
+        assert!(!std::rc::Rc::ptr_eq(&a, &c));
+        Spi::clear_prepared();
+    }
+
+    #[pg_test(error = "prepared statement expects 1 arguments, got 0")]
+    fn test_prepared_statement_wrong_nargs() {
+        Spi::execute(|client| {
+            let stmt = client.prepare("SELECT $1::int", &[PgBuiltInOids::INT4OID.oid()]);
+            client.select(&stmt, None, None);
+        });
+    }
+
+    #[pg_test(error = "prepared statement argument 1 is of type text, got integer")]
+    fn test_prepared_statement_wrong_arg_type() {
+        Spi::execute(|client| {
+            let stmt = client.prepare("SELECT $1::text", &[PgBuiltInOids::TEXTOID.oid()]);
+            client.select(&stmt, None, (42i32,));
+        });
+    }
+
+    #[pg_test]
+    fn test_try_get_one() {
+        assert_eq!(Ok(Some(42)), Spi::try_get_one::<i32>("SELECT 42"));
+    }
+
+    #[pg_test]
+    fn test_try_select_syntax_error() {
+        let result = Spi::try_connect(|client| client.try_select("THIS IS NOT SQL", None, None));
+        match result {
+            Err(SpiError::PostgresError { sqlstate, message, .. }) => {
+                assert_eq!(PgSqlErrorCode::ERRCODE_SYNTAX_ERROR, sqlstate);
+                assert_eq!("syntax error at or near \"THIS\"", message);
+            }
+            _ => panic!("expected a syntax error"),
+        }
+    }
+
+    #[pg_test]
+    fn test_try_update_recovers_from_unique_violation() {
+        Spi::run("CREATE TABLE tests.try_unique (id int PRIMARY KEY)");
+        Spi::run("INSERT INTO tests.try_unique VALUES (1)");
+
+        let result = Spi::try_run("INSERT INTO tests.try_unique VALUES (1)");
+        match result {
+            Err(SpiError::PostgresError { sqlstate, detail, .. }) => {
+                assert_eq!(PgSqlErrorCode::ERRCODE_UNIQUE_VIOLATION, sqlstate);
+                assert_eq!(Some("Key (id)=(1) already exists.".to_string()), detail);
+            }
+            _ => panic!("expected a unique violation"),
+        }
+
+        // the outer transaction is still usable
+        Spi::run("INSERT INTO tests.try_unique VALUES (2)");
+        assert_eq!(Some(2), Spi::get_one::<i64>("SELECT count(*) FROM tests.try_unique"));
+    }
+
+    #[pg_test]
+    fn test_try_run_rolls_back_failed_statement() {
+        Spi::run("CREATE TABLE tests.try_rollback (id int)");
+        let result = Spi::try_run("INSERT INTO tests.try_rollback VALUES (1); SELECT 1 / 0");
+        assert!(matches!(
+            result,
+            Err(SpiError::PostgresError { sqlstate: PgSqlErrorCode::ERRCODE_DIVISION_BY_ZERO, .. })
+        ));
+        assert_eq!(Some(0), Spi::get_one::<i64>("SELECT count(*) FROM tests.try_rollback"));
+    }
+
+    #[pg_test]
+    fn test_try_error_hint() {
+        let result = Spi::try_get_one::<i32>("SELECT tests.this_function_does_not_exist(1)");
+        match result {
+            Err(SpiError::PostgresError { sqlstate, hint, .. }) => {
+                assert_eq!(PgSqlErrorCode::ERRCODE_UNDEFINED_FUNCTION, sqlstate);
+                assert!(hint.is_some());
+            }
+            _ => panic!("expected an undefined function error"),
+        }
+    }
+
+    #[pg_test]
+    fn test_try_find_cursor_not_found() {
+        let result = Spi::try_connect(|client| client.try_find_cursor("NOT A CURSOR").map(|_| ()));
+        assert_eq!(Err(SpiError::CursorNotFound("NOT A CURSOR".to_string())), result);
+    }
+
+    #[pg_test]
+    fn test_try_prepare_failure() {
+        let result =
+            Spi::try_connect(|client| client.try_prepare("THIS IS NOT SQL", &[]).map(|_| ()));
+        assert!(matches!(
+            result,
+            Err(SpiError::PostgresError { sqlstate: PgSqlErrorCode::ERRCODE_SYNTAX_ERROR, .. })
+        ));
+    }
+
+    #[derive(FromSpiRow, Debug, PartialEq)]
+    struct Dog {
+        name: String,
+        #[spi(name = "scritches")]
+        pets: Option<i32>,
+    }
+
+    #[derive(FromSpiRow, Debug, PartialEq)]
+    struct Pair(i64, Option<String>);
+
+    #[pg_test]
+    fn test_from_spi_row_by_name() {
+        Spi::execute(|client| {
+            let row = client.select("SELECT 42 AS scritches, 'Nami' AS name", None, None).first();
+            let dog = row.into_typed::<Dog>().next().unwrap().unwrap();
+            assert_eq!(Dog { name: "Nami".into(), pets: Some(42) }, dog);
+        });
+    }
+
+    #[pg_test]
+    fn test_from_spi_row_by_ordinal() {
+        Spi::execute(|client| {
+            let pairs = client
+                .select("SELECT * FROM (VALUES (1::bigint, 'a'), (2, NULL)) v", None, None)
+                .into_typed::<Pair>()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(vec![Pair(1, Some("a".into())), Pair(2, None)], pairs);
+        });
+    }
+
+    #[pg_test]
+    fn test_from_spi_row_null_into_option() {
+        Spi::execute(|client| {
+            let dog = client
+                .select("SELECT 'Brandy' AS name, NULL::int AS scritches", None, None)
+                .into_typed::<Dog>()
+                .next()
+                .unwrap();
+            assert_eq!(Ok(Dog { name: "Brandy".into(), pets: None }), dog);
+        });
+    }
+
+    #[pg_test]
+    fn test_from_spi_row_unexpected_null() {
+        Spi::execute(|client| {
+            let dog = client
+                .select("SELECT NULL::text AS name, 1 AS scritches", None, None)
+                .into_typed::<Dog>()
+                .next()
+                .unwrap();
+            assert_eq!(Err(FromSpiRowError::UnexpectedNull("name".into())), dog);
+        });
+    }
+
+    #[pg_test]
+    fn test_from_spi_row_missing_column() {
+        Spi::execute(|client| {
+            let dog = client.select("SELECT 'Nami' AS name", None, None).into_typed::<Dog>().next();
+            assert_eq!(Some(Err(FromSpiRowError::NoSuchColumn("scritches".into()))), dog);
+
+            let pair = client.select("SELECT 1::bigint", None, None).into_typed::<Pair>().next();
+            assert_eq!(Some(Err(FromSpiRowError::NoSuchColumn("#2".into()))), pair);
+        });
+    }
+
+    #[pg_test]
+    fn test_from_spi_row_incompatible_types() {
+        Spi::execute(|client| {
+            let dog = client
+                .select("SELECT 'Nami' AS name, 'lots' AS scritches", None, None)
+                .into_typed::<Dog>()
+                .next()
+                .unwrap();
+            assert!(matches!(
+                dog,
+                Err(FromSpiRowError::IncompatibleTypes { ref column, type_oid, .. })
+                    if column == "scritches" && type_oid == pg_sys::TEXTOID
+            ));
+        });
+    }
+
     #[pg_test]
     fn test_open_multiple_tuptables() {
         Spi::execute(|client| {
@@ -321,4 +674,51 @@ mod tests {
             assert!(a.get_datum::<i32>(1).is_none());
         });
     }
+
+    #[pg_extern(stable)]
+    fn spi_client_is_read_only_in_stable() -> bool {
+        Spi::connect(|client| Ok(Some(client.is_read_only()))).unwrap()
+    }
+
+    #[pg_extern]
+    fn spi_client_is_read_only_in_volatile() -> bool {
+        Spi::connect(|client| Ok(Some(client.is_read_only()))).unwrap()
+    }
+
+    #[pg_test]
+    fn test_read_only_follows_volatility() {
+        assert_eq!(Spi::get_one("SELECT tests.spi_client_is_read_only_in_stable()"), Some(true));
+        assert_eq!(Spi::get_one("SELECT tests.spi_client_is_read_only_in_volatile()"), Some(false));
+    }
+
+    #[pg_test]
+    fn test_connect_read_only() {
+        let result = Spi::connect_read_only(|client| {
+            assert!(client.is_read_only());
+            Ok(client.select("SELECT 42", None, ()).first().get_one::<i32>())
+        });
+        assert_eq!(result, Some(42));
+    }
+
+    #[pg_test(error = "INSERT is not allowed in a non-volatile function")]
+    fn test_connect_read_only_rejects_writes() {
+        Spi::run("CREATE TABLE tests.read_only_writes (id int)");
+        Spi::connect_read_only(|client| {
+            client.update("INSERT INTO tests.read_only_writes VALUES (1)", None, ());
+            Ok(Some(()))
+        });
+    }
+
+    #[pg_test]
+    fn test_select_readonly_uses_callers_snapshot() {
+        Spi::run("CREATE TABLE tests.read_only_snapshot (id int)");
+        Spi::execute(|client| {
+            client.update("INSERT INTO tests.read_only_snapshot VALUES (1)", None, ());
+
+            // a read-only statement doesn't see changes made since the calling query started
+            let count = "SELECT count(*) FROM tests.read_only_snapshot";
+            assert_eq!(client.select(count, None, ()).first().get_one::<i64>(), Some(1));
+            assert_eq!(client.select_readonly(count, None, ()).first().get_one::<i64>(), Some(0));
+        });
+    }
 }
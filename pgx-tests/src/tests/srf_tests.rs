@@ -76,13 +76,66 @@ fn split_table_with_borrow<'a>(
     TableIterator::new(input.split_terminator(pattern).enumerate().map(|(i, s)| (i as i32, s)))
 }
 
+#[pg_extern]
+fn cursor_generate_series(end: i32, batch_size: i64) -> SetOfIterator<'static, i32> {
+    Spi::connect(|client| {
+        let cursor = client.open_cursor("SELECT g FROM generate_series(1, $1) g", (end,));
+        Ok(Some(SetOfIterator::from_cursor(cursor, batch_size, |row| {
+            row["g"].value::<i32>().expect("g was NULL")
+        })))
+    })
+    .unwrap()
+}
+
+#[pg_extern]
+fn cursor_composite_set(
+    end: i32,
+) -> TableIterator<'static, (name!(idx, i32), name!(value, Option<String>))> {
+    Spi::connect(|client| {
+        let cursor = client.open_cursor(
+            "SELECT g AS idx, nullif('row ' || g, 'row 2') AS value FROM generate_series(1, $1) g",
+            (end,),
+        );
+        Ok(Some(TableIterator::from_cursor(cursor, 2, |row| {
+            (row["idx"].value::<i32>().expect("idx was NULL"), row["value"].value::<String>())
+        })))
+    })
+    .unwrap()
+}
+
+#[pg_extern(materialize)]
+fn materialized_generate_series(end: i32) -> SetOfIterator<'static, i32> {
+    SetOfIterator::new(1..=end)
+}
+
+#[pg_extern(materialize)]
+fn materialized_composite_set(
+    words: &str,
+) -> TableIterator<'_, (name!(idx, i32), name!(word, Option<&str>))> {
+    TableIterator::new(
+        words
+            .split(' ')
+            .enumerate()
+            .map(|(idx, word)| ((idx + 1) as i32, if word.is_empty() { None } else { Some(word) })),
+    )
+}
+
+#[pg_extern(materialize)]
+fn materialized_none() -> Option<SetOfIterator<'static, i32>> {
+    None
+}
+
 #[cfg(any(test, feature = "pg_test"))]
 #[pgx::pg_schema]
 mod tests {
     #[allow(unused_imports)]
     use crate as pgx_tests;
 
+    use pgx::pgx_sql_entity_graph::metadata::{
+        ArgumentError, Returns, ReturnsError, SqlMapping, SqlTranslatable,
+    };
     use pgx::prelude::*;
+    use pgx::{PgTupleDesc, Tuplestore};
 
     #[pg_test]
     fn test_generate_series() {
@@ -210,4 +263,181 @@ mod tests {
         });
         assert_eq!(cnt, Some(1000000))
     }
+
+    #[pg_test]
+    fn test_cursor_setof() {
+        let (count, sum) = Spi::get_two::<i64, i64>(
+            "SELECT count(*), sum(g) FROM cursor_generate_series(1000, 7) g",
+        );
+        assert_eq!(count, Some(1000));
+        assert_eq!(sum, Some(500500));
+    }
+
+    #[pg_test]
+    fn test_cursor_setof_empty() {
+        let count = Spi::get_one::<i64>("SELECT count(*) FROM cursor_generate_series(0, 10)");
+        assert_eq!(count, Some(0));
+    }
+
+    #[pg_test]
+    fn test_cursor_table() {
+        let values = Spi::connect(|client| {
+            let table = client.select("SELECT * FROM cursor_composite_set(3)", None, ());
+            Ok(Some(
+                table
+                    .map(|row| (row["idx"].value::<i32>(), row["value"].value::<String>()))
+                    .collect::<Vec<_>>(),
+            ))
+        });
+        assert_eq!(
+            values,
+            Some(vec![
+                (Some(1), Some("row 1".to_string())),
+                (Some(2), None),
+                (Some(3), Some("row 3".to_string())),
+            ])
+        );
+    }
+
+    #[pg_test]
+    fn test_cursor_closed_when_srf_stops_early() {
+        let count = Spi::get_one::<i64>(
+            "SELECT count(*) FROM (SELECT cursor_generate_series(1000, 10) LIMIT 5) x",
+        );
+        assert_eq!(count, Some(5));
+
+        let open_cursors = Spi::get_one::<i64>("SELECT count(*) FROM pg_cursors");
+        assert_eq!(open_cursors, Some(0));
+    }
+
+    #[pg_test]
+    fn test_materialized_generate_series() {
+        let result = Spi::get_two::<i64, i64>(
+            "SELECT count(*), sum(g) FROM materialized_generate_series(10000) g",
+        );
+        assert_eq!(result, (Some(10000), Some(50005000)));
+    }
+
+    #[pg_test]
+    fn test_materialized_in_targetlist() {
+        let count = Spi::get_one::<i64>(
+            "SELECT count(*) FROM (SELECT materialized_generate_series(100) LIMIT 5) x",
+        );
+        assert_eq!(count, Some(5));
+    }
+
+    #[pg_test]
+    fn test_materialized_none() {
+        let count = Spi::get_one::<i64>("SELECT count(*) FROM materialized_none()");
+        assert_eq!(count, Some(0));
+    }
+
+    #[pg_test]
+    fn test_materialized_table() {
+        let values = Spi::connect(|client| {
+            let table =
+                client.select("SELECT * FROM materialized_composite_set('a  c')", None, None);
+            Ok(Some(
+                table
+                    .map(|row| (row["idx"].value::<i32>(), row["word"].value::<String>()))
+                    .collect::<Vec<_>>(),
+            ))
+        });
+        assert_eq!(
+            values,
+            Some(vec![
+                (Some(1), Some("a".to_string())),
+                (Some(2), None),
+                (Some(3), Some("c".to_string())),
+            ])
+        );
+    }
+
+    #[pg_test]
+    fn test_tuplestore() {
+        let tupdesc = unsafe {
+            PgTupleDesc::from_pg_is_copy(pg_sys::TypeGetTupleDesc(
+                pg_sys::INT4OID,
+                std::ptr::null_mut(),
+            ))
+        };
+        let mut store = Tuplestore::new(&tupdesc, true);
+        assert!(store.is_empty());
+
+        for i in 1..=3 {
+            store.put_values(&[i.into_datum()]);
+        }
+        store.put_values(&[None]);
+        assert_eq!(store.len(), 4);
+
+        let read = |store: &mut Tuplestore| {
+            let mut values = Vec::new();
+            while let Some(row) = store.next_values() {
+                values.push(row[0].and_then(|datum| unsafe { i32::from_datum(datum, false) }));
+            }
+            values
+        };
+        assert_eq!(read(&mut store), vec![Some(1), Some(2), Some(3), None]);
+
+        store.rescan();
+        assert_eq!(read(&mut store), vec![Some(1), Some(2), Some(3), None]);
+    }
+
+    #[pg_test]
+    fn test_table_iterator_into_datum() {
+        let table = TableIterator::new(
+            vec![(1, Some("a".to_string())), (2, None), (3, Some("c d".to_string()))].into_iter(),
+        );
+        let records = table.into_datum();
+        let text = Spi::get_one_with_args::<String>(
+            "SELECT $1::text",
+            vec![(PgBuiltInOids::RECORDARRAYOID.oid(), records)],
+        );
+        assert_eq!(text, Some(r#"{"(1,a)","(2,)","(3,\"c d\")"}"#.to_string()));
+    }
+
+    #[pg_test]
+    fn test_table_iterator_into_datum_empty() {
+        let table = TableIterator::<(i32, i64)>::new(std::iter::empty());
+        let records = table.into_datum();
+        let count = Spi::get_one_with_args::<i32>(
+            "SELECT cardinality($1)",
+            vec![(PgBuiltInOids::RECORDARRAYOID.oid(), records)],
+        );
+        assert_eq!(count, Some(0));
+    }
+
+    /// An `int4` whose `SqlTranslatable` impl declares SQL that isn't valid type syntax
+    struct OddlyDeclared(i32);
+
+    impl IntoDatum for OddlyDeclared {
+        fn into_datum(self) -> Option<pg_sys::Datum> {
+            self.0.into_datum()
+        }
+
+        fn type_oid() -> pg_sys::Oid {
+            pg_sys::INT4OID
+        }
+    }
+
+    unsafe impl SqlTranslatable for OddlyDeclared {
+        fn argument_sql() -> Result<SqlMapping, ArgumentError> {
+            Ok(SqlMapping::literal("not a (type"))
+        }
+
+        fn return_sql() -> Result<Returns, ReturnsError> {
+            Ok(Returns::One(SqlMapping::literal("not a (type")))
+        }
+    }
+
+    #[pg_test]
+    fn test_table_iterator_into_datum_invalid_sql() {
+        let table = TableIterator::new(vec![(OddlyDeclared(1),), (OddlyDeclared(2),)].into_iter());
+        let records = table.into_datum();
+        let text = Spi::get_one_with_args::<String>(
+            "SELECT $1::text",
+            vec![(PgBuiltInOids::RECORDARRAYOID.oid(), records)],
+        );
+        assert_eq!(text, Some(r#"{(1),(2)}"#.to_string()));
+    }
 }
@@ -10,8 +10,13 @@ Use of this source code is governed by the MIT license that can be found in the
 //! Safely create Postgres Background Workers, including with full SPI support
 //!
 //! See: [https://www.postgresql.org/docs/current/bgworker.html](https://www.postgresql.org/docs/current/bgworker.html)
+//!
+//! A worker whose main loop just runs some jobs every so often can leave that loop to a
+//! [`Scheduler`].
 use crate::pg_sys;
+use crate::shm_mq::Receiver;
 use pgx_pg_sys::PgTryBuilder;
+use serde::Serialize;
 use std::convert::TryInto;
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
@@ -19,6 +24,11 @@ use std::ptr::null_mut;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
+mod payload;
+mod scheduler;
+pub use payload::SetResultError;
+pub use scheduler::{CronParseError, CronSchedule, Schedule, Scheduler};
+
 pub static mut PREV_SHMEM_STARTUP_HOOK: Option<unsafe extern "C" fn()> = None;
 static GOT_SIGHUP: AtomicBool = AtomicBool::new(false);
 static GOT_SIGTERM: AtomicBool = AtomicBool::new(false);
@@ -39,7 +49,7 @@ bitflags! {
 }
 
 bitflags! {
-    struct WLflags: i32 {
+    pub(crate) struct WLflags: i32 {
         const WL_LATCH_SET         = pg_sys::WL_LATCH_SET as i32;
         const WL_SOCKET_READABLE   = pg_sys::WL_SOCKET_READABLE as i32;
         const WL_SOCKET_WRITEABLE  = pg_sys::WL_SOCKET_WRITEABLE as i32;
@@ -222,10 +232,19 @@ unsafe extern "C" fn worker_spi_sigterm(_signal_args: i32) {
     pg_sys::SetLatch(pg_sys::MyLatch);
 }
 
+/// Has this background worker received a SIGTERM that it has yet to act on?
+///
+/// Unlike [`BackgroundWorker::sigterm_received`] this leaves the flag set, for code that needs to
+/// stop waiting on behalf of a worker's main loop, which will then see the SIGTERM itself.
+pub(crate) fn sigterm_pending() -> bool {
+    GOT_SIGTERM.load(Ordering::SeqCst)
+}
+
 /// Dynamic background worker handle
 pub struct DynamicBackgroundWorker {
-    handle: *mut pg_sys::BackgroundWorkerHandle,
+    pub(crate) handle: *mut pg_sys::BackgroundWorkerHandle,
     notify_pid: pg_sys::pid_t,
+    result: Option<Receiver<serde_cbor::Value>>,
 }
 
 /// PID
@@ -379,6 +398,8 @@ pub struct BackgroundWorkerBuilder {
     bgw_extra: String,
     bgw_notify_pid: pg_sys::pid_t,
     shared_memory_startup_fn: Option<unsafe extern "C" fn()>,
+    payload: Option<serde_cbor::Value>,
+    result: bool,
 }
 
 impl BackgroundWorkerBuilder {
@@ -401,6 +422,8 @@ impl BackgroundWorkerBuilder {
             bgw_extra: "".to_string(),
             bgw_notify_pid: 0,
             shared_memory_startup_fn: None,
+            payload: None,
+            result: false,
         }
     }
 
@@ -441,6 +464,8 @@ impl BackgroundWorkerBuilder {
     /// the interval, in seconds, that postgres should wait before restarting the process,
     /// in case it crashes. It can be `Some(any positive duration value), or
     /// `None`, indicating not to restart the process in case of a crash.
+    ///
+    /// Dynamic workers given a [`BackgroundWorkerBuilder::with_payload`] can't be restarted.
     pub fn set_restart_time(mut self: Self, input: Option<Duration>) -> Self {
         self.bgw_restart_time = input;
         self
@@ -520,6 +545,54 @@ impl BackgroundWorkerBuilder {
         self
     }
 
+    /// A value of any type that can be serialized with `serde`, which the background worker gets
+    /// a copy of with [`BackgroundWorker::payload`]
+    ///
+    /// Small payloads are passed in the worker's `extra` data, so this can't be combined with
+    /// [`BackgroundWorkerBuilder::set_extra`].  Larger ones are passed in dynamic shared memory,
+    /// which is only possible with [`BackgroundWorkerBuilder::load_dynamic`], and which lasts
+    /// until the worker calls [`BackgroundWorker::payload`] and exits, or the server restarts.
+    ///
+    /// A worker restarted by Postgres would find that memory gone, so a dynamic worker with a
+    /// payload can't also be given a [`BackgroundWorkerBuilder::set_restart_time`].
+    ///
+    /// ```rust,no_run
+    /// use pgx::prelude::*;
+    /// use pgx::bgworkers::{BackgroundWorker, BackgroundWorkerBuilder};
+    ///
+    /// BackgroundWorkerBuilder::new("Example")
+    ///     .set_function("background_worker_main")
+    ///     .set_library("example")
+    ///     .with_payload(vec!["some".to_string(), "tables".to_string()])
+    ///     .load_dynamic();
+    ///
+    /// #[pg_guard]
+    /// #[no_mangle]
+    /// pub extern "C" fn background_worker_main(_arg: pg_sys::Datum) {
+    ///     let tables = BackgroundWorker::payload::<Vec<String>>().unwrap();
+    /// }
+    /// ```
+    pub fn with_payload<T: Serialize>(mut self: Self, payload: T) -> Self {
+        self.payload = Some(
+            serde_cbor::value::to_value(payload)
+                .expect("failed to serialize background worker payload"),
+        );
+        self.bgw_flags = self.bgw_flags | BGWflags::BGWORKER_SHMEM_ACCESS;
+        self
+    }
+
+    /// Let the background worker report a result with [`BackgroundWorker::set_result`], which
+    /// the launching backend waits for with [`DynamicBackgroundWorker::wait_for_result`]
+    ///
+    /// As with [`BackgroundWorkerBuilder::with_payload`] this can't be combined with
+    /// [`BackgroundWorkerBuilder::set_extra`].  Ignored if [`BackgroundWorkerBuilder::load`] is
+    /// used.
+    pub fn enable_result(mut self: Self) -> Self {
+        self.result = true;
+        self.bgw_flags = self.bgw_flags | BGWflags::BGWORKER_SHMEM_ACCESS;
+        self
+    }
+
     /// PID of a PostgreSQL backend process to which the postmaster should send SIGUSR1
     /// when the process is started or exits. It should be 0 for workers registered at
     /// postmaster startup time, or when the backend registering the worker does not wish
@@ -532,7 +605,14 @@ impl BackgroundWorkerBuilder {
 
     /// Once properly configured, call `load()` to get the BackgroundWorker registered and
     /// started at the proper time by Postgres.
-    pub fn load(self: Self) {
+    pub fn load(mut self: Self) {
+        if self.payload.is_some() {
+            assert!(
+                self.bgw_extra.is_empty(),
+                "`set_extra()` can't be combined with `with_payload()`"
+            );
+            self.bgw_extra = payload::Envelope::encode(self.payload.take(), None, false).0;
+        }
         let mut bgw: pg_sys::BackgroundWorker = (&self).into();
 
         unsafe {
@@ -547,15 +627,41 @@ impl BackgroundWorkerBuilder {
     }
 
     /// Once properly configured, call `load_dynamic()` to get the BackgroundWorker registered and started dynamically.
-    pub fn load_dynamic(self: Self) -> DynamicBackgroundWorker {
+    pub fn load_dynamic(mut self: Self) -> DynamicBackgroundWorker {
+        assert!(
+            self.payload.is_none() || self.bgw_restart_time.is_none(),
+            "`with_payload()` can't be combined with `set_restart_time()` for a dynamic background worker"
+        );
+        let mut result = self.result.then(|| Receiver::create(payload::RESULT_QUEUE_SIZE));
+        let mut payload_segment = None;
+        if self.payload.is_some() || result.is_some() {
+            assert!(
+                self.bgw_extra.is_empty(),
+                "`set_extra()` can't be combined with `with_payload()` or `enable_result()`"
+            );
+            let result_handle = result.as_ref().map(Receiver::handle);
+            let (extra, segment) =
+                payload::Envelope::encode(self.payload.take(), result_handle, true);
+            self.bgw_extra = extra;
+            payload_segment = segment;
+        }
+
         let mut bgw: pg_sys::BackgroundWorker = (&self).into();
         let mut handle: *mut pg_sys::BackgroundWorkerHandle = null_mut();
 
-        unsafe {
-            pg_sys::RegisterDynamicBackgroundWorker(&mut bgw, &mut handle);
-        };
-
-        DynamicBackgroundWorker { handle, notify_pid: bgw.bgw_notify_pid }
+        let registered = unsafe { pg_sys::RegisterDynamicBackgroundWorker(&mut bgw, &mut handle) };
+        let mut worker =
+            DynamicBackgroundWorker { handle, notify_pid: bgw.bgw_notify_pid, result: None };
+        if registered {
+            if let Some(receiver) = &mut result {
+                receiver.set_worker(&worker);
+            }
+            worker.result = result;
+        } else if let Some(segment) = payload_segment {
+            // nothing will ever attach to the payload, so don't leave it pinned
+            crate::dsm::DsmSegment::unpin(segment);
+        }
+        worker
     }
 }
 
@@ -591,7 +697,7 @@ impl<'a> Into<pg_sys::BackgroundWorker> for &'a BackgroundWorkerBuilder {
     }
 }
 
-fn wait_latch(timeout: i64, wakeup_flags: WLflags) -> i32 {
+pub(crate) fn wait_latch(timeout: i64, wakeup_flags: WLflags) -> i32 {
     unsafe {
         let latch = pg_sys::WaitLatch(
             pg_sys::MyLatch,
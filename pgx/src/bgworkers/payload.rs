@@ -0,0 +1,177 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Typed payloads for background workers, and typed results back from dynamic ones
+//!
+//! Both are described by an [`Envelope`] that takes the place of the worker's `bgw_extra`.  A
+//! payload small enough to fit travels in the envelope itself, and a larger one in a pinned
+//! [`DsmSegment`] that the worker unpins once it has attached to it.  A result is sent through a
+//! [`shm_mq`](crate::shm_mq) the launching backend creates.
+use crate::bgworkers::{BackgroundWorker, DynamicBackgroundWorker};
+use crate::dsm::{DsmHandle, DsmSegment};
+use crate::pg_sys;
+use crate::shm_mq::{Sender, ShmMqError};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Marks a `bgw_extra` that holds an [`Envelope`], rather than whatever `set_extra()` was given
+const ENVELOPE_PREFIX: &str = "pgx:";
+
+/// `bgw_extra` is 128 bytes, including its null terminator
+const MAX_EXTRA_LEN: usize = 127;
+
+/// Describes why [`BackgroundWorker::set_result`] could not report a result
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum SetResultError {
+    #[error("A result has already been sent")]
+    AlreadySent,
+    #[error(transparent)]
+    ShmMq(#[from] ShmMqError),
+}
+
+/// Has [`BackgroundWorker::set_result`] been called?  Each worker is a process of its own, so
+/// this is per worker.
+static RESULT_SENT: AtomicBool = AtomicBool::new(false);
+
+/// How big a queue results are sent through.  Larger results are fine, they just pass through
+/// the queue in pieces.
+pub(super) const RESULT_QUEUE_SIZE: usize = 16 * 1024;
+
+#[derive(Serialize, Deserialize, Default)]
+pub(super) struct Envelope {
+    payload: Option<Payload>,
+    result: Option<DsmHandle>,
+}
+
+#[derive(Serialize, Deserialize)]
+enum Payload {
+    Inline(serde_cbor::Value),
+    Dsm(DsmHandle),
+}
+
+impl Envelope {
+    /// Wrap up `payload` and the handle of the result queue, if any, as a `bgw_extra`
+    ///
+    /// If `dynamic`, a payload too large to fit is put in a new, pinned, segment, which is
+    /// returned so it can be unpinned should the worker fail to register.
+    pub(super) fn encode(
+        payload: Option<serde_cbor::Value>,
+        result: Option<DsmHandle>,
+        dynamic: bool,
+    ) -> (String, Option<DsmHandle>) {
+        let payload = match payload {
+            Some(payload) => payload,
+            None => return (Envelope { payload: None, result }.to_extra(), None),
+        };
+        let bytes =
+            serde_cbor::to_vec(&payload).expect("failed to serialize background worker payload");
+        let extra = Envelope { payload: Some(Payload::Inline(payload)), result }.to_extra();
+        if extra.len() <= MAX_EXTRA_LEN {
+            return (extra, None);
+        }
+
+        assert!(
+            dynamic,
+            "a background worker payload this large can only be given to a dynamic background worker"
+        );
+        let mut segment = DsmSegment::create(std::mem::size_of::<usize>() + bytes.len());
+        unsafe {
+            let chunk = segment.as_mut_slice();
+            let (len, rest) = chunk.split_at_mut(std::mem::size_of::<usize>());
+            len.copy_from_slice(&bytes.len().to_ne_bytes());
+            rest.copy_from_slice(&bytes);
+        }
+        // the worker can't attach to the segment once we detach from it, unless it's pinned
+        segment.pin();
+
+        let handle = segment.handle();
+        let envelope = Envelope { payload: Some(Payload::Dsm(handle)), result };
+        (envelope.to_extra(), Some(handle))
+    }
+
+    fn to_extra(&self) -> String {
+        let bytes = serde_cbor::ser::to_vec_packed(self).expect("failed to serialize envelope");
+        format!("{}{}", ENVELOPE_PREFIX, base64::encode_config(bytes, base64::STANDARD_NO_PAD))
+    }
+
+    /// The envelope in this background worker's `bgw_extra`, or an empty one if there isn't one
+    fn this_worker() -> Self {
+        let encoded = match BackgroundWorker::get_extra().strip_prefix(ENVELOPE_PREFIX) {
+            Some(encoded) => encoded,
+            None => return Envelope::default(),
+        };
+        let bytes = base64::decode_config(encoded, base64::STANDARD_NO_PAD)
+            .expect("invalid background worker envelope");
+        serde_cbor::from_slice(&bytes).expect("invalid background worker envelope")
+    }
+}
+
+impl BackgroundWorker {
+    /// The payload given to [`BackgroundWorkerBuilder::with_payload`](crate::bgworkers::BackgroundWorkerBuilder::with_payload),
+    /// or `None` if there wasn't one
+    ///
+    /// Panics if the payload can't be deserialized as a `T`.
+    pub fn payload<T: DeserializeOwned>() -> Option<T> {
+        let payload = match Envelope::this_worker().payload? {
+            Payload::Inline(value) => serde_cbor::value::from_value(value),
+            Payload::Dsm(handle) => unsafe {
+                let mut seg = pg_sys::dsm_find_mapping(handle.0);
+                if seg.is_null() {
+                    seg = pg_sys::dsm_attach(handle.0);
+                    assert!(!seg.is_null(), "the background worker payload is gone");
+                    // stay attached until we exit, so the segment goes away with us
+                    pg_sys::dsm_pin_mapping(seg);
+                    pg_sys::dsm_unpin_segment(handle.0);
+                }
+                let chunk = pg_sys::dsm_segment_address(seg) as *const u8;
+                let len = (chunk as *const usize).read_unaligned();
+                let bytes =
+                    std::slice::from_raw_parts(chunk.add(std::mem::size_of::<usize>()), len);
+                serde_cbor::from_slice(bytes)
+            },
+        };
+        Some(payload.expect("failed to deserialize background worker payload"))
+    }
+
+    /// Report `result` back to the backend that launched this dynamic worker, which receives it
+    /// with [`DynamicBackgroundWorker::wait_for_result`]
+    ///
+    /// This can only be done once, and any later call fails with [`SetResultError::AlreadySent`].
+    /// It waits for the launching backend to receive the result if it's larger than the queue it's
+    /// sent through, and fails with [`ShmMqError::Detached`] if the worker wasn't built with
+    /// [`BackgroundWorkerBuilder::enable_result`](crate::bgworkers::BackgroundWorkerBuilder::enable_result),
+    /// or the launching backend has gone away.
+    pub fn set_result<R: Serialize>(result: &R) -> Result<(), SetResultError> {
+        // the queue can only ever have one sender, even if the first attempt failed
+        if RESULT_SENT.swap(true, Ordering::Relaxed) {
+            return Err(SetResultError::AlreadySent);
+        }
+        let handle = Envelope::this_worker().result.ok_or(ShmMqError::Detached)?;
+        let mut sender = Sender::<R>::attach(handle).ok_or(ShmMqError::Detached)?;
+        Ok(sender.send(result)?)
+    }
+}
+
+impl DynamicBackgroundWorker {
+    /// Wait for the worker to report its result with [`BackgroundWorker::set_result`]
+    ///
+    /// This fails with [`ShmMqError::Detached`] if the worker exits without reporting a result,
+    /// and with [`ShmMqError::InvalidMessage`] if the result can't be deserialized as an `R`.
+    /// Panics if the worker wasn't built with [`BackgroundWorkerBuilder::enable_result`](crate::bgworkers::BackgroundWorkerBuilder::enable_result),
+    /// or failed to register.
+    pub fn wait_for_result<R: DeserializeOwned>(&mut self) -> Result<R, ShmMqError> {
+        let receiver = self
+            .result
+            .as_mut()
+            .expect("this background worker has no result queue, or failed to register");
+        let value = receiver.recv()?;
+        serde_cbor::value::from_value(value).map_err(|e| ShmMqError::InvalidMessage(e.to_string()))
+    }
+}
@@ -0,0 +1,377 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! A main loop for background workers that run jobs on a schedule
+use crate::bgworkers::{sigterm_pending, BackgroundWorker, SignalWakeFlags};
+use crate::debug1;
+use std::panic::AssertUnwindSafe;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// When a [`Scheduler`] job runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Schedule {
+    /// Every so often, starting one interval after the scheduler starts
+    Every(Duration),
+    /// Whenever the time, in UTC, matches a cron expression
+    Cron(CronSchedule),
+}
+
+impl Schedule {
+    /// Parse a cron expression, such as `"*/15 9-17 * * mon-fri"`
+    ///
+    /// See [`CronSchedule`] for the syntax.
+    pub fn cron(expr: &str) -> Result<Schedule, CronParseError> {
+        expr.parse().map(Schedule::Cron)
+    }
+
+    /// The next time the job should run after `time`, or `None` if it never will
+    pub fn next_after(&self, time: SystemTime) -> Option<SystemTime> {
+        match self {
+            Schedule::Every(interval) => Some(time + *interval),
+            Schedule::Cron(cron) => cron.next_after(time),
+        }
+    }
+}
+
+/// The problem with a cron expression given to [`Schedule::cron`]
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum CronParseError {
+    #[error("The cron expression has {0} fields, rather than 5")]
+    WrongFieldCount(usize),
+    #[error("The cron field `{0}` is invalid")]
+    InvalidField(String),
+}
+
+/// A parsed cron expression, which is matched against the time in UTC
+///
+/// The expression has the usual five fields: minute (`0-59`), hour (`0-23`), day of the month
+/// (`1-31`), month (`1-12` or `jan-dec`) and day of the week (`0-7` or `sun-sat`, where both `0`
+/// and `7` are Sunday).  Each field is `*`, a value, a range `a-b`, any of those with a step such
+/// as `*/15`, or a comma-separated list of them.  As with `cron` itself, if both the day of the
+/// month and the day of the week are restricted then a day matching either one matches.
+///
+/// `@yearly`, `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight` and `@hourly` are also
+/// accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+const MONTH_NAMES: [&str; 12] =
+    ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+const DAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+impl std::str::FromStr for CronSchedule {
+    type Err = CronParseError;
+
+    fn from_str(expr: &str) -> Result<Self, Self::Err> {
+        let expr = match expr.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            expr => expr,
+        };
+        let fields = expr.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 5 {
+            return Err(CronParseError::WrongFieldCount(fields.len()));
+        }
+
+        let mut days_of_week = parse_field(fields[4], 0, 7, &DAY_NAMES, 0)?;
+        if days_of_week & (1 << 7) != 0 {
+            // 7 is another way of saying Sunday
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+
+        Ok(CronSchedule {
+            minutes: parse_field(fields[0], 0, 59, &[], 0)?,
+            hours: parse_field(fields[1], 0, 23, &[], 0)?,
+            days_of_month: parse_field(fields[2], 1, 31, &[], 0)?,
+            months: parse_field(fields[3], 1, 12, &MONTH_NAMES, 1)?,
+            days_of_week,
+            any_day_of_month: fields[2].starts_with('*'),
+            any_day_of_week: fields[4].starts_with('*'),
+        })
+    }
+}
+
+/// Parse one field of a cron expression into a bitmask of the values it matches
+fn parse_field(
+    field: &str,
+    min: u32,
+    max: u32,
+    names: &[&str],
+    first_name: u32,
+) -> Result<u64, CronParseError> {
+    let invalid = || CronParseError::InvalidField(field.to_string());
+    let value = |s: &str| -> Result<u32, CronParseError> {
+        let value = match names.iter().position(|name| name.eq_ignore_ascii_case(s)) {
+            Some(idx) => idx as u32 + first_name,
+            None => s.parse().map_err(|_| invalid())?,
+        };
+        if (min..=max).contains(&value) {
+            Ok(value)
+        } else {
+            Err(invalid())
+        }
+    };
+
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, Some(step.parse::<u32>().map_err(|_| invalid())?)),
+            None => (part, None),
+        };
+        let (start, end) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((start, end)) => (value(start)?, value(end)?),
+            // like `cron`, a single value with a step runs to the end of the field's range
+            None if step.is_some() => (value(range)?, max),
+            None => (value(range)?, value(range)?),
+        };
+        let step = step.unwrap_or(1);
+        if start > end || step == 0 {
+            return Err(invalid());
+        }
+        for v in (start..=end).step_by(step as usize) {
+            mask |= 1 << v;
+        }
+    }
+    Ok(mask)
+}
+
+impl CronSchedule {
+    /// The first whole minute after `time` that matches the expression, or `None` if no date
+    /// does, such as for `"0 0 30 2 *"`
+    pub fn next_after(&self, time: SystemTime) -> Option<SystemTime> {
+        let after = match time.duration_since(UNIX_EPOCH) {
+            Ok(since) => (since.as_secs() / 60) as i64,
+            Err(before) => -((before.duration().as_secs() as i64 + 59) / 60),
+        };
+
+        // every possible date comes around within a leap year cycle
+        let mut t = after + 1;
+        let limit = t + 8 * 366 * MINUTES_PER_DAY;
+        while t < limit {
+            let days = t.div_euclid(MINUTES_PER_DAY);
+            let (year, month, day) = civil_from_days(days);
+            if self.months & (1 << month) == 0 {
+                let (year, month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+                t = days_from_civil(year, month, 1) * MINUTES_PER_DAY;
+                continue;
+            }
+            if !self.day_matches(day, (days + 4).rem_euclid(7)) {
+                t = (days + 1) * MINUTES_PER_DAY;
+                continue;
+            }
+            let minute_of_day = t.rem_euclid(MINUTES_PER_DAY);
+            let (hour, minute) = (minute_of_day / 60, minute_of_day % 60);
+            if self.hours & (1 << hour) == 0 {
+                t = days * MINUTES_PER_DAY + (hour + 1) * 60;
+                continue;
+            }
+            if self.minutes & (1 << minute) == 0 {
+                t += 1;
+                continue;
+            }
+
+            return Some(if t >= 0 {
+                UNIX_EPOCH + Duration::from_secs(t as u64 * 60)
+            } else {
+                UNIX_EPOCH - Duration::from_secs(t.unsigned_abs() * 60)
+            });
+        }
+        None
+    }
+
+    fn day_matches(&self, day_of_month: i64, day_of_week: i64) -> bool {
+        let dom = self.days_of_month & (1 << day_of_month) != 0;
+        let dow = self.days_of_week & (1 << day_of_week) != 0;
+        match (self.any_day_of_month, self.any_day_of_week) {
+            (false, false) => dom || dow,
+            _ => dom && dow,
+        }
+    }
+}
+
+/// The `(year, month, day)` that is `days` since 1970-01-01, from Howard Hinnant's `chrono`
+/// algorithms
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// The inverse of [`civil_from_days`]
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+struct Job {
+    name: String,
+    schedule: Schedule,
+    body: Box<dyn FnMut()>,
+    next: Option<SystemTime>,
+}
+
+/// The main loop of a background worker that runs named jobs on a [`Schedule`]
+///
+/// Each job runs in its own transaction, with [`BackgroundWorker::transaction`], so it can use
+/// `Spi`.  Between jobs the worker waits on its latch.  On SIGHUP the configuration file has
+/// already been reloaded by the time the [`Scheduler::on_reload`] callback runs, and on SIGTERM
+/// [`Scheduler::run`] returns once the running job, if any, has finished.
+///
+/// A job that raises an ERROR (including by panicking) ends the background worker, as with any
+/// other ERROR in a worker's main function, and Postgres restarts it according to its
+/// [`BackgroundWorkerBuilder::set_restart_time`](crate::bgworkers::BackgroundWorkerBuilder::set_restart_time).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use pgx::prelude::*;
+/// use pgx::bgworkers::Scheduler;
+/// use std::time::Duration;
+///
+/// #[pg_guard]
+/// #[no_mangle]
+/// pub extern "C" fn background_worker_main(_arg: pg_sys::Datum) {
+///     Scheduler::new()
+///         .connect(Some("postgres"), None)
+///         .every("vacuum queue", Duration::from_secs(60), || {
+///             Spi::run("DELETE FROM queue WHERE done");
+///         })
+///         .cron("nightly report", "0 3 * * *", || {
+///             Spi::run("INSERT INTO reports SELECT * FROM build_report()");
+///         })
+///         .run();
+/// }
+/// ```
+pub struct Scheduler {
+    jobs: Vec<Job>,
+    dbname: Option<String>,
+    username: Option<String>,
+    on_reload: Option<Box<dyn FnMut()>>,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Scheduler::new()
+    }
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Scheduler { jobs: Vec::new(), dbname: None, username: None, on_reload: None }
+    }
+
+    /// The database and user to connect to, as with [`BackgroundWorker::connect_worker_to_spi`]
+    pub fn connect(mut self, dbname: Option<&str>, username: Option<&str>) -> Self {
+        self.dbname = dbname.map(str::to_string);
+        self.username = username.map(str::to_string);
+        self
+    }
+
+    /// Run `body` whenever `schedule` says to
+    pub fn job<F: FnMut() + 'static>(mut self, name: &str, schedule: Schedule, body: F) -> Self {
+        self.jobs.push(Job { name: name.to_string(), schedule, body: Box::new(body), next: None });
+        self
+    }
+
+    /// Run `body` every `interval`, starting one interval after the scheduler starts
+    pub fn every<F: FnMut() + 'static>(self, name: &str, interval: Duration, body: F) -> Self {
+        self.job(name, Schedule::Every(interval), body)
+    }
+
+    /// Run `body` whenever the cron expression `expr` matches, in UTC
+    ///
+    /// Panics if `expr` is invalid, which [`Schedule::cron`] can check ahead of time.
+    pub fn cron<F: FnMut() + 'static>(self, name: &str, expr: &str, body: F) -> Self {
+        let schedule = Schedule::cron(expr)
+            .unwrap_or_else(|e| panic!("invalid schedule for job `{}`: {}", name, e));
+        self.job(name, schedule, body)
+    }
+
+    /// Run `callback` after the configuration file has been reloaded on SIGHUP, such as to pick
+    /// up new values of the extension's GUCs
+    pub fn on_reload<F: FnMut() + 'static>(mut self, callback: F) -> Self {
+        self.on_reload = Some(Box::new(callback));
+        self
+    }
+
+    /// Attach the signal handlers, connect to the database, and run the jobs until SIGTERM
+    ///
+    /// This must be called from a background worker with SPI access enabled.
+    pub fn run(mut self) {
+        BackgroundWorker::attach_signal_handlers(
+            SignalWakeFlags::SIGHUP | SignalWakeFlags::SIGTERM,
+        );
+        BackgroundWorker::connect_worker_to_spi(self.dbname.as_deref(), self.username.as_deref());
+
+        let start = SystemTime::now();
+        for job in &mut self.jobs {
+            job.next = job.schedule.next_after(start);
+        }
+
+        loop {
+            let now = SystemTime::now();
+            let timeout = self
+                .jobs
+                .iter()
+                .filter_map(|job| job.next)
+                .min()
+                .map(|next| next.duration_since(now).unwrap_or_default());
+            if !BackgroundWorker::wait_latch(timeout) {
+                return;
+            }
+
+            if BackgroundWorker::sighup_received() {
+                if let Some(on_reload) = &mut self.on_reload {
+                    on_reload();
+                }
+            }
+
+            let now = SystemTime::now();
+            for job in &mut self.jobs {
+                if job.next.is_none_or(|next| next > now) {
+                    continue;
+                }
+                if sigterm_pending() {
+                    // the next trip around the loop will see it
+                    break;
+                }
+
+                debug1!("running scheduled job `{}`", job.name);
+                let body = &mut job.body;
+                BackgroundWorker::transaction(AssertUnwindSafe(body));
+                job.next = job.schedule.next_after(now);
+            }
+        }
+    }
+}
@@ -0,0 +1,352 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Dynamic shared memory, which unlike [`crate::shmem`] can be created at any time by any
+//! backend or background worker rather than only when the postmaster starts
+//!
+//! A [`DsmSegment`] is a fixed-size chunk of shared memory, and a [`DsaArea`] is a growable
+//! area that memory can be allocated from and freed back to.  Both are identified to other
+//! processes by a handle ([`DsmHandle`] and [`DsaHandle`]) that can be passed to a background
+//! worker with [`BackgroundWorkerBuilder::set_argument`](crate::bgworkers::BackgroundWorkerBuilder::set_argument),
+//! and which the worker then attaches to.
+//!
+//! Memory in a [`DsaArea`] is addressed by [`DsaPointer`]s, which are meaningful in every
+//! process attached to the area, whereas a plain pointer is only good in the process that got it.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use pgx::prelude::*;
+//! use pgx::bgworkers::BackgroundWorkerBuilder;
+//! use pgx::dsm::{DsmHandle, DsmSegment};
+//! use pgx::{FromDatum, IntoDatum};
+//!
+//! let mut segment = DsmSegment::create(1024);
+//! unsafe { segment.as_mut_slice() }.fill(42);
+//!
+//! // the segment stays around for as long as `segment` does, or the worker is attached to it
+//! BackgroundWorkerBuilder::new("Example")
+//!     .set_function("background_worker_main")
+//!     .set_library("example")
+//!     .enable_shmem_access(None)
+//!     .set_argument(segment.handle().into_datum())
+//!     .load_dynamic();
+//!
+//! #[pg_guard]
+//! #[no_mangle]
+//! pub extern "C" fn background_worker_main(arg: pg_sys::Datum) {
+//!     let handle = unsafe { DsmHandle::from_datum(arg, false) }.unwrap();
+//!     let segment = DsmSegment::attach(handle).expect("the segment is gone");
+//!     assert_eq!(unsafe { segment.as_slice() }[0], 42);
+//! }
+//! ```
+use crate::{pg_sys, FromDatum, IntoDatum};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+
+/// Identifies a [`DsmSegment`] to other processes, which can [`DsmSegment::attach`] to it
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DsmHandle(pub pg_sys::dsm_handle);
+
+/// Identifies a [`DsaArea`] to other processes, which can [`DsaArea::attach`] to it
+///
+/// Along with Postgres' own handle, this carries the LWLock tranche of the area's locks, so the
+/// processes that attach to it can name the tranche too.  As a datum it's a `bigint`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DsaHandle {
+    handle: pg_sys::dsa_handle,
+    tranche_id: i32,
+}
+
+macro_rules! handle_datum {
+    ($handle:ident) => {
+        impl IntoDatum for $handle {
+            #[inline]
+            fn into_datum(self) -> Option<pg_sys::Datum> {
+                self.0.into_datum()
+            }
+
+            fn type_oid() -> u32 {
+                u32::type_oid()
+            }
+        }
+
+        impl FromDatum for $handle {
+            #[inline]
+            unsafe fn from_polymorphic_datum(
+                datum: pg_sys::Datum,
+                is_null: bool,
+                typoid: pg_sys::Oid,
+            ) -> Option<Self> {
+                u32::from_polymorphic_datum(datum, is_null, typoid).map($handle)
+            }
+        }
+    };
+}
+
+handle_datum!(DsmHandle);
+
+impl IntoDatum for DsaHandle {
+    #[inline]
+    fn into_datum(self) -> Option<pg_sys::Datum> {
+        (((self.tranche_id as u32 as u64) << 32 | self.handle as u64) as i64).into_datum()
+    }
+
+    fn type_oid() -> u32 {
+        i64::type_oid()
+    }
+}
+
+impl FromDatum for DsaHandle {
+    #[inline]
+    unsafe fn from_polymorphic_datum(
+        datum: pg_sys::Datum,
+        is_null: bool,
+        typoid: pg_sys::Oid,
+    ) -> Option<Self> {
+        i64::from_polymorphic_datum(datum, is_null, typoid).map(|packed| DsaHandle {
+            handle: packed as u32 as pg_sys::dsa_handle,
+            tranche_id: (packed as u64 >> 32) as u32 as i32,
+        })
+    }
+}
+
+/// A segment of dynamic shared memory, created with [`DsmSegment::create`] or attached to
+/// with [`DsmSegment::attach`]
+///
+/// The segment is mapped into this process until the `DsmSegment` is dropped, regardless of
+/// any transaction or resource owner.  Postgres destroys the segment once no process has it
+/// mapped, unless it has been [`DsmSegment::pin`]ned.
+pub struct DsmSegment {
+    pub(crate) seg: *mut pg_sys::dsm_segment,
+}
+
+impl DsmSegment {
+    /// Create a new segment of `size` bytes, which are uninitialized
+    pub fn create(size: usize) -> Self {
+        unsafe {
+            let seg = pg_sys::dsm_create(size, 0);
+            pg_sys::dsm_pin_mapping(seg);
+            DsmSegment { seg }
+        }
+    }
+
+    /// Attach to the segment identified by `handle`, or `None` if it no longer exists
+    ///
+    /// A process can only be attached to a segment once, so it is an ERROR to attach to a
+    /// segment this process created or is already attached to.
+    pub fn attach(handle: DsmHandle) -> Option<Self> {
+        unsafe {
+            let seg = pg_sys::dsm_attach(handle.0);
+            if seg.is_null() {
+                None
+            } else {
+                pg_sys::dsm_pin_mapping(seg);
+                Some(DsmSegment { seg })
+            }
+        }
+    }
+
+    /// The handle other processes can use to attach to this segment
+    pub fn handle(&self) -> DsmHandle {
+        DsmHandle(unsafe { pg_sys::dsm_segment_handle(self.seg) })
+    }
+
+    /// The size of the segment, in bytes
+    pub fn len(&self) -> usize {
+        unsafe { pg_sys::dsm_segment_map_length(self.seg) }
+    }
+
+    /// Is this a zero-length segment?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Where the segment is mapped in this process
+    pub fn as_ptr(&self) -> *mut u8 {
+        unsafe { pg_sys::dsm_segment_address(self.seg) as *mut u8 }
+    }
+
+    /// The segment's contents
+    ///
+    /// # Safety
+    ///
+    /// Other processes attached to the segment may be writing to it concurrently, and the
+    /// contents of a newly-created segment are uninitialized.  It's up to the caller to
+    /// coordinate access, such as with a lock or an atomic stored in the segment.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        std::slice::from_raw_parts(self.as_ptr(), self.len())
+    }
+
+    /// The segment's contents, mutably
+    ///
+    /// # Safety
+    ///
+    /// As with [`DsmSegment::as_slice`], the caller must coordinate access with any other
+    /// processes attached to the segment.
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+        std::slice::from_raw_parts_mut(self.as_ptr(), self.len())
+    }
+
+    /// Keep the segment around even once no process has it mapped, until the server restarts
+    /// or [`DsmSegment::unpin`] is called with its handle
+    pub fn pin(&self) {
+        unsafe { pg_sys::dsm_pin_segment(self.seg) }
+    }
+
+    /// Undo a [`DsmSegment::pin`], from any process, so the segment is destroyed once no process
+    /// has it mapped
+    pub fn unpin(handle: DsmHandle) {
+        unsafe { pg_sys::dsm_unpin_segment(handle.0) }
+    }
+}
+
+impl Drop for DsmSegment {
+    fn drop(&mut self) {
+        unsafe { pg_sys::dsm_detach(self.seg) }
+    }
+}
+
+/// The location of some memory allocated from a [`DsaArea`], which is the same in every process
+/// attached to the area
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DsaPointer(pub pg_sys::dsa_pointer);
+
+impl DsaPointer {
+    /// The equivalent of a null pointer
+    pub const INVALID: DsaPointer = DsaPointer(0);
+
+    /// Does this point anywhere?
+    pub fn is_valid(&self) -> bool {
+        *self != DsaPointer::INVALID
+    }
+}
+
+/// A dynamic shared area, which memory can be allocated from much like a memory context and
+/// which grows by creating new dynamic shared memory segments as needed
+///
+/// As with [`DsmSegment`], this process stays attached to the area until the `DsaArea` is
+/// dropped.  Postgres destroys the area once no process is attached to it, unless it has been
+/// [`DsaArea::pin`]ned.
+pub struct DsaArea {
+    area: *mut pg_sys::dsa_area,
+    tranche_id: i32,
+}
+
+/// The name of the LWLock tranche that [`DsaArea`]s' locks belong to
+const DSA_TRANCHE_NAME: &[u8] = b"pgx_dsa\0";
+
+/// The tranche for the areas this backend creates.  Tranche ids are never given back, so it's
+/// shared by all of them rather than taking a new one each time.
+static DSA_TRANCHE_ID: OnceCell<i32> = OnceCell::new();
+
+impl DsaArea {
+    /// Create a new, empty area
+    pub fn create() -> Self {
+        let tranche_id = *DSA_TRANCHE_ID.get_or_init(|| unsafe {
+            let tranche_id = pg_sys::LWLockNewTrancheId();
+            pg_sys::LWLockRegisterTranche(tranche_id, DSA_TRANCHE_NAME.as_ptr().cast());
+            tranche_id
+        });
+        unsafe {
+            let area = pg_sys::dsa_create(tranche_id);
+            pg_sys::dsa_pin_mapping(area);
+            DsaArea { area, tranche_id }
+        }
+    }
+
+    /// Attach to the area identified by `handle`
+    ///
+    /// It is an ERROR if the area no longer exists, or this process is already attached to it.
+    pub fn attach(handle: DsaHandle) -> Self {
+        unsafe {
+            // the tranche was registered by the process that created the area, not by this one
+            pg_sys::LWLockRegisterTranche(handle.tranche_id, DSA_TRANCHE_NAME.as_ptr().cast());
+            let area = pg_sys::dsa_attach(handle.handle);
+            pg_sys::dsa_pin_mapping(area);
+            DsaArea { area, tranche_id: handle.tranche_id }
+        }
+    }
+
+    /// The handle other processes can use to attach to this area
+    pub fn handle(&self) -> DsaHandle {
+        DsaHandle {
+            handle: unsafe { pg_sys::dsa_get_handle(self.area) },
+            tranche_id: self.tranche_id,
+        }
+    }
+
+    /// Allocate `size` bytes, which are uninitialized.  It is an ERROR if the area can't grow
+    /// enough to fit them.
+    pub fn allocate(&self, size: usize) -> DsaPointer {
+        DsaPointer(unsafe { pg_sys::dsa_allocate_extended(self.area, size, 0) })
+    }
+
+    /// Allocate `size` bytes, all set to zero.  It is an ERROR if the area can't grow enough
+    /// to fit them.
+    pub fn allocate_zeroed(&self, size: usize) -> DsaPointer {
+        DsaPointer(unsafe {
+            pg_sys::dsa_allocate_extended(self.area, size, pg_sys::DSA_ALLOC_ZERO as _)
+        })
+    }
+
+    /// Allocate `size` bytes, or `None` if the area can't grow enough to fit them
+    pub fn try_allocate(&self, size: usize) -> Option<DsaPointer> {
+        let dp = DsaPointer(unsafe {
+            pg_sys::dsa_allocate_extended(self.area, size, pg_sys::DSA_ALLOC_NO_OOM as _)
+        });
+        dp.is_valid().then_some(dp)
+    }
+
+    /// Give memory from [`DsaArea::allocate`] back to the area
+    ///
+    /// # Safety
+    ///
+    /// `dp` must have been allocated from this area and not already freed, and no process
+    /// may use it afterwards.
+    pub unsafe fn free(&self, dp: DsaPointer) {
+        pg_sys::dsa_free(self.area, dp.0)
+    }
+
+    /// Where the memory `dp` points to is mapped in this process, or null if `dp` is
+    /// [`DsaPointer::INVALID`]
+    ///
+    /// As with [`DsmSegment::as_slice`], accessing the memory requires coordinating with any
+    /// other processes attached to the area.
+    pub fn get_address(&self, dp: DsaPointer) -> *mut u8 {
+        unsafe { pg_sys::dsa_get_address(self.area, dp.0) as *mut u8 }
+    }
+
+    /// Limit the total size of the segments backing the area to `limit` bytes
+    pub fn set_size_limit(&self, limit: usize) {
+        unsafe { pg_sys::dsa_set_size_limit(self.area, limit) }
+    }
+
+    /// Give back any memory the area is holding onto but not using
+    pub fn trim(&self) {
+        unsafe { pg_sys::dsa_trim(self.area) }
+    }
+
+    /// Keep the area around even once no process is attached to it, until the server restarts
+    /// or [`DsaArea::unpin`] is called
+    pub fn pin(&self) {
+        unsafe { pg_sys::dsa_pin(self.area) }
+    }
+
+    /// Undo a [`DsaArea::pin`], so the area is destroyed once no process is attached to it
+    pub fn unpin(&self) {
+        unsafe { pg_sys::dsa_unpin(self.area) }
+    }
+}
+
+impl Drop for DsaArea {
+    fn drop(&mut self) {
+        unsafe { pg_sys::dsa_detach(self.area) }
+    }
+}
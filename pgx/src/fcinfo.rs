@@ -11,7 +11,10 @@ Use of this source code is governed by the MIT license that can be found in the
 //!
 //! Typically these functions are not necessary to call directly as they're used behind
 //! the scenes by the code generated by the `#[pg_extern]` macro.
-use crate::{pg_sys, void_mut_ptr, FromDatum, PgBox, PgMemoryContexts};
+use crate::{
+    ereport, pg_sys, void_mut_ptr, FromDatum, PgBox, PgMemoryContexts, PgSqlErrorCode, PgTupleDesc,
+    Tuplestore,
+};
 
 /// A macro for specifying default argument values so they get propery translated to SQL in
 /// `CREATE FUNCTION` statements
@@ -448,3 +451,96 @@ pub unsafe fn srf_return_done(
     let mut rsi = PgBox::from_pg(fcinfo.resultinfo as *mut pg_sys::ReturnSetInfo);
     rsi.isDone = pg_sys::ExprDoneCond_ExprEndResult;
 }
+
+/// Return every row produced by `rows` at once, in a [`Tuplestore`], as a materialize-mode
+/// set-returning function
+///
+/// This is what `#[pg_extern(materialize)]` functions call.  `into_values` converts each row into
+/// one `Datum` per column of the function's result type, or into a single composite `Datum` when
+/// the function returns rows of a composite type, and is called within a short-lived memory context
+/// which is reset after each row is stored.
+#[doc(hidden)]
+pub unsafe fn srf_materialize<T, I, const N: usize>(
+    fcinfo: pg_sys::FunctionCallInfo,
+    rows: impl FnOnce() -> Option<I>,
+    into_values: impl Fn(T) -> [Option<pg_sys::Datum>; N],
+) -> pg_sys::Datum
+where
+    I: IntoIterator<Item = T>,
+{
+    let rsinfo = (*fcinfo).resultinfo as *mut pg_sys::ReturnSetInfo;
+    if rsinfo.is_null() || !crate::is_a(rsinfo.cast(), pg_sys::NodeTag_T_ReturnSetInfo) {
+        ereport!(
+            ERROR,
+            PgSqlErrorCode::ERRCODE_FEATURE_NOT_SUPPORTED,
+            "set-valued function called in context that cannot accept a set"
+        );
+    }
+
+    let allowed_modes = (*rsinfo).allowedModes;
+    if allowed_modes & pg_sys::SetFunctionReturnMode_SFRM_Materialize as i32 == 0 {
+        ereport!(
+            ERROR,
+            PgSqlErrorCode::ERRCODE_FEATURE_NOT_SUPPORTED,
+            "materialize mode required, but it is not allowed in this context"
+        );
+    }
+    let random_access =
+        allowed_modes & pg_sys::SetFunctionReturnMode_SFRM_Materialize_Random as i32 != 0;
+
+    // the tuplestore, and the tupdesc it carries, are read by the executor after we return, so
+    // they must live in the per-query context
+    let per_query = (*(*rsinfo).econtext).ecxt_per_query_memory;
+    let (mut store, composite) = PgMemoryContexts::For(per_query).unguarded_switch_to(|_| {
+        let mut typid = pg_sys::InvalidOid;
+        let mut tupdesc = std::ptr::null_mut();
+        let (tupdesc, composite) =
+            match pg_sys::get_call_result_type(fcinfo, &mut typid, &mut tupdesc) {
+                pg_sys::TypeFuncClass_TYPEFUNC_COMPOSITE
+                | pg_sys::TypeFuncClass_TYPEFUNC_COMPOSITE_DOMAIN => {
+                    // this could be the caller's `expectedDesc`, so we mustn't free it
+                    (PgTupleDesc::from_pg_unchecked(tupdesc), true)
+                }
+                pg_sys::TypeFuncClass_TYPEFUNC_SCALAR => (
+                    PgTupleDesc::from_pg_is_copy(pg_sys::TypeGetTupleDesc(
+                        typid,
+                        std::ptr::null_mut(),
+                    )),
+                    false,
+                ),
+                _ => {
+                    ereport!(
+                    ERROR,
+                    PgSqlErrorCode::ERRCODE_FEATURE_NOT_SUPPORTED,
+                    "function returning record called in context that cannot accept type record"
+                );
+                }
+            };
+        (Tuplestore::new(&tupdesc, random_access), composite)
+    });
+
+    // a `SetOfIterator` of a composite type, or a `TableIterator` whose only column is one, makes
+    // rows of one composite value rather than one value per column
+    let composite_rows = composite && N == 1;
+
+    if let Some(rows) = rows() {
+        let mut per_row = PgMemoryContexts::new("pgx materialize row");
+        for row in rows {
+            let previous = pg_sys::MemoryContextSwitchTo(per_row.value());
+            let values = into_values(row);
+            if composite_rows {
+                store.put_composite(values[0]);
+            } else {
+                store.put_values(&values);
+            }
+            pg_sys::MemoryContextSwitchTo(previous);
+            per_row.reset();
+        }
+    }
+
+    let (state, tupdesc) = store.into_pg();
+    (*rsinfo).returnMode = pg_sys::SetFunctionReturnMode_SFRM_Materialize;
+    (*rsinfo).setResult = state;
+    (*rsinfo).setDesc = tupdesc;
+    pg_sys::Datum::from(0usize)
+}
@@ -8,11 +8,61 @@ Use of this source code is governed by the MIT license that can be found in the
 */
 
 //! A trait and registration system for hooking Postgres internal operations such as its planner and executor
+//!
+//! Each hook point also has its own module, such as [`executor_start`], for registering handlers
+//! independently of any others.  Handlers registered that way run in the order they were
+//! registered, each deciding whether to continue down the chain, and only the hooks that have
+//! handlers are installed into Postgres:
+//!
+//! ```rust,no_run
+//! use pgx::prelude::*;
+//! use pgx::hooks::executor_start;
+//!
+//! #[pg_guard]
+//! pub extern "C" fn _PG_init() {
+//!     executor_start::register(|query_desc, eflags, next| {
+//!         info!("starting a query");
+//!         next(query_desc, eflags)
+//!     });
+//! }
+//! ```
 use crate as pgx; // for #[pg_guard] support from within ourself
 use crate::prelude::*;
-use crate::{void_mut_ptr, PgBox, PgList};
+use crate::{
+    void_mut_ptr, FromDatum, IntoDatum, PgBox, PgList, PgLogLevel, PgMemoryContexts,
+    PgSqlErrorCode, TimestampWithTimeZone,
+};
+use std::any::Any;
+use std::borrow::Cow;
+use std::ffi::{CStr, CString};
 use std::ops::Deref;
 
+#[macro_use]
+mod chain;
+
+pub mod check_password;
+pub mod client_authentication;
+pub mod create_upper_paths;
+pub mod emit_log;
+pub mod executor_check_perms;
+pub mod executor_end;
+pub mod executor_finish;
+pub mod executor_run;
+pub mod executor_start;
+pub mod explain_get_index_name;
+pub mod explain_one_query;
+pub mod fmgr_hook;
+pub mod get_relation_info;
+pub mod needs_fmgr_hook;
+pub mod object_access;
+#[cfg(feature = "pg15")]
+pub mod object_access_str;
+pub mod planner;
+pub mod post_parse_analyze;
+pub mod process_utility;
+pub mod set_join_pathlist;
+pub mod set_rel_pathlist;
+
 #[cfg(any(feature = "pg10", feature = "pg11", feature = "pg12", feature = "pg13"))]
 // JumbleState is not defined prior to postgres v14.
 // This zero-sized type is here to provide an inner type for
@@ -41,6 +91,597 @@ impl<T> Deref for HookResult<T> {
     }
 }
 
+/// The [`pg_sys::ErrorData`] of a message that's about to be logged, as given to
+/// [`PgHooks::emit_log`] and the handlers registered with [`emit_log::register`]
+///
+/// Changes made through this wrapper only affect what's written to the server log, and, for
+/// messages that are also sent to the client, what the client sees.
+pub struct ErrorDataWrapper {
+    edata: *mut pg_sys::ErrorData,
+}
+
+impl ErrorDataWrapper {
+    /// Wrap a `pg_sys::ErrorData` pointer
+    ///
+    /// ## Safety
+    ///
+    /// `edata` must be a valid, non-null pointer to the `ErrorData` currently being reported, and
+    /// must remain valid for as long as the wrapper is used
+    pub unsafe fn from_pg(edata: *mut pg_sys::ErrorData) -> Self {
+        assert!(!edata.is_null(), "ErrorData pointer is NULL");
+        ErrorDataWrapper { edata }
+    }
+
+    pub fn as_ptr(&self) -> *mut pg_sys::ErrorData {
+        self.edata
+    }
+
+    /// The primary error message
+    pub fn message(&self) -> Option<Cow<'_, str>> {
+        unsafe { cstr_to_str((*self.edata).message) }
+    }
+
+    pub fn set_message(&mut self, message: &str) {
+        unsafe { (*self.edata).message = self.alloc_cstr(message) }
+    }
+
+    /// The optional secondary message, sent to both the client and the server log
+    pub fn detail(&self) -> Option<Cow<'_, str>> {
+        unsafe { cstr_to_str((*self.edata).detail) }
+    }
+
+    /// Replace the detail message, or remove it with `None`
+    pub fn set_detail(&mut self, detail: Option<&str>) {
+        unsafe {
+            (*self.edata).detail = match detail {
+                Some(detail) => self.alloc_cstr(detail),
+                None => std::ptr::null_mut(),
+            }
+        }
+    }
+
+    /// The SQLSTATE of this message
+    pub fn sqlstate(&self) -> PgSqlErrorCode {
+        unsafe { PgSqlErrorCode::from((*self.edata).sqlerrcode) }
+    }
+
+    pub fn set_sqlstate(&mut self, sqlstate: PgSqlErrorCode) {
+        unsafe { (*self.edata).sqlerrcode = sqlstate as i32 }
+    }
+
+    /// The level this message is reported at
+    pub fn elevel(&self) -> PgLogLevel {
+        unsafe { PgLogLevel::from((*self.edata).elevel) }
+    }
+
+    /// Change the level this message is labeled with in the server log
+    ///
+    /// The message has already been raised by this point, so this does not change whether it
+    /// aborts the transaction.
+    pub fn set_elevel(&mut self, elevel: PgLogLevel) {
+        unsafe { (*self.edata).elevel = elevel as i32 }
+    }
+
+    /// Will this message be written to the server log?
+    pub fn output_to_server(&self) -> bool {
+        unsafe { (*self.edata).output_to_server }
+    }
+
+    /// Setting this to `false` keeps the message out of the server log, without affecting whether
+    /// it's sent to the client
+    pub fn set_output_to_server(&mut self, output_to_server: bool) {
+        unsafe { (*self.edata).output_to_server = output_to_server }
+    }
+
+    /// Copy `s` into the memory context the rest of this `ErrorData` lives in
+    fn alloc_cstr(&self, s: &str) -> *mut std::os::raw::c_char {
+        let s = CString::new(s).expect("message contained a NUL byte");
+        unsafe { pg_sys::MemoryContextStrdup((*self.edata).assoc_context, s.as_ptr()) }
+    }
+}
+
+unsafe fn cstr_to_str<'a>(ptr: *const std::os::raw::c_char) -> Option<Cow<'a, str>> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(ptr).to_string_lossy())
+    }
+}
+
+/// The client connection being authenticated, as given to [`PgHooks::client_authentication`]
+pub struct PortWrapper {
+    port: *mut pg_sys::Port,
+}
+
+impl PortWrapper {
+    /// Wrap a `pg_sys::Port` pointer
+    ///
+    /// ## Safety
+    ///
+    /// `port` must be a valid, non-null pointer to a `Port`, and must remain valid for as long as
+    /// the wrapper is used
+    pub unsafe fn from_pg(port: *mut pg_sys::Port) -> Self {
+        assert!(!port.is_null(), "Port pointer is NULL");
+        PortWrapper { port }
+    }
+
+    pub fn as_ptr(&self) -> *mut pg_sys::Port {
+        self.port
+    }
+
+    /// The role the client is trying to log in as
+    pub fn user_name(&self) -> Option<&str> {
+        unsafe { port_str((*self.port).user_name) }
+    }
+
+    /// The database the client is trying to connect to
+    pub fn database_name(&self) -> Option<&str> {
+        unsafe { port_str((*self.port).database_name) }
+    }
+
+    /// The client's IP address, or its host name if `log_hostname` is on.  For Unix-domain socket
+    /// connections this is `[local]`
+    pub fn remote_host(&self) -> Option<&str> {
+        unsafe { port_str((*self.port).remote_host) }
+    }
+
+    /// The client's port number, as text.  Empty for Unix-domain socket connections
+    pub fn remote_port(&self) -> Option<&str> {
+        unsafe { port_str((*self.port).remote_port) }
+    }
+
+    /// The `application_name` the client sent in its startup packet, if any
+    #[cfg(any(feature = "pg12", feature = "pg13", feature = "pg14", feature = "pg15"))]
+    pub fn application_name(&self) -> Option<&str> {
+        unsafe { port_str((*self.port).application_name) }
+    }
+}
+
+unsafe fn port_str<'a>(ptr: *const std::os::raw::c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(ptr).to_str().expect("connection string was not valid UTF-8"))
+    }
+}
+
+/// The outcome of Postgres' own authentication of a client, as given to
+/// [`PgHooks::client_authentication`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientAuthStatus {
+    /// The client authenticated successfully
+    Ok,
+    /// Authentication failed
+    Error,
+    /// The client disconnected before authentication finished
+    Eof,
+}
+
+impl ClientAuthStatus {
+    fn from_pg(status: i32) -> Self {
+        match status {
+            pg_sys::STATUS_ERROR => ClientAuthStatus::Error,
+            pg_sys::STATUS_EOF => ClientAuthStatus::Eof,
+            _ => ClientAuthStatus::Ok,
+        }
+    }
+
+    fn into_pg(self) -> i32 {
+        match self {
+            ClientAuthStatus::Ok => pg_sys::STATUS_OK as i32,
+            ClientAuthStatus::Error => pg_sys::STATUS_ERROR,
+            ClientAuthStatus::Eof => pg_sys::STATUS_EOF,
+        }
+    }
+}
+
+/// A new password given to `CREATE ROLE` or `ALTER ROLE`, as given to [`PgHooks::check_password`]
+///
+/// Clients may send a password that they've already hashed, in which case only the hash is known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Password<'a> {
+    Plaintext(&'a str),
+    Md5(&'a str),
+    ScramSha256(&'a str),
+}
+
+impl<'a> Password<'a> {
+    unsafe fn from_pg(
+        shadow_pass: *const std::os::raw::c_char,
+        password_type: pg_sys::PasswordType,
+    ) -> Self {
+        let password = CStr::from_ptr(shadow_pass).to_str().expect("password was not valid UTF-8");
+        match password_type {
+            pg_sys::PasswordType_PASSWORD_TYPE_MD5 => Password::Md5(password),
+            pg_sys::PasswordType_PASSWORD_TYPE_SCRAM_SHA_256 => Password::ScramSha256(password),
+            _ => Password::Plaintext(password),
+        }
+    }
+
+    fn into_pg(self) -> (CString, pg_sys::PasswordType) {
+        let (password, password_type) = match self {
+            Password::Plaintext(password) => {
+                (password, pg_sys::PasswordType_PASSWORD_TYPE_PLAINTEXT)
+            }
+            Password::Md5(password) => (password, pg_sys::PasswordType_PASSWORD_TYPE_MD5),
+            Password::ScramSha256(password) => {
+                (password, pg_sys::PasswordType_PASSWORD_TYPE_SCRAM_SHA_256)
+            }
+        };
+        (CString::new(password).expect("password contained a NUL byte"), password_type)
+    }
+}
+
+/// What happened to the object given to [`PgHooks::object_access`] and
+/// [`PgHooks::object_access_str`]
+#[derive(Debug, PartialEq, Eq)]
+pub enum ObjectAccessEvent<'a> {
+    /// The object has just been created.  `is_internal` is true when it was created by the
+    /// system rather than directly by the user
+    PostCreate { is_internal: bool },
+    /// The object is about to be dropped.  `flags` are Postgres' `PERFORM_DELETION_*` flags
+    Drop { flags: i32 },
+    /// The object has just been altered.  `auxiliary_id` identifies the sub-object, such as a
+    /// constraint or trigger, when there is one
+    PostAlter { auxiliary_id: pg_sys::Oid, is_internal: bool },
+    /// The namespace is about to be searched for an object.  Setting `result` to `false` denies
+    /// the search, and raises an error if `ereport_on_violation` is set
+    NamespaceSearch { ereport_on_violation: bool, result: &'a mut bool },
+    /// The function is about to be executed
+    FunctionExecute,
+    /// The relation is about to be truncated.  Only raised by Postgres 13 and later
+    Truncate,
+}
+
+impl<'a> ObjectAccessEvent<'a> {
+    unsafe fn from_pg(access: pg_sys::ObjectAccessType, arg: void_mut_ptr) -> Option<Self> {
+        match access {
+            pg_sys::ObjectAccessType_OAT_POST_CREATE => {
+                let arg = (arg as *mut pg_sys::ObjectAccessPostCreate).as_ref();
+                Some(ObjectAccessEvent::PostCreate {
+                    is_internal: arg.map(|arg| arg.is_internal).unwrap_or(false),
+                })
+            }
+            pg_sys::ObjectAccessType_OAT_DROP => {
+                let arg = (arg as *mut pg_sys::ObjectAccessDrop).as_ref();
+                Some(ObjectAccessEvent::Drop { flags: arg.map(|arg| arg.dropflags).unwrap_or(0) })
+            }
+            pg_sys::ObjectAccessType_OAT_POST_ALTER => {
+                let arg = (arg as *mut pg_sys::ObjectAccessPostAlter).as_ref()?;
+                Some(ObjectAccessEvent::PostAlter {
+                    auxiliary_id: arg.auxiliary_id,
+                    is_internal: arg.is_internal,
+                })
+            }
+            pg_sys::ObjectAccessType_OAT_NAMESPACE_SEARCH => {
+                let arg = (arg as *mut pg_sys::ObjectAccessNamespaceSearch).as_mut()?;
+                Some(ObjectAccessEvent::NamespaceSearch {
+                    ereport_on_violation: arg.ereport_on_violation,
+                    result: &mut arg.result,
+                })
+            }
+            pg_sys::ObjectAccessType_OAT_FUNCTION_EXECUTE => {
+                Some(ObjectAccessEvent::FunctionExecute)
+            }
+            #[cfg(any(feature = "pg13", feature = "pg14", feature = "pg15"))]
+            pg_sys::ObjectAccessType_OAT_TRUNCATE => Some(ObjectAccessEvent::Truncate),
+            _ => None,
+        }
+    }
+
+    /// Hand this event to `f` as the `ObjectAccessType` and argument Postgres expects
+    fn with_pg<R>(self, f: impl FnOnce(pg_sys::ObjectAccessType, void_mut_ptr) -> R) -> Option<R> {
+        match self {
+            ObjectAccessEvent::PostCreate { is_internal } => {
+                let mut arg = pg_sys::ObjectAccessPostCreate { is_internal };
+                Some(f(
+                    pg_sys::ObjectAccessType_OAT_POST_CREATE,
+                    &mut arg as *mut _ as void_mut_ptr,
+                ))
+            }
+            ObjectAccessEvent::Drop { flags } => {
+                let mut arg = pg_sys::ObjectAccessDrop { dropflags: flags };
+                Some(f(pg_sys::ObjectAccessType_OAT_DROP, &mut arg as *mut _ as void_mut_ptr))
+            }
+            ObjectAccessEvent::PostAlter { auxiliary_id, is_internal } => {
+                let mut arg = pg_sys::ObjectAccessPostAlter { auxiliary_id, is_internal };
+                Some(f(pg_sys::ObjectAccessType_OAT_POST_ALTER, &mut arg as *mut _ as void_mut_ptr))
+            }
+            ObjectAccessEvent::NamespaceSearch { ereport_on_violation, result } => {
+                let mut arg =
+                    pg_sys::ObjectAccessNamespaceSearch { ereport_on_violation, result: *result };
+                let r = f(
+                    pg_sys::ObjectAccessType_OAT_NAMESPACE_SEARCH,
+                    &mut arg as *mut _ as void_mut_ptr,
+                );
+                *result = arg.result;
+                Some(r)
+            }
+            ObjectAccessEvent::FunctionExecute => {
+                Some(f(pg_sys::ObjectAccessType_OAT_FUNCTION_EXECUTE, std::ptr::null_mut()))
+            }
+            #[cfg(any(feature = "pg13", feature = "pg14", feature = "pg15"))]
+            ObjectAccessEvent::Truncate => {
+                Some(f(pg_sys::ObjectAccessType_OAT_TRUNCATE, std::ptr::null_mut()))
+            }
+            #[cfg(any(feature = "pg11", feature = "pg12"))]
+            ObjectAccessEvent::Truncate => None,
+        }
+    }
+}
+
+/// The output format requested by `EXPLAIN (FORMAT ...)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplainFormat {
+    Text,
+    Xml,
+    Json,
+    Yaml,
+}
+
+/// The state of an `EXPLAIN` being run, as given to [`PgHooks::explain_one_query`] and the
+/// handlers registered with [`explain_one_query::register`]
+///
+/// Properties added through this wrapper are formatted to match the rest of the `EXPLAIN`
+/// output, whichever format was asked for.
+pub struct ExplainStateWrapper {
+    es: *mut pg_sys::ExplainState,
+}
+
+impl ExplainStateWrapper {
+    /// Wrap a `pg_sys::ExplainState` pointer
+    ///
+    /// ## Safety
+    ///
+    /// `es` must be a valid, non-null pointer to an `ExplainState`, and must remain valid for as
+    /// long as the wrapper is used
+    pub unsafe fn from_pg(es: *mut pg_sys::ExplainState) -> Self {
+        assert!(!es.is_null(), "ExplainState pointer is NULL");
+        ExplainStateWrapper { es }
+    }
+
+    pub fn as_ptr(&self) -> *mut pg_sys::ExplainState {
+        self.es
+    }
+
+    pub fn format(&self) -> ExplainFormat {
+        match unsafe { (*self.es).format } {
+            pg_sys::ExplainFormat_EXPLAIN_FORMAT_XML => ExplainFormat::Xml,
+            pg_sys::ExplainFormat_EXPLAIN_FORMAT_JSON => ExplainFormat::Json,
+            pg_sys::ExplainFormat_EXPLAIN_FORMAT_YAML => ExplainFormat::Yaml,
+            _ => ExplainFormat::Text,
+        }
+    }
+
+    /// Was `VERBOSE` requested?
+    pub fn verbose(&self) -> bool {
+        unsafe { (*self.es).verbose }
+    }
+
+    /// Is this an `EXPLAIN ANALYZE`?
+    pub fn analyze(&self) -> bool {
+        unsafe { (*self.es).analyze }
+    }
+
+    /// Were `COSTS` requested?
+    pub fn costs(&self) -> bool {
+        unsafe { (*self.es).costs }
+    }
+
+    pub fn explain_property_text(&mut self, label: &str, value: &str) {
+        let label = explain_cstring(label);
+        let value = explain_cstring(value);
+        unsafe { pg_sys::ExplainPropertyText(label.as_ptr(), value.as_ptr(), self.es) }
+    }
+
+    /// Add an integer property, with an optional `unit` such as `"kB"` that's only shown in
+    /// `TEXT` format
+    pub fn explain_property_integer(&mut self, label: &str, unit: Option<&str>, value: i64) {
+        let label = explain_cstring(label);
+        let unit = unit.map(explain_cstring);
+        unsafe {
+            pg_sys::ExplainPropertyInteger(
+                label.as_ptr(),
+                unit.as_ref().map_or(std::ptr::null(), |unit| unit.as_ptr()),
+                value,
+                self.es,
+            )
+        }
+    }
+
+    /// Add a floating-point property, shown with `ndigits` digits after the decimal point, and an
+    /// optional `unit` that's only shown in `TEXT` format
+    pub fn explain_property_float(
+        &mut self,
+        label: &str,
+        unit: Option<&str>,
+        value: f64,
+        ndigits: i32,
+    ) {
+        let label = explain_cstring(label);
+        let unit = unit.map(explain_cstring);
+        unsafe {
+            pg_sys::ExplainPropertyFloat(
+                label.as_ptr(),
+                unit.as_ref().map_or(std::ptr::null(), |unit| unit.as_ptr()),
+                value,
+                ndigits,
+                self.es,
+            )
+        }
+    }
+
+    pub fn explain_property_bool(&mut self, label: &str, value: bool) {
+        let label = explain_cstring(label);
+        unsafe { pg_sys::ExplainPropertyBool(label.as_ptr(), value, self.es) }
+    }
+
+    /// Start a group of properties, which must be ended by a matching
+    /// [`ExplainStateWrapper::explain_close_group`]
+    ///
+    /// `objtype` names the group in `XML` format.  A `labeled` group is an object whose `label`
+    /// is its key in `JSON` and `YAML` format, and an unlabeled group is an array.  Groups aren't
+    /// shown in `TEXT` format.
+    pub fn explain_open_group(&mut self, objtype: &str, label: Option<&str>, labeled: bool) {
+        let objtype = explain_cstring(objtype);
+        let label = label.map(explain_cstring);
+        unsafe {
+            pg_sys::ExplainOpenGroup(
+                objtype.as_ptr(),
+                label.as_ref().map_or(std::ptr::null(), |label| label.as_ptr()),
+                labeled,
+                self.es,
+            )
+        }
+    }
+
+    /// End a group started by [`ExplainStateWrapper::explain_open_group`], with the same arguments
+    pub fn explain_close_group(&mut self, objtype: &str, label: Option<&str>, labeled: bool) {
+        let objtype = explain_cstring(objtype);
+        let label = label.map(explain_cstring);
+        unsafe {
+            pg_sys::ExplainCloseGroup(
+                objtype.as_ptr(),
+                label.as_ref().map_or(std::ptr::null(), |label| label.as_ptr()),
+                labeled,
+                self.es,
+            )
+        }
+    }
+
+    /// Add the properties written by `f` as an object named `objtype`, which is the value of the
+    /// key `label` in `JSON` and `YAML` format, or an element of the enclosing array when `label`
+    /// is `None`
+    ///
+    /// At the top level of an `EXPLAIN`, such as in an [`explain_one_query`] handler, objects must
+    /// be unlabeled.  In `TEXT` format, where Postgres doesn't show groups, a `label` is written
+    /// on a line of its own with the group's properties indented beneath it.
+    pub fn explain_group<R>(
+        &mut self,
+        objtype: &str,
+        label: Option<&str>,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        match (self.format(), label) {
+            (ExplainFormat::Text, None) => f(self),
+            (ExplainFormat::Text, Some(label)) => {
+                let line = explain_cstring(&format!("{}:\n", label));
+                unsafe {
+                    pg_sys::appendStringInfoSpaces((*self.es).str_, (*self.es).indent * 2);
+                    pg_sys::appendStringInfoString((*self.es).str_, line.as_ptr());
+                    (*self.es).indent += 1;
+                }
+                let result = f(self);
+                unsafe { (*self.es).indent -= 1 }
+                result
+            }
+            (_, label) => {
+                self.explain_open_group(objtype, label, true);
+                let result = f(self);
+                self.explain_close_group(objtype, label, true);
+                result
+            }
+        }
+    }
+}
+
+fn explain_cstring(s: &str) -> CString {
+    CString::new(s).expect("EXPLAIN output contained a NUL byte")
+}
+
+/// When `fmgr_hook` is being called, relative to the function it's hooking
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FmgrHookEvent {
+    /// Before the function is called
+    Start,
+    /// After the function has returned
+    End,
+    /// The function raised an error, which will be re-raised once the hook returns
+    Abort,
+}
+
+impl FmgrHookEvent {
+    fn from_pg(event: pg_sys::FmgrHookEventType) -> Self {
+        match event {
+            pg_sys::FmgrHookEventType_FHET_START => FmgrHookEvent::Start,
+            pg_sys::FmgrHookEventType_FHET_END => FmgrHookEvent::End,
+            pg_sys::FmgrHookEventType_FHET_ABORT => FmgrHookEvent::Abort,
+            _ => panic!("unrecognized FmgrHookEventType: {}", event),
+        }
+    }
+
+    fn into_pg(self) -> pg_sys::FmgrHookEventType {
+        match self {
+            FmgrHookEvent::Start => pg_sys::FmgrHookEventType_FHET_START,
+            FmgrHookEvent::End => pg_sys::FmgrHookEventType_FHET_END,
+            FmgrHookEvent::Abort => pg_sys::FmgrHookEventType_FHET_ABORT,
+        }
+    }
+}
+
+/// Private state for one call of a function hooked by `fmgr_hook`, kept from the call's
+/// [`FmgrHookEvent::Start`] until its [`FmgrHookEvent::End`] or [`FmgrHookEvent::Abort`]
+///
+/// Values are stored by their type, so each hook should use a type of its own to keep its values
+/// apart from any other hook's.
+pub struct FmgrHookState {
+    values: Vec<Box<dyn Any>>,
+    /// The private slot handed to the `fmgr_hook` installed before ours
+    prev_private: pg_sys::Datum,
+}
+
+impl FmgrHookState {
+    /// The stored value of type `T`
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.values.iter().find_map(|value| value.downcast_ref())
+    }
+
+    /// The stored value of type `T`, mutably
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.values.iter_mut().find_map(|value| value.downcast_mut())
+    }
+
+    /// Store `value`, returning the value of the same type it replaced, if any
+    pub fn insert<T: 'static>(&mut self, value: T) -> Option<T> {
+        let prev = self.remove::<T>();
+        self.values.push(Box::new(value));
+        prev
+    }
+
+    /// Remove and return the stored value of type `T`
+    pub fn remove<T: 'static>(&mut self) -> Option<T> {
+        let idx = self.values.iter().position(|value| value.is::<T>())?;
+        self.values.swap_remove(idx).downcast().ok().map(|value| *value)
+    }
+
+    /// Call `f` with the state kept in `private`, Postgres' private slot for this call of the
+    /// function, which is created at the start of the call and freed once it ends or aborts
+    ///
+    /// The state is also freed if `f` raises an error, as Postgres doesn't call the hook again for
+    /// this call once it has: not even with [`FmgrHookEvent::Abort`] when `f` refuses the call at
+    /// [`FmgrHookEvent::Start`].
+    unsafe fn with_pg<R>(
+        event: FmgrHookEvent,
+        private: *mut pg_sys::Datum,
+        f: impl FnOnce(&mut FmgrHookState) -> R,
+    ) -> R {
+        if (*private).is_null() {
+            let state = FmgrHookState { values: Vec::new(), prev_private: pg_sys::Datum::from(0) };
+            *private = pg_sys::Datum::from(Box::into_raw(Box::new(state)));
+        }
+        let state = (*private).cast_mut_ptr::<FmgrHookState>();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(&mut *state)));
+        if event != FmgrHookEvent::Start || result.is_err() {
+            drop(Box::from_raw(state));
+            *private = pg_sys::Datum::from(0);
+        }
+        match result {
+            Ok(result) => result,
+            Err(e) => std::panic::resume_unwind(e),
+        }
+    }
+}
+
 pub trait PgHooks {
     /// Hook for plugins to get control in ExecutorStart()
     fn executor_start(
@@ -165,6 +806,236 @@ pub trait PgHooks {
         prev_hook(pstate, query, jumble_state)
     }
 
+    /// Hook for plugins to add their own paths, such as custom scans, for scanning a base relation
+    fn set_rel_pathlist(
+        &mut self,
+        root: PgBox<pg_sys::PlannerInfo>,
+        rel: PgBox<pg_sys::RelOptInfo>,
+        rti: pg_sys::Index,
+        rte: PgBox<pg_sys::RangeTblEntry>,
+        prev_hook: fn(
+            root: PgBox<pg_sys::PlannerInfo>,
+            rel: PgBox<pg_sys::RelOptInfo>,
+            rti: pg_sys::Index,
+            rte: PgBox<pg_sys::RangeTblEntry>,
+        ) -> HookResult<()>,
+    ) -> HookResult<()> {
+        prev_hook(root, rel, rti, rte)
+    }
+
+    /// Hook for plugins to add their own paths for joining `outerrel` and `innerrel`
+    fn set_join_pathlist(
+        &mut self,
+        root: PgBox<pg_sys::PlannerInfo>,
+        joinrel: PgBox<pg_sys::RelOptInfo>,
+        outerrel: PgBox<pg_sys::RelOptInfo>,
+        innerrel: PgBox<pg_sys::RelOptInfo>,
+        jointype: pg_sys::JoinType,
+        extra: PgBox<pg_sys::JoinPathExtraData>,
+        prev_hook: fn(
+            root: PgBox<pg_sys::PlannerInfo>,
+            joinrel: PgBox<pg_sys::RelOptInfo>,
+            outerrel: PgBox<pg_sys::RelOptInfo>,
+            innerrel: PgBox<pg_sys::RelOptInfo>,
+            jointype: pg_sys::JoinType,
+            extra: PgBox<pg_sys::JoinPathExtraData>,
+        ) -> HookResult<()>,
+    ) -> HookResult<()> {
+        prev_hook(root, joinrel, outerrel, innerrel, jointype, extra)
+    }
+
+    /// Hook for plugins to adjust what the planner knows about a relation, such as its size
+    /// estimates or the indexes it can use, after Postgres has read it from the catalogs
+    fn get_relation_info(
+        &mut self,
+        root: PgBox<pg_sys::PlannerInfo>,
+        relation_oid: pg_sys::Oid,
+        inhparent: bool,
+        rel: PgBox<pg_sys::RelOptInfo>,
+        prev_hook: fn(
+            root: PgBox<pg_sys::PlannerInfo>,
+            relation_oid: pg_sys::Oid,
+            inhparent: bool,
+            rel: PgBox<pg_sys::RelOptInfo>,
+        ) -> HookResult<()>,
+    ) -> HookResult<()> {
+        prev_hook(root, relation_oid, inhparent, rel)
+    }
+
+    /// Hook for plugins to add their own paths for post-scan/join processing, such as grouping,
+    /// sorting, or `LIMIT`
+    ///
+    /// What `extra` points to depends on `stage`, and it may be null.
+    fn create_upper_paths(
+        &mut self,
+        root: PgBox<pg_sys::PlannerInfo>,
+        stage: pg_sys::UpperRelationKind,
+        input_rel: PgBox<pg_sys::RelOptInfo>,
+        output_rel: PgBox<pg_sys::RelOptInfo>,
+        extra: void_mut_ptr,
+        prev_hook: fn(
+            root: PgBox<pg_sys::PlannerInfo>,
+            stage: pg_sys::UpperRelationKind,
+            input_rel: PgBox<pg_sys::RelOptInfo>,
+            output_rel: PgBox<pg_sys::RelOptInfo>,
+            extra: void_mut_ptr,
+        ) -> HookResult<()>,
+    ) -> HookResult<()> {
+        prev_hook(root, stage, input_rel, output_rel, extra)
+    }
+
+    /// Hook for plugins to get control of planning and explaining a query for `EXPLAIN`
+    ///
+    /// Call `prev_hook` to have the query planned and explained as usual, and add properties to
+    /// `es` before or after it.
+    fn explain_one_query(
+        &mut self,
+        query: PgBox<pg_sys::Query>,
+        cursor_options: i32,
+        into: PgBox<pg_sys::IntoClause>,
+        es: &mut ExplainStateWrapper,
+        query_string: &std::ffi::CStr,
+        params: PgBox<pg_sys::ParamListInfoData>,
+        query_env: PgBox<pg_sys::QueryEnvironment>,
+        prev_hook: fn(
+            query: PgBox<pg_sys::Query>,
+            cursor_options: i32,
+            into: PgBox<pg_sys::IntoClause>,
+            es: &mut ExplainStateWrapper,
+            query_string: &std::ffi::CStr,
+            params: PgBox<pg_sys::ParamListInfoData>,
+            query_env: PgBox<pg_sys::QueryEnvironment>,
+        ) -> HookResult<()>,
+    ) -> HookResult<()> {
+        prev_hook(query, cursor_options, into, es, query_string, params, query_env)
+    }
+
+    /// Hook for plugins to name indexes in `EXPLAIN` output, such as hypothetical indexes that
+    /// don't exist in the catalogs.  Return `None` to use the index's usual name
+    fn explain_get_index_name(
+        &mut self,
+        index_oid: pg_sys::Oid,
+        prev_hook: fn(index_oid: pg_sys::Oid) -> HookResult<Option<String>>,
+    ) -> HookResult<Option<String>> {
+        prev_hook(index_oid)
+    }
+
+    /// Hook for plugins to get control before a message is written to the server log
+    ///
+    /// This is called for every message that's bound for the server log, including those raised
+    /// while already handling an error, so it must not raise errors of its own; a panic is
+    /// swallowed, as described in [`emit_log`](mod@emit_log).  Any previously installed
+    /// `emit_log_hook` is called afterwards, and sees the changes made here.
+    fn emit_log(&mut self, error: &mut ErrorDataWrapper) {
+        let _ = error;
+    }
+
+    /// Hook for plugins to get control after Postgres has authenticated a client, whether or not
+    /// it succeeded
+    ///
+    /// Raise an error to refuse the connection.  Only takes effect when the extension is loaded
+    /// through `shared_preload_libraries`.
+    fn client_authentication(
+        &mut self,
+        port: &PortWrapper,
+        status: ClientAuthStatus,
+        prev_hook: fn(port: &PortWrapper, status: ClientAuthStatus) -> HookResult<()>,
+    ) -> HookResult<()> {
+        prev_hook(port, status)
+    }
+
+    /// Hook for plugins to check a role's new password in `CREATE ROLE` and `ALTER ROLE`
+    ///
+    /// Raise an error to reject the password.  `valid_until` is the role's `VALID UNTIL` time, if
+    /// it has one.
+    fn check_password(
+        &mut self,
+        username: &str,
+        password: Password,
+        valid_until: Option<TimestampWithTimeZone>,
+        prev_hook: fn(
+            username: &str,
+            password: Password,
+            valid_until: Option<TimestampWithTimeZone>,
+        ) -> HookResult<()>,
+    ) -> HookResult<()> {
+        prev_hook(username, password, valid_until)
+    }
+
+    /// Hook for plugins to get control when a database object identified by its OID is created,
+    /// altered, dropped, searched, or executed
+    ///
+    /// `class_id` is the OID of the system catalog the object lives in, such as
+    /// `pg_sys::RelationRelationId`, and `sub_id` is a column number, or zero.
+    fn object_access(
+        &mut self,
+        event: ObjectAccessEvent,
+        class_id: pg_sys::Oid,
+        object_id: pg_sys::Oid,
+        sub_id: i32,
+        prev_hook: fn(
+            event: ObjectAccessEvent,
+            class_id: pg_sys::Oid,
+            object_id: pg_sys::Oid,
+            sub_id: i32,
+        ) -> HookResult<()>,
+    ) -> HookResult<()> {
+        prev_hook(event, class_id, object_id, sub_id)
+    }
+
+    /// Like [`PgHooks::object_access`], for objects identified by name rather than by OID, such
+    /// as the configuration parameters changed by `SET` and `ALTER SYSTEM`
+    ///
+    /// Only raised by Postgres 15 and later.
+    fn object_access_str(
+        &mut self,
+        event: ObjectAccessEvent,
+        class_id: pg_sys::Oid,
+        object_name: &str,
+        sub_id: i32,
+        prev_hook: fn(
+            event: ObjectAccessEvent,
+            class_id: pg_sys::Oid,
+            object_name: &str,
+            sub_id: i32,
+        ) -> HookResult<()>,
+    ) -> HookResult<()> {
+        prev_hook(event, class_id, object_name, sub_id)
+    }
+
+    /// Hook for plugins to choose which functions, by OID, they want `fmgr_hook` called for
+    ///
+    /// Functions that need it are called through the same path as `SECURITY DEFINER` functions,
+    /// which is slower and keeps them from being inlined, so only return `true` for the functions
+    /// of interest.
+    fn needs_fmgr_hook(
+        &mut self,
+        fn_oid: pg_sys::Oid,
+        prev_hook: fn(fn_oid: pg_sys::Oid) -> HookResult<bool>,
+    ) -> HookResult<bool> {
+        prev_hook(fn_oid)
+    }
+
+    /// Hook for plugins to get control around calls to the functions chosen by
+    /// [`PgHooks::needs_fmgr_hook`], as well as to `SECURITY DEFINER` functions and functions
+    /// with `SET` clauses
+    ///
+    /// Raise an error at [`FmgrHookEvent::Start`] to refuse the call.  `state` is kept for the
+    /// length of the call, such as to time it.
+    fn fmgr_hook(
+        &mut self,
+        event: FmgrHookEvent,
+        flinfo: PgBox<pg_sys::FmgrInfo>,
+        state: &mut FmgrHookState,
+        prev_hook: fn(
+            event: FmgrHookEvent,
+            flinfo: PgBox<pg_sys::FmgrInfo>,
+            state: &mut FmgrHookState,
+        ) -> HookResult<()>,
+    ) -> HookResult<()> {
+        prev_hook(event, flinfo, state)
+    }
+
     /// Called when the transaction aborts
     fn abort(&mut self) {}
 
@@ -182,11 +1053,29 @@ struct Hooks {
     prev_process_utility_hook: pg_sys::ProcessUtility_hook_type,
     prev_planner_hook: pg_sys::planner_hook_type,
     prev_post_parse_analyze_hook: pg_sys::post_parse_analyze_hook_type,
+    prev_set_rel_pathlist_hook: pg_sys::set_rel_pathlist_hook_type,
+    prev_set_join_pathlist_hook: pg_sys::set_join_pathlist_hook_type,
+    prev_get_relation_info_hook: pg_sys::get_relation_info_hook_type,
+    prev_create_upper_paths_hook: pg_sys::create_upper_paths_hook_type,
+    prev_explain_get_index_name_hook: pg_sys::explain_get_index_name_hook_type,
+    prev_client_authentication_hook: pg_sys::ClientAuthentication_hook_type,
+    prev_check_password_hook: pg_sys::check_password_hook_type,
+    prev_object_access_hook: pg_sys::object_access_hook_type,
+    #[cfg(feature = "pg15")]
+    prev_object_access_hook_str: pg_sys::object_access_hook_type_str,
 }
 
 static mut HOOKS: Option<Hooks> = None;
 
 /// Register a `PgHook` instance to respond to the various hook points
+///
+/// This installs every hook [`PgHooks`] has a method for, whether or not `hook` overrides it, and
+/// only one instance can be registered per backend.  [`PgHooks::emit_log`],
+/// [`PgHooks::explain_one_query`], [`PgHooks::needs_fmgr_hook`] and [`PgHooks::fmgr_hook`] are
+/// installed as handlers on their per-hook module's chain, after any handlers already there.
+///
+/// Use the per-hook modules, such as [`executor_start::register`], to install only some hooks or
+/// to register handlers from more than one place.
 pub unsafe fn register_hook(hook: &'static mut (dyn PgHooks)) {
     if HOOKS.is_some() {
         panic!("PgHook instance already registered");
@@ -216,7 +1105,49 @@ pub unsafe fn register_hook(hook: &'static mut (dyn PgHooks)) {
             .or(Some(pgx_standard_planner_wrapper)),
         prev_post_parse_analyze_hook: pg_sys::post_parse_analyze_hook
             .replace(pgx_post_parse_analyze),
+        prev_set_rel_pathlist_hook: pg_sys::set_rel_pathlist_hook.replace(pgx_set_rel_pathlist),
+        prev_set_join_pathlist_hook: pg_sys::set_join_pathlist_hook.replace(pgx_set_join_pathlist),
+        prev_get_relation_info_hook: pg_sys::get_relation_info_hook.replace(pgx_get_relation_info),
+        prev_create_upper_paths_hook: pg_sys::create_upper_paths_hook
+            .replace(pgx_create_upper_paths),
+        prev_explain_get_index_name_hook: pg_sys::explain_get_index_name_hook
+            .replace(pgx_explain_get_index_name),
+        prev_client_authentication_hook: pg_sys::ClientAuthentication_hook
+            .replace(pgx_client_authentication),
+        prev_check_password_hook: pg_sys::check_password_hook.replace(pgx_check_password),
+        prev_object_access_hook: pg_sys::object_access_hook.replace(pgx_object_access),
+        #[cfg(feature = "pg15")]
+        prev_object_access_hook_str: pg_sys::object_access_hook_str.replace(pgx_object_access_str),
+    });
+
+    // these go through the same chains as the per-hook modules' handlers
+    emit_log::register(|error, next| {
+        HOOKS.as_mut().unwrap().current_hook.emit_log(error);
+        next(error)
     });
+    needs_fmgr_hook::register(|fn_oid, next| {
+        NEEDS_FMGR_HOOK_NEXT = Some(std::mem::transmute(next));
+        HOOKS.as_mut().unwrap().current_hook.needs_fmgr_hook(fn_oid, needs_fmgr_hook_prev)
+    });
+    fmgr_hook::register(|event, flinfo, state, next| {
+        FMGR_HOOK_NEXT = Some(std::mem::transmute(next));
+        HOOKS.as_mut().unwrap().current_hook.fmgr_hook(event, flinfo, state, fmgr_hook_prev)
+    });
+    explain_one_query::register(
+        |query, cursor_options, into, es, query_string, params, query_env, next| {
+            EXPLAIN_ONE_QUERY_NEXT = Some(std::mem::transmute(next));
+            HOOKS.as_mut().unwrap().current_hook.explain_one_query(
+                query,
+                cursor_options,
+                into,
+                es,
+                query_string,
+                params,
+                query_env,
+                explain_one_query_prev,
+            )
+        },
+    );
 
     #[pg_guard]
     unsafe extern "C" fn xact_callback(event: pg_sys::XactEvent, _data: void_mut_ptr) {
@@ -540,6 +1471,397 @@ unsafe extern "C" fn pgx_post_parse_analyze(
     .inner
 }
 
+#[pg_guard]
+unsafe extern "C" fn pgx_set_rel_pathlist(
+    root: *mut pg_sys::PlannerInfo,
+    rel: *mut pg_sys::RelOptInfo,
+    rti: pg_sys::Index,
+    rte: *mut pg_sys::RangeTblEntry,
+) {
+    fn prev(
+        root: PgBox<pg_sys::PlannerInfo>,
+        rel: PgBox<pg_sys::RelOptInfo>,
+        rti: pg_sys::Index,
+        rte: PgBox<pg_sys::RangeTblEntry>,
+    ) -> HookResult<()> {
+        HookResult::new(unsafe {
+            match HOOKS.as_mut().unwrap().prev_set_rel_pathlist_hook.as_ref() {
+                None => (),
+                Some(f) => (f)(root.as_ptr(), rel.as_ptr(), rti, rte.as_ptr()),
+            }
+        })
+    }
+
+    let hook = &mut HOOKS.as_mut().unwrap().current_hook;
+    hook.set_rel_pathlist(PgBox::from_pg(root), PgBox::from_pg(rel), rti, PgBox::from_pg(rte), prev)
+        .inner
+}
+
+#[pg_guard]
+unsafe extern "C" fn pgx_set_join_pathlist(
+    root: *mut pg_sys::PlannerInfo,
+    joinrel: *mut pg_sys::RelOptInfo,
+    outerrel: *mut pg_sys::RelOptInfo,
+    innerrel: *mut pg_sys::RelOptInfo,
+    jointype: pg_sys::JoinType,
+    extra: *mut pg_sys::JoinPathExtraData,
+) {
+    fn prev(
+        root: PgBox<pg_sys::PlannerInfo>,
+        joinrel: PgBox<pg_sys::RelOptInfo>,
+        outerrel: PgBox<pg_sys::RelOptInfo>,
+        innerrel: PgBox<pg_sys::RelOptInfo>,
+        jointype: pg_sys::JoinType,
+        extra: PgBox<pg_sys::JoinPathExtraData>,
+    ) -> HookResult<()> {
+        HookResult::new(unsafe {
+            match HOOKS.as_mut().unwrap().prev_set_join_pathlist_hook.as_ref() {
+                None => (),
+                Some(f) => (f)(
+                    root.as_ptr(),
+                    joinrel.as_ptr(),
+                    outerrel.as_ptr(),
+                    innerrel.as_ptr(),
+                    jointype,
+                    extra.as_ptr(),
+                ),
+            }
+        })
+    }
+
+    let hook = &mut HOOKS.as_mut().unwrap().current_hook;
+    hook.set_join_pathlist(
+        PgBox::from_pg(root),
+        PgBox::from_pg(joinrel),
+        PgBox::from_pg(outerrel),
+        PgBox::from_pg(innerrel),
+        jointype,
+        PgBox::from_pg(extra),
+        prev,
+    )
+    .inner
+}
+
+#[pg_guard]
+unsafe extern "C" fn pgx_get_relation_info(
+    root: *mut pg_sys::PlannerInfo,
+    relation_oid: pg_sys::Oid,
+    inhparent: bool,
+    rel: *mut pg_sys::RelOptInfo,
+) {
+    fn prev(
+        root: PgBox<pg_sys::PlannerInfo>,
+        relation_oid: pg_sys::Oid,
+        inhparent: bool,
+        rel: PgBox<pg_sys::RelOptInfo>,
+    ) -> HookResult<()> {
+        HookResult::new(unsafe {
+            match HOOKS.as_mut().unwrap().prev_get_relation_info_hook.as_ref() {
+                None => (),
+                Some(f) => (f)(root.as_ptr(), relation_oid, inhparent, rel.as_ptr()),
+            }
+        })
+    }
+
+    let hook = &mut HOOKS.as_mut().unwrap().current_hook;
+    hook.get_relation_info(PgBox::from_pg(root), relation_oid, inhparent, PgBox::from_pg(rel), prev)
+        .inner
+}
+
+#[pg_guard]
+unsafe extern "C" fn pgx_create_upper_paths(
+    root: *mut pg_sys::PlannerInfo,
+    stage: pg_sys::UpperRelationKind,
+    input_rel: *mut pg_sys::RelOptInfo,
+    output_rel: *mut pg_sys::RelOptInfo,
+    extra: void_mut_ptr,
+) {
+    fn prev(
+        root: PgBox<pg_sys::PlannerInfo>,
+        stage: pg_sys::UpperRelationKind,
+        input_rel: PgBox<pg_sys::RelOptInfo>,
+        output_rel: PgBox<pg_sys::RelOptInfo>,
+        extra: void_mut_ptr,
+    ) -> HookResult<()> {
+        HookResult::new(unsafe {
+            match HOOKS.as_mut().unwrap().prev_create_upper_paths_hook.as_ref() {
+                None => (),
+                Some(f) => {
+                    (f)(root.as_ptr(), stage, input_rel.as_ptr(), output_rel.as_ptr(), extra)
+                }
+            }
+        })
+    }
+
+    let hook = &mut HOOKS.as_mut().unwrap().current_hook;
+    hook.create_upper_paths(
+        PgBox::from_pg(root),
+        stage,
+        PgBox::from_pg(input_rel),
+        PgBox::from_pg(output_rel),
+        extra,
+        prev,
+    )
+    .inner
+}
+
+/// The `next` of the `explain_one_query` handler [`register_hook`] registers, for the `prev_hook`
+/// it gives [`PgHooks::explain_one_query`].  Handlers aren't re-entered, so one is enough.
+static mut EXPLAIN_ONE_QUERY_NEXT: Option<explain_one_query::Next<'static>> = None;
+
+fn explain_one_query_prev(
+    query: PgBox<pg_sys::Query>,
+    cursor_options: i32,
+    into: PgBox<pg_sys::IntoClause>,
+    es: &mut ExplainStateWrapper,
+    query_string: &std::ffi::CStr,
+    params: PgBox<pg_sys::ParamListInfoData>,
+    query_env: PgBox<pg_sys::QueryEnvironment>,
+) -> HookResult<()> {
+    unsafe {
+        (EXPLAIN_ONE_QUERY_NEXT.as_mut().unwrap())(
+            query,
+            cursor_options,
+            into,
+            es,
+            query_string,
+            params,
+            query_env,
+        )
+    }
+}
+
+/// Like [`EXPLAIN_ONE_QUERY_NEXT`], for [`PgHooks::needs_fmgr_hook`]
+static mut NEEDS_FMGR_HOOK_NEXT: Option<needs_fmgr_hook::Next<'static>> = None;
+
+fn needs_fmgr_hook_prev(fn_oid: pg_sys::Oid) -> HookResult<bool> {
+    unsafe { (NEEDS_FMGR_HOOK_NEXT.as_mut().unwrap())(fn_oid) }
+}
+
+/// Like [`EXPLAIN_ONE_QUERY_NEXT`], for [`PgHooks::fmgr_hook`]
+static mut FMGR_HOOK_NEXT: Option<fmgr_hook::Next<'static>> = None;
+
+fn fmgr_hook_prev(
+    event: FmgrHookEvent,
+    flinfo: PgBox<pg_sys::FmgrInfo>,
+    state: &mut FmgrHookState,
+) -> HookResult<()> {
+    unsafe { (FMGR_HOOK_NEXT.as_mut().unwrap())(event, flinfo, state) }
+}
+
+#[pg_guard]
+unsafe extern "C" fn pgx_explain_get_index_name(
+    index_oid: pg_sys::Oid,
+) -> *const ::std::os::raw::c_char {
+    fn prev(index_oid: pg_sys::Oid) -> HookResult<Option<String>> {
+        HookResult::new(unsafe {
+            match HOOKS.as_mut().unwrap().prev_explain_get_index_name_hook.as_ref() {
+                None => None,
+                Some(f) => {
+                    let name = (f)(index_oid);
+                    if name.is_null() {
+                        None
+                    } else {
+                        Some(CStr::from_ptr(name).to_string_lossy().into_owned())
+                    }
+                }
+            }
+        })
+    }
+
+    let hook = &mut HOOKS.as_mut().unwrap().current_hook;
+    match hook.explain_get_index_name(index_oid, prev).inner {
+        Some(name) => PgMemoryContexts::CurrentMemoryContext.pstrdup(&name),
+        None => std::ptr::null(),
+    }
+}
+
+#[pg_guard]
+unsafe extern "C" fn pgx_client_authentication(port: *mut pg_sys::Port, status: i32) {
+    fn prev(port: &PortWrapper, status: ClientAuthStatus) -> HookResult<()> {
+        HookResult::new(unsafe {
+            match HOOKS.as_mut().unwrap().prev_client_authentication_hook.as_ref() {
+                None => (),
+                Some(f) => (f)(port.as_ptr(), status.into_pg()),
+            }
+        })
+    }
+
+    let hook = &mut HOOKS.as_mut().unwrap().current_hook;
+    hook.client_authentication(&PortWrapper::from_pg(port), ClientAuthStatus::from_pg(status), prev)
+        .inner
+}
+
+#[pg_guard]
+unsafe extern "C" fn pgx_check_password(
+    username: *const ::std::os::raw::c_char,
+    shadow_pass: *const ::std::os::raw::c_char,
+    password_type: pg_sys::PasswordType,
+    validuntil_time: pg_sys::Datum,
+    validuntil_null: bool,
+) {
+    fn prev(
+        username: &str,
+        password: Password,
+        valid_until: Option<TimestampWithTimeZone>,
+    ) -> HookResult<()> {
+        HookResult::new(unsafe {
+            match HOOKS.as_mut().unwrap().prev_check_password_hook.as_ref() {
+                None => (),
+                Some(f) => {
+                    let username = CString::new(username).expect("username contained a NUL byte");
+                    let (shadow_pass, password_type) = password.into_pg();
+                    let validuntil_time = valid_until.into_datum();
+                    (f)(
+                        username.as_ptr(),
+                        shadow_pass.as_ptr(),
+                        password_type,
+                        validuntil_time.unwrap_or(pg_sys::Datum::from(0)),
+                        validuntil_time.is_none(),
+                    )
+                }
+            }
+        })
+    }
+
+    let hook = &mut HOOKS.as_mut().unwrap().current_hook;
+    hook.check_password(
+        CStr::from_ptr(username).to_str().expect("username was not valid UTF-8"),
+        Password::from_pg(shadow_pass, password_type),
+        TimestampWithTimeZone::from_datum(validuntil_time, validuntil_null),
+        prev,
+    )
+    .inner
+}
+
+#[pg_guard]
+unsafe extern "C" fn pgx_object_access(
+    access: pg_sys::ObjectAccessType,
+    class_id: pg_sys::Oid,
+    object_id: pg_sys::Oid,
+    sub_id: i32,
+    arg: void_mut_ptr,
+) {
+    fn prev(
+        event: ObjectAccessEvent,
+        class_id: pg_sys::Oid,
+        object_id: pg_sys::Oid,
+        sub_id: i32,
+    ) -> HookResult<()> {
+        unsafe {
+            if let Some(f) = HOOKS.as_mut().unwrap().prev_object_access_hook {
+                event.with_pg(|access, arg| f(access, class_id, object_id, sub_id, arg));
+            }
+        }
+        HookResult::new(())
+    }
+
+    let hooks = HOOKS.as_mut().unwrap();
+    match ObjectAccessEvent::from_pg(access, arg) {
+        Some(event) => {
+            hooks.current_hook.object_access(event, class_id, object_id, sub_id, prev).inner
+        }
+        // an event we don't know how to describe, which is still the previous hook's business
+        None => {
+            if let Some(f) = hooks.prev_object_access_hook {
+                f(access, class_id, object_id, sub_id, arg)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "pg15")]
+#[pg_guard]
+unsafe extern "C" fn pgx_object_access_str(
+    access: pg_sys::ObjectAccessType,
+    class_id: pg_sys::Oid,
+    object_str: *const ::std::os::raw::c_char,
+    sub_id: i32,
+    arg: void_mut_ptr,
+) {
+    fn prev(
+        event: ObjectAccessEvent,
+        class_id: pg_sys::Oid,
+        object_name: &str,
+        sub_id: i32,
+    ) -> HookResult<()> {
+        unsafe {
+            if let Some(f) = HOOKS.as_mut().unwrap().prev_object_access_hook_str {
+                let object_str =
+                    CString::new(object_name).expect("object name contained a NUL byte");
+                event.with_pg(|access, arg| f(access, class_id, object_str.as_ptr(), sub_id, arg));
+            }
+        }
+        HookResult::new(())
+    }
+
+    let hooks = HOOKS.as_mut().unwrap();
+    match ObjectAccessEvent::from_pg(access, arg) {
+        Some(event) => {
+            let object_name =
+                CStr::from_ptr(object_str).to_str().expect("object name was not valid UTF-8");
+            hooks.current_hook.object_access_str(event, class_id, object_name, sub_id, prev).inner
+        }
+        None => {
+            if let Some(f) = hooks.prev_object_access_hook_str {
+                f(access, class_id, object_str, sub_id, arg)
+            }
+        }
+    }
+}
+
+/// What Postgres' own (static) `ExplainOneQuery()` does when there's no hook: plan the query,
+/// then explain the plan
+#[pg_guard]
+unsafe extern "C" fn pgx_standard_explain_one_query_wrapper(
+    query: *mut pg_sys::Query,
+    cursor_options: i32,
+    into: *mut pg_sys::IntoClause,
+    es: *mut pg_sys::ExplainState,
+    query_string: *const ::std::os::raw::c_char,
+    params: pg_sys::ParamListInfo,
+    query_env: *mut pg_sys::QueryEnvironment,
+) {
+    #[cfg(any(feature = "pg13", feature = "pg14", feature = "pg15"))]
+    let bufusage_start = pg_sys::pgBufferUsage;
+    let mut planstart = pg_sys::instr_time::default();
+    pg_sys::INSTR_TIME_SET_CURRENT(&mut planstart);
+
+    #[cfg(any(feature = "pg11", feature = "pg12"))]
+    let plan = pg_sys::pg_plan_query(query, cursor_options, params);
+
+    #[cfg(any(feature = "pg13", feature = "pg14", feature = "pg15"))]
+    let plan = pg_sys::pg_plan_query(query, query_string, cursor_options, params);
+
+    let mut planduration = pg_sys::instr_time::default();
+    pg_sys::INSTR_TIME_SET_CURRENT(&mut planduration);
+    pg_sys::INSTR_TIME_SUBTRACT(&mut planduration, &mut planstart);
+
+    #[cfg(any(feature = "pg11", feature = "pg12"))]
+    pg_sys::ExplainOnePlan(plan, into, es, query_string, params, query_env, &planduration);
+
+    #[cfg(any(feature = "pg13", feature = "pg14", feature = "pg15"))]
+    {
+        let mut bufusage = pg_sys::BufferUsage::default();
+        let bufusage = if (*es).buffers {
+            pg_sys::BufferUsageAccumDiff(&mut bufusage, &pg_sys::pgBufferUsage, &bufusage_start);
+            &bufusage as *const pg_sys::BufferUsage
+        } else {
+            std::ptr::null()
+        };
+        pg_sys::ExplainOnePlan(
+            plan,
+            into,
+            es,
+            query_string,
+            params,
+            query_env,
+            &planduration,
+            bufusage,
+        );
+    }
+}
+
 #[pg_guard]
 unsafe extern "C" fn pgx_standard_executor_start_wrapper(
     query_desc: *mut pg_sys::QueryDesc,
@@ -0,0 +1,125 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Plumbing shared by the per-hook registration modules, such as [`super::executor_start`]
+use std::cell::Cell;
+
+/// The Rust handlers registered for one Postgres hook, and the hook that was installed before them
+pub(crate) struct HookChain<H: ?Sized, P> {
+    handlers: Vec<Entry<H>>,
+    prev: Option<P>,
+}
+
+struct Entry<H: ?Sized> {
+    handler: Box<H>,
+    /// Boxed, like the handler, so [`Running`] can point at it while more handlers are registered
+    running: Box<Cell<bool>>,
+}
+
+/// What [`HookChain::enter`] found at a position in the chain
+pub(crate) enum Enter<H: ?Sized> {
+    /// A handler that's now marked as running, until the [`Running`] guard is dropped
+    Handler(*mut H, Running),
+    /// A handler that's already running further up the stack, and must not be re-entered
+    Busy,
+    /// The end of the chain
+    End,
+}
+
+/// Marks a handler as no longer running when dropped, including when unwinding out of it
+pub(crate) struct Running(*const Cell<bool>);
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        unsafe { (*self.0).set(false) }
+    }
+}
+
+impl<H: ?Sized, P: Copy> HookChain<H, P> {
+    pub(crate) const fn new() -> Self {
+        HookChain { handlers: Vec::new(), prev: None }
+    }
+
+    /// Add `handler` to the end of the chain, calling `install` to swap our hook into Postgres the
+    /// first time.  `install` returns the hook it replaced
+    pub(crate) fn register(&mut self, handler: Box<H>, install: impl FnOnce() -> P) {
+        if self.prev.is_none() {
+            self.prev = Some(install());
+        }
+        self.handlers.push(Entry { handler, running: Box::new(Cell::new(false)) });
+    }
+
+    /// Start running the handler at `idx`
+    ///
+    /// A handler can cause its own hook to be called again, such as by running a query through
+    /// SPI, and it's skipped while it's still running so that it's never borrowed twice.  Handlers
+    /// are boxed, so the pointer stays valid even if more are registered while it's used.
+    pub(crate) fn enter(&mut self, idx: usize) -> Enter<H> {
+        match self.handlers.get_mut(idx) {
+            None => Enter::End,
+            Some(entry) if entry.running.get() => Enter::Busy,
+            Some(entry) => {
+                entry.running.set(true);
+                Enter::Handler(&mut *entry.handler as *mut H, Running(&*entry.running))
+            }
+        }
+    }
+
+    /// The hook Postgres had installed before ours
+    pub(crate) fn prev(&self) -> P {
+        self.prev.expect("hook chain has not been installed")
+    }
+}
+
+/// Define a per-hook module's `register()` function, along with the `call()` function its
+/// Postgres hook uses to run the chain of registered handlers
+///
+/// The module must define `unsafe fn install()`, which swaps its hook into Postgres and returns
+/// the one it replaced, and `fn prev()`, which calls that replaced hook.
+macro_rules! hook_chain {
+    ($hook_type:ty, fn($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty $(,)?) => {
+        /// Continues down the chain: the handlers registered after this one, followed by whatever
+        /// hook was installed before them
+        pub type Next<'a> = &'a mut dyn FnMut($($ty),*) -> $crate::hooks::HookResult<$ret>;
+
+        type Handler = dyn FnMut($($ty,)* Next<'_>) -> $crate::hooks::HookResult<$ret>;
+
+        static mut CHAIN: $crate::hooks::chain::HookChain<Handler, $hook_type> =
+            $crate::hooks::chain::HookChain::new();
+
+        /// Register `handler` to run when this hook is called, after any handlers registered
+        /// before it
+        ///
+        /// Each handler is given `next` as its last argument, which it calls to continue down the
+        /// chain.  Postgres' hook pointer isn't touched until the first handler is registered.
+        /// Handlers can't be unregistered, and are typically registered from `_PG_init()`.
+        ///
+        /// Should the hook be called again while `handler` is running, such as for a query it runs
+        /// through SPI, `handler` is skipped and that call goes straight to the handlers after it.
+        pub fn register<F>(handler: F)
+        where
+            F: FnMut($($ty,)* Next<'_>) -> $crate::hooks::HookResult<$ret> + 'static,
+        {
+            unsafe { CHAIN.register(Box::new(handler), || install()) }
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn call(idx: usize, $($arg: $ty),*) -> $crate::hooks::HookResult<$ret> {
+            unsafe {
+                match CHAIN.enter(idx) {
+                    $crate::hooks::chain::Enter::Handler(handler, _running) => {
+                        (*handler)($($arg,)* &mut |$($arg: $ty),*| call(idx + 1, $($arg),*))
+                    }
+                    $crate::hooks::chain::Enter::Busy => call(idx + 1, $($arg),*),
+                    $crate::hooks::chain::Enter::End => prev($($arg),*),
+                }
+            }
+        }
+    };
+}
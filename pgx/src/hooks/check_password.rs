@@ -0,0 +1,62 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `check_password_hook`, called to check a role's new password in `CREATE ROLE` and
+//! `ALTER ROLE`
+//!
+//! Raise an error to reject the password.
+
+use super::*;
+
+hook_chain!(
+    pg_sys::check_password_hook_type,
+    fn(username: &str, password: Password, valid_until: Option<TimestampWithTimeZone>) -> ()
+);
+
+unsafe fn install() -> pg_sys::check_password_hook_type {
+    pg_sys::check_password_hook.replace(hook)
+}
+
+fn prev(
+    username: &str,
+    password: Password,
+    valid_until: Option<TimestampWithTimeZone>,
+) -> HookResult<()> {
+    unsafe {
+        if let Some(f) = CHAIN.prev() {
+            let username = CString::new(username).expect("username contained a NUL byte");
+            let (shadow_pass, password_type) = password.into_pg();
+            let validuntil_time = valid_until.into_datum();
+            f(
+                username.as_ptr(),
+                shadow_pass.as_ptr(),
+                password_type,
+                validuntil_time.unwrap_or(pg_sys::Datum::from(0)),
+                validuntil_time.is_none(),
+            );
+        }
+    }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(
+    username: *const ::std::os::raw::c_char,
+    shadow_pass: *const ::std::os::raw::c_char,
+    password_type: pg_sys::PasswordType,
+    validuntil_time: pg_sys::Datum,
+    validuntil_null: bool,
+) {
+    call(
+        0,
+        CStr::from_ptr(username).to_str().expect("username was not valid UTF-8"),
+        Password::from_pg(shadow_pass, password_type),
+        TimestampWithTimeZone::from_datum(validuntil_time, validuntil_null),
+    );
+}
@@ -0,0 +1,39 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `ClientAuthentication_hook`, called after Postgres has authenticated a client,
+//! whether or not it succeeded
+//!
+//! Raise an error to refuse the connection.  Only takes effect when the extension is loaded through
+//! `shared_preload_libraries`.
+
+use super::*;
+
+hook_chain!(
+    pg_sys::ClientAuthentication_hook_type,
+    fn(port: &PortWrapper, status: ClientAuthStatus) -> ()
+);
+
+unsafe fn install() -> pg_sys::ClientAuthentication_hook_type {
+    pg_sys::ClientAuthentication_hook.replace(hook)
+}
+
+fn prev(port: &PortWrapper, status: ClientAuthStatus) -> HookResult<()> {
+    unsafe {
+        if let Some(f) = CHAIN.prev() {
+            f(port.as_ptr(), status.into_pg());
+        }
+    }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(port: *mut pg_sys::Port, status: i32) {
+    call(0, &PortWrapper::from_pg(port), ClientAuthStatus::from_pg(status));
+}
@@ -0,0 +1,63 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `create_upper_paths_hook`, which lets plugins add their own paths for post-scan/join
+//! processing, such as grouping, sorting, or `LIMIT`
+//!
+//! What `extra` points to depends on `stage`, and it may be null.
+
+use super::*;
+
+hook_chain!(
+    pg_sys::create_upper_paths_hook_type,
+    fn(
+        root: PgBox<pg_sys::PlannerInfo>,
+        stage: pg_sys::UpperRelationKind,
+        input_rel: PgBox<pg_sys::RelOptInfo>,
+        output_rel: PgBox<pg_sys::RelOptInfo>,
+        extra: void_mut_ptr,
+    ) -> ()
+);
+
+unsafe fn install() -> pg_sys::create_upper_paths_hook_type {
+    pg_sys::create_upper_paths_hook.replace(hook)
+}
+
+fn prev(
+    root: PgBox<pg_sys::PlannerInfo>,
+    stage: pg_sys::UpperRelationKind,
+    input_rel: PgBox<pg_sys::RelOptInfo>,
+    output_rel: PgBox<pg_sys::RelOptInfo>,
+    extra: void_mut_ptr,
+) -> HookResult<()> {
+    unsafe {
+        if let Some(f) = CHAIN.prev() {
+            f(root.as_ptr(), stage, input_rel.as_ptr(), output_rel.as_ptr(), extra);
+        }
+    }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(
+    root: *mut pg_sys::PlannerInfo,
+    stage: pg_sys::UpperRelationKind,
+    input_rel: *mut pg_sys::RelOptInfo,
+    output_rel: *mut pg_sys::RelOptInfo,
+    extra: void_mut_ptr,
+) {
+    call(
+        0,
+        PgBox::from_pg(root),
+        stage,
+        PgBox::from_pg(input_rel),
+        PgBox::from_pg(output_rel),
+        extra,
+    );
+}
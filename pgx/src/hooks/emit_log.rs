@@ -0,0 +1,49 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `emit_log_hook`, called before a message is written to the server log
+//!
+//! This is called for every message that's bound for the server log, including those raised while
+//! already handling an error, so handlers must not raise errors of their own.  A handler that
+//! panics, or raises an error with `error!()`, is cut short and the message is logged as it is.
+//! Errors raised by Postgres itself, from within a handler, can't be recovered from here and are
+//! still raised.
+
+use super::*;
+use pgx_pg_sys::panic::CaughtError;
+use std::panic::AssertUnwindSafe;
+
+hook_chain!(pg_sys::emit_log_hook_type, fn(error: &mut ErrorDataWrapper) -> ());
+
+unsafe fn install() -> pg_sys::emit_log_hook_type {
+    pg_sys::emit_log_hook.replace(hook)
+}
+
+fn prev(error: &mut ErrorDataWrapper) -> HookResult<()> {
+    unsafe {
+        if let Some(f) = CHAIN.prev() {
+            f(error.as_ptr());
+        }
+    }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(edata: *mut pg_sys::ErrorData) {
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        call(0, &mut ErrorDataWrapper::from_pg(edata))
+    }));
+    if let Err(payload) = result {
+        // raising an error of our own from here would replace the message being logged, or worse,
+        // but Postgres' own error state has to be unwound through
+        if let Some(CaughtError::PostgresError(_)) = payload.downcast_ref::<CaughtError>() {
+            std::panic::resume_unwind(payload);
+        }
+    }
+}
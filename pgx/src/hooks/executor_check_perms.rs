@@ -0,0 +1,35 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `ExecutorCheckPerms_hook`, called by `ExecCheckRTPerms()`
+
+use super::*;
+
+hook_chain!(
+    pg_sys::ExecutorCheckPerms_hook_type,
+    fn(range_table: PgList<*mut pg_sys::RangeTblEntry>, ereport_on_violation: bool) -> bool
+);
+
+unsafe fn install() -> pg_sys::ExecutorCheckPerms_hook_type {
+    pg_sys::ExecutorCheckPerms_hook
+        .replace(hook)
+        .or(Some(pgx_standard_executor_check_perms_wrapper))
+}
+
+fn prev(
+    range_table: PgList<*mut pg_sys::RangeTblEntry>,
+    ereport_on_violation: bool,
+) -> HookResult<bool> {
+    HookResult::new(unsafe { (CHAIN.prev().unwrap())(range_table.into_pg(), ereport_on_violation) })
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(range_table: *mut pg_sys::List, ereport_on_violation: bool) -> bool {
+    call(0, PgList::from_pg(range_table), ereport_on_violation).inner
+}
@@ -0,0 +1,28 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `ExecutorEnd_hook`, called by `ExecutorEnd()`
+
+use super::*;
+
+hook_chain!(pg_sys::ExecutorEnd_hook_type, fn(query_desc: PgBox<pg_sys::QueryDesc>) -> ());
+
+unsafe fn install() -> pg_sys::ExecutorEnd_hook_type {
+    pg_sys::ExecutorEnd_hook.replace(hook).or(Some(pgx_standard_executor_end_wrapper))
+}
+
+fn prev(query_desc: PgBox<pg_sys::QueryDesc>) -> HookResult<()> {
+    unsafe { (CHAIN.prev().unwrap())(query_desc.into_pg()) }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(query_desc: *mut pg_sys::QueryDesc) {
+    call(0, PgBox::from_pg(query_desc));
+}
@@ -0,0 +1,46 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `ExecutorRun_hook`, called by `ExecutorRun()`
+
+use super::*;
+
+hook_chain!(
+    pg_sys::ExecutorRun_hook_type,
+    fn(
+        query_desc: PgBox<pg_sys::QueryDesc>,
+        direction: pg_sys::ScanDirection,
+        count: u64,
+        execute_once: bool,
+    ) -> ()
+);
+
+unsafe fn install() -> pg_sys::ExecutorRun_hook_type {
+    pg_sys::ExecutorRun_hook.replace(hook).or(Some(pgx_standard_executor_run_wrapper))
+}
+
+fn prev(
+    query_desc: PgBox<pg_sys::QueryDesc>,
+    direction: pg_sys::ScanDirection,
+    count: u64,
+    execute_once: bool,
+) -> HookResult<()> {
+    unsafe { (CHAIN.prev().unwrap())(query_desc.into_pg(), direction, count, execute_once) }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(
+    query_desc: *mut pg_sys::QueryDesc,
+    direction: pg_sys::ScanDirection,
+    count: u64,
+    execute_once: bool,
+) {
+    call(0, PgBox::from_pg(query_desc), direction, count, execute_once);
+}
@@ -0,0 +1,31 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `ExecutorStart_hook`, called by `ExecutorStart()`
+
+use super::*;
+
+hook_chain!(
+    pg_sys::ExecutorStart_hook_type,
+    fn(query_desc: PgBox<pg_sys::QueryDesc>, eflags: i32) -> ()
+);
+
+unsafe fn install() -> pg_sys::ExecutorStart_hook_type {
+    pg_sys::ExecutorStart_hook.replace(hook).or(Some(pgx_standard_executor_start_wrapper))
+}
+
+fn prev(query_desc: PgBox<pg_sys::QueryDesc>, eflags: i32) -> HookResult<()> {
+    unsafe { (CHAIN.prev().unwrap())(query_desc.into_pg(), eflags) }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(query_desc: *mut pg_sys::QueryDesc, eflags: i32) {
+    call(0, PgBox::from_pg(query_desc), eflags);
+}
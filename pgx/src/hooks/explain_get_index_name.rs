@@ -0,0 +1,45 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `explain_get_index_name_hook`, which lets plugins name indexes in `EXPLAIN` output,
+//! such as hypothetical indexes that don't exist in the catalogs
+//!
+//! Return `None` to use the index's usual name.
+
+use super::*;
+
+hook_chain!(pg_sys::explain_get_index_name_hook_type, fn(index_oid: pg_sys::Oid) -> Option<String>);
+
+unsafe fn install() -> pg_sys::explain_get_index_name_hook_type {
+    pg_sys::explain_get_index_name_hook.replace(hook)
+}
+
+fn prev(index_oid: pg_sys::Oid) -> HookResult<Option<String>> {
+    HookResult::new(unsafe {
+        match CHAIN.prev() {
+            None => None,
+            Some(f) => {
+                let name = f(index_oid);
+                if name.is_null() {
+                    None
+                } else {
+                    Some(CStr::from_ptr(name).to_string_lossy().into_owned())
+                }
+            }
+        }
+    })
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(index_oid: pg_sys::Oid) -> *const ::std::os::raw::c_char {
+    match call(0, index_oid).inner {
+        Some(name) => PgMemoryContexts::CurrentMemoryContext.pstrdup(&name),
+        None => std::ptr::null(),
+    }
+}
@@ -0,0 +1,77 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `ExplainOneQuery_hook`, which lets plugins get control of planning and explaining
+//! a query for `EXPLAIN`
+//!
+//! Calling `next` at the end of the chain plans and explains the query as usual.
+
+use super::*;
+
+hook_chain!(
+    pg_sys::ExplainOneQuery_hook_type,
+    fn(
+        query: PgBox<pg_sys::Query>,
+        cursor_options: i32,
+        into: PgBox<pg_sys::IntoClause>,
+        es: &mut ExplainStateWrapper,
+        query_string: &CStr,
+        params: PgBox<pg_sys::ParamListInfoData>,
+        query_env: PgBox<pg_sys::QueryEnvironment>,
+    ) -> ()
+);
+
+unsafe fn install() -> pg_sys::ExplainOneQuery_hook_type {
+    pg_sys::ExplainOneQuery_hook.replace(hook).or(Some(pgx_standard_explain_one_query_wrapper))
+}
+
+fn prev(
+    query: PgBox<pg_sys::Query>,
+    cursor_options: i32,
+    into: PgBox<pg_sys::IntoClause>,
+    es: &mut ExplainStateWrapper,
+    query_string: &CStr,
+    params: PgBox<pg_sys::ParamListInfoData>,
+    query_env: PgBox<pg_sys::QueryEnvironment>,
+) -> HookResult<()> {
+    unsafe {
+        (CHAIN.prev().unwrap())(
+            query.into_pg(),
+            cursor_options,
+            into.into_pg(),
+            es.as_ptr(),
+            query_string.as_ptr(),
+            params.into_pg(),
+            query_env.into_pg(),
+        )
+    }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(
+    query: *mut pg_sys::Query,
+    cursor_options: i32,
+    into: *mut pg_sys::IntoClause,
+    es: *mut pg_sys::ExplainState,
+    query_string: *const ::std::os::raw::c_char,
+    params: pg_sys::ParamListInfo,
+    query_env: *mut pg_sys::QueryEnvironment,
+) {
+    call(
+        0,
+        PgBox::from_pg(query),
+        cursor_options,
+        PgBox::from_pg(into),
+        &mut ExplainStateWrapper::from_pg(es),
+        CStr::from_ptr(query_string),
+        PgBox::from_pg(params),
+        PgBox::from_pg(query_env),
+    );
+}
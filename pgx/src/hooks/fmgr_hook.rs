@@ -0,0 +1,52 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `fmgr_hook`, called around calls to the functions chosen by
+//! [`super::needs_fmgr_hook`], as well as to `SECURITY DEFINER` functions and functions with `SET`
+//! clauses
+//!
+//! Raise an error at [`FmgrHookEvent::Start`] to refuse the call, in which case no handler is
+//! called again for it.  The [`FmgrHookState`] is kept for the length of the call, and shared by
+//! every handler.
+
+use super::*;
+
+hook_chain!(
+    pg_sys::fmgr_hook_type,
+    fn(event: FmgrHookEvent, flinfo: PgBox<pg_sys::FmgrInfo>, state: &mut FmgrHookState) -> ()
+);
+
+unsafe fn install() -> pg_sys::fmgr_hook_type {
+    pg_sys::fmgr_hook.replace(hook)
+}
+
+fn prev(
+    event: FmgrHookEvent,
+    flinfo: PgBox<pg_sys::FmgrInfo>,
+    state: &mut FmgrHookState,
+) -> HookResult<()> {
+    unsafe {
+        if let Some(f) = CHAIN.prev() {
+            f(event.into_pg(), flinfo.as_ptr(), &mut state.prev_private);
+        }
+    }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(
+    event: pg_sys::FmgrHookEventType,
+    flinfo: *mut pg_sys::FmgrInfo,
+    private: *mut pg_sys::Datum,
+) {
+    let event = FmgrHookEvent::from_pg(event);
+    FmgrHookState::with_pg(event, private, |state| {
+        call(0, event, PgBox::from_pg(flinfo), state);
+    })
+}
@@ -0,0 +1,51 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `get_relation_info_hook`, which lets plugins adjust what the planner knows about a
+//! relation after Postgres has read it from the catalogs
+
+use super::*;
+
+hook_chain!(
+    pg_sys::get_relation_info_hook_type,
+    fn(
+        root: PgBox<pg_sys::PlannerInfo>,
+        relation_oid: pg_sys::Oid,
+        inhparent: bool,
+        rel: PgBox<pg_sys::RelOptInfo>,
+    ) -> ()
+);
+
+unsafe fn install() -> pg_sys::get_relation_info_hook_type {
+    pg_sys::get_relation_info_hook.replace(hook)
+}
+
+fn prev(
+    root: PgBox<pg_sys::PlannerInfo>,
+    relation_oid: pg_sys::Oid,
+    inhparent: bool,
+    rel: PgBox<pg_sys::RelOptInfo>,
+) -> HookResult<()> {
+    unsafe {
+        if let Some(f) = CHAIN.prev() {
+            f(root.as_ptr(), relation_oid, inhparent, rel.as_ptr());
+        }
+    }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(
+    root: *mut pg_sys::PlannerInfo,
+    relation_oid: pg_sys::Oid,
+    inhparent: bool,
+    rel: *mut pg_sys::RelOptInfo,
+) {
+    call(0, PgBox::from_pg(root), relation_oid, inhparent, PgBox::from_pg(rel));
+}
@@ -0,0 +1,37 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `needs_fmgr_hook`, which choose the functions, by OID, that [`super::fmgr_hook`]
+//! is called for
+//!
+//! Functions that need it are called through the same path as `SECURITY DEFINER` functions, which
+//! is slower and keeps them from being inlined, so only return `true` for the functions of
+//! interest.
+
+use super::*;
+
+hook_chain!(pg_sys::needs_fmgr_hook_type, fn(fn_oid: pg_sys::Oid) -> bool);
+
+unsafe fn install() -> pg_sys::needs_fmgr_hook_type {
+    pg_sys::needs_fmgr_hook.replace(hook)
+}
+
+fn prev(fn_oid: pg_sys::Oid) -> HookResult<bool> {
+    HookResult::new(unsafe {
+        match CHAIN.prev() {
+            None => false,
+            Some(f) => f(fn_oid),
+        }
+    })
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(fn_oid: pg_sys::Oid) -> bool {
+    call(0, fn_oid).inner
+}
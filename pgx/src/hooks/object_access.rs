@@ -0,0 +1,59 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `object_access_hook`, called when a database object identified by its OID is
+//! created, altered, dropped, searched, or executed
+//!
+//! Events that [`ObjectAccessEvent`] can't describe skip the handlers and go straight to whatever
+//! hook was installed before them.
+
+use super::*;
+
+hook_chain!(
+    pg_sys::object_access_hook_type,
+    fn(event: ObjectAccessEvent, class_id: pg_sys::Oid, object_id: pg_sys::Oid, sub_id: i32) -> ()
+);
+
+unsafe fn install() -> pg_sys::object_access_hook_type {
+    pg_sys::object_access_hook.replace(hook)
+}
+
+fn prev(
+    event: ObjectAccessEvent,
+    class_id: pg_sys::Oid,
+    object_id: pg_sys::Oid,
+    sub_id: i32,
+) -> HookResult<()> {
+    unsafe {
+        if let Some(f) = CHAIN.prev() {
+            event.with_pg(|access, arg| f(access, class_id, object_id, sub_id, arg));
+        }
+    }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(
+    access: pg_sys::ObjectAccessType,
+    class_id: pg_sys::Oid,
+    object_id: pg_sys::Oid,
+    sub_id: i32,
+    arg: void_mut_ptr,
+) {
+    match ObjectAccessEvent::from_pg(access, arg) {
+        Some(event) => {
+            call(0, event, class_id, object_id, sub_id);
+        }
+        None => {
+            if let Some(f) = CHAIN.prev() {
+                f(access, class_id, object_id, sub_id, arg)
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `object_access_hook_str`, called like [`super::object_access`] for objects
+//! identified by name rather than by OID, such as the configuration parameters changed by `SET`
+//! and `ALTER SYSTEM`
+//!
+//! Only available on Postgres 15 and later.
+
+use super::*;
+
+hook_chain!(
+    pg_sys::object_access_hook_type_str,
+    fn(event: ObjectAccessEvent, class_id: pg_sys::Oid, object_name: &str, sub_id: i32) -> ()
+);
+
+unsafe fn install() -> pg_sys::object_access_hook_type_str {
+    pg_sys::object_access_hook_str.replace(hook)
+}
+
+fn prev(
+    event: ObjectAccessEvent,
+    class_id: pg_sys::Oid,
+    object_name: &str,
+    sub_id: i32,
+) -> HookResult<()> {
+    unsafe {
+        if let Some(f) = CHAIN.prev() {
+            let object_str = CString::new(object_name).expect("object name contained a NUL byte");
+            event.with_pg(|access, arg| f(access, class_id, object_str.as_ptr(), sub_id, arg));
+        }
+    }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(
+    access: pg_sys::ObjectAccessType,
+    class_id: pg_sys::Oid,
+    object_str: *const ::std::os::raw::c_char,
+    sub_id: i32,
+    arg: void_mut_ptr,
+) {
+    match ObjectAccessEvent::from_pg(access, arg) {
+        Some(event) => {
+            let object_name =
+                CStr::from_ptr(object_str).to_str().expect("object name was not valid UTF-8");
+            call(0, event, class_id, object_name, sub_id);
+        }
+        None => {
+            if let Some(f) = CHAIN.prev() {
+                f(access, class_id, object_str, sub_id, arg)
+            }
+        }
+    }
+}
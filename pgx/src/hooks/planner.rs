@@ -0,0 +1,74 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `planner_hook`, called by `planner()`
+//!
+//! `query_string` is only passed by Postgres 13 and later, and is null before that.
+
+use super::*;
+
+hook_chain!(
+    pg_sys::planner_hook_type,
+    fn(
+        parse: PgBox<pg_sys::Query>,
+        query_string: *const std::os::raw::c_char,
+        cursor_options: i32,
+        bound_params: PgBox<pg_sys::ParamListInfoData>,
+    ) -> *mut pg_sys::PlannedStmt
+);
+
+unsafe fn install() -> pg_sys::planner_hook_type {
+    pg_sys::planner_hook.replace(hook).or(Some(pgx_standard_planner_wrapper))
+}
+
+fn prev(
+    parse: PgBox<pg_sys::Query>,
+    #[allow(unused_variables)] query_string: *const std::os::raw::c_char,
+    cursor_options: i32,
+    bound_params: PgBox<pg_sys::ParamListInfoData>,
+) -> HookResult<*mut pg_sys::PlannedStmt> {
+    HookResult::new(unsafe {
+        #[cfg(any(feature = "pg11", feature = "pg12"))]
+        {
+            (CHAIN.prev().unwrap())(parse.into_pg(), cursor_options, bound_params.into_pg())
+        }
+
+        #[cfg(any(feature = "pg13", feature = "pg14", feature = "pg15"))]
+        {
+            (CHAIN.prev().unwrap())(
+                parse.into_pg(),
+                query_string,
+                cursor_options,
+                bound_params.into_pg(),
+            )
+        }
+    })
+}
+
+#[cfg(any(feature = "pg11", feature = "pg12"))]
+#[pg_guard]
+unsafe extern "C" fn hook(
+    parse: *mut pg_sys::Query,
+    cursor_options: i32,
+    bound_params: pg_sys::ParamListInfo,
+) -> *mut pg_sys::PlannedStmt {
+    call(0, PgBox::from_pg(parse), std::ptr::null(), cursor_options, PgBox::from_pg(bound_params))
+        .inner
+}
+
+#[cfg(any(feature = "pg13", feature = "pg14", feature = "pg15"))]
+#[pg_guard]
+unsafe extern "C" fn hook(
+    parse: *mut pg_sys::Query,
+    query_string: *const ::std::os::raw::c_char,
+    cursor_options: i32,
+    bound_params: pg_sys::ParamListInfo,
+) -> *mut pg_sys::PlannedStmt {
+    call(0, PgBox::from_pg(parse), query_string, cursor_options, PgBox::from_pg(bound_params)).inner
+}
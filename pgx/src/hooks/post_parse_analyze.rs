@@ -0,0 +1,60 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `post_parse_analyze_hook`, called once a statement has been parsed and analyzed
+//!
+//! `jumble_state` is only passed by Postgres 14 and later, and is `None` before that.
+
+use super::*;
+
+hook_chain!(
+    pg_sys::post_parse_analyze_hook_type,
+    fn(
+        pstate: PgBox<pg_sys::ParseState>,
+        query: PgBox<pg_sys::Query>,
+        jumble_state: Option<PgBox<JumbleState>>,
+    ) -> ()
+);
+
+unsafe fn install() -> pg_sys::post_parse_analyze_hook_type {
+    pg_sys::post_parse_analyze_hook.replace(hook)
+}
+
+fn prev(
+    pstate: PgBox<pg_sys::ParseState>,
+    query: PgBox<pg_sys::Query>,
+    #[allow(unused_variables)] jumble_state: Option<PgBox<JumbleState>>,
+) -> HookResult<()> {
+    unsafe {
+        if let Some(f) = CHAIN.prev() {
+            #[cfg(any(feature = "pg11", feature = "pg12", feature = "pg13"))]
+            f(pstate.as_ptr(), query.as_ptr());
+
+            #[cfg(any(feature = "pg14", feature = "pg15"))]
+            f(pstate.as_ptr(), query.as_ptr(), jumble_state.unwrap().as_ptr());
+        }
+    }
+    HookResult::new(())
+}
+
+#[cfg(any(feature = "pg11", feature = "pg12", feature = "pg13"))]
+#[pg_guard]
+unsafe extern "C" fn hook(pstate: *mut pg_sys::ParseState, query: *mut pg_sys::Query) {
+    call(0, PgBox::from_pg(pstate), PgBox::from_pg(query), None);
+}
+
+#[cfg(any(feature = "pg14", feature = "pg15"))]
+#[pg_guard]
+unsafe extern "C" fn hook(
+    pstate: *mut pg_sys::ParseState,
+    query: *mut pg_sys::Query,
+    jumble_state: *mut JumbleState,
+) {
+    call(0, PgBox::from_pg(pstate), PgBox::from_pg(query), Some(PgBox::from_pg(jumble_state)));
+}
@@ -0,0 +1,119 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `ProcessUtility_hook`, called by `ProcessUtility()`
+//!
+//! `read_only_tree` is only passed by Postgres 14 and later, and is `None` before that.
+
+use super::*;
+
+hook_chain!(
+    pg_sys::ProcessUtility_hook_type,
+    fn(
+        pstmt: PgBox<pg_sys::PlannedStmt>,
+        query_string: &CStr,
+        read_only_tree: Option<bool>,
+        context: pg_sys::ProcessUtilityContext,
+        params: PgBox<pg_sys::ParamListInfoData>,
+        query_env: PgBox<pg_sys::QueryEnvironment>,
+        dest: PgBox<pg_sys::DestReceiver>,
+        completion_tag: *mut pg_sys::QueryCompletion,
+    ) -> ()
+);
+
+unsafe fn install() -> pg_sys::ProcessUtility_hook_type {
+    pg_sys::ProcessUtility_hook.replace(hook).or(Some(pgx_standard_process_utility_wrapper))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn prev(
+    pstmt: PgBox<pg_sys::PlannedStmt>,
+    query_string: &CStr,
+    #[allow(unused_variables)] read_only_tree: Option<bool>,
+    context: pg_sys::ProcessUtilityContext,
+    params: PgBox<pg_sys::ParamListInfoData>,
+    query_env: PgBox<pg_sys::QueryEnvironment>,
+    dest: PgBox<pg_sys::DestReceiver>,
+    completion_tag: *mut pg_sys::QueryCompletion,
+) -> HookResult<()> {
+    unsafe {
+        #[cfg(any(feature = "pg11", feature = "pg12", feature = "pg13"))]
+        (CHAIN.prev().unwrap())(
+            pstmt.into_pg(),
+            query_string.as_ptr(),
+            context,
+            params.into_pg(),
+            query_env.into_pg(),
+            dest.into_pg(),
+            completion_tag,
+        );
+
+        #[cfg(any(feature = "pg14", feature = "pg15"))]
+        (CHAIN.prev().unwrap())(
+            pstmt.into_pg(),
+            query_string.as_ptr(),
+            read_only_tree.unwrap(),
+            context,
+            params.into_pg(),
+            query_env.into_pg(),
+            dest.into_pg(),
+            completion_tag,
+        );
+    }
+    HookResult::new(())
+}
+
+#[cfg(any(feature = "pg11", feature = "pg12", feature = "pg13"))]
+#[pg_guard]
+unsafe extern "C" fn hook(
+    pstmt: *mut pg_sys::PlannedStmt,
+    query_string: *const ::std::os::raw::c_char,
+    context: pg_sys::ProcessUtilityContext,
+    params: pg_sys::ParamListInfo,
+    query_env: *mut pg_sys::QueryEnvironment,
+    dest: *mut pg_sys::DestReceiver,
+    completion_tag: *mut pg_sys::QueryCompletion,
+) {
+    call(
+        0,
+        PgBox::from_pg(pstmt),
+        CStr::from_ptr(query_string),
+        None,
+        context,
+        PgBox::from_pg(params),
+        PgBox::from_pg(query_env),
+        PgBox::from_pg(dest),
+        completion_tag,
+    );
+}
+
+#[cfg(any(feature = "pg14", feature = "pg15"))]
+#[pg_guard]
+unsafe extern "C" fn hook(
+    pstmt: *mut pg_sys::PlannedStmt,
+    query_string: *const ::std::os::raw::c_char,
+    read_only_tree: bool,
+    context: pg_sys::ProcessUtilityContext,
+    params: pg_sys::ParamListInfo,
+    query_env: *mut pg_sys::QueryEnvironment,
+    dest: *mut pg_sys::DestReceiver,
+    completion_tag: *mut pg_sys::QueryCompletion,
+) {
+    call(
+        0,
+        PgBox::from_pg(pstmt),
+        CStr::from_ptr(query_string),
+        Some(read_only_tree),
+        context,
+        PgBox::from_pg(params),
+        PgBox::from_pg(query_env),
+        PgBox::from_pg(dest),
+        completion_tag,
+    );
+}
@@ -0,0 +1,72 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `set_join_pathlist_hook`, which lets plugins add their own paths for joining two
+//! relations
+
+use super::*;
+
+hook_chain!(
+    pg_sys::set_join_pathlist_hook_type,
+    fn(
+        root: PgBox<pg_sys::PlannerInfo>,
+        joinrel: PgBox<pg_sys::RelOptInfo>,
+        outerrel: PgBox<pg_sys::RelOptInfo>,
+        innerrel: PgBox<pg_sys::RelOptInfo>,
+        jointype: pg_sys::JoinType,
+        extra: PgBox<pg_sys::JoinPathExtraData>,
+    ) -> ()
+);
+
+unsafe fn install() -> pg_sys::set_join_pathlist_hook_type {
+    pg_sys::set_join_pathlist_hook.replace(hook)
+}
+
+fn prev(
+    root: PgBox<pg_sys::PlannerInfo>,
+    joinrel: PgBox<pg_sys::RelOptInfo>,
+    outerrel: PgBox<pg_sys::RelOptInfo>,
+    innerrel: PgBox<pg_sys::RelOptInfo>,
+    jointype: pg_sys::JoinType,
+    extra: PgBox<pg_sys::JoinPathExtraData>,
+) -> HookResult<()> {
+    unsafe {
+        if let Some(f) = CHAIN.prev() {
+            f(
+                root.as_ptr(),
+                joinrel.as_ptr(),
+                outerrel.as_ptr(),
+                innerrel.as_ptr(),
+                jointype,
+                extra.as_ptr(),
+            );
+        }
+    }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(
+    root: *mut pg_sys::PlannerInfo,
+    joinrel: *mut pg_sys::RelOptInfo,
+    outerrel: *mut pg_sys::RelOptInfo,
+    innerrel: *mut pg_sys::RelOptInfo,
+    jointype: pg_sys::JoinType,
+    extra: *mut pg_sys::JoinPathExtraData,
+) {
+    call(
+        0,
+        PgBox::from_pg(root),
+        PgBox::from_pg(joinrel),
+        PgBox::from_pg(outerrel),
+        PgBox::from_pg(innerrel),
+        jointype,
+        PgBox::from_pg(extra),
+    );
+}
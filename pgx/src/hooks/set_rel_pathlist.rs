@@ -0,0 +1,51 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Handlers for `set_rel_pathlist_hook`, which lets plugins add their own paths, such as custom
+//! scans, for scanning a base relation
+
+use super::*;
+
+hook_chain!(
+    pg_sys::set_rel_pathlist_hook_type,
+    fn(
+        root: PgBox<pg_sys::PlannerInfo>,
+        rel: PgBox<pg_sys::RelOptInfo>,
+        rti: pg_sys::Index,
+        rte: PgBox<pg_sys::RangeTblEntry>,
+    ) -> ()
+);
+
+unsafe fn install() -> pg_sys::set_rel_pathlist_hook_type {
+    pg_sys::set_rel_pathlist_hook.replace(hook)
+}
+
+fn prev(
+    root: PgBox<pg_sys::PlannerInfo>,
+    rel: PgBox<pg_sys::RelOptInfo>,
+    rti: pg_sys::Index,
+    rte: PgBox<pg_sys::RangeTblEntry>,
+) -> HookResult<()> {
+    unsafe {
+        if let Some(f) = CHAIN.prev() {
+            f(root.as_ptr(), rel.as_ptr(), rti, rte.as_ptr());
+        }
+    }
+    HookResult::new(())
+}
+
+#[pg_guard]
+unsafe extern "C" fn hook(
+    root: *mut pg_sys::PlannerInfo,
+    rel: *mut pg_sys::RelOptInfo,
+    rti: pg_sys::Index,
+    rte: *mut pg_sys::RangeTblEntry,
+) {
+    call(0, PgBox::from_pg(root), PgBox::from_pg(rel), rti, PgBox::from_pg(rte));
+}
@@ -1,9 +1,12 @@
 use pgx_sql_entity_graph::metadata::{
     ArgumentError, Returns, ReturnsError, SqlMapping, SqlTranslatable,
 };
+use std::ffi::CString;
 use std::iter::once;
 
-use crate::{pg_sys, IntoDatum};
+use crate::htup::heap_tuple_get_datum;
+use crate::spi::{in_subtransaction, SpiCursor, SpiCursorRows, SpiHeapTupleData};
+use crate::{error, pg_sys, IntoDatum, PgMemoryContexts, PgTupleDesc};
 
 pub struct SetOfIterator<'a, T> {
     iter: Box<dyn Iterator<Item = T> + 'a>,
@@ -16,6 +19,34 @@ impl<'a, T> SetOfIterator<'a, T> {
     {
         Self { iter: Box::new(iter.into_iter()) }
     }
+
+    /// Stream the rows of an [`SpiCursor`], converting each one to a `T` with `map`
+    ///
+    /// Rows are fetched lazily, `batch_size` at a time, as Postgres asks for them.  The cursor
+    /// outlives the [`SpiClient`](crate::spi::SpiClient) that opened it and is closed once it has
+    /// been exhausted or the set-returning function is finished.
+    ///
+    /// ```rust,no_run
+    /// use pgx::prelude::*;
+    ///
+    /// #[pg_extern]
+    /// fn all_names() -> SetOfIterator<'static, String> {
+    ///     Spi::connect(|client| {
+    ///         let cursor = client.open_cursor("SELECT name FROM people", ());
+    ///         Ok(Some(SetOfIterator::from_cursor(cursor, 100, |row| {
+    ///             row["name"].value::<String>().expect("name was NULL")
+    ///         })))
+    ///     })
+    ///     .unwrap()
+    /// }
+    /// ```
+    pub fn from_cursor<F>(cursor: SpiCursor<'_>, batch_size: i64, map: F) -> Self
+    where
+        T: 'a,
+        F: FnMut(&SpiHeapTupleData) -> T + 'a,
+    {
+        Self::new(SpiCursorRows::new(cursor, batch_size, map))
+    }
 }
 
 impl<'a, T> Iterator for SetOfIterator<'a, T> {
@@ -55,6 +86,17 @@ impl<'a, T> TableIterator<'a, T> {
         Self { iter: Box::new(iter) }
     }
 
+    /// Stream the rows of an [`SpiCursor`], converting each one to a `T` with `map`
+    ///
+    /// See [`SetOfIterator::from_cursor`].
+    pub fn from_cursor<F>(cursor: SpiCursor<'_>, batch_size: i64, map: F) -> Self
+    where
+        T: 'a,
+        F: FnMut(&SpiHeapTupleData) -> T + 'a,
+    {
+        Self::new(SpiCursorRows::new(cursor, batch_size, map))
+    }
+
     pub fn once(value: T) -> TableIterator<'a, T>
     where
         T: 'a,
@@ -71,16 +113,98 @@ impl<'a, T> Iterator for TableIterator<'a, T> {
     }
 }
 
-impl<'a, T> IntoDatum for TableIterator<'a, T>
-where
-    T: SqlTranslatable,
-{
-    fn into_datum(self) -> Option<pg_sys::Datum> {
-        todo!()
+/// Build the anonymous record type of a [`TableIterator`]'s rows, blessed so its values can be
+/// read back by the rest of this backend
+///
+/// Each column's type is its [`IntoDatum::type_oid`].  The SQL declared by its [`SqlTranslatable`]
+/// impl only supplies a typmod, such as a `numeric`'s precision and scale, when it names that same
+/// type, or the type when `type_oid` doesn't know it.
+fn table_row_tupdesc(columns: &[SqlMapping], type_oids: &[pg_sys::Oid]) -> PgTupleDesc<'static> {
+    unsafe {
+        #[cfg(feature = "pg11")]
+        let tupdesc = pg_sys::CreateTemplateTupleDesc(type_oids.len() as i32, false);
+
+        #[cfg(any(feature = "pg12", feature = "pg13", feature = "pg14", feature = "pg15"))]
+        let tupdesc = pg_sys::CreateTemplateTupleDesc(type_oids.len() as i32);
+
+        for (idx, type_oid) in type_oids.iter().enumerate() {
+            let parsed = match columns.get(idx) {
+                Some(SqlMapping::As(sql)) => parse_type_string(sql),
+                _ => None,
+            };
+            let (typid, typmod) = match parsed {
+                Some((typid, typmod)) if *type_oid == pg_sys::InvalidOid || typid == *type_oid => {
+                    (typid, typmod)
+                }
+                _ if *type_oid == pg_sys::InvalidOid => match columns.get(idx) {
+                    Some(SqlMapping::As(sql)) => {
+                        error!("TableIterator column f{} is of unknown type `{}`", idx + 1, sql)
+                    }
+                    _ => error!("TableIterator column f{} is of unknown type", idx + 1),
+                },
+                _ => (*type_oid, -1),
+            };
+
+            // the same column names Postgres gives a `ROW(...)`
+            let name = CString::new(format!("f{}", idx + 1)).unwrap();
+            pg_sys::TupleDescInitEntry(
+                tupdesc,
+                (idx + 1) as pg_sys::AttrNumber,
+                name.as_ptr(),
+                typid,
+                typmod,
+                0,
+            );
+        }
+
+        PgTupleDesc::from_pg_is_copy(pg_sys::BlessTupleDesc(tupdesc))
     }
+}
+
+/// The type and typmod `sql` names, or `None` if it isn't a type Postgres knows about
+///
+/// `parseTypeString()`'s `missing_ok` only covers unknown type names, so this runs it in a
+/// subtransaction to also recover from the ERROR it raises for something that isn't type syntax.
+fn parse_type_string(sql: &str) -> Option<(pg_sys::Oid, i32)> {
+    let sql = CString::new(sql).ok()?;
+    in_subtransaction(|| {
+        let mut typid = pg_sys::InvalidOid;
+        let mut typmod = -1;
+        unsafe { pg_sys::parseTypeString(sql.as_ptr(), &mut typid, &mut typmod, true) };
+        Ok((typid != pg_sys::InvalidOid).then_some((typid, typmod)))
+    })
+    .ok()
+    .flatten()
+}
 
-    fn type_oid() -> pg_sys::Oid {
-        todo!()
+/// Collect `rows` into a `record[]`, where each row is already a list of column values
+fn table_into_record_array<I, const N: usize>(
+    tupdesc: &PgTupleDesc,
+    rows: I,
+) -> Option<pg_sys::Datum>
+where
+    I: Iterator<Item = [Option<pg_sys::Datum>; N]>,
+{
+    unsafe {
+        let mut state = pg_sys::initArrayResult(
+            pg_sys::RECORDOID,
+            PgMemoryContexts::CurrentMemoryContext.value(),
+            false,
+        );
+        for row in rows {
+            let mut datums = row.map(|datum| datum.unwrap_or(pg_sys::Datum::from(0)));
+            let mut nulls = row.map(|datum| datum.is_none());
+            let tuple =
+                pg_sys::heap_form_tuple(tupdesc.as_ptr(), datums.as_mut_ptr(), nulls.as_mut_ptr());
+            state = pg_sys::accumArrayResult(
+                state,
+                heap_tuple_get_datum(tuple),
+                false,
+                pg_sys::RECORDOID,
+                PgMemoryContexts::CurrentMemoryContext.value(),
+            );
+        }
+        Some(pg_sys::makeArrayResult(state, PgMemoryContexts::CurrentMemoryContext.value()))
     }
 }
 
@@ -97,16 +221,38 @@ seq_macro::seq!(I in 0..=32 {
                     Err(ArgumentError::Table)
                 }
                 fn return_sql() -> Result<Returns, ReturnsError> {
-                    let mut vec = Vec::new();
-                    #(
-                        vec.push(match Input~N::return_sql() {
-                            Ok(Returns::One(sql)) => sql,
-                            Ok(Returns::SetOf(_)) => return Err(ReturnsError::TableContainingSetOf),
-                            Ok(Returns::Table(_)) => return Err(ReturnsError::NestedTable),
-                            Err(err) => return Err(err),
-                        });
-                    )*
-                    Ok(Returns::Table(vec))
+                    Ok(Returns::Table(vec![
+                        #(
+                            match Input~N::return_sql() {
+                                Ok(Returns::One(sql)) => sql,
+                                Ok(Returns::SetOf(_)) => return Err(ReturnsError::TableContainingSetOf),
+                                Ok(Returns::Table(_)) => return Err(ReturnsError::NestedTable),
+                                Err(err) => return Err(err),
+                            },
+                        )*
+                    ]))
+                }
+            }
+
+            /// A `TableIterator` is converted to an array of anonymous records (`record[]`), one
+            /// per row, whose columns are named `f1`, `f2`, ...
+            impl<'a, #(Input~N,)*> IntoDatum for TableIterator<'a, (#(Input~N,)*)>
+            where
+                #(
+                    Input~N: IntoDatum + SqlTranslatable + 'static,
+                )*
+            {
+                fn into_datum(self) -> Option<pg_sys::Datum> {
+                    let columns = match Self::return_sql() {
+                        Ok(Returns::Table(columns)) => columns,
+                        _ => Vec::new(),
+                    };
+                    let tupdesc = table_row_tupdesc(&columns, &[#(Input~N::type_oid(),)*]);
+                    table_into_record_array(&tupdesc, self.map(|row| [#(row.N.into_datum(),)*]))
+                }
+
+                fn type_oid() -> pg_sys::Oid {
+                    pg_sys::RECORDARRAYOID
                 }
             }
         });
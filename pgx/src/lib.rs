@@ -41,6 +41,7 @@ pub mod atomics;
 pub mod bgworkers;
 pub mod callbacks;
 pub mod datum;
+pub mod dsm;
 pub mod enum_helper;
 pub mod fcinfo;
 pub mod ffi;
@@ -57,14 +58,18 @@ pub mod memcxt;
 pub mod misc;
 pub mod namespace;
 pub mod nodes;
+pub mod parallel;
 pub mod pgbox;
 pub mod rel;
 pub mod shmem;
+pub mod shmem_hash;
+pub mod shm_mq;
 pub mod spi;
 pub mod spinlock;
 pub mod stringinfo;
 pub mod trigger_support;
 pub mod tupdesc;
+pub mod tuplestore;
 pub mod varlena;
 pub mod wrappers;
 pub mod xid;
@@ -95,10 +100,12 @@ pub use nodes::*;
 pub use pgbox::*;
 pub use rel::*;
 pub use shmem::*;
+pub use shmem_hash::*;
 pub use spi::*;
 pub use stringinfo::*;
 pub use trigger_support::*;
 pub use tupdesc::*;
+pub use tuplestore::*;
 pub use varlena::*;
 pub use wrappers::*;
 pub use xid::*;
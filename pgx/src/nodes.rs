@@ -9,7 +9,10 @@ Use of this source code is governed by the MIT license that can be found in the
 
 //! Helper functions and such for Postgres' various query tree `Node`s
 
-use crate::pg_sys;
+use crate as pgx; // for #[pg_guard] support from within ourself
+use crate::pg_sys::PgTaggedNode;
+use crate::prelude::*;
+use crate::{void_mut_ptr, WhoAllocated};
 
 /// #define IsA(nodeptr,_type_)            (nodeTag(nodeptr) == T_##_type_)
 #[inline]
@@ -38,3 +41,201 @@ pub unsafe fn node_to_string<'a>(nodeptr: *mut pg_sys::Node) -> Option<&'a str>
         }
     }
 }
+
+/// Convert a [pg_sys::Node] tree into JSON
+///
+/// Every `pg_sys` node type implements [`serde::Serialize`], and a node serializes as an object
+/// whose `"node"` member names its type.  Pointers to other nodes are followed, so this dumps
+/// the whole tree, such as a `pg_sys::Query` or `pg_sys::PlannedStmt`.  A null `nodeptr` is
+/// JSON `null`.
+///
+/// A pointer back to a node further up the tree, as the planner's `RelOptInfo`s and `Path`s have,
+/// is written as `{"node": "Ref", "up": n}` rather than followed, where `n` counts the levels up
+/// to that node from the one holding the pointer, starting at `0`.
+///
+/// Fields that can't be represented generically are left out: unions, such as Postgres 15's
+/// `A_Const.val`, as well as arrays, bitfields, and function pointers.  So are pointers to
+/// anything that isn't a node, a string, or a `List`.
+///
+/// ### Safety
+///
+/// We cannot guarantee the provided `nodeptr` is a valid pointer
+pub unsafe fn node_to_json(nodeptr: *mut pg_sys::Node) -> serde_json::Value {
+    serde_json::to_value(pg_sys::node_serde::NodePtr::new(nodeptr))
+        .expect("unable to convert Node into JSON")
+}
+
+/// Downcast `node` to the concrete node type `T`, if that's what it is
+///
+/// ### Safety
+///
+/// We cannot guarantee the provided `nodeptr` is a valid pointer
+pub unsafe fn downcast_node<T: PgTaggedNode>(nodeptr: *mut pg_sys::Node) -> Option<PgBox<T>> {
+    if is_a(nodeptr, T::NODE_TAG) {
+        Some(PgBox::from_pg(nodeptr as *mut T))
+    } else {
+        None
+    }
+}
+
+impl<AllocatedBy: WhoAllocated> PgBox<pg_sys::Node, AllocatedBy> {
+    /// Is this node of the concrete node type `T`?
+    pub fn is_a<T: PgTaggedNode>(&self) -> bool {
+        unsafe { is_a(self.as_ptr(), T::NODE_TAG) }
+    }
+
+    /// This node as the concrete node type `T`, if that's what it is
+    pub fn downcast<T: PgTaggedNode>(&self) -> Option<PgBox<T>> {
+        unsafe { downcast_node(self.as_ptr()) }
+    }
+}
+
+/// What a [`NodeVisitor`] wants done after visiting a node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visit {
+    /// Go on to visit the node's children
+    Continue,
+    /// Go on with the walk, but without visiting the node's children
+    SkipChildren,
+    /// End the walk
+    Stop,
+}
+
+/// Visits the nodes of a tree walked by [`walk_tree`] or [`walk_raw_tree`]
+pub trait NodeVisitor {
+    /// Called with each node in the tree, which is never null, before any of its children
+    fn visit(&mut self, node: PgBox<pg_sys::Node>) -> Visit;
+
+    /// The `QTW_*` flags used to walk `Query` nodes, such as `pg_sys::QTW_EXAMINE_RTES_BEFORE` to
+    /// also visit range table entries
+    fn query_walker_flags(&self) -> u32 {
+        0
+    }
+}
+
+/// What a [`NodeMutator`] wants done with a node
+pub enum Mutate {
+    /// Copy the node, with its children mutated
+    Continue,
+    /// Put this node, which may be null, in the node's place, without visiting its children
+    Replace(*mut pg_sys::Node),
+}
+
+/// Mutates the nodes of a tree copied by [`mutate_tree`]
+pub trait NodeMutator {
+    /// Called with each node in the tree, which is never null, before any of its children
+    fn mutate(&mut self, node: PgBox<pg_sys::Node>) -> Mutate;
+
+    /// The `QTW_*` flags used to mutate `Query` nodes, such as `pg_sys::QTW_DONT_COPY_QUERY`
+    fn query_mutator_flags(&self) -> u32 {
+        0
+    }
+}
+
+struct WalkContext<'a> {
+    visitor: &'a mut dyn NodeVisitor,
+    raw: bool,
+}
+
+/// Walk the tree under `node`, which is an analyzed `Query` or an expression within one, calling
+/// `visitor` with each node, starting with `node` itself
+///
+/// This wraps Postgres' `query_tree_walker()` and `expression_tree_walker()`, and descends into
+/// sub-queries.  Returns `true` if the visitor stopped the walk.
+///
+/// ### Safety
+///
+/// We cannot guarantee the provided `node` is a valid pointer to an analyzed tree
+pub unsafe fn walk_tree(node: *mut pg_sys::Node, visitor: &mut impl NodeVisitor) -> bool {
+    let mut context = WalkContext { visitor, raw: false };
+    node_walker(node, &mut context as *mut WalkContext as void_mut_ptr)
+}
+
+/// Walk the raw parse tree under `node`, as returned by `pg_sys::pg_parse_query()`, calling
+/// `visitor` with each node, starting with `node` itself
+///
+/// This wraps Postgres' `raw_expression_tree_walker()`.  Returns `true` if the visitor stopped the
+/// walk.
+///
+/// ### Safety
+///
+/// We cannot guarantee the provided `node` is a valid pointer to a raw parse tree
+pub unsafe fn walk_raw_tree(node: *mut pg_sys::Node, visitor: &mut impl NodeVisitor) -> bool {
+    let mut context = WalkContext { visitor, raw: true };
+    node_walker(node, &mut context as *mut WalkContext as void_mut_ptr)
+}
+
+#[pg_guard]
+unsafe extern "C" fn node_walker(node: *mut pg_sys::Node, context: void_mut_ptr) -> bool {
+    if node.is_null() {
+        return false;
+    }
+
+    let walk = &mut *(context as *mut WalkContext);
+    match walk.visitor.visit(PgBox::from_pg(node)) {
+        Visit::Stop => true,
+        Visit::SkipChildren => false,
+        Visit::Continue if walk.raw => pg_sys::raw_expression_tree_walker(
+            node,
+            Some(std::mem::transmute(
+                node_walker as unsafe extern "C" fn(*mut pg_sys::Node, void_mut_ptr) -> bool,
+            )),
+            context,
+        ),
+        Visit::Continue if is_a(node, pg_sys::NodeTag_T_Query) => pg_sys::query_tree_walker(
+            node as *mut pg_sys::Query,
+            Some(node_walker),
+            context,
+            walk.visitor.query_walker_flags() as _,
+        ),
+        // query_tree_walker() walks a range table entry's children itself, once we return
+        Visit::Continue if is_a(node, pg_sys::NodeTag_T_RangeTblEntry) => false,
+        Visit::Continue => pg_sys::expression_tree_walker(node, Some(node_walker), context),
+    }
+}
+
+struct MutateContext<'a> {
+    mutator: &'a mut dyn NodeMutator,
+}
+
+/// Copy the tree under `node`, which is an analyzed `Query` or an expression within one, with
+/// each node passed through `mutator`, starting with `node` itself
+///
+/// This wraps Postgres' `query_tree_mutator()` and `expression_tree_mutator()`, and descends into
+/// sub-queries.  The copy is allocated in `CurrentMemoryContext`.
+///
+/// ### Safety
+///
+/// We cannot guarantee the provided `node` is a valid pointer to an analyzed tree
+pub unsafe fn mutate_tree(
+    node: *mut pg_sys::Node,
+    mutator: &mut impl NodeMutator,
+) -> *mut pg_sys::Node {
+    let mut context = MutateContext { mutator };
+    node_mutator(node, &mut context as *mut MutateContext as void_mut_ptr)
+}
+
+#[pg_guard]
+unsafe extern "C" fn node_mutator(
+    node: *mut pg_sys::Node,
+    context: void_mut_ptr,
+) -> *mut pg_sys::Node {
+    if node.is_null() {
+        return node;
+    }
+
+    let mutator = &mut *(*(context as *mut MutateContext)).mutator;
+    let mutator_fn = Some(std::mem::transmute(
+        node_mutator as unsafe extern "C" fn(*mut pg_sys::Node, void_mut_ptr) -> *mut pg_sys::Node,
+    ));
+    match mutator.mutate(PgBox::from_pg(node)) {
+        Mutate::Replace(replacement) => replacement,
+        Mutate::Continue if is_a(node, pg_sys::NodeTag_T_Query) => pg_sys::query_tree_mutator(
+            node as *mut pg_sys::Query,
+            mutator_fn,
+            context,
+            mutator.query_mutator_flags() as _,
+        ) as *mut pg_sys::Node,
+        Mutate::Continue => pg_sys::expression_tree_mutator(node, mutator_fn, context),
+    }
+}
@@ -0,0 +1,180 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Fanning work out to parallel workers, the way Postgres' own `CREATE INDEX` does
+//!
+//! A [`ParallelContext`] launches workers that each run a named function from an extension's
+//! library, giving every worker a copy of some state that can be serialized with `serde`.  The
+//! function must be `#[no_mangle] extern "C"` and take the dynamic shared memory segment and
+//! table of contents Postgres passes to parallel workers, which it then hands to
+//! [`ParallelWorker::new`] to get at that state.
+//!
+//! Workers run in parallel mode, so like a parallel query they can read the database but can't
+//! write to it.  Anything they need to report back to the launching backend has to go through
+//! shared memory, such as a [`DsmSegment`](crate::dsm::DsmSegment) or
+//! [`shm_mq`](crate::shm_mq) whose handle is part of the state.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use pgx::prelude::*;
+//! use pgx::parallel::{ParallelContext, ParallelWorker};
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Work {
+//!     index_oid: pg_sys::Oid,
+//! }
+//!
+//! let mut pcxt = ParallelContext::new("my_extension", "build_index_worker", 4, &Work { index_oid: 42 });
+//! let launched = pcxt.launch();
+//! // ... the launching backend can do its share of the work too ...
+//! pcxt.wait();
+//!
+//! #[pg_guard]
+//! #[no_mangle]
+//! pub extern "C" fn build_index_worker(seg: *mut pg_sys::dsm_segment, toc: *mut pg_sys::shm_toc) {
+//!     let worker = unsafe { ParallelWorker::new(seg, toc) };
+//!     let work = worker.state::<Work>();
+//!     // ... do this worker's share of the work, according to `worker.number()` ...
+//! }
+//! ```
+use crate::pg_sys;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::ffi::CString;
+
+/// The key of the workers' serialized state in the table of contents, which is clear of both
+/// Postgres' own keys (`0xFFFFFFFFFFFFxxxx`) and the small integers extensions tend to use
+const PGX_STATE_KEY: u64 = 0x5047_5800_0000_0001;
+
+/// A set of parallel workers, launched by this backend
+///
+/// The workers are waited for, and their shared memory released, by [`ParallelContext::wait`]
+/// or when the `ParallelContext` is dropped.  Either must happen before the transaction ends.
+pub struct ParallelContext {
+    pcxt: *mut pg_sys::ParallelContext,
+}
+
+impl ParallelContext {
+    /// Prepare to launch up to `nworkers` workers, which each run `function` from `library` and
+    /// can get a copy of `state` with [`ParallelWorker::state`]
+    ///
+    /// Postgres may launch fewer workers than asked for, or none at all, so the launching
+    /// backend must be prepared to do the work itself.
+    pub fn new<T: Serialize>(library: &str, function: &str, nworkers: i32, state: &T) -> Self {
+        let library = CString::new(library).expect("library name contains a null byte");
+        let function = CString::new(function).expect("function name contains a null byte");
+        let state = serde_cbor::to_vec(state).expect("failed to serialize parallel worker state");
+        let chunk_size = std::mem::size_of::<usize>() + state.len();
+
+        unsafe {
+            pg_sys::EnterParallelMode();
+
+            #[cfg(feature = "pg11")]
+            let pcxt =
+                pg_sys::CreateParallelContext(library.as_ptr(), function.as_ptr(), nworkers, false);
+            #[cfg(not(feature = "pg11"))]
+            let pcxt = pg_sys::CreateParallelContext(library.as_ptr(), function.as_ptr(), nworkers);
+
+            // the equivalent of `shm_toc_estimate_chunk()` and `shm_toc_estimate_keys()`
+            let align = pg_sys::ALIGNOF_BUFFER as usize;
+            (*pcxt).estimator.space_for_chunks += (chunk_size + align - 1) & !(align - 1);
+            (*pcxt).estimator.number_of_keys += 1;
+
+            pg_sys::InitializeParallelDSM(pcxt);
+
+            // the state is stored as its length followed by its bytes
+            let chunk = pg_sys::shm_toc_allocate((*pcxt).toc, chunk_size) as *mut u8;
+            (chunk as *mut usize).write_unaligned(state.len());
+            std::ptr::copy_nonoverlapping(
+                state.as_ptr(),
+                chunk.add(std::mem::size_of::<usize>()),
+                state.len(),
+            );
+            pg_sys::shm_toc_insert((*pcxt).toc, PGX_STATE_KEY, chunk.cast());
+
+            ParallelContext { pcxt }
+        }
+    }
+
+    /// Launch the workers, returning how many were actually launched
+    pub fn launch(&mut self) -> usize {
+        unsafe {
+            pg_sys::LaunchParallelWorkers(self.pcxt);
+            (*self.pcxt).nworkers_launched as usize
+        }
+    }
+
+    /// How many workers [`ParallelContext::launch`] launched
+    pub fn nworkers_launched(&self) -> usize {
+        unsafe { (*self.pcxt).nworkers_launched as usize }
+    }
+
+    /// Wait for every launched worker to finish
+    ///
+    /// If a worker raised an ERROR (including by panicking) it is raised again here, in the
+    /// launching backend.
+    pub fn wait(self) {
+        unsafe { pg_sys::WaitForParallelWorkersToFinish(self.pcxt) }
+    }
+}
+
+impl Drop for ParallelContext {
+    fn drop(&mut self) {
+        // if we're unwinding from an ERROR, Postgres cleans up the context when it aborts the
+        // transaction, and waiting for workers here could raise an ERROR of its own
+        if !std::thread::panicking() {
+            unsafe {
+                pg_sys::DestroyParallelContext(self.pcxt);
+                pg_sys::ExitParallelMode();
+            }
+        }
+    }
+}
+
+/// A parallel worker's view of the [`ParallelContext`] that launched it
+pub struct ParallelWorker {
+    seg: *mut pg_sys::dsm_segment,
+    toc: *mut pg_sys::shm_toc,
+}
+
+impl ParallelWorker {
+    /// # Safety
+    ///
+    /// `seg` and `toc` must be the arguments Postgres passed to the parallel worker's function.
+    pub unsafe fn new(seg: *mut pg_sys::dsm_segment, toc: *mut pg_sys::shm_toc) -> Self {
+        ParallelWorker { seg, toc }
+    }
+
+    /// Which of the launched workers this is, counting from zero
+    pub fn number(&self) -> usize {
+        unsafe { pg_sys::ParallelWorkerNumber as usize }
+    }
+
+    /// A copy of the state given to [`ParallelContext::new`]
+    pub fn state<T: DeserializeOwned>(&self) -> T {
+        unsafe {
+            let chunk = pg_sys::shm_toc_lookup(self.toc, PGX_STATE_KEY, false) as *const u8;
+            let len = (chunk as *const usize).read_unaligned();
+            let bytes = std::slice::from_raw_parts(chunk.add(std::mem::size_of::<usize>()), len);
+            serde_cbor::from_slice(bytes).expect("failed to deserialize parallel worker state")
+        }
+    }
+
+    /// The dynamic shared memory segment the worker's state is in
+    pub fn segment(&self) -> *mut pg_sys::dsm_segment {
+        self.seg
+    }
+
+    /// The table of contents the worker's state is in, which Postgres' own parallel code looks
+    /// things up in with `shm_toc_lookup()`
+    pub fn toc(&self) -> *mut pg_sys::shm_toc {
+        self.toc
+    }
+}
@@ -0,0 +1,342 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Typed message queues between processes, over Postgres' `shm_mq` in a [`DsmSegment`]
+//!
+//! One process creates the queue with [`Sender::create`] or [`Receiver::create`], and passes
+//! its [`DsmHandle`] to another process (usually a background worker it's launching) which
+//! attaches to the other end with [`Receiver::attach`] or [`Sender::attach`].  Messages are any
+//! type that can be serialized with `serde`.
+//!
+//! Blocking sends and receives wait on the process latch, so they are woken by the other end of
+//! the queue, by Postgres interrupts (such as a query being canceled), and by a background
+//! worker receiving SIGTERM.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use pgx::prelude::*;
+//! use pgx::bgworkers::BackgroundWorkerBuilder;
+//! use pgx::dsm::DsmHandle;
+//! use pgx::shm_mq::{Receiver, Sender};
+//! use pgx::{FromDatum, IntoDatum};
+//!
+//! let mut receiver = Receiver::<String>::create(64 * 1024);
+//! let worker = BackgroundWorkerBuilder::new("Example")
+//!     .set_function("background_worker_main")
+//!     .set_library("example")
+//!     .enable_shmem_access(None)
+//!     .set_argument(receiver.handle().into_datum())
+//!     .set_notify_pid(unsafe { pg_sys::MyProcPid })
+//!     .load_dynamic();
+//! // notice if the worker exits without ever attaching to the queue
+//! receiver.set_worker(&worker);
+//!
+//! // the iterator ends once the worker detaches from the queue
+//! let messages = receiver.iter().collect::<Vec<_>>();
+//! assert_eq!(messages, vec!["hello", "world"]);
+//!
+//! #[pg_guard]
+//! #[no_mangle]
+//! pub extern "C" fn background_worker_main(arg: pg_sys::Datum) {
+//!     let handle = unsafe { DsmHandle::from_datum(arg, false) }.unwrap();
+//!     let mut sender = Sender::<String>::attach(handle).expect("the queue is gone");
+//!     sender.send(&"hello".to_string()).unwrap();
+//!     sender.send(&"world".to_string()).unwrap();
+//! }
+//! ```
+use crate::bgworkers::{self, DynamicBackgroundWorker, WLflags};
+use crate::dsm::{DsmHandle, DsmSegment};
+use crate::{pg_sys, PgMemoryContexts};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::marker::PhantomData;
+
+/// Describes why a message could not be sent or received
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ShmMqError {
+    #[error("The other end of the queue has detached")]
+    Detached,
+    #[error("The queue is full, or empty")]
+    WouldBlock,
+    #[error("This background worker has received a SIGTERM")]
+    Terminated,
+    #[error("The message could not be (de)serialized: {0}")]
+    InvalidMessage(String),
+}
+
+/// How much of a message [`Sender::try_send`] was able to put in the queue
+#[must_use = "a partly sent message must be finished with `Sender::flush`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SendStatus {
+    /// The whole message is in the queue
+    Sent,
+    /// Only part of the message fit, or the receiver hasn't attached yet.  The [`Sender`] keeps
+    /// the rest, which [`Sender::flush`] finishes sending.
+    Partial,
+}
+
+/// One end of a `shm_mq`, and the segment it lives in
+struct Queue {
+    mqh: *mut pg_sys::shm_mq_handle,
+    // declared after `mqh`, so the segment outlives our attachment to the queue
+    segment: DsmSegment,
+}
+
+impl Queue {
+    fn create(size: usize, sender: bool) -> Self {
+        unsafe {
+            assert!(
+                size >= pg_sys::shm_mq_minimum_size,
+                "a shm_mq must be at least {} bytes",
+                pg_sys::shm_mq_minimum_size
+            );
+            let segment = DsmSegment::create(size);
+            let mq = pg_sys::shm_mq_create(segment.as_ptr().cast(), size);
+            Queue::attach_to(segment, mq, sender)
+        }
+    }
+
+    fn attach(handle: DsmHandle, sender: bool) -> Option<Self> {
+        let segment = DsmSegment::attach(handle)?;
+        let mq = segment.as_ptr().cast();
+        Some(unsafe { Queue::attach_to(segment, mq, sender) })
+    }
+
+    unsafe fn attach_to(segment: DsmSegment, mq: *mut pg_sys::shm_mq, sender: bool) -> Self {
+        if sender {
+            pg_sys::shm_mq_set_sender(mq, pg_sys::MyProc);
+        } else {
+            pg_sys::shm_mq_set_receiver(mq, pg_sys::MyProc);
+        }
+
+        // the handle, and the messages received through it, must outlive the current transaction
+        let seg = segment.seg;
+        let mqh = PgMemoryContexts::TopMemoryContext
+            .switch_to(|_| pg_sys::shm_mq_attach(mq, seg, std::ptr::null_mut()));
+        Queue { mqh, segment }
+    }
+
+    fn set_worker(&mut self, worker: &DynamicBackgroundWorker) {
+        unsafe { pg_sys::shm_mq_set_handle(self.mqh, worker.handle) }
+    }
+
+    /// Keep trying `op` until it doesn't need to wait for the other end of the queue
+    fn block_on(
+        &mut self,
+        mut op: impl FnMut(&mut Self) -> pg_sys::shm_mq_result,
+    ) -> Result<(), ShmMqError> {
+        loop {
+            match check(op(self)) {
+                Err(ShmMqError::WouldBlock) => {
+                    if bgworkers::sigterm_pending() {
+                        return Err(ShmMqError::Terminated);
+                    }
+                    let rc = bgworkers::wait_latch(
+                        0,
+                        WLflags::WL_LATCH_SET | WLflags::WL_POSTMASTER_DEATH,
+                    );
+                    if rc & WLflags::WL_POSTMASTER_DEATH.bits() != 0 {
+                        unsafe { pg_sys::proc_exit(1) }
+                    }
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn send_bytes(&mut self, bytes: &[u8]) -> pg_sys::shm_mq_result {
+        unsafe {
+            #[cfg(not(feature = "pg15"))]
+            let result = pg_sys::shm_mq_send(self.mqh, bytes.len(), bytes.as_ptr().cast(), true);
+            // flush each message as it's sent, rather than once a quarter of the queue is used
+            #[cfg(feature = "pg15")]
+            let result =
+                pg_sys::shm_mq_send(self.mqh, bytes.len(), bytes.as_ptr().cast(), true, true);
+            result
+        }
+    }
+
+    /// Receive a message without waiting, setting `message` to where it is and how long it is
+    fn receive_bytes(&mut self, message: &mut (*const u8, usize)) -> pg_sys::shm_mq_result {
+        let mut len = 0;
+        let mut data = std::ptr::null_mut();
+        let result = unsafe { pg_sys::shm_mq_receive(self.mqh, &mut len, &mut data, true) };
+        if result == pg_sys::shm_mq_result_SHM_MQ_SUCCESS {
+            *message = (data as *const u8, len);
+        }
+        result
+    }
+}
+
+impl Drop for Queue {
+    fn drop(&mut self) {
+        unsafe { pg_sys::shm_mq_detach(self.mqh) }
+    }
+}
+
+fn check(result: pg_sys::shm_mq_result) -> Result<(), ShmMqError> {
+    match result {
+        pg_sys::shm_mq_result_SHM_MQ_SUCCESS => Ok(()),
+        pg_sys::shm_mq_result_SHM_MQ_WOULD_BLOCK => Err(ShmMqError::WouldBlock),
+        _ => Err(ShmMqError::Detached),
+    }
+}
+
+/// The sending end of a message queue
+pub struct Sender<T> {
+    queue: Queue,
+    // a message `try_send` couldn't finish, which must be retried before anything else is sent
+    pending: Option<Vec<u8>>,
+    _marker: PhantomData<fn(&T)>,
+}
+
+impl<T> Sender<T> {
+    /// Create a queue of `size` bytes in a new [`DsmSegment`], whose other end can be attached
+    /// to with [`Receiver::attach`]
+    pub fn create(size: usize) -> Self {
+        Sender { queue: Queue::create(size, true), pending: None, _marker: PhantomData }
+    }
+
+    /// Attach to the queue created by a [`Receiver`], or `None` if it no longer exists
+    pub fn attach(handle: DsmHandle) -> Option<Self> {
+        Some(Sender { queue: Queue::attach(handle, true)?, pending: None, _marker: PhantomData })
+    }
+
+    /// The handle the receiving process can use to attach to this queue
+    pub fn handle(&self) -> DsmHandle {
+        self.queue.segment.handle()
+    }
+
+    /// Tell the queue which background worker will attach to its other end, so sending fails
+    /// with [`ShmMqError::Detached`] if that worker exits without attaching, rather than waiting
+    /// forever
+    pub fn set_worker(&mut self, worker: &DynamicBackgroundWorker) {
+        self.queue.set_worker(worker)
+    }
+}
+
+impl<T: Serialize> Sender<T> {
+    /// Send `msg`, waiting for the receiver to make room for it if the queue is full
+    pub fn send(&mut self, msg: &T) -> Result<(), ShmMqError> {
+        self.flush()?;
+        let bytes = encode(msg)?;
+        self.queue.block_on(|queue| queue.send_bytes(&bytes))
+    }
+
+    /// Send `msg` without waiting, or fail with [`ShmMqError::WouldBlock`] if a message from an
+    /// earlier call still can't be finished
+    ///
+    /// Once part of the message is in the queue the rest must follow it, so if the queue fills up
+    /// partway through, the remainder is kept and this returns [`SendStatus::Partial`].  It's
+    /// sent by the next call to [`Sender::send`], [`Sender::try_send`] or [`Sender::flush`], and
+    /// should the `Sender` be dropped before then, the message is lost.
+    pub fn try_send(&mut self, msg: &T) -> Result<SendStatus, ShmMqError> {
+        if let Some(pending) = &self.pending {
+            check(self.queue.send_bytes(pending))?;
+            self.pending = None;
+        }
+
+        let bytes = encode(msg)?;
+        match check(self.queue.send_bytes(&bytes)) {
+            Ok(()) => Ok(SendStatus::Sent),
+            Err(ShmMqError::WouldBlock) => {
+                self.pending = Some(bytes);
+                Ok(SendStatus::Partial)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Finish sending whatever part of a message [`Sender::try_send`] couldn't, waiting for the
+    /// receiver to make room for it if need be
+    pub fn flush(&mut self) -> Result<(), ShmMqError> {
+        match self.pending.take() {
+            Some(pending) => self.queue.block_on(|queue| queue.send_bytes(&pending)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        if let Some(pending) = self.pending.take() {
+            // one last try, without waiting, as the receiver may have made room by now
+            if check(self.queue.send_bytes(&pending)).is_err() && !std::thread::panicking() {
+                crate::warning!(
+                    "a message partly sent through a shm_mq was lost, as its Sender was dropped before being flushed"
+                );
+            }
+        }
+    }
+}
+
+/// The receiving end of a message queue
+pub struct Receiver<T> {
+    queue: Queue,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Receiver<T> {
+    /// Create a queue of `size` bytes in a new [`DsmSegment`], whose other end can be attached
+    /// to with [`Sender::attach`]
+    pub fn create(size: usize) -> Self {
+        Receiver { queue: Queue::create(size, false), _marker: PhantomData }
+    }
+
+    /// Attach to the queue created by a [`Sender`], or `None` if it no longer exists
+    pub fn attach(handle: DsmHandle) -> Option<Self> {
+        Some(Receiver { queue: Queue::attach(handle, false)?, _marker: PhantomData })
+    }
+
+    /// The handle the sending process can use to attach to this queue
+    pub fn handle(&self) -> DsmHandle {
+        self.queue.segment.handle()
+    }
+
+    /// Tell the queue which background worker will attach to its other end, so receiving fails
+    /// with [`ShmMqError::Detached`] if that worker exits without attaching, rather than waiting
+    /// forever
+    pub fn set_worker(&mut self, worker: &DynamicBackgroundWorker) {
+        self.queue.set_worker(worker)
+    }
+}
+
+impl<T: DeserializeOwned> Receiver<T> {
+    /// Receive the next message, waiting for one to be sent if the queue is empty
+    pub fn recv(&mut self) -> Result<T, ShmMqError> {
+        let mut message = (std::ptr::null(), 0);
+        self.queue.block_on(|queue| queue.receive_bytes(&mut message))?;
+        decode(message)
+    }
+
+    /// Receive the next message without waiting, or fail with [`ShmMqError::WouldBlock`] if
+    /// there isn't one yet
+    pub fn try_recv(&mut self) -> Result<T, ShmMqError> {
+        let mut message = (std::ptr::null(), 0);
+        check(self.queue.receive_bytes(&mut message))?;
+        decode(message)
+    }
+
+    /// Receive each message in turn until the sender detaches, or an error occurs
+    pub fn iter(&mut self) -> impl Iterator<Item = T> + '_ {
+        std::iter::from_fn(move || self.recv().ok())
+    }
+}
+
+fn encode<T: Serialize>(msg: &T) -> Result<Vec<u8>, ShmMqError> {
+    serde_cbor::to_vec(msg).map_err(|e| ShmMqError::InvalidMessage(e.to_string()))
+}
+
+/// Deserialize a message from where [`Queue::receive_bytes`] said it is
+fn decode<T: DeserializeOwned>((data, len): (*const u8, usize)) -> Result<T, ShmMqError> {
+    // SAFETY: the message stays put until the next time the queue is received from
+    let bytes = unsafe { std::slice::from_raw_parts(data, len) };
+    serde_cbor::from_slice(bytes).map_err(|e| ShmMqError::InvalidMessage(e.to_string()))
+}
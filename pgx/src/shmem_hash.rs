@@ -0,0 +1,354 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! A key/value map in Postgres Shared Memory, built on Postgres' own `ShmemInitHash()`
+use crate::{pg_sys, PGXSharedMemory, PgSharedMemoryInitialization};
+use once_cell::sync::OnceCell;
+use std::marker::PhantomData;
+use uuid::Uuid;
+
+/// The number of LWLocks a [`PgSharedHashMap`] splits its entries between, unless created with
+/// [`PgSharedHashMap::with_partitions`]
+pub const DEFAULT_NUM_PARTITIONS: usize = 16;
+
+/// Describes errors that can occur when changing a [`PgSharedHashMap`]
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgSharedHashMapError {
+    #[error("The shared hash map is full")]
+    HashMapFull,
+}
+
+/// A hash map in Postgres Shared Memory, visible to every backend and background worker
+///
+/// Like the other shared memory types, it must be a `static` global and be passed to
+/// `pg_shmem_init!()` during `_PG_init()`, which means the extension **must** be loaded via
+/// `postgresql.conf`'s `shared_preload_libraries` configuration setting.
+///
+/// Its capacity is fixed when it's created.  Entries are split between a number of partitions,
+/// each protected by its own LWLock, so that backends working on different keys rarely wait on
+/// each other.
+///
+/// Keys are hashed and compared by their bytes, so a key type must not contain padding or
+/// pointers.  Values are copied in and out of shared memory.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use pgx::prelude::*;
+/// use pgx::{pg_shmem_init, PgSharedHashMap, PgSharedMemoryInitialization};
+///
+/// // up to 1024 roles, and how many queries each has run
+/// static QUERY_COUNTS: PgSharedHashMap<pg_sys::Oid, u64> = PgSharedHashMap::new(1024);
+///
+/// #[pg_guard]
+/// pub extern "C" fn _PG_init() {
+///     pg_shmem_init!(QUERY_COUNTS);
+/// }
+///
+/// #[pg_extern]
+/// fn count_query(role: pg_sys::Oid) -> i64 {
+///     let count = QUERY_COUNTS.update(role, 0, |count| {
+///         *count += 1;
+///         *count
+///     });
+///     count.expect("too many roles") as i64
+/// }
+/// ```
+pub struct PgSharedHashMap<K, V> {
+    max_entries: usize,
+    num_partitions: usize,
+    name: OnceCell<&'static str>,
+    inner: OnceCell<PgSharedHashMapInner>,
+    _marker: PhantomData<(K, V)>,
+}
+
+unsafe impl<K: Send, V: Send> Send for PgSharedHashMap<K, V> {}
+unsafe impl<K: Send + Sync, V: Send + Sync> Sync for PgSharedHashMap<K, V> {}
+
+struct PgSharedHashMapInner {
+    htab: *mut pg_sys::HTAB,
+    locks: *mut pg_sys::LWLockPadded,
+}
+
+/// How an entry is laid out in the Postgres hash table, which expects the key first
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+impl<K, V> PgSharedHashMap<K, V>
+where
+    K: PGXSharedMemory + Copy + Eq,
+    V: PGXSharedMemory + Copy,
+{
+    /// Create an empty map which can hold up to `max_entries`, to be initialized by
+    /// `pg_shmem_init!()`
+    pub const fn new(max_entries: usize) -> Self {
+        Self::with_partitions(max_entries, DEFAULT_NUM_PARTITIONS)
+    }
+
+    /// Like [`PgSharedHashMap::new`], with its entries split between `num_partitions` LWLocks,
+    /// which must be a power of two
+    pub const fn with_partitions(max_entries: usize, num_partitions: usize) -> Self {
+        assert!(num_partitions.is_power_of_two(), "num_partitions must be a power of two");
+        PgSharedHashMap {
+            max_entries,
+            num_partitions,
+            name: OnceCell::new(),
+            inner: OnceCell::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the name of the shared memory hash table, and of its LWLock tranche
+    pub fn get_name(&self) -> &'static str {
+        self.name.get_or_init(|| Box::leak(Uuid::new_v4().to_string().into_boxed_str()))
+    }
+
+    /// The most entries the map can hold
+    pub fn capacity(&self) -> usize {
+        self.max_entries
+    }
+
+    /// Get a copy of the value stored for `key`
+    pub fn get(&self, key: &K) -> Option<V> {
+        let (hashcode, lock) = self.partition(key);
+        let _guard = PartitionGuard::acquire(lock, pg_sys::LWLockMode_LW_SHARED);
+        unsafe {
+            let entry = self.search(key, hashcode, pg_sys::HASHACTION_HASH_FIND);
+            entry.as_ref().map(|entry| entry.value)
+        }
+    }
+
+    /// Is there a value stored for `key`?
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Store `value` for `key`, returning the value it replaced, if any
+    ///
+    /// Fails when `key` isn't already in the map and the map is full.  The entry count is checked
+    /// with only `key`'s partition locked, so backends inserting into different partitions at the
+    /// same moment can take the map slightly past its capacity, space permitting.
+    pub fn insert(&self, key: K, value: V) -> Result<Option<V>, PgSharedHashMapError> {
+        let (hashcode, lock) = self.partition(&key);
+        let _guard = PartitionGuard::acquire(lock, pg_sys::LWLockMode_LW_EXCLUSIVE);
+        unsafe {
+            match self.find_or_enter(&key, hashcode, value)? {
+                (entry, true) => Ok(Some(std::mem::replace(&mut (*entry).value, value))),
+                (_, false) => Ok(None),
+            }
+        }
+    }
+
+    /// Change the value stored for `key` in place with `f`, storing `default` first if there
+    /// isn't one, and return what `f` returns
+    ///
+    /// `key`'s partition stays exclusively locked while `f` runs, so no other backend can change
+    /// the value between reading and writing it, as could happen with a [`get`](Self::get)
+    /// followed by an [`insert`](Self::insert).  `f` should be quick, and must not use this map.
+    ///
+    /// Fails like [`insert`](Self::insert) when `key` would have to be added to a full map.
+    pub fn update<R>(
+        &self,
+        key: K,
+        default: V,
+        f: impl FnOnce(&mut V) -> R,
+    ) -> Result<R, PgSharedHashMapError> {
+        let (hashcode, lock) = self.partition(&key);
+        let _guard = PartitionGuard::acquire(lock, pg_sys::LWLockMode_LW_EXCLUSIVE);
+        unsafe {
+            let (entry, _) = self.find_or_enter(&key, hashcode, default)?;
+            Ok(f(&mut (*entry).value))
+        }
+    }
+
+    /// Remove `key` from the map, returning the value that was stored for it, if any
+    pub fn remove(&self, key: &K) -> Option<V> {
+        let (hashcode, lock) = self.partition(key);
+        let _guard = PartitionGuard::acquire(lock, pg_sys::LWLockMode_LW_EXCLUSIVE);
+        unsafe {
+            let entry = self.search(key, hashcode, pg_sys::HASHACTION_HASH_REMOVE);
+            // dynahash leaves a removed entry on its free list untouched, so it's still readable
+            entry.as_ref().map(|entry| entry.value)
+        }
+    }
+
+    /// The number of entries in the map
+    pub fn len(&self) -> usize {
+        let _guards = self.lock_all();
+        unsafe { pg_sys::hash_get_num_entries(self.inner().htab) as usize }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over a snapshot of every entry in the map, in no particular order
+    ///
+    /// The entries are copied out with every partition locked in shared mode, and the locks are
+    /// released before this returns, so the map can be used while iterating.
+    pub fn iter(&self) -> std::vec::IntoIter<(K, V)> {
+        let _guards = self.lock_all();
+        let mut entries = Vec::new();
+        unsafe {
+            let mut status = pg_sys::HASH_SEQ_STATUS::default();
+            pg_sys::hash_seq_init(&mut status, self.inner().htab);
+
+            // dynahash ends the scan itself once it runs out of entries
+            while let Some(entry) =
+                (pg_sys::hash_seq_search(&mut status) as *mut Entry<K, V>).as_ref()
+            {
+                entries.push((entry.key, entry.value));
+            }
+        }
+        entries.into_iter()
+    }
+
+    fn inner(&self) -> &PgSharedHashMapInner {
+        self.inner.get().expect("PgSharedHashMap has not been initialized with pg_shmem_init!()")
+    }
+
+    /// The hash of `key`, and the LWLock of the partition it belongs to
+    fn partition(&self, key: &K) -> (u32, *mut pg_sys::LWLock) {
+        let inner = self.inner();
+        unsafe {
+            let hashcode =
+                pg_sys::get_hash_value(inner.htab, key as *const K as *const std::os::raw::c_void);
+            let partition = hashcode as usize % self.num_partitions;
+            (hashcode, &mut (*inner.locks.add(partition)).lock)
+        }
+    }
+
+    /// Lock every partition, in order, as Postgres does for its own partitioned hash tables
+    fn lock_all(&self) -> Vec<PartitionGuard> {
+        let inner = self.inner();
+        (0..self.num_partitions)
+            .map(|partition| unsafe {
+                PartitionGuard::acquire(
+                    &mut (*inner.locks.add(partition)).lock,
+                    pg_sys::LWLockMode_LW_SHARED,
+                )
+            })
+            .collect()
+    }
+
+    /// Find `key`'s entry, or add one holding `value`, and say whether it was already there
+    ///
+    /// `key`'s partition must be exclusively locked.
+    unsafe fn find_or_enter(
+        &self,
+        key: &K,
+        hashcode: u32,
+        value: V,
+    ) -> Result<(*mut Entry<K, V>, bool), PgSharedHashMapError> {
+        let entry = self.search(key, hashcode, pg_sys::HASHACTION_HASH_FIND);
+        if !entry.is_null() {
+            return Ok((entry, true));
+        }
+
+        // Postgres lets shared hash tables grow into any spare shared memory, so enforce the
+        // capacity ourselves
+        if pg_sys::hash_get_num_entries(self.inner().htab) as usize >= self.max_entries {
+            return Err(PgSharedHashMapError::HashMapFull);
+        }
+        let entry = self.search(key, hashcode, pg_sys::HASHACTION_HASH_ENTER_NULL);
+        if entry.is_null() {
+            return Err(PgSharedHashMapError::HashMapFull);
+        }
+        std::ptr::write(&mut (*entry).value, value);
+        Ok((entry, false))
+    }
+
+    unsafe fn search(
+        &self,
+        key: &K,
+        hashcode: u32,
+        action: pg_sys::HASHACTION,
+    ) -> *mut Entry<K, V> {
+        pg_sys::hash_search_with_hash_value(
+            self.inner().htab,
+            key as *const K as *const std::os::raw::c_void,
+            hashcode,
+            action,
+            std::ptr::null_mut(),
+        ) as *mut Entry<K, V>
+    }
+}
+
+impl<K, V> PgSharedMemoryInitialization for PgSharedHashMap<K, V>
+where
+    K: PGXSharedMemory + Copy + Eq + 'static,
+    V: PGXSharedMemory + Copy + 'static,
+{
+    fn pg_init(&'static self) {
+        unsafe {
+            let name = std::ffi::CString::new(self.get_name()).expect("CString::new failed");
+            pg_sys::RequestAddinShmemSpace(pg_sys::hash_estimate_size(
+                self.max_entries as _,
+                std::mem::size_of::<Entry<K, V>>(),
+            ));
+            pg_sys::RequestNamedLWLockTranche(name.as_ptr(), self.num_partitions as _);
+        }
+    }
+
+    fn shmem_init(&'static self) {
+        unsafe {
+            let name = std::ffi::CString::new(self.get_name()).expect("CString::new failed");
+            let addin_shmem_init_lock: *mut pg_sys::LWLock =
+                &mut (*pg_sys::MainLWLockArray.add(21)).lock;
+            pg_sys::LWLockAcquire(addin_shmem_init_lock, pg_sys::LWLockMode_LW_EXCLUSIVE);
+
+            let mut info = pg_sys::HASHCTL {
+                keysize: std::mem::size_of::<K>(),
+                entrysize: std::mem::size_of::<Entry<K, V>>(),
+                num_partitions: self.num_partitions as _,
+                ..Default::default()
+            };
+            let htab = pg_sys::ShmemInitHash(
+                name.as_ptr(),
+                self.max_entries as _,
+                self.max_entries as _,
+                &mut info,
+                (pg_sys::HASH_ELEM | pg_sys::HASH_BLOBS | pg_sys::HASH_PARTITION) as _,
+            );
+            let locks = pg_sys::GetNamedLWLockTranche(name.as_ptr());
+
+            self.inner
+                .set(PgSharedHashMapInner { htab, locks })
+                .ok()
+                .expect("PgSharedHashMap has already been initialized");
+            pg_sys::LWLockRelease(addin_shmem_init_lock);
+        }
+    }
+}
+
+/// Releases a partition's LWLock on drop
+struct PartitionGuard {
+    lock: *mut pg_sys::LWLock,
+}
+
+impl PartitionGuard {
+    fn acquire(lock: *mut pg_sys::LWLock, mode: pg_sys::LWLockMode) -> Self {
+        unsafe {
+            pg_sys::LWLockAcquire(lock, mode);
+        }
+        PartitionGuard { lock }
+    }
+}
+
+impl Drop for PartitionGuard {
+    fn drop(&mut self) {
+        unsafe {
+            pg_sys::LWLockRelease(self.lock);
+        }
+    }
+}
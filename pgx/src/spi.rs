@@ -9,14 +9,23 @@ Use of this source code is governed by the MIT license that can be found in the
 
 //! Safe access to Postgres' *Server Programming Interface* (SPI).
 
-use crate::{pg_sys, FromDatum, IntoDatum, Json, PgMemoryContexts, PgOid};
+use crate::pg_sys::errcodes::PgSqlErrorCode;
+use crate::pg_sys::panic::{CaughtError, ErrorReportWithLevel};
+use bitflags::bitflags;
+
+use crate::{
+    pg_sys, FromDatum, IntoDatum, Json, PgMemoryContexts, PgOid, PgTryBuilder, TryFromDatumError,
+};
 use std::collections::HashMap;
 use std::ffi::CStr;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::mem;
-use std::ops::{Index, IndexMut};
+use std::num::NonZeroUsize;
+use std::ops::{Deref, Index, IndexMut};
+use std::panic::AssertUnwindSafe;
 use std::ptr::NonNull;
+use std::rc::Rc;
 
 /// These match the Postgres `#define`d constants prefixed `SPI_OK_*` that you can find in `pg_sys`.
 #[derive(Debug, PartialEq)]
@@ -47,9 +56,9 @@ pub enum SpiOk {
 /// These match the Postgres `#define`d constants prefixed `SPI_ERROR_*` that you can find in `pg_sys`.
 /// It is hypothetically possible for a Postgres-defined status code to be `0`, AKA `NULL`, however,
 /// this should not usually occur in Rust code paths. If it does happen, please report such bugs to the pgx repo.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(i32)]
-pub enum SpiError {
+pub enum SpiErrorCodes {
     Connect = -1,
     Copy = -2,
     OpUnknown = -3,
@@ -71,15 +80,15 @@ pub struct UnknownVariant;
 
 impl TryFrom<libc::c_int> for SpiOk {
     // Yes, this gives us nested results.
-    type Error = Result<SpiError, UnknownVariant>;
+    type Error = Result<SpiErrorCodes, UnknownVariant>;
 
-    fn try_from(code: libc::c_int) -> Result<SpiOk, Result<SpiError, UnknownVariant>> {
+    fn try_from(code: libc::c_int) -> Result<SpiOk, Result<SpiErrorCodes, UnknownVariant>> {
         // Cast to assure that we're obeying repr rules even on platforms where c_ints are not 4 bytes wide,
         // as we don't support any but we may wish to in the future.
         match code as i32 {
             err @ -13..=-1 => Err(Ok(
-                // SAFETY: These values are described in SpiError, thus they are inbounds for transmute
-                unsafe { mem::transmute::<i32, SpiError>(err) },
+                // SAFETY: These values are described in SpiErrorCodes, thus they are inbounds for transmute
+                unsafe { mem::transmute::<i32, SpiErrorCodes>(err) },
             )),
             ok @ 1..=18 => Ok(
                 //SAFETY: These values are described in SpiOk, thus they are inbounds for transmute
@@ -90,11 +99,11 @@ impl TryFrom<libc::c_int> for SpiOk {
     }
 }
 
-impl TryFrom<libc::c_int> for SpiError {
+impl TryFrom<libc::c_int> for SpiErrorCodes {
     // Yes, this gives us nested results.
     type Error = Result<SpiOk, UnknownVariant>;
 
-    fn try_from(code: libc::c_int) -> Result<SpiError, Result<SpiOk, UnknownVariant>> {
+    fn try_from(code: libc::c_int) -> Result<SpiErrorCodes, Result<SpiOk, UnknownVariant>> {
         match SpiOk::try_from(code) {
             Ok(ok) => Err(Ok(ok)),
             Err(Ok(err)) => Ok(err),
@@ -103,20 +112,92 @@ impl TryFrom<libc::c_int> for SpiError {
     }
 }
 
+/// An error from the `try_*` family of SPI functions, such as [`SpiClient::try_update`]
+///
+/// Postgres `ERROR`s raised while running a statement are caught, along with everything needed to
+/// decide how to recover from them.  For example, to ignore a duplicate row:
+///
+/// ```rust,no_run
+/// use pgx::prelude::*;
+/// use pgx::SpiError;
+///
+/// let result = Spi::try_connect(|client| {
+///     client.try_update("INSERT INTO dogs (name) VALUES ('Nami')", None, None)?;
+///     Ok(())
+/// });
+///
+/// match result {
+///     Ok(()) => {}
+///     Err(SpiError::PostgresError { sqlstate: PgSqlErrorCode::ERRCODE_UNIQUE_VIOLATION, .. }) => {
+///         // Nami was already there and the current transaction can carry on
+///     }
+///     Err(e) => panic!("{}", e),
+/// }
+/// ```
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum SpiError {
+    /// An SPI function returned one of the `SPI_ERROR_*` status codes
+    #[error("SPI error: {0:?}")]
+    Status(SpiErrorCodes),
+
+    /// Postgres raised an `ERROR` while running the statement.  The subtransaction the statement
+    /// ran in has been rolled back, so the current transaction remains usable
+    #[error("{message}")]
+    PostgresError {
+        sqlstate: PgSqlErrorCode,
+        message: String,
+        detail: Option<String>,
+        hint: Option<String>,
+    },
+
+    /// No cursor with the given name is open in the current transaction
+    #[error("cursor named \"{0}\" not found")]
+    CursorNotFound(String),
+}
+
+impl From<SpiErrorCodes> for SpiError {
+    fn from(code: SpiErrorCodes) -> Self {
+        SpiError::Status(code)
+    }
+}
+
+impl From<ErrorReportWithLevel> for SpiError {
+    fn from(ereport: ErrorReportWithLevel) -> Self {
+        SpiError::PostgresError {
+            sqlstate: ereport.sql_error_code(),
+            message: ereport.message().to_string(),
+            detail: ereport.detail().map(|detail| detail.to_string()),
+            hint: ereport.hint().map(|hint| hint.to_string()),
+        }
+    }
+}
+
 pub struct Spi;
 
 // TODO: should `'conn` be invariant?
-pub struct SpiClient<'conn>(PhantomData<&'conn SpiConnection>);
+pub struct SpiClient<'conn> {
+    read_only: bool,
+    _phantom: PhantomData<&'conn SpiConnection>,
+}
 
 /// a struct to manage our SPI connection lifetime
 struct SpiConnection(PhantomData<*mut ()>);
 
 impl SpiConnection {
     /// Connect to Postgres' SPI system
-    fn connect() -> Self {
+    fn connect() -> Result<Self, SpiError> {
         // connect to SPI
-        Spi::check_status(unsafe { pg_sys::SPI_connect() });
-        SpiConnection(PhantomData)
+        Spi::try_check_status(unsafe { pg_sys::SPI_connect() })?;
+        Ok(SpiConnection(PhantomData))
+    }
+
+    /// Connect to Postgres' SPI system in non-atomic mode, which allows the transaction to be
+    /// committed or rolled back while connected
+    fn connect_nonatomic() -> Result<Self, SpiError> {
+        Spi::try_check_status(unsafe {
+            pg_sys::SPI_connect_ext(pg_sys::SPI_OPT_NONATOMIC as std::os::raw::c_int)
+        })?;
+        Ok(SpiConnection(PhantomData))
     }
 }
 
@@ -130,8 +211,41 @@ impl Drop for SpiConnection {
 
 impl SpiConnection {
     /// Return a client that with a lifetime scoped to this connection.
-    fn client(&self) -> SpiClient<'_> {
-        SpiClient(PhantomData)
+    fn client(&self, read_only: bool) -> SpiClient<'_> {
+        SpiClient { read_only, _phantom: PhantomData }
+    }
+}
+
+/// Whether new [`SpiClient`]s are read-only by default.  This is set from the volatility of the
+/// `#[pg_extern]` function currently being called, by its [`ReadOnlyScope`].
+static mut READ_ONLY: bool = false;
+
+/// Sets whether new [`SpiClient`]s are read-only for as long as it lives
+///
+/// The wrapper of every `#[pg_extern]` function holds one of these while the function runs, so
+/// that `stable` and `immutable` functions get read-only SPI connections.
+#[doc(hidden)]
+pub struct ReadOnlyScope {
+    previous: bool,
+}
+
+impl ReadOnlyScope {
+    pub fn enter(read_only: bool) -> Self {
+        // SAFETY: Postgres backends are single-threaded
+        unsafe {
+            let previous = READ_ONLY;
+            READ_ONLY = read_only;
+            ReadOnlyScope { previous }
+        }
+    }
+}
+
+impl Drop for ReadOnlyScope {
+    fn drop(&mut self) {
+        // SAFETY: Postgres backends are single-threaded
+        unsafe {
+            READ_ONLY = self.previous;
+        }
     }
 }
 
@@ -191,17 +305,17 @@ impl Spi {
 
     pub fn get_one_with_args<A: FromDatum + IntoDatum>(
         query: &str,
-        args: Vec<(PgOid, Option<pg_sys::Datum>)>,
+        args: impl SpiArgs,
     ) -> Option<A> {
-        Spi::connect(|client| Ok(client.select(query, Some(1), Some(args)).first().get_one()))
+        Spi::connect(|client| Ok(client.select(query, Some(1), args).first().get_one()))
     }
 
     pub fn get_two_with_args<A: FromDatum + IntoDatum, B: FromDatum + IntoDatum>(
         query: &str,
-        args: Vec<(PgOid, Option<pg_sys::Datum>)>,
+        args: impl SpiArgs,
     ) -> (Option<A>, Option<B>) {
         Spi::connect(|client| {
-            let (a, b) = client.select(query, Some(1), Some(args)).first().get_two::<A, B>();
+            let (a, b) = client.select(query, Some(1), args).first().get_two::<A, B>();
             Ok(Some((a, b)))
         })
         .unwrap()
@@ -213,11 +327,10 @@ impl Spi {
         C: FromDatum + IntoDatum,
     >(
         query: &str,
-        args: Vec<(PgOid, Option<pg_sys::Datum>)>,
+        args: impl SpiArgs,
     ) -> (Option<A>, Option<B>, Option<C>) {
         Spi::connect(|client| {
-            let (a, b, c) =
-                client.select(query, Some(1), Some(args)).first().get_three::<A, B, C>();
+            let (a, b, c) = client.select(query, Some(1), args).first().get_three::<A, B, C>();
             Ok(Some((a, b, c)))
         })
         .unwrap()
@@ -229,7 +342,7 @@ impl Spi {
     ///
     /// The statement runs in read/write mode
     pub fn run(query: &str) {
-        Spi::run_with_args(query, None)
+        Spi::run_with_args(query, ())
     }
 
     /// run an arbitrary SQL statement with args.
@@ -237,22 +350,20 @@ impl Spi {
     /// ## Safety
     ///
     /// The statement runs in read/write mode
-    pub fn run_with_args(query: &str, args: Option<Vec<(PgOid, Option<pg_sys::Datum>)>>) {
-        Spi::execute(|client| {
+    pub fn run_with_args(query: &str, args: impl SpiArgs) {
+        Spi::connect(|client| {
             client.update(query, None, args);
-        })
+            Ok(Some(()))
+        });
     }
 
     /// explain a query, returning its result in json form
     pub fn explain(query: &str) -> Json {
-        Spi::explain_with_args(query, None)
+        Spi::explain_with_args(query, ())
     }
 
     /// explain a query with args, returning its result in json form
-    pub fn explain_with_args(
-        query: &str,
-        args: Option<Vec<(PgOid, Option<pg_sys::Datum>)>>,
-    ) -> Json {
+    pub fn explain_with_args(query: &str, args: impl SpiArgs) -> Json {
         Spi::connect(|client| {
             let table =
                 client.update(&format!("EXPLAIN (format json) {}", query), None, args).first();
@@ -272,6 +383,9 @@ impl Spi {
     /// execute SPI commands via the provided `SpiClient` and return a value from SPI which is
     /// automatically copied into the `CurrentMemoryContext` at the time of this function call
     ///
+    /// When called from a `stable` or `immutable` `#[pg_extern]` function the `SpiClient` is
+    /// read-only, as if it came from [`Spi::connect_read_only`].
+    ///
     /// Note that `SpiClient` is scoped to the connection lifetime and the following code will
     /// not compile:
     ///
@@ -283,31 +397,258 @@ impl Spi {
         f: F,
     ) -> Option<R> {
         // connect to SPI
-        let connection = SpiConnection::connect();
+        let connection = SpiConnection::connect().unwrap_or_else(|e| panic!("{}", e));
 
         // run the provided closure within the memory context that SPI_connect()
         // just put us un.  We'll disconnect from SPI when the closure is finished.
         // If there's a panic or elog(ERROR), we don't care about also disconnecting from
         // SPI b/c Postgres will do that for us automatically
-        f(connection.client()).unwrap()
+        f(connection.client(unsafe { READ_ONLY })).unwrap()
+    }
+
+    /// execute SPI commands via the provided read-only `SpiClient` and return a value from SPI
+    /// which is automatically copied into the `CurrentMemoryContext` at the time of this function
+    /// call
+    ///
+    /// Every statement is executed with `read_only = true`, exactly like a Postgres `STABLE` or
+    /// `IMMUTABLE` function.  Statements don't increment the command counter and see the snapshot
+    /// of the calling query, and statements that would modify the database raise an `ERROR`.
+    pub fn connect_read_only<
+        R,
+        F: FnOnce(SpiClient<'_>) -> std::result::Result<Option<R>, SpiError>,
+    >(
+        f: F,
+    ) -> Option<R> {
+        let connection = SpiConnection::connect().unwrap_or_else(|e| panic!("{}", e));
+        f(connection.client(true)).unwrap()
+    }
+
+    /// execute SPI commands via the provided `SpiClient` from within a `#[pg_procedure]`, allowing
+    /// the transaction to be controlled with [`SpiClient::commit`] and [`SpiClient::rollback`]
+    ///
+    /// `fcinfo` is the procedure's own `pg_sys::FunctionCallInfo` argument.  Postgres decides
+    /// whether the procedure may control transactions: it can't when it's `CALL`ed inside an
+    /// explicit transaction block or from a function.  In that case the connection is atomic and
+    /// `commit()` or `rollback()` raise an "invalid transaction termination" `ERROR`.
+    ///
+    /// ## Safety
+    ///
+    /// `fcinfo` must be NULL or the `pg_sys::FunctionCallInfo` Postgres passed to the function
+    /// that's calling this, as it's dereferenced to find the procedure's `CallContext`.
+    ///
+    /// ```rust,no_run
+    /// use pgx::prelude::*;
+    ///
+    /// #[pg_procedure]
+    /// fn purge_log(fcinfo: pg_sys::FunctionCallInfo) {
+    ///     const PURGE: &str = "DELETE FROM log WHERE ctid IN (SELECT ctid FROM log LIMIT 1000)";
+    ///
+    ///     // SAFETY: `fcinfo` is the one Postgres gave us
+    ///     unsafe {
+    ///         Spi::connect_nonatomic(fcinfo, |client| {
+    ///             // commit every batch so a long purge doesn't hold its locks for the whole run
+    ///             while client.update(PURGE, None, ()).len() > 0 {
+    ///                 client.commit();
+    ///             }
+    ///             Ok(Some(()))
+    ///         });
+    ///     }
+    /// }
+    /// ```
+    pub unsafe fn connect_nonatomic<
+        R,
+        F: FnOnce(SpiClient<'_>) -> std::result::Result<Option<R>, SpiError>,
+    >(
+        fcinfo: pg_sys::FunctionCallInfo,
+        f: F,
+    ) -> Option<R> {
+        // SAFETY: the caller promises `fcinfo` is either NULL or came from Postgres, and
+        // `context` is a `Node`
+        let nonatomic = !fcinfo.is_null() && {
+            let context = (*fcinfo).context;
+            crate::is_a(context, pg_sys::NodeTag_T_CallContext)
+                && !(*(context as *mut pg_sys::CallContext)).atomic
+        };
+
+        let connection =
+            if nonatomic { SpiConnection::connect_nonatomic() } else { SpiConnection::connect() }
+                .unwrap_or_else(|e| panic!("{}", e));
+
+        f(connection.client(false)).unwrap()
     }
 
     pub fn check_status(status_code: i32) -> SpiOk {
+        Spi::try_check_status(status_code).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like [`Spi::check_status`], but returns `SPI_ERROR_*` codes as a [`SpiError`]
+    pub fn try_check_status(status_code: i32) -> Result<SpiOk, SpiError> {
         match SpiOk::try_from(status_code) {
-            Ok(ok) => ok,
+            Ok(ok) => Ok(ok),
             Err(Err(UnknownVariant)) => panic!("unrecognized SPI status code: {status_code}"),
-            Err(Ok(code)) => panic!("{code:?}"),
+            Err(Ok(code)) => Err(SpiError::Status(code)),
         }
     }
 }
 
+/// Fallible versions of the [`Spi`] functions
+///
+/// Each statement runs in its own subtransaction.  If Postgres raises an `ERROR`, that
+/// subtransaction is rolled back and the error is returned as a [`SpiError::PostgresError`],
+/// instead of aborting the current transaction.
+impl Spi {
+    pub fn try_get_one<A: FromDatum + IntoDatum>(query: &str) -> Result<Option<A>, SpiError> {
+        Spi::try_get_one_with_args(query, ())
+    }
+
+    pub fn try_get_two<A: FromDatum + IntoDatum, B: FromDatum + IntoDatum>(
+        query: &str,
+    ) -> Result<(Option<A>, Option<B>), SpiError> {
+        Spi::try_get_two_with_args(query, ())
+    }
+
+    pub fn try_get_three<
+        A: FromDatum + IntoDatum,
+        B: FromDatum + IntoDatum,
+        C: FromDatum + IntoDatum,
+    >(
+        query: &str,
+    ) -> Result<(Option<A>, Option<B>, Option<C>), SpiError> {
+        Spi::try_get_three_with_args(query, ())
+    }
+
+    pub fn try_get_one_with_args<A: FromDatum + IntoDatum>(
+        query: &str,
+        args: impl SpiArgs,
+    ) -> Result<Option<A>, SpiError> {
+        Spi::try_connect(|client| Ok(client.try_select(query, Some(1), args)?.first().get_one()))
+    }
+
+    pub fn try_get_two_with_args<A: FromDatum + IntoDatum, B: FromDatum + IntoDatum>(
+        query: &str,
+        args: impl SpiArgs,
+    ) -> Result<(Option<A>, Option<B>), SpiError> {
+        Spi::try_connect(|client| {
+            Ok(client.try_select(query, Some(1), args)?.first().get_two::<A, B>())
+        })
+    }
+
+    pub fn try_get_three_with_args<
+        A: FromDatum + IntoDatum,
+        B: FromDatum + IntoDatum,
+        C: FromDatum + IntoDatum,
+    >(
+        query: &str,
+        args: impl SpiArgs,
+    ) -> Result<(Option<A>, Option<B>, Option<C>), SpiError> {
+        Spi::try_connect(|client| {
+            Ok(client.try_select(query, Some(1), args)?.first().get_three::<A, B, C>())
+        })
+    }
+
+    /// just run an arbitrary SQL statement, returning any error it raises
+    ///
+    /// ## Safety
+    ///
+    /// The statement runs in read/write mode
+    pub fn try_run(query: &str) -> Result<(), SpiError> {
+        Spi::try_run_with_args(query, ())
+    }
+
+    /// run an arbitrary SQL statement with args, returning any error it raises
+    ///
+    /// ## Safety
+    ///
+    /// The statement runs in read/write mode
+    pub fn try_run_with_args(query: &str, args: impl SpiArgs) -> Result<(), SpiError> {
+        Spi::try_connect(|client| client.try_update(query, None, args).map(|_| ()))
+    }
+
+    /// explain a query, returning its result in json form or any error it raises
+    pub fn try_explain(query: &str) -> Result<Json, SpiError> {
+        Spi::try_explain_with_args(query, ())
+    }
+
+    /// explain a query with args, returning its result in json form or any error it raises
+    pub fn try_explain_with_args(query: &str, args: impl SpiArgs) -> Result<Json, SpiError> {
+        Spi::try_connect(|client| {
+            let table =
+                client.try_update(&format!("EXPLAIN (format json) {}", query), None, args)?.first();
+            Ok(table.get_one::<Json>().expect("failed to get json EXPLAIN result"))
+        })
+    }
+
+    /// execute SPI commands via the provided `SpiClient`, returning the closure's result
+    ///
+    /// Unlike [`Spi::connect`], an `Err` returned by the closure is passed back to the caller
+    /// rather than causing a panic.  Use the `try_*` functions of [`SpiClient`] inside the closure
+    /// to have Postgres `ERROR`s returned as a [`SpiError`] too.
+    pub fn try_connect<R, F: FnOnce(SpiClient<'_>) -> Result<R, SpiError>>(
+        f: F,
+    ) -> Result<R, SpiError> {
+        let connection = SpiConnection::connect()?;
+        f(connection.client(unsafe { READ_ONLY }))
+    }
+}
+
+/// Run `f` in an internal subtransaction, converting any Postgres `ERROR` it raises into a
+/// [`SpiError::PostgresError`]
+///
+/// The subtransaction is released if `f` succeeds and rolled back otherwise, which leaves the
+/// outer transaction usable either way.  This is what PL/pgSQL does for `BEGIN ... EXCEPTION`.
+pub(crate) fn in_subtransaction<R>(f: impl FnOnce() -> Result<R, SpiError>) -> Result<R, SpiError> {
+    // SAFETY: our callers only get here with a `SpiClient` or from a function Postgres called,
+    // so we're inside a transaction
+    let (memcxt, owner) = unsafe {
+        let memcxt = pg_sys::CurrentMemoryContext;
+        let owner = pg_sys::CurrentResourceOwner;
+        pg_sys::BeginInternalSubTransaction(std::ptr::null());
+
+        // keep allocating in the caller's context so results outlive the subtransaction
+        pg_sys::MemoryContextSwitchTo(memcxt);
+        (memcxt, owner)
+    };
+
+    PgTryBuilder::new(AssertUnwindSafe(|| {
+        let result = f();
+        unsafe {
+            if result.is_ok() {
+                pg_sys::ReleaseCurrentSubTransaction();
+            } else {
+                pg_sys::RollbackAndReleaseCurrentSubTransaction();
+            }
+            pg_sys::MemoryContextSwitchTo(memcxt);
+            pg_sys::CurrentResourceOwner = owner;
+        }
+        result
+    }))
+    .catch_others(|error| {
+        unsafe {
+            // we're done with the error (it's already been copied into `error`), and we must be
+            // out of the error subsystem before aborting the subtransaction
+            pg_sys::FlushErrorState();
+            pg_sys::RollbackAndReleaseCurrentSubTransaction();
+            pg_sys::MemoryContextSwitchTo(memcxt);
+            pg_sys::CurrentResourceOwner = owner;
+        }
+
+        match error {
+            CaughtError::PostgresError(ereport) => Err(SpiError::from(ereport)),
+
+            // anything raised from Rust is a bug in the caller, not a statement failure
+            other => other.rethrow(),
+        }
+    })
+    .execute()
+}
+
 impl<'a> SpiClient<'a> {
     /// perform a SELECT statement
-    pub fn select(
+    pub fn select<Q: Query, A: SpiArgs>(
         &self,
-        query: &str,
+        query: Q,
         limit: Option<i64>,
-        args: Option<Vec<(PgOid, Option<pg_sys::Datum>)>>,
+        args: A,
     ) -> SpiTupleTable {
         // Postgres docs say:
         //
@@ -315,78 +656,119 @@ impl<'a> SpiClient<'a> {
         //    using SPI; that could result in very confusing behavior, since the read-only queries
         //    would not see the results of any database updates done by the read-write queries.
         //
-        // As such, we don't actually set read-only to true here
+        // As such, we only set read-only to true when this client is read-only.  Use
+        // `select_readonly()` to ask for it explicitly
+        query.execute(self, limit, args).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// perform a SELECT statement in read-only mode, regardless of whether this client is
+    /// read-only
+    ///
+    /// The statement doesn't increment the command counter, so it sees the snapshot of the calling
+    /// query rather than the effects of earlier statements made through this client.
+    pub fn select_readonly<Q: Query, A: SpiArgs>(
+        &self,
+        query: Q,
+        limit: Option<i64>,
+        args: A,
+    ) -> SpiTupleTable {
+        query.execute(&self.as_read_only(), limit, args).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// perform a SELECT statement in read-only mode, returning any error it raises
+    ///
+    /// The statement runs in a subtransaction which is rolled back if Postgres raises an `ERROR`.
+    pub fn try_select_readonly<Q: Query, A: SpiArgs>(
+        &self,
+        query: Q,
+        limit: Option<i64>,
+        args: A,
+    ) -> Result<SpiTupleTable, SpiError> {
+        in_subtransaction(|| query.execute(&self.as_read_only(), limit, args))
+    }
+
+    /// Is this client read-only?
+    ///
+    /// Read-only clients come from [`Spi::connect_read_only`], or from [`Spi::connect`] when it's
+    /// called by a `stable` or `immutable` function.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    fn as_read_only(&self) -> SpiClient<'a> {
+        SpiClient { read_only: true, _phantom: PhantomData }
+    }
 
-        // TODO:  can we detect if the command counter (or something?) has incremented and if yes
-        //        then we set read_only=false, else we can set it to true?
-        //        Is this even a good idea?
-        self.execute(query, false, limit, args)
+    /// perform a SELECT statement, returning any error it raises
+    ///
+    /// The statement runs in a subtransaction which is rolled back if Postgres raises an `ERROR`.
+    pub fn try_select<Q: Query, A: SpiArgs>(
+        &self,
+        query: Q,
+        limit: Option<i64>,
+        args: A,
+    ) -> Result<SpiTupleTable, SpiError> {
+        in_subtransaction(|| query.execute(self, limit, args))
     }
 
     /// perform any query (including utility statements) that modify the database in some way
-    pub fn update(
+    ///
+    /// If this client [is read-only](SpiClient::is_read_only), Postgres will refuse to run
+    /// statements that modify the database.
+    pub fn update<Q: Query, A: SpiArgs>(
         &self,
-        query: &str,
+        query: Q,
         limit: Option<i64>,
-        args: Option<Vec<(PgOid, Option<pg_sys::Datum>)>>,
+        args: A,
     ) -> SpiTupleTable {
-        self.execute(query, false, limit, args)
+        query.execute(self, limit, args).unwrap_or_else(|e| panic!("{}", e))
     }
 
-    fn execute(
+    /// perform any query (including utility statements) that modify the database in some way,
+    /// returning any error it raises
+    ///
+    /// The statement runs in a subtransaction which is rolled back if Postgres raises an `ERROR`.
+    pub fn try_update<Q: Query, A: SpiArgs>(
         &self,
-        query: &str,
-        read_only: bool,
+        query: Q,
         limit: Option<i64>,
-        args: Option<Vec<(PgOid, Option<pg_sys::Datum>)>>,
-    ) -> SpiTupleTable {
+        args: A,
+    ) -> Result<SpiTupleTable, SpiError> {
+        in_subtransaction(|| query.execute(self, limit, args))
+    }
+
+    /// commit the current transaction and immediately start a new one
+    ///
+    /// This is only allowed on a client from [`Spi::connect_nonatomic`] whose procedure was
+    /// `CALL`ed at the top level.  Otherwise Postgres raises an "invalid transaction termination"
+    /// `ERROR`.
+    ///
+    /// Cursors that were opened without [`CursorOptions::HOLD`] are closed by the commit, and
+    /// any [`SpiTupleTable`]s from before the commit must not be used after it.
+    pub fn commit(&self) {
         unsafe {
-            pg_sys::SPI_tuptable = std::ptr::null_mut();
+            pg_sys::SPI_commit();
+
+            // only needed on Postgres 11-14, and a no-op on later versions
+            pg_sys::SPI_start_transaction();
         }
+    }
 
-        let src = std::ffi::CString::new(query).expect("query contained a null byte");
-        let status_code = match args {
-            Some(args) => {
-                let nargs = args.len();
-                let mut argtypes = vec![];
-                let mut datums = vec![];
-                let mut nulls = vec![];
-
-                for (argtype, datum) in args {
-                    argtypes.push(argtype.value());
-
-                    match datum {
-                        Some(datum) => {
-                            // ' ' here means that the datum is not null
-                            datums.push(datum);
-                            nulls.push(' ' as std::os::raw::c_char);
-                        }
-
-                        None => {
-                            // 'n' here means that the datum is null
-                            datums.push(pg_sys::Datum::from(0usize));
-                            nulls.push('n' as std::os::raw::c_char);
-                        }
-                    }
-                }
+    /// roll back the current transaction and immediately start a new one
+    ///
+    /// The same restrictions as [`SpiClient::commit`] apply.
+    pub fn rollback(&self) {
+        unsafe {
+            pg_sys::SPI_rollback();
 
-                unsafe {
-                    pg_sys::SPI_execute_with_args(
-                        src.as_ptr(),
-                        nargs as i32,
-                        argtypes.as_mut_ptr(),
-                        datums.as_mut_ptr(),
-                        nulls.as_ptr(),
-                        read_only,
-                        limit.unwrap_or(0),
-                    )
-                }
-            }
-            None => unsafe { pg_sys::SPI_execute(src.as_ptr(), read_only, limit.unwrap_or(0)) },
-        };
+            // only needed on Postgres 11-14, and a no-op on later versions
+            pg_sys::SPI_start_transaction();
+        }
+    }
 
-        SpiTupleTable {
-            status_code: Spi::check_status(status_code),
+    fn prepare_tuple_table(status_code: i32) -> Result<SpiTupleTable, SpiError> {
+        Ok(SpiTupleTable {
+            status_code: Spi::try_check_status(status_code)?,
             table: unsafe { pg_sys::SPI_tuptable },
             size: unsafe { pg_sys::SPI_processed as usize },
             tupdesc: if unsafe { pg_sys::SPI_tuptable }.is_null() {
@@ -395,7 +777,7 @@ impl<'a> SpiClient<'a> {
                 Some(unsafe { (*pg_sys::SPI_tuptable).tupdesc })
             },
             current: -1,
-        }
+        })
     }
 
     /// Set up a cursor that will execute the specified query
@@ -403,36 +785,393 @@ impl<'a> SpiClient<'a> {
     /// Rows may be then fetched using [`SpiCursor::fetch`].
     ///
     /// See [`SpiCursor`] docs for usage details.
-    pub fn open_cursor(
+    pub fn open_cursor<Q: Query, A: SpiArgs>(&self, query: Q, args: A) -> SpiCursor {
+        query.open_cursor(self, args, CursorOptions::empty())
+    }
+
+    /// Set up a cursor that will execute the specified query, with the given [`CursorOptions`]
+    ///
+    /// Use [`CursorOptions::SCROLL`] to be able to fetch backwards and reposition the cursor
+    /// with [`SpiCursor::fetch_direction`] and the `move_*` functions of [`SpiCursor`].
+    ///
+    /// The options of a [`PreparedStatement`] are fixed when it is prepared, with
+    /// [`SpiClient::prepare_cursor`], so `options` must be empty when `query` is one.
+    pub fn open_cursor_with_options<Q: Query, A: SpiArgs>(
         &self,
-        query: &str,
-        args: Option<Vec<(PgOid, Option<pg_sys::Datum>)>>,
+        query: Q,
+        args: A,
+        options: CursorOptions,
     ) -> SpiCursor {
+        query.open_cursor(self, args, options)
+    }
+
+    /// Set up a cursor that will execute the specified query, returning any error it raises
+    ///
+    /// See [`SpiClient::open_cursor`].
+    pub fn try_open_cursor<Q: Query, A: SpiArgs>(
+        &self,
+        query: Q,
+        args: A,
+    ) -> Result<SpiCursor, SpiError> {
+        self.try_open_cursor_with_options(query, args, CursorOptions::empty())
+    }
+
+    /// Set up a cursor that will execute the specified query, with the given [`CursorOptions`],
+    /// returning any error it raises
+    ///
+    /// See [`SpiClient::open_cursor_with_options`].
+    pub fn try_open_cursor_with_options<Q: Query, A: SpiArgs>(
+        &self,
+        query: Q,
+        args: A,
+        options: CursorOptions,
+    ) -> Result<SpiCursor, SpiError> {
+        in_subtransaction(|| Ok(query.open_cursor(self, args, options)))
+    }
+
+    /// Find a cursor in transaction by name
+    ///
+    /// A cursor for a query can be opened using [`SpiClient::open_cursor`].
+    /// Cursor are automatically closed on drop unless [`SpiCursor::detach_into_name`] is used.
+    /// Returned name can be used with this method to retrieve the open cursor.
+    ///
+    /// See [`SpiCursor`] docs for usage details.
+    pub fn find_cursor(&self, name: &str) -> SpiCursor {
+        self.try_find_cursor(name).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Find a cursor in transaction by name, returning [`SpiError::CursorNotFound`] if there
+    /// is no such cursor
+    ///
+    /// See [`SpiClient::find_cursor`].
+    pub fn try_find_cursor(&self, name: &str) -> Result<SpiCursor, SpiError> {
+        use pgx_pg_sys::AsPgCStr;
+
+        let ptr = NonNull::new(unsafe { pg_sys::SPI_cursor_find(name.as_pg_cstr()) })
+            .ok_or_else(|| SpiError::CursorNotFound(name.to_string()))?;
+        Ok(SpiCursor { ptr, _phantom: PhantomData })
+    }
+
+    /// Prepares a statement that is valid for the lifetime of the client
+    ///
+    /// The returned [`PreparedStatement`] can be executed any number of times via
+    /// [`SpiClient::select`], [`SpiClient::update`] and [`SpiClient::open_cursor`] without
+    /// Postgres having to parse and plan `query` again.  Use [`PreparedStatement::keep`] to
+    /// make it outlive the current SPI session.
+    pub fn prepare(&self, query: &str, args: &[PgOid]) -> PreparedStatement<'a> {
+        self.prepare_cursor(query, args, CursorOptions::empty())
+    }
+
+    /// Prepares a statement, like [`SpiClient::prepare`], whose cursors are opened with the given
+    /// [`CursorOptions`]
+    pub fn prepare_cursor(
+        &self,
+        query: &str,
+        args: &[PgOid],
+        options: CursorOptions,
+    ) -> PreparedStatement<'a> {
+        Self::prepare_plan(query, args, options).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Prepares a statement that is valid for the lifetime of the client, returning any error
+    /// raised while parsing or planning it
+    ///
+    /// See [`SpiClient::prepare`].
+    pub fn try_prepare(
+        &self,
+        query: &str,
+        args: &[PgOid],
+    ) -> Result<PreparedStatement<'a>, SpiError> {
+        self.try_prepare_cursor(query, args, CursorOptions::empty())
+    }
+
+    /// Prepares a statement whose cursors are opened with the given [`CursorOptions`], returning
+    /// any error raised while parsing or planning it
+    ///
+    /// See [`SpiClient::prepare_cursor`].
+    pub fn try_prepare_cursor(
+        &self,
+        query: &str,
+        args: &[PgOid],
+        options: CursorOptions,
+    ) -> Result<PreparedStatement<'a>, SpiError> {
+        in_subtransaction(|| Self::prepare_plan(query, args, options))
+    }
+
+    fn prepare_plan(
+        query: &str,
+        args: &[PgOid],
+        options: CursorOptions,
+    ) -> Result<PreparedStatement<'a>, SpiError> {
         let src = std::ffi::CString::new(query).expect("query contained a null byte");
-        let args = args.unwrap_or(vec![]);
+        let mut argtypes = args.iter().map(|oid| oid.value()).collect::<Vec<_>>();
 
-        let nargs = args.len();
-        let mut argtypes = vec![];
-        let mut datums = vec![];
-        let mut nulls = vec![];
-
-        for (argtype, datum) in args {
-            argtypes.push(argtype.value());
-
-            match datum {
-                Some(datum) => {
-                    // ' ' here means that the datum is not null
-                    datums.push(datum);
-                    nulls.push(' ' as std::os::raw::c_char);
-                }
+        // SAFETY: `src` and `argtypes` are valid for the duration of this call, and Postgres
+        // copies what it needs into the plan
+        let plan = unsafe {
+            pg_sys::SPI_prepare_cursor(
+                src.as_ptr(),
+                argtypes.len() as i32,
+                argtypes.as_mut_ptr(),
+                options.bits(),
+            )
+        };
+        match NonNull::new(plan) {
+            Some(plan) => Ok(PreparedStatement { plan, _phantom: PhantomData }),
+            None => {
+                // a NULL plan means that SPI_result holds the reason why
+                Spi::try_check_status(unsafe { pg_sys::SPI_result })?;
+                panic!("SPI_prepare returned a NULL plan")
+            }
+        }
+    }
+
+    /// Prepares a statement once per backend and caches it for the rest of the session
+    ///
+    /// The first call for a given `query` and `args` combination prepares and keeps the plan,
+    /// and subsequent calls, from any SPI session, return the same [`OwnedPreparedStatement`].
+    /// Postgres automatically re-plans cached statements when the objects they reference change.
+    ///
+    /// Cached statements can be evicted with [`Spi::forget_prepared`] or [`Spi::clear_prepared`].
+    pub fn prepare_cached(&self, query: &str, args: &[PgOid]) -> Rc<OwnedPreparedStatement> {
+        let key = (query.to_string(), args.iter().map(|oid| oid.value()).collect::<Vec<_>>());
+
+        // SAFETY: Postgres backends are single-threaded, so nothing else can be touching the cache
+        let cache = unsafe { PREPARED_STATEMENTS.get_or_insert_with(HashMap::new) };
+        cache.entry(key).or_insert_with(|| Rc::new(self.prepare(query, args).keep())).clone()
+    }
+}
+
+/// The backend-local cache of statements prepared through [`SpiClient::prepare_cached`]
+static mut PREPARED_STATEMENTS: Option<
+    HashMap<(String, Vec<pg_sys::Oid>), Rc<OwnedPreparedStatement>>,
+> = None;
 
-                None => {
-                    // 'n' here means that the datum is null
-                    datums.push(pg_sys::Datum::from(0usize));
-                    nulls.push('n' as std::os::raw::c_char);
+impl Spi {
+    /// Evict the statement cached for `query` and `args` by [`SpiClient::prepare_cached`]
+    ///
+    /// The underlying plan is freed once no [`OwnedPreparedStatement`] references to it remain.
+    pub fn forget_prepared(query: &str, args: &[PgOid]) {
+        let key = (query.to_string(), args.iter().map(|oid| oid.value()).collect::<Vec<_>>());
+
+        // SAFETY: Postgres backends are single-threaded, so nothing else can be touching the cache
+        if let Some(cache) = unsafe { PREPARED_STATEMENTS.as_mut() } {
+            cache.remove(&key);
+        }
+    }
+
+    /// Evict every statement cached by [`SpiClient::prepare_cached`]
+    pub fn clear_prepared() {
+        // SAFETY: Postgres backends are single-threaded, so nothing else can be touching the cache
+        unsafe {
+            PREPARED_STATEMENTS.take();
+        }
+    }
+}
+
+/// A generalized interface to what constitutes a query
+///
+/// Its primary purpose is to abstract away differences between one-off statements
+/// and prepared statements, but it can potentially be implemented for other types,
+/// provided they can be converted into a query.
+pub trait Query {
+    /// Execute a query given a client and other arguments
+    ///
+    /// `SPI_ERROR_*` status codes are returned as a [`SpiError`], while Postgres `ERROR`s are
+    /// raised as usual
+    fn execute<A: SpiArgs>(
+        self,
+        client: &SpiClient,
+        limit: Option<i64>,
+        args: A,
+    ) -> Result<SpiTupleTable, SpiError>;
+
+    /// Open a cursor for the query
+    fn open_cursor<'c: 'cc, 'cc, A: SpiArgs>(
+        self,
+        client: &'cc SpiClient<'c>,
+        args: A,
+        options: CursorOptions,
+    ) -> SpiCursor<'c>;
+}
+
+bitflags! {
+    /// Options for cursors opened with [`SpiClient::open_cursor_with_options`]
+    ///
+    /// These match the Postgres `CURSOR_OPT_*` constants, and what they mean is described by
+    /// [`DECLARE`](https://www.postgresql.org/docs/current/sql-declare.html).
+    #[derive(Default)]
+    pub struct CursorOptions: i32 {
+        /// The cursor can fetch backwards and be repositioned (`SCROLL`)
+        const SCROLL    = pg_sys::CURSOR_OPT_SCROLL as i32;
+        /// The cursor can only fetch forwards (`NO SCROLL`)
+        const NO_SCROLL = pg_sys::CURSOR_OPT_NO_SCROLL as i32;
+        /// The cursor can still be used after the transaction that created it commits (`WITH HOLD`)
+        const HOLD      = pg_sys::CURSOR_OPT_HOLD as i32;
+    }
+}
+
+/// A set of arguments that can be bound to a query's `$1`, `$2`, ... parameters
+///
+/// This is implemented for tuples and slices of [`IntoDatum`] values, whose Postgres types are
+/// taken from [`IntoDatum::type_oid()`], so arguments can be passed as naturally as:
+///
+/// ```rust,no_run
+/// use pgx::prelude::*;
+///
+/// Spi::execute(|client| {
+///     client.select("SELECT $1 + 1, $2, $3", None, (42_i32, "name", None::<pgx::Uuid>));
+/// });
+/// ```
+///
+/// `()` and `None` mean "no arguments", and explicit `(PgOid, Option<pg_sys::Datum>)` pairs
+/// (optionally wrapped in `Some`) are still accepted for full control over argument types.
+pub trait SpiArgs {
+    /// Convert these arguments into `(type, value)` pairs, where a `None` value is SQL `NULL`
+    fn into_args(self) -> Vec<(PgOid, Option<pg_sys::Datum>)>;
+}
+
+impl SpiArgs for () {
+    fn into_args(self) -> Vec<(PgOid, Option<pg_sys::Datum>)> {
+        vec![]
+    }
+}
+
+impl SpiArgs for Vec<(PgOid, Option<pg_sys::Datum>)> {
+    fn into_args(self) -> Vec<(PgOid, Option<pg_sys::Datum>)> {
+        self
+    }
+}
+
+impl SpiArgs for Option<Vec<(PgOid, Option<pg_sys::Datum>)>> {
+    fn into_args(self) -> Vec<(PgOid, Option<pg_sys::Datum>)> {
+        self.unwrap_or_default()
+    }
+}
+
+impl<'a, T: IntoDatum + Clone> SpiArgs for &'a [T] {
+    fn into_args(self) -> Vec<(PgOid, Option<pg_sys::Datum>)> {
+        self.iter().map(|arg| (PgOid::from(T::type_oid()), arg.clone().into_datum())).collect()
+    }
+}
+
+seq_macro::seq!(I in 0..=15 {
+    #(
+        seq_macro::seq!(N in 0..=I {
+            impl<#(Arg~N,)*> SpiArgs for (#(Arg~N,)*)
+            where
+                #(
+                    Arg~N: IntoDatum,
+                )*
+            {
+                fn into_args(self) -> Vec<(PgOid, Option<pg_sys::Datum>)> {
+                    vec![
+                        #(
+                            (PgOid::from(Arg~N::type_oid()), self.N.into_datum()),
+                        )*
+                    ]
                 }
             }
+        });
+    )*
+});
+
+impl<'a> Query for &'a String {
+    fn execute<A: SpiArgs>(
+        self,
+        client: &SpiClient,
+        limit: Option<i64>,
+        args: A,
+    ) -> Result<SpiTupleTable, SpiError> {
+        self.as_str().execute(client, limit, args)
+    }
+
+    fn open_cursor<'c: 'cc, 'cc, A: SpiArgs>(
+        self,
+        client: &'cc SpiClient<'c>,
+        args: A,
+        options: CursorOptions,
+    ) -> SpiCursor<'c> {
+        self.as_str().open_cursor(client, args, options)
+    }
+}
+
+/// Split `(type, value)` argument pairs into the parallel `argtypes`, `Values` and `Nulls`
+/// arrays that Postgres' SPI functions expect
+fn prepare_args(
+    args: Vec<(PgOid, Option<pg_sys::Datum>)>,
+) -> (Vec<pg_sys::Oid>, Vec<pg_sys::Datum>, Vec<std::os::raw::c_char>) {
+    let mut argtypes = vec![];
+    let mut datums = vec![];
+    let mut nulls = vec![];
+
+    for (argtype, datum) in args {
+        argtypes.push(argtype.value());
+
+        match datum {
+            Some(datum) => {
+                // ' ' here means that the datum is not null
+                datums.push(datum);
+                nulls.push(' ' as std::os::raw::c_char);
+            }
+
+            None => {
+                // 'n' here means that the datum is null
+                datums.push(pg_sys::Datum::from(0usize));
+                nulls.push('n' as std::os::raw::c_char);
+            }
         }
+    }
+
+    (argtypes, datums, nulls)
+}
+
+impl<'a> Query for &'a str {
+    fn execute<A: SpiArgs>(
+        self,
+        client: &SpiClient,
+        limit: Option<i64>,
+        args: A,
+    ) -> Result<SpiTupleTable, SpiError> {
+        unsafe {
+            pg_sys::SPI_tuptable = std::ptr::null_mut();
+        }
+
+        let src = std::ffi::CString::new(self).expect("query contained a null byte");
+        let args = args.into_args();
+        let status_code = if args.is_empty() {
+            unsafe { pg_sys::SPI_execute(src.as_ptr(), client.read_only, limit.unwrap_or(0)) }
+        } else {
+            let nargs = args.len();
+            let (mut argtypes, mut datums, nulls) = prepare_args(args);
+
+            unsafe {
+                pg_sys::SPI_execute_with_args(
+                    src.as_ptr(),
+                    nargs as i32,
+                    argtypes.as_mut_ptr(),
+                    datums.as_mut_ptr(),
+                    nulls.as_ptr(),
+                    client.read_only,
+                    limit.unwrap_or(0),
+                )
+            }
+        };
+
+        SpiClient::prepare_tuple_table(status_code)
+    }
+
+    fn open_cursor<'c: 'cc, 'cc, A: SpiArgs>(
+        self,
+        client: &'cc SpiClient<'c>,
+        args: A,
+        options: CursorOptions,
+    ) -> SpiCursor<'c> {
+        let src = std::ffi::CString::new(self).expect("query contained a null byte");
+        let args = args.into_args();
+
+        let nargs = args.len();
+        let (mut argtypes, mut datums, nulls) = prepare_args(args);
 
         let ptr = NonNull::new(unsafe {
             pg_sys::SPI_cursor_open_with_args(
@@ -442,30 +1181,202 @@ impl<'a> SpiClient<'a> {
                 argtypes.as_mut_ptr(),
                 datums.as_mut_ptr(),
                 nulls.as_ptr(),
-                false,
-                0,
+                client.read_only,
+                options.bits(),
             )
         })
         .expect("Portal ptr was null");
         SpiCursor { ptr, _phantom: PhantomData }
     }
+}
 
-    /// Find a cursor in transaction by name
-    ///
-    /// A cursor for a query can be opened using [`SpiClient::open_cursor`].
-    /// Cursor are automatically closed on drop unless [`SpiCursor::detach_into_name`] is used.
-    /// Returned name can be used with this method to retrieve the open cursor.
-    ///
-    /// See [`SpiCursor`] docs for usage details.
-    pub fn find_cursor(&self, name: &str) -> SpiCursor {
-        use pgx_pg_sys::AsPgCStr;
+/// A statement that has been parsed and planned once, and can be executed many times
+///
+/// Created by [`SpiClient::prepare`], a `PreparedStatement` is only valid for the lifetime of
+/// the SPI session it was prepared in.  Use [`PreparedStatement::keep`] to turn it into an
+/// [`OwnedPreparedStatement`] that survives across sessions.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pgx::prelude::*;
+///
+/// Spi::execute(|client| {
+///     let stmt = client.prepare("SELECT $1::int + 1", &[PgBuiltInOids::INT4OID.oid()]);
+///     for i in 0..10_i32 {
+///         let result = client.select(&stmt, Some(1), (i,));
+///         assert_eq!(Some(i + 1), result.first().get_one::<i32>());
+///     }
+/// });
+/// ```
+pub struct PreparedStatement<'a> {
+    plan: NonNull<pg_sys::_SPI_plan>,
+    _phantom: PhantomData<&'a ()>,
+}
 
-        let ptr = NonNull::new(unsafe { pg_sys::SPI_cursor_find(name.as_pg_cstr()) })
-            .unwrap_or_else(|| panic!("cursor named \"{}\" not found", name));
+/// A [`PreparedStatement`] whose plan has been saved with `SPI_keepplan()`
+///
+/// It is no longer bound to the SPI session it was prepared in and can be executed
+/// by any later [`SpiClient`] in this backend.  The plan is freed when this is dropped.
+pub struct OwnedPreparedStatement(PreparedStatement<'static>);
+
+impl Deref for OwnedPreparedStatement {
+    type Target = PreparedStatement<'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Drop for OwnedPreparedStatement {
+    fn drop(&mut self) {
+        // SAFETY: the plan was kept with SPI_keepplan() and we're its only owner
+        unsafe {
+            pg_sys::SPI_freeplan(self.0.plan.as_ptr());
+        }
+    }
+}
+
+impl<'a> PreparedStatement<'a> {
+    /// Saves the plan with `SPI_keepplan()` so that it outlives the current SPI session
+    pub fn keep(self) -> OwnedPreparedStatement {
+        // SAFETY: self.plan is a valid, un-kept plan returned by SPI_prepare()
+        Spi::check_status(unsafe { pg_sys::SPI_keepplan(self.plan.as_ptr()) });
+        OwnedPreparedStatement(PreparedStatement { plan: self.plan, _phantom: PhantomData })
+    }
+
+    /// The number of arguments this statement expects
+    pub fn nargs(&self) -> usize {
+        // SAFETY: self.plan is a valid plan
+        unsafe { pg_sys::SPI_getargcount(self.plan.as_ptr()) as usize }
+    }
+
+    /// Returns the type of the argument at the 0-based `index`, if there is one
+    pub fn arg_type_oid(&self, index: usize) -> Option<PgOid> {
+        if index >= self.nargs() {
+            None
+        } else {
+            // SAFETY: self.plan is a valid plan and index is in bounds
+            Some(PgOid::from(unsafe { pg_sys::SPI_getargtypeid(self.plan.as_ptr(), index as i32) }))
+        }
+    }
+
+    fn args_to_datums<A: SpiArgs>(
+        &self,
+        args: A,
+    ) -> (Vec<pg_sys::Datum>, Vec<std::os::raw::c_char>) {
+        let args = args.into_args();
+        let nargs = self.nargs();
+        if args.len() != nargs {
+            panic!("prepared statement expects {} arguments, got {}", nargs, args.len());
+        }
+
+        // the plan reads each value as the type it was prepared with, so a by-value integer given
+        // for a `text` argument would be taken for a pointer
+        for (i, (oid, _)) in args.iter().enumerate() {
+            // SAFETY: self.plan is a valid plan and i is in bounds
+            let expected = unsafe { pg_sys::SPI_getargtypeid(self.plan.as_ptr(), i as i32) };
+            if oid.value() != expected {
+                panic!(
+                    "prepared statement argument {} is of type {}, got {}",
+                    i + 1,
+                    type_name(expected),
+                    type_name(oid.value())
+                );
+            }
+        }
+
+        args.into_iter()
+            .map(|(_, datum)| match datum {
+                // ' ' here means that the datum is not null
+                Some(datum) => (datum, ' ' as std::os::raw::c_char),
+                // 'n' here means that the datum is null
+                None => (pg_sys::Datum::from(0usize), 'n' as std::os::raw::c_char),
+            })
+            .unzip()
+    }
+}
+
+/// The name of the type `oid` for messages, such as `integer` or `text`
+fn type_name(oid: pg_sys::Oid) -> String {
+    // SAFETY: format_type_be() copes with any oid, returning a palloc'd, null-terminated name
+    unsafe { std::ffi::CStr::from_ptr(pg_sys::format_type_be(oid)) }.to_string_lossy().into_owned()
+}
+
+impl<'a: 'b, 'b> Query for &'b PreparedStatement<'a> {
+    fn execute<A: SpiArgs>(
+        self,
+        client: &SpiClient,
+        limit: Option<i64>,
+        args: A,
+    ) -> Result<SpiTupleTable, SpiError> {
+        unsafe {
+            pg_sys::SPI_tuptable = std::ptr::null_mut();
+        }
+
+        let (mut datums, nulls) = self.args_to_datums(args);
+
+        // SAFETY: self.plan is a valid plan and `datums`/`nulls` hold exactly as many
+        // elements as the plan has arguments
+        let status_code = unsafe {
+            pg_sys::SPI_execute_plan(
+                self.plan.as_ptr(),
+                datums.as_mut_ptr(),
+                nulls.as_ptr(),
+                client.read_only,
+                limit.unwrap_or(0),
+            )
+        };
+
+        SpiClient::prepare_tuple_table(status_code)
+    }
+
+    fn open_cursor<'c: 'cc, 'cc, A: SpiArgs>(
+        self,
+        client: &'cc SpiClient<'c>,
+        args: A,
+        options: CursorOptions,
+    ) -> SpiCursor<'c> {
+        assert!(
+            options.is_empty(),
+            "the cursor options of a prepared statement are set by SpiClient::prepare_cursor"
+        );
+        let (mut datums, nulls) = self.args_to_datums(args);
+
+        let ptr = NonNull::new(unsafe {
+            pg_sys::SPI_cursor_open(
+                std::ptr::null_mut(), // let postgres assign a name
+                self.plan.as_ptr(),
+                datums.as_mut_ptr(),
+                nulls.as_ptr(),
+                client.read_only,
+            )
+        })
+        .expect("Portal ptr was null");
         SpiCursor { ptr, _phantom: PhantomData }
     }
 }
 
+impl<'a> Query for &'a OwnedPreparedStatement {
+    fn execute<A: SpiArgs>(
+        self,
+        client: &SpiClient,
+        limit: Option<i64>,
+        args: A,
+    ) -> Result<SpiTupleTable, SpiError> {
+        (&self.0).execute(client, limit, args)
+    }
+
+    fn open_cursor<'c: 'cc, 'cc, A: SpiArgs>(
+        self,
+        client: &'cc SpiClient<'c>,
+        args: A,
+        options: CursorOptions,
+    ) -> SpiCursor<'c> {
+        (&self.0).open_cursor(client, args, options)
+    }
+}
+
 type CursorName = String;
 
 /// An SPI Cursor from a query
@@ -529,11 +1440,19 @@ impl SpiCursor<'_> {
     ///
     /// If `fetch` runs off the end of the available rows, an empty [`SpiTupleTable`] is returned.
     pub fn fetch(&mut self, count: i64) -> SpiTupleTable {
+        self.fetch_direction(FetchDirection::Forward, count)
+    }
+
+    /// Fetch rows from the cursor in the given [`FetchDirection`]
+    ///
+    /// Fetching anything but [`FetchDirection::Forward`] requires a cursor opened with
+    /// [`CursorOptions::SCROLL`], or one that Postgres can scroll anyways.
+    pub fn fetch_direction(&mut self, direction: FetchDirection, count: i64) -> SpiTupleTable {
         unsafe {
             pg_sys::SPI_tuptable = std::ptr::null_mut();
         }
         // SAFETY: SPI functions to create/find cursors fail via elog, so self.ptr is valid if we successfully set it
-        unsafe { pg_sys::SPI_cursor_fetch(self.ptr.as_mut(), true, count) }
+        unsafe { pg_sys::SPI_scroll_cursor_fetch(self.ptr.as_mut(), direction as _, count as _) }
         SpiTupleTable {
             status_code: SpiOk::Fetch,
             table: unsafe { pg_sys::SPI_tuptable },
@@ -547,6 +1466,68 @@ impl SpiCursor<'_> {
         }
     }
 
+    /// Fetch up to `count` rows from the cursor, moving forward, returning any error raised
+    ///
+    /// The fetch runs in a subtransaction which is rolled back if Postgres raises an `ERROR`.
+    pub fn try_fetch(&mut self, count: i64) -> Result<SpiTupleTable, SpiError> {
+        in_subtransaction(|| Ok(self.fetch(count)))
+    }
+
+    /// Fetch rows from the cursor in the given [`FetchDirection`], returning any error raised
+    ///
+    /// See [`SpiCursor::fetch_direction`].
+    pub fn try_fetch_direction(
+        &mut self,
+        direction: FetchDirection,
+        count: i64,
+    ) -> Result<SpiTupleTable, SpiError> {
+        in_subtransaction(|| Ok(self.fetch_direction(direction, count)))
+    }
+
+    /// Reposition the cursor in the given [`FetchDirection`] without fetching any rows,
+    /// returning how many rows it moved over
+    ///
+    /// This is the equivalent of SQL's `MOVE` statement.
+    pub fn move_direction(&mut self, direction: FetchDirection, count: i64) -> usize {
+        // SAFETY: SPI functions to create/find cursors fail via elog, so self.ptr is valid if we successfully set it
+        unsafe {
+            pg_sys::SPI_scroll_cursor_move(self.ptr.as_mut(), direction as _, count as _);
+            pg_sys::SPI_processed as usize
+        }
+    }
+
+    /// Reposition the cursor without fetching any rows, returning any error raised
+    ///
+    /// See [`SpiCursor::move_direction`].
+    pub fn try_move_direction(
+        &mut self,
+        direction: FetchDirection,
+        count: i64,
+    ) -> Result<usize, SpiError> {
+        in_subtransaction(|| Ok(self.move_direction(direction, count)))
+    }
+
+    /// Move the cursor forward by `count` rows
+    pub fn move_forward(&mut self, count: i64) -> usize {
+        self.move_direction(FetchDirection::Forward, count)
+    }
+
+    /// Move the cursor backward by `count` rows
+    pub fn move_backward(&mut self, count: i64) -> usize {
+        self.move_direction(FetchDirection::Backward, count)
+    }
+
+    /// Move the cursor onto the 1-based row `position`.  A negative `position` counts from the
+    /// end, and `0` positions the cursor before the first row
+    pub fn move_absolute(&mut self, position: i64) -> usize {
+        self.move_direction(FetchDirection::Absolute, position)
+    }
+
+    /// Move the cursor by `offset` rows relative to the current row, which may be negative
+    pub fn move_relative(&mut self, offset: i64) -> usize {
+        self.move_direction(FetchDirection::Relative, offset)
+    }
+
     /// Consume the cursor, returning its name
     ///
     /// The actual Postgres cursor is kept alive for the duration of the transaction.
@@ -566,6 +1547,101 @@ impl SpiCursor<'_> {
     }
 }
 
+/// Lazily fetches the rows of a detached cursor in batches, converting each one with `map`
+///
+/// This is what [`SetOfIterator::from_cursor`](crate::iter::SetOfIterator::from_cursor) and
+/// [`TableIterator::from_cursor`](crate::iter::TableIterator::from_cursor) are built on.  It only
+/// holds the cursor's name, so it isn't tied to the [`SpiClient`] that opened the cursor, and it
+/// connects to SPI again to fetch each batch.  The cursor is closed once all of its rows have been
+/// fetched, or when this is dropped.
+pub(crate) struct SpiCursorRows<T, F> {
+    cursor: Option<CursorName>,
+    batch_size: i64,
+    batch: std::vec::IntoIter<T>,
+    map: F,
+}
+
+impl<T, F: FnMut(&SpiHeapTupleData) -> T> SpiCursorRows<T, F> {
+    pub(crate) fn new(cursor: SpiCursor<'_>, batch_size: i64, map: F) -> Self {
+        assert!(batch_size > 0, "cursor batch size must be positive");
+        SpiCursorRows {
+            cursor: Some(cursor.detach_into_name()),
+            batch_size,
+            batch: Vec::new().into_iter(),
+            map,
+        }
+    }
+}
+
+impl<T, F> SpiCursorRows<T, F> {
+    fn close(&mut self) {
+        use pgx_pg_sys::AsPgCStr;
+
+        if let Some(name) = self.cursor.take() {
+            unsafe {
+                // if the transaction is aborting, Postgres closes the cursor itself
+                if pg_sys::IsTransactionState() {
+                    let portal = pg_sys::SPI_cursor_find(name.as_pg_cstr());
+                    if !portal.is_null() {
+                        pg_sys::SPI_cursor_close(portal);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T, F: FnMut(&SpiHeapTupleData) -> T> Iterator for SpiCursorRows<T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.batch.next() {
+                return Some(value);
+            }
+
+            let name = self.cursor.as_deref()?;
+            let (batch_size, map) = (self.batch_size, &mut self.map);
+
+            // `map` has to turn each row into an owned value before we disconnect from SPI,
+            // which frees the batch's tuples
+            let batch = Spi::connect(|client| {
+                let mut cursor = client.find_cursor(name);
+                let batch = cursor.fetch(batch_size).map(|row| map(&row)).collect::<Vec<_>>();
+                cursor.detach_into_name();
+                Ok(Some(batch))
+            })
+            .unwrap_or_default();
+
+            if batch.is_empty() {
+                self.close();
+                return None;
+            }
+            self.batch = batch.into_iter();
+        }
+    }
+}
+
+impl<T, F> Drop for SpiCursorRows<T, F> {
+    fn drop(&mut self) {
+        self.close()
+    }
+}
+
+/// The direction in which [`SpiCursor::fetch_direction`] and [`SpiCursor::move_direction`] move
+/// a cursor, and how they interpret their `count` argument
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchDirection {
+    /// `count` rows forward from the current position
+    Forward = pg_sys::FetchDirection_FETCH_FORWARD as isize,
+    /// `count` rows backward from the current position
+    Backward = pg_sys::FetchDirection_FETCH_BACKWARD as isize,
+    /// the single row at the 1-based position `count`, counting from the end if it is negative
+    Absolute = pg_sys::FetchDirection_FETCH_ABSOLUTE as isize,
+    /// the single row `count` rows away from the current position
+    Relative = pg_sys::FetchDirection_FETCH_RELATIVE as isize,
+}
+
 impl Drop for SpiCursor<'_> {
     fn drop(&mut self) {
         // SAFETY: SPI functions to create/find cursors fail via elog, so self.ptr is valid if we successfully set it
@@ -713,6 +1789,112 @@ impl SpiTupleTable {
             None => None,
         }
     }
+
+    /// Iterate over every row of this table, converting each into a `T`
+    ///
+    /// Typically `T` is a struct that `#[derive(FromSpiRow)]`.
+    ///
+    /// ```rust,no_run
+    /// use pgx::prelude::*;
+    ///
+    /// #[derive(FromSpiRow)]
+    /// struct Dog {
+    ///     name: String,
+    ///     #[spi(name = "scritches")]
+    ///     pets: Option<i32>,
+    /// }
+    ///
+    /// Spi::execute(|client| {
+    ///     let table = client.select("SELECT 'Nami' AS name, 42 AS scritches", None, None);
+    ///     for dog in table.into_typed::<Dog>() {
+    ///         let dog = dog.expect("row could not be converted into a Dog");
+    ///         assert_eq!("Nami", dog.name);
+    ///     }
+    /// });
+    /// ```
+    pub fn into_typed<T: FromSpiRow>(mut self) -> impl Iterator<Item = Result<T, FromSpiRowError>> {
+        // always start from the first row, regardless of where we've been positioned
+        self.current = -1;
+        self.map(|row| T::from_spi_row(&row))
+    }
+}
+
+/// A type that can be built from a single row of an SPI result set
+///
+/// This is usually implemented via `#[derive(FromSpiRow)]`, which maps each struct field to the
+/// column of the same name.  Fields can be mapped to a differently-named column with
+/// `#[spi(name = "column")]`, or to a column position with `#[spi(ordinal = 1)]` (1-based).
+/// Fields of tuple structs are mapped by position.
+///
+/// A field whose type is an `Option<T>` receives `None` for a SQL `NULL`, otherwise a `NULL`
+/// value is reported as [`FromSpiRowError::UnexpectedNull`].
+pub trait FromSpiRow: Sized {
+    fn from_spi_row(row: &SpiHeapTupleData) -> Result<Self, FromSpiRowError>;
+}
+
+/// Describes why an SPI result row could not be converted into a [`FromSpiRow`] type
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum FromSpiRowError {
+    #[error("column `{0}` is not present in the result set")]
+    NoSuchColumn(String),
+
+    #[error("column `{column}` has type oid {type_oid}, which is not compatible with the Rust type `{rust_type}`")]
+    IncompatibleTypes { column: String, type_oid: pg_sys::Oid, rust_type: &'static str },
+
+    #[error("column `{0}` is NULL, but the Rust type it maps to is not an `Option`")]
+    UnexpectedNull(String),
+}
+
+/// Support functions for `#[derive(FromSpiRow)]`
+#[doc(hidden)]
+pub mod from_spi_row {
+    use super::{FromSpiRowError, SpiHeapTupleData};
+    use crate::{FromDatum, IntoDatum, TryFromDatumError};
+    use std::num::NonZeroUsize;
+
+    pub fn by_name<T: FromDatum + IntoDatum + 'static>(
+        row: &SpiHeapTupleData,
+        name: &str,
+    ) -> Result<Option<T>, FromSpiRowError> {
+        let type_oid = row.by_name(name).map(|entry| entry.oid()).unwrap_or_default();
+        row.get_by_name::<T>(name).map_err(|e| convert_error::<T>(e, name.to_string(), type_oid))
+    }
+
+    pub fn by_ordinal<T: FromDatum + IntoDatum + 'static>(
+        row: &SpiHeapTupleData,
+        ordinal: usize,
+    ) -> Result<Option<T>, FromSpiRowError> {
+        let column = format!("#{}", ordinal);
+        match NonZeroUsize::new(ordinal) {
+            Some(attno) => {
+                let type_oid = row.by_ordinal(ordinal).map(|entry| entry.oid()).unwrap_or_default();
+                row.get_by_index::<T>(attno).map_err(|e| convert_error::<T>(e, column, type_oid))
+            }
+            None => Err(FromSpiRowError::NoSuchColumn(column)),
+        }
+    }
+
+    pub fn not_null<T>(value: Option<T>, column: &str) -> Result<T, FromSpiRowError> {
+        value.ok_or_else(|| FromSpiRowError::UnexpectedNull(column.to_string()))
+    }
+
+    fn convert_error<T>(
+        error: TryFromDatumError,
+        column: String,
+        type_oid: crate::pg_sys::Oid,
+    ) -> FromSpiRowError {
+        match error {
+            TryFromDatumError::NoSuchAttributeName(_)
+            | TryFromDatumError::NoSuchAttributeNumber(_) => FromSpiRowError::NoSuchColumn(column),
+            TryFromDatumError::IncompatibleTypes | TryFromDatumError::NullDatumPointer => {
+                FromSpiRowError::IncompatibleTypes {
+                    column,
+                    type_oid,
+                    rust_type: std::any::type_name::<T>(),
+                }
+            }
+        }
+    }
 }
 
 impl SpiHeapTupleData {
@@ -733,6 +1915,48 @@ impl SpiHeapTupleData {
         data
     }
 
+    /// Retrieve the value of the specified column, by name.
+    ///
+    /// ## Errors
+    /// - return [`TryFromDatumError::NoSuchAttributeName`] if the column does not exist
+    /// - return [`TryFromDatumError::IncompatibleTypes`] if the Rust type of the `value` is not
+    /// compatible with the column's Postgres type
+    pub fn get_by_name<T: FromDatum + IntoDatum + 'static>(
+        &self,
+        name: &str,
+    ) -> std::result::Result<Option<T>, TryFromDatumError> {
+        use crate::pg_sys::AsPgCStr;
+        let fnumber = unsafe { pg_sys::SPI_fnumber(self.tupdesc, name.as_pg_cstr()) };
+        if fnumber < 1 {
+            // either SPI_ERROR_NOATTRIBUTE or a system column, which we don't have
+            Err(TryFromDatumError::NoSuchAttributeName(name.to_owned()))
+        } else {
+            self.get_by_index(NonZeroUsize::new(fnumber as usize).unwrap())
+        }
+    }
+
+    /// Retrieve the value of the specified column, by its 1-based ordinal position.
+    ///
+    /// ## Errors
+    /// - return [`TryFromDatumError::NoSuchAttributeNumber`] if the column does not exist
+    /// - return [`TryFromDatumError::IncompatibleTypes`] if the Rust type of the `value` is not
+    /// compatible with the column's Postgres type
+    pub fn get_by_index<T: FromDatum + IntoDatum + 'static>(
+        &self,
+        ordinal: NonZeroUsize,
+    ) -> std::result::Result<Option<T>, TryFromDatumError> {
+        match self.entries.get(&ordinal.get()) {
+            None => Err(TryFromDatumError::NoSuchAttributeNumber(ordinal)),
+            Some(entry) if !T::is_compatible_with(entry.type_oid) => {
+                Err(TryFromDatumError::IncompatibleTypes)
+            }
+            Some(entry) => match entry.datum {
+                None => Ok(None),
+                Some(datum) => unsafe { T::try_from_datum(datum, false, entry.type_oid) },
+            },
+        }
+    }
+
     /// Get a typed Datum value from this HeapTuple by its ordinal position.  
     ///
     /// The ordinal position is 1-based
@@ -748,26 +1972,26 @@ impl SpiHeapTupleData {
     ///
     /// The ordinal position is 1-based.
     ///
-    /// If the specified ordinal is out of bounds a `Err(SpiError::NoAttribute)` is returned
+    /// If the specified ordinal is out of bounds a `Err(SpiError::Status(SpiErrorCodes::NoAttribute))` is returned
     pub fn by_ordinal(
         &self,
         ordinal: usize,
     ) -> std::result::Result<&SpiHeapTupleDataEntry, SpiError> {
         match self.entries.get(&ordinal) {
             Some(datum) => Ok(datum),
-            None => Err(SpiError::NoAttribute),
+            None => Err(SpiError::Status(SpiErrorCodes::NoAttribute)),
         }
     }
 
     /// Get a typed Datum value from this HeapTuple by its field name.  
     ///
-    /// If the specified name does not exist a `Err(SpiError::NoAttribute)` is returned
+    /// If the specified name does not exist a `Err(SpiError::Status(SpiErrorCodes::NoAttribute))` is returned
     pub fn by_name(&self, name: &str) -> std::result::Result<&SpiHeapTupleDataEntry, SpiError> {
         use crate::pg_sys::AsPgCStr;
         unsafe {
             let fnumber = pg_sys::SPI_fnumber(self.tupdesc, name.as_pg_cstr());
             if fnumber == pg_sys::SPI_ERROR_NOATTRIBUTE {
-                Err(SpiError::NoAttribute)
+                Err(SpiError::Status(SpiErrorCodes::NoAttribute))
             } else {
                 self.by_ordinal(fnumber as usize)
             }
@@ -778,20 +2002,20 @@ impl SpiHeapTupleData {
     ///
     /// The ordinal position is 1-based.
     ///
-    /// If the specified ordinal is out of bounds a `Err(SpiError::NoAttribute)` is returned
+    /// If the specified ordinal is out of bounds a `Err(SpiError::Status(SpiErrorCodes::NoAttribute))` is returned
     pub fn by_ordinal_mut(
         &mut self,
         ordinal: usize,
     ) -> std::result::Result<&mut SpiHeapTupleDataEntry, SpiError> {
         match self.entries.get_mut(&ordinal) {
             Some(datum) => Ok(datum),
-            None => Err(SpiError::NoAttribute),
+            None => Err(SpiError::Status(SpiErrorCodes::NoAttribute)),
         }
     }
 
     /// Get a mutable typed Datum value from this HeapTuple by its field name.  
     ///
-    /// If the specified name does not exist a `Err(SpiError::NoAttribute)` is returned
+    /// If the specified name does not exist a `Err(SpiError::Status(SpiErrorCodes::NoAttribute))` is returned
     pub fn by_name_mut(
         &mut self,
         name: &str,
@@ -800,7 +2024,7 @@ impl SpiHeapTupleData {
         unsafe {
             let fnumber = pg_sys::SPI_fnumber(self.tupdesc, name.as_pg_cstr());
             if fnumber == pg_sys::SPI_ERROR_NOATTRIBUTE {
-                Err(SpiError::NoAttribute)
+                Err(SpiError::Status(SpiErrorCodes::NoAttribute))
             } else {
                 self.by_ordinal_mut(fnumber as usize)
             }
@@ -809,7 +2033,7 @@ impl SpiHeapTupleData {
 
     /// Set a datum value for the specified ordinal position
     ///
-    /// If the specified ordinal is out of bounds a `Err(SpiError::NoAttribute)` is returned
+    /// If the specified ordinal is out of bounds a `Err(SpiError::Status(SpiErrorCodes::NoAttribute))` is returned
     pub fn set_by_ordinal<T: IntoDatum + FromDatum>(
         &mut self,
         ordinal: usize,
@@ -817,7 +2041,7 @@ impl SpiHeapTupleData {
     ) -> std::result::Result<(), SpiError> {
         unsafe {
             if ordinal < 1 || ordinal > self.tupdesc.as_ref().unwrap().natts as usize {
-                Err(SpiError::NoAttribute)
+                Err(SpiError::Status(SpiErrorCodes::NoAttribute))
             } else {
                 self.entries.insert(
                     ordinal,
@@ -830,7 +2054,7 @@ impl SpiHeapTupleData {
 
     /// Set a datum value for the specified field name
     ///
-    /// If the specified name does not exist a `Err(SpiError::NoAttribute)` is returned
+    /// If the specified name does not exist a `Err(SpiError::Status(SpiErrorCodes::NoAttribute))` is returned
     pub fn set_by_name<T: IntoDatum + FromDatum>(
         &mut self,
         name: &str,
@@ -840,7 +2064,7 @@ impl SpiHeapTupleData {
         unsafe {
             let fnumber = pg_sys::SPI_fnumber(self.tupdesc, name.as_pg_cstr());
             if fnumber == pg_sys::SPI_ERROR_NOATTRIBUTE {
-                Err(SpiError::NoAttribute)
+                Err(SpiError::Status(SpiErrorCodes::NoAttribute))
             } else {
                 self.set_by_ordinal(fnumber as usize, datum)
             }
@@ -0,0 +1,234 @@
+/*
+Portions Copyright 2019-2021 ZomboDB, LLC.
+Portions Copyright 2021-2022 Technology Concepts & Design, Inc. <support@tcdi.com>
+
+All rights reserved.
+
+Use of this source code is governed by the MIT license that can be found in the LICENSE file.
+*/
+
+//! Safe access to a Postgres [`pg_sys::Tuplestorestate`], which stores rows in memory and spills
+//! them to a temporary file once they grow beyond a size limit
+use crate::htup::heap_tuple_header_get_datum_length;
+use crate::{pg_sys, PgTupleDesc};
+use std::ptr::NonNull;
+
+/// A store of rows which all share a [`PgTupleDesc`]
+///
+/// Rows are kept in memory until they take up more than `max_kbytes` (`work_mem`, by default),
+/// after which they're written to a temporary file.  This is what Postgres uses for the results of
+/// materialize-mode set-returning functions, `WITH HOLD` cursors, and CTEs.
+///
+/// A `Tuplestore` allocates from the `CurrentMemoryContext` at the time it is created, which
+/// must outlive it.  The store and its temporary file, if any, are released when it is dropped.
+///
+/// ## Examples
+///
+/// ```rust,no_run
+/// use pgx::prelude::*;
+/// use pgx::{IntoDatum, PgTupleDesc, Tuplestore};
+///
+/// let tupdesc = unsafe {
+///     PgTupleDesc::from_pg_is_copy(pg_sys::TypeGetTupleDesc(pg_sys::INT4OID, std::ptr::null_mut()))
+/// };
+/// let mut store = Tuplestore::new(&tupdesc, false);
+/// store.put_values(&[1_i32.into_datum()]);
+/// store.put_values(&[None]);
+///
+/// assert_eq!(store.len(), 2);
+/// while let Some(values) = store.next_values() {
+///     // ...
+/// }
+/// ```
+pub struct Tuplestore {
+    state: NonNull<pg_sys::Tuplestorestate>,
+    tupdesc: PgTupleDesc<'static>,
+    context: pg_sys::MemoryContext,
+    slot: Option<NonNull<pg_sys::TupleTableSlot>>,
+}
+
+impl Tuplestore {
+    /// Create an empty `Tuplestore` for rows described by `tupdesc`, which spills to disk once it
+    /// holds more than `work_mem` kilobytes of rows
+    ///
+    /// `random_access` allows the rows to be read backwards as well as forwards, at the cost of
+    /// a slightly larger temporary file.
+    pub fn new(tupdesc: &PgTupleDesc, random_access: bool) -> Self {
+        Tuplestore::with_max_kbytes(tupdesc, random_access, unsafe { pg_sys::work_mem })
+    }
+
+    /// Create an empty `Tuplestore` for rows described by `tupdesc`, which spills to disk once it
+    /// holds more than `max_kbytes` kilobytes of rows
+    pub fn with_max_kbytes(tupdesc: &PgTupleDesc, random_access: bool, max_kbytes: i32) -> Self {
+        unsafe {
+            // SAFETY: a PgTupleDesc always wraps a valid TupleDesc, and we keep our own copy of
+            // it so it lives as long as the rows that use it
+            let tupdesc = PgTupleDesc::from_pg_copy(tupdesc.as_ptr());
+            let state = pg_sys::tuplestore_begin_heap(random_access, false, max_kbytes);
+            Tuplestore {
+                state: NonNull::new(state).expect("tuplestore_begin_heap returned NULL"),
+                tupdesc,
+                context: pg_sys::CurrentMemoryContext,
+                slot: None,
+            }
+        }
+    }
+
+    /// The [`PgTupleDesc`] that describes this store's rows
+    pub fn tupdesc(&self) -> &PgTupleDesc<'static> {
+        &self.tupdesc
+    }
+
+    /// How many rows have been stored?
+    pub fn len(&self) -> usize {
+        unsafe { pg_sys::tuplestore_tuple_count(self.state.as_ptr()) as usize }
+    }
+
+    /// Are there no rows?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Add a row, where a `None` value is SQL `NULL`
+    ///
+    /// The values are copied into the store, so they don't need to outlive this call.
+    ///
+    /// ## Panics
+    ///
+    /// If the number of values differs from the number of attributes in [`Tuplestore::tupdesc`].
+    pub fn put_values(&mut self, values: &[Option<pg_sys::Datum>]) {
+        assert_eq!(
+            values.len(),
+            self.tupdesc.len(),
+            "a row must have one value per attribute of the tuplestore's tupdesc"
+        );
+
+        let (datums, nulls): (Vec<_>, Vec<_>) = values
+            .iter()
+            .map(|value| match value {
+                Some(datum) => (*datum, false),
+                None => (pg_sys::Datum::from(0usize), true),
+            })
+            .unzip();
+
+        // SAFETY: we've made sure there are as many values as there are attributes
+        unsafe {
+            pg_sys::tuplestore_putvalues(
+                self.state.as_ptr(),
+                self.tupdesc.as_ptr(),
+                datums.as_ptr() as *mut pg_sys::Datum,
+                nulls.as_ptr() as *mut bool,
+            );
+        }
+    }
+
+    /// Add a row from a composite value of the tuplestore's row type, where `None` is a row of all
+    /// `NULL`s
+    ///
+    /// The value is copied into the store, so it doesn't need to outlive this call.
+    ///
+    /// ## Safety
+    ///
+    /// `value` must be a composite datum, such as a [`PgHeapTuple`](crate::PgHeapTuple)'s, whose
+    /// type matches [`Tuplestore::tupdesc`].
+    pub unsafe fn put_composite(&mut self, value: Option<pg_sys::Datum>) {
+        match value {
+            Some(datum) => {
+                let header =
+                    pg_sys::pg_detoast_datum(datum.cast_mut_ptr()) as pg_sys::HeapTupleHeader;
+                let mut tuple = pg_sys::HeapTupleData {
+                    t_len: heap_tuple_header_get_datum_length(header) as u32,
+                    t_data: header,
+                    ..Default::default()
+                };
+                pg_sys::tuplestore_puttuple(self.state.as_ptr(), &mut tuple);
+            }
+            None => self.put_values(&vec![None; self.tupdesc.len()]),
+        }
+    }
+
+    /// Read the next row, or `None` once every row has been read
+    ///
+    /// Pass-by-reference datums point into the store, and are only valid until the next call to
+    /// `next_values()` or [`Tuplestore::rescan`].
+    pub fn next_values(&mut self) -> Option<Vec<Option<pg_sys::Datum>>> {
+        let slot = self.slot();
+        let natts = self.tupdesc.len();
+
+        unsafe {
+            // SAFETY: the slot was made for our tupdesc
+            if !pg_sys::tuplestore_gettupleslot(self.state.as_ptr(), true, false, slot) {
+                return None;
+            }
+
+            #[cfg(feature = "pg11")]
+            pg_sys::slot_getallattrs(slot);
+
+            #[cfg(any(feature = "pg12", feature = "pg13", feature = "pg14", feature = "pg15"))]
+            if ((*slot).tts_nvalid as usize) < natts {
+                pg_sys::slot_getsomeattrs_int(slot, natts as i32);
+            }
+
+            let values = std::slice::from_raw_parts((*slot).tts_values, natts);
+            let isnull = std::slice::from_raw_parts((*slot).tts_isnull, natts);
+            Some(
+                values
+                    .iter()
+                    .zip(isnull)
+                    .map(|(datum, isnull)| if *isnull { None } else { Some(*datum) })
+                    .collect(),
+            )
+        }
+    }
+
+    /// Start reading from the first row again
+    pub fn rescan(&mut self) {
+        unsafe { pg_sys::tuplestore_rescan(self.state.as_ptr()) }
+    }
+
+    /// Give up ownership of the underlying `Tuplestorestate` and the copy of its `TupleDesc`, as
+    /// when handing them to Postgres as the result of a set-returning function
+    pub fn into_pg(mut self) -> (*mut pg_sys::Tuplestorestate, pg_sys::TupleDesc) {
+        self.drop_slot();
+
+        let state = self.state.as_ptr();
+        // SAFETY: we're taking `tupdesc` out of `self`, which is then forgotten without dropping
+        let tupdesc = unsafe { std::ptr::read(&self.tupdesc) };
+        std::mem::forget(self);
+        (state, tupdesc.into_pg())
+    }
+
+    /// The slot rows are read into, created the first time it's needed
+    fn slot(&mut self) -> *mut pg_sys::TupleTableSlot {
+        if self.slot.is_none() {
+            let context = self.context;
+            let tupdesc = self.tupdesc.as_ptr();
+            unsafe {
+                let previous = pg_sys::MemoryContextSwitchTo(context);
+
+                #[cfg(feature = "pg11")]
+                let slot = pg_sys::MakeSingleTupleTableSlot(tupdesc);
+
+                #[cfg(any(feature = "pg12", feature = "pg13", feature = "pg14", feature = "pg15"))]
+                let slot = pg_sys::MakeSingleTupleTableSlot(tupdesc, &pg_sys::TTSOpsMinimalTuple);
+
+                pg_sys::MemoryContextSwitchTo(previous);
+                self.slot = NonNull::new(slot);
+            }
+        }
+        self.slot.expect("MakeSingleTupleTableSlot returned NULL").as_ptr()
+    }
+
+    fn drop_slot(&mut self) {
+        if let Some(slot) = self.slot.take() {
+            unsafe { pg_sys::ExecDropSingleTupleTableSlot(slot.as_ptr()) }
+        }
+    }
+}
+
+impl Drop for Tuplestore {
+    fn drop(&mut self) {
+        self.drop_slot();
+        unsafe { pg_sys::tuplestore_end(self.state.as_ptr()) }
+    }
+}